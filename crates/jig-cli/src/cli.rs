@@ -43,16 +43,21 @@ crate::command_enum! {
     (Commit, commands::Commit),
     (Issues, commands::Issues),
     (Daemon, commands::Daemon),
+    (Events, commands::Events),
     (Exit, commands::Exit),
     (Config, commands::Config),
     (Repos, commands::Repos),
     (Spawn, commands::Spawn),
+    (Stats, commands::Stats),
     (Pr, commands::Pr),
     (Ps, commands::Ps),
     (Attach, commands::Attach),
+    (Peek, commands::Peek),
     (Resume, commands::Resume),
     (Review, commands::Review),
     (Merge, commands::Merge),
+    (Queue, commands::Queue),
+    (Conflicts, commands::Conflicts),
     (Notify, commands::Notify),
     (Kill, commands::Kill),
     (Nuke, commands::Nuke),
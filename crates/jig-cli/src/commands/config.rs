@@ -340,6 +340,22 @@ fn show_config(ctx: &RepoCtx) -> Result<ConfigOutput, ConfigError> {
                 );
             }
         }
+    } else if display.issues_provider == "github" {
+        let github = display.github.clone().unwrap_or_default();
+        eprintln!(
+            "  {} {} {}",
+            ui::dim("Repo:"),
+            ui::highlight(github.repo.as_deref().unwrap_or("(from git remote)")),
+            src(&display.issues_source)
+        );
+        if !github.labels.is_empty() {
+            eprintln!(
+                "  {} {} {}",
+                ui::dim("Labels:"),
+                ui::highlight(&github.labels.join(", ")),
+                src(&display.issues_source)
+            );
+        }
     } else {
         eprintln!(
             "  {} {} {}",
@@ -0,0 +1,156 @@
+//! Conflicts command — which in-flight workers are editing the same files
+
+use std::fmt;
+
+use clap::Args;
+use comfy_table::{Cell, Color};
+
+use jig_core::config::JigToml;
+use jig_core::overlap::{self, Overlap, WorkerFiles};
+use jig_core::RepoContext;
+
+use crate::op::{GlobalCtx, Op, RepoCtx};
+use crate::ui;
+
+/// Show file overlap between in-flight workers
+#[derive(Args, Debug, Clone)]
+pub struct Conflicts {}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConflictsError {
+    #[error(transparent)]
+    Core(#[from] jig_core::Error),
+}
+
+#[derive(Debug)]
+pub struct ConflictsOutput {
+    repos: Vec<RepoOverlaps>,
+    show_repo: bool,
+}
+
+#[derive(Debug)]
+struct RepoOverlaps {
+    name: String,
+    workers: Vec<WorkerFiles>,
+    overlaps: Vec<Overlap>,
+}
+
+impl Op for Conflicts {
+    type Error = ConflictsError;
+    type Output = ConflictsOutput;
+
+    fn run(&self, ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
+        Ok(ConflictsOutput {
+            repos: vec![repo_overlaps(ctx.repo()?)],
+            show_repo: false,
+        })
+    }
+
+    fn run_global(&self, ctx: &GlobalCtx) -> Result<Self::Output, Self::Error> {
+        Ok(ConflictsOutput {
+            repos: ctx.repos.iter().map(repo_overlaps).collect(),
+            show_repo: true,
+        })
+    }
+}
+
+fn repo_overlaps(repo: &RepoContext) -> RepoOverlaps {
+    let ignore = JigToml::load(&repo.repo_root)
+        .ok()
+        .flatten()
+        .map(|toml| toml.spawn)
+        .unwrap_or_default()
+        .resolve_overlap_ignore();
+    let workers = overlap::active_workers(repo, &ignore);
+    let overlaps = overlap::overlaps(&workers);
+    RepoOverlaps {
+        name: repo
+            .repo_root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+        workers,
+        overlaps,
+    }
+}
+
+impl fmt::Display for ConflictsOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repos: Vec<&RepoOverlaps> = self
+            .repos
+            .iter()
+            .filter(|r| !r.overlaps.is_empty())
+            .collect();
+        if repos.is_empty() {
+            return write!(f, "{}", ui::dim("No overlapping workers"));
+        }
+
+        if ui::is_plain() {
+            for repo in repos {
+                for o in &repo.overlaps {
+                    if self.show_repo {
+                        write!(f, "{}\t", repo.name)?;
+                    }
+                    writeln!(f, "{}\t{}\t{}", o.a, o.b, o.files.join(","))?;
+                }
+            }
+            return Ok(());
+        }
+
+        for (i, repo) in repos.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if self.show_repo {
+                writeln!(f, "{}", ui::bold(&repo.name))?;
+            }
+            write_matrix(f, repo)?;
+            writeln!(f)?;
+            for o in &repo.overlaps {
+                writeln!(
+                    f,
+                    "{} {} {}: {}",
+                    ui::highlight(&o.a),
+                    ui::dim("↔"),
+                    ui::highlight(&o.b),
+                    o.files.join(", ")
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shared file counts between the workers that overlap another. The
+/// diagonal is each worker's own file count.
+fn write_matrix(f: &mut fmt::Formatter<'_>, repo: &RepoOverlaps) -> fmt::Result {
+    let workers: Vec<&WorkerFiles> = repo
+        .workers
+        .iter()
+        .filter(|w| {
+            repo.overlaps
+                .iter()
+                .any(|o| o.a == w.worker || o.b == w.worker)
+        })
+        .collect();
+
+    let mut headers = vec![""];
+    headers.extend(workers.iter().map(|w| w.worker.as_str()));
+    let mut table = ui::new_table(&headers);
+    for row in &workers {
+        let mut cells = vec![Cell::new(&row.worker).fg(Color::Cyan)];
+        for col in &workers {
+            let cell = if row.worker == col.worker {
+                Cell::new(row.files.len()).fg(Color::DarkGrey)
+            } else {
+                match row.files.intersection(&col.files).count() {
+                    0 => Cell::new("-").fg(Color::DarkGrey),
+                    n => Cell::new(n).fg(Color::Yellow),
+                }
+            };
+            cells.push(cell);
+        }
+        table.add_row(cells);
+    }
+    writeln!(f, "{table}")
+}
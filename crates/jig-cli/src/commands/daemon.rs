@@ -1,16 +1,24 @@
-//! Daemon command — run the orchestrator loop
+//! Daemon command — run the orchestrator loop, manage it as a service, or control a running one
 
-use clap::Args;
+use std::fmt;
 
+use clap::{Args, Subcommand};
+
+use jig_core::daemon::control::{ControlClient, ControlRequest, DaemonStatus};
+use jig_core::daemon::service::{self, ServiceStatus};
 use jig_core::daemon::{self, DaemonConfig};
 use jig_core::global::GlobalConfig;
 
-use crate::op::{NoOutput, Op, RepoCtx};
+use crate::commands::merge::Strategy;
+use crate::op::{Op, RepoCtx};
 use crate::ui;
 
 /// Run the daemon loop to monitor workers and dispatch actions
 #[derive(Args, Debug, Clone)]
 pub struct Daemon {
+    #[command(subcommand)]
+    pub subcommand: Option<DaemonCommands>,
+
     /// Poll interval in seconds
     #[arg(long)]
     interval: Option<u64>,
@@ -20,17 +28,194 @@ pub struct Daemon {
     once: bool,
 }
 
+#[derive(Subcommand, Debug, Clone)]
+pub enum DaemonCommands {
+    /// Start the daemon in the background
+    Start {
+        /// Poll interval in seconds
+        #[arg(long)]
+        interval: Option<u64>,
+    },
+    /// Stop the background daemon
+    Stop,
+    /// Stop the daemon if running, then start it again
+    Restart {
+        /// Poll interval in seconds
+        #[arg(long)]
+        interval: Option<u64>,
+    },
+    /// Show whether the daemon is running
+    Status {
+        /// Print status as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print (or install) a systemd user unit for the daemon
+    Unit {
+        /// Write the unit to ~/.config/systemd/user/jig-daemon.service
+        #[arg(long)]
+        install: bool,
+    },
+    /// Control the running daemon over its socket
+    Ctl {
+        #[command(subcommand)]
+        action: CtlCommands,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CtlCommands {
+    /// Run a tick now
+    Tick,
+    /// Pause auto-spawn (workers are still monitored and nudged)
+    Pause,
+    /// Resume auto-spawn
+    Resume,
+    /// Nudge a worker now
+    Nudge {
+        /// Worker name, or repo/worker when the name is ambiguous
+        worker: String,
+        /// Send this text instead of the worker's nudge template
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// Kill a worker's tmux window and unregister it
+    Kill {
+        /// Worker name, or repo/worker when the name is ambiguous
+        worker: String,
+    },
+    /// Resume a worker whose tmux window is gone
+    ResumeWorker {
+        /// Worker name, or repo/worker when the name is ambiguous
+        worker: String,
+    },
+    /// Merge a worker as `jig merge` does
+    Merge {
+        /// Worker name, or repo/worker when the name is ambiguous
+        worker: String,
+        /// How to land the branch
+        #[arg(long, short, value_enum, default_value_t = Strategy::Merge)]
+        strategy: Strategy,
+        /// Also remove the worktree, archive the event log and complete the linked issue
+        #[arg(long)]
+        cleanup: bool,
+    },
+    /// Re-read the global config
+    Reload,
+    /// Show daemon status
+    Status {
+        /// Print status as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Stream tick results as they happen
+    Watch {
+        /// Print each tick result as a JSON line
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Debug)]
+pub enum DaemonOutput {
+    None,
+    Status(DaemonStatus),
+    StatusJson(String),
+    Service(ServiceStatus, Option<DaemonStatus>),
+    Unit(String),
+}
+
+impl fmt::Display for DaemonOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => Ok(()),
+            Self::StatusJson(text) | Self::Unit(text) => writeln!(f, "{}", text.trim_end()),
+            Self::Service(service, loop_status) => {
+                let now = chrono::Utc::now().timestamp();
+                let ago = |ts: i64| ui::format_duration_short((now - ts).max(0) as u64);
+                match service.pid {
+                    Some(pid) => {
+                        let uptime = service
+                            .started_at
+                            .map(|ts| format!(", up {}", ago(ts)))
+                            .unwrap_or_default();
+                        writeln!(f, "running (pid {}{})", pid, uptime)?;
+                    }
+                    None => writeln!(f, "not running")?,
+                }
+                if service.previous_run_crashed {
+                    writeln!(f, "previous run crashed (no clean shutdown logged)")?;
+                } else if let (Some(ts), Some(reason)) = (service.stopped_at, &service.stop_reason)
+                {
+                    writeln!(f, "last stop:  {} ago ({})", ago(ts), reason)?;
+                }
+                if let Some(status) = loop_status {
+                    writeln!(
+                        f,
+                        "auto-spawn: {}",
+                        if status.paused { "paused" } else { "active" }
+                    )?;
+                    writeln!(f, "ticks:      {}", status.ticks)?;
+                }
+                if let Some(ref path) = service.log_path {
+                    writeln!(f, "log:        {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Status(status) => {
+                let now = chrono::Utc::now().timestamp();
+                let ago = |ts: i64| ui::format_duration_short((now - ts).max(0) as u64);
+                writeln!(f, "pid:        {}", status.pid)?;
+                writeln!(f, "uptime:     {}", ago(status.started_at))?;
+                writeln!(f, "interval:   {}s", status.interval_seconds)?;
+                writeln!(
+                    f,
+                    "auto-spawn: {}",
+                    if status.paused { "paused" } else { "active" }
+                )?;
+                writeln!(f, "ticks:      {}", status.ticks)?;
+                match status.last_tick_at {
+                    Some(ts) => writeln!(
+                        f,
+                        "last tick:  {} ago ({} workers)",
+                        ago(ts),
+                        status.workers
+                    ),
+                    None => writeln!(f, "last tick:  never"),
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum DaemonError {
     #[error(transparent)]
     Core(#[from] jig_core::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
 }
 
 impl Op for Daemon {
     type Error = DaemonError;
-    type Output = NoOutput;
+    type Output = DaemonOutput;
 
     fn run(&self, _ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
+        match &self.subcommand {
+            Some(DaemonCommands::Ctl { action }) => return run_ctl(action),
+            Some(DaemonCommands::Start { interval }) => {
+                return run_start(interval.or(self.interval))
+            }
+            Some(DaemonCommands::Stop) => return run_stop(),
+            Some(DaemonCommands::Restart { interval }) => {
+                run_stop()?;
+                return run_start(interval.or(self.interval));
+            }
+            Some(DaemonCommands::Status { json }) => return run_status(*json),
+            Some(DaemonCommands::Unit { install }) => return run_unit(*install),
+            None => {}
+        }
+
         let global = GlobalConfig::load().unwrap_or_default();
         let interval = self.interval.unwrap_or(global.daemon.interval_seconds);
         let config = DaemonConfig {
@@ -49,6 +234,135 @@ impl Op for Daemon {
 
         daemon::run(&config)?;
 
-        Ok(NoOutput)
+        Ok(DaemonOutput::None)
+    }
+}
+
+fn run_start(interval: Option<u64>) -> Result<DaemonOutput, DaemonError> {
+    let args: Vec<String> = interval
+        .map(|i| vec!["--interval".to_string(), i.to_string()])
+        .unwrap_or_default();
+    let pid = service::start(&args)?;
+    ui::success(&format!("Daemon started (pid {})", pid));
+    if let Ok(path) = jig_core::global::daemon_output_path() {
+        ui::detail(&format!("log: {}", path.display()));
+    }
+    Ok(DaemonOutput::None)
+}
+
+fn run_stop() -> Result<DaemonOutput, DaemonError> {
+    match service::stop(service::STOP_TIMEOUT)? {
+        Some(pid) => ui::success(&format!("Daemon stopped (pid {})", pid)),
+        None => eprintln!("{}", ui::dim("Daemon is not running")),
+    }
+    Ok(DaemonOutput::None)
+}
+
+fn run_status(json: bool) -> Result<DaemonOutput, DaemonError> {
+    let service = ServiceStatus::global()?;
+    if json {
+        return Ok(DaemonOutput::StatusJson(serde_json::to_string_pretty(
+            &service,
+        )?));
+    }
+    // Loop details are only available from a daemon serving the socket.
+    let loop_status = service
+        .is_running()
+        .then(|| ControlClient::global().ok())
+        .flatten()
+        .and_then(|c| c.request(&ControlRequest::Status).ok())
+        .and_then(|r| r.status);
+    Ok(DaemonOutput::Service(service, loop_status))
+}
+
+fn run_unit(install: bool) -> Result<DaemonOutput, DaemonError> {
+    let exe = std::env::current_exe().map_err(jig_core::Error::from)?;
+    let unit = service::systemd_unit(&exe);
+    if !install {
+        return Ok(DaemonOutput::Unit(unit));
+    }
+
+    let path = service::systemd_unit_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(jig_core::Error::from)?;
+    }
+    std::fs::write(&path, unit).map_err(jig_core::Error::from)?;
+    ui::success(&format!("Wrote {}", path.display()));
+    ui::detail("systemctl --user daemon-reload && systemctl --user enable --now jig-daemon");
+    Ok(DaemonOutput::None)
+}
+
+fn run_ctl(action: &CtlCommands) -> Result<DaemonOutput, DaemonError> {
+    let client = ControlClient::global()?;
+
+    let request = match action {
+        CtlCommands::Tick => ControlRequest::Tick,
+        CtlCommands::Pause => ControlRequest::Pause,
+        CtlCommands::Resume => ControlRequest::Resume,
+        CtlCommands::Nudge { worker, message } => ControlRequest::Nudge {
+            worker: worker.clone(),
+            message: message.clone(),
+        },
+        CtlCommands::Kill { worker } => ControlRequest::Kill {
+            worker: worker.clone(),
+        },
+        CtlCommands::ResumeWorker { worker } => ControlRequest::ResumeWorker {
+            worker: worker.clone(),
+        },
+        CtlCommands::Merge {
+            worker,
+            strategy,
+            cleanup,
+        } => ControlRequest::Merge {
+            worker: worker.clone(),
+            strategy: (*strategy).into(),
+            cleanup: *cleanup,
+        },
+        CtlCommands::Reload => ControlRequest::Reload,
+        CtlCommands::Status { json } => {
+            let response = client.request(&ControlRequest::Status)?.into_result()?;
+            let status = response.status.unwrap_or_default();
+            return Ok(if *json {
+                DaemonOutput::StatusJson(serde_json::to_string_pretty(&status)?)
+            } else {
+                DaemonOutput::Status(status)
+            });
+        }
+        CtlCommands::Watch { json } => {
+            client.subscribe(|tick| {
+                if *json {
+                    println!("{}", tick);
+                } else {
+                    println!("{}", format_tick(&tick));
+                }
+                true
+            })?;
+            return Ok(DaemonOutput::None);
+        }
+    };
+
+    let response = client.request(&request)?.into_result()?;
+    if let Some(message) = response.message {
+        ui::success(&message);
+    }
+    Ok(DaemonOutput::None)
+}
+
+/// One-line summary of a streamed tick result.
+fn format_tick(tick: &serde_json::Value) -> String {
+    let count = |key: &str| tick[key].as_u64().unwrap_or(0);
+    let errors = tick["errors"].as_array().map(Vec::len).unwrap_or(0);
+    let mut line = format!(
+        "[{}] {} workers, {} actions, {} nudges, {} notifications, {} errors",
+        chrono::Local::now().format("%H:%M:%S"),
+        count("workers_checked"),
+        count("actions_dispatched"),
+        count("nudges_sent"),
+        count("notifications_sent"),
+        errors,
+    );
+    if tick["auto_spawn_paused"].as_bool().unwrap_or(false) {
+        line.push_str(" (auto-spawn paused)");
     }
+    line
 }
@@ -0,0 +1,484 @@
+//! Events command — query, tail and maintain worker event logs
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use clap::{Args, Subcommand};
+
+use jig_core::events::{
+    CompactStats, Event, EventLog, EventType, Telemetry, Validation, WorkerState,
+};
+use jig_core::worker::WorkerStatus;
+
+use crate::op::{Op, RepoCtx};
+use crate::ui;
+
+/// How often `--follow` checks logs for new events.
+const FOLLOW_POLL: Duration = Duration::from_millis(500);
+
+/// Longest field value shown before truncating.
+const MAX_FIELD_WIDTH: usize = 80;
+
+/// Show, tail and maintain worker event logs
+#[derive(Args, Debug, Clone)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Events {
+    #[command(subcommand)]
+    pub subcommand: Option<EventsCommands>,
+
+    /// Worker in the current repo
+    worker: Option<String>,
+
+    /// Keep printing events as they are appended
+    #[arg(short, long)]
+    follow: bool,
+
+    /// Only show these event types (comma-separated, e.g. commit,nudge)
+    #[arg(long = "type", value_delimiter = ',')]
+    types: Vec<EventType>,
+
+    /// Only show events from the last duration (e.g. 30m, 1h, 2d)
+    #[arg(long, value_parser = ui::parse_duration)]
+    since: Option<u64>,
+
+    /// Print events as JSON lines
+    #[arg(long)]
+    json: bool,
+
+    /// Merge every worker's log in timestamp order
+    #[arg(long, conflicts_with = "worker")]
+    all: bool,
+
+    /// Summarize tool calls, files, commands and token usage instead
+    #[arg(long, conflicts_with_all = ["follow", "types", "since"])]
+    summary: bool,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum EventsCommands {
+    /// Snapshot event logs and rotate them into archives
+    Compact {
+        /// Worker in the current repo (default: every worker's log)
+        worker: Option<String>,
+    },
+    /// Report event log lines that don't match the event schema
+    Validate {
+        /// Worker in the current repo (default: every worker's log)
+        worker: Option<String>,
+    },
+}
+
+#[derive(Debug)]
+pub enum EventsOutput {
+    Lines(Vec<String>),
+    Summary(Vec<(String, Telemetry)>),
+    Compacted(Vec<(String, CompactStats)>),
+    Validated(Vec<(String, Validation)>),
+}
+
+impl fmt::Display for EventsOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lines(lines) => {
+                for line in lines {
+                    writeln!(f, "{}", line)?;
+                }
+                Ok(())
+            }
+            Self::Summary(summaries) => {
+                for (name, telemetry) in summaries {
+                    write_summary(f, name, telemetry)?;
+                }
+                Ok(())
+            }
+            Self::Compacted(compacted) => {
+                for (name, stats) in compacted {
+                    let rotated = match stats.rotated_bytes {
+                        0 => "nothing to rotate".to_string(),
+                        bytes => format!("archived {}", ui::format_bytes(bytes)),
+                    };
+                    writeln!(f, "{}: {} ({} archives)", name, rotated, stats.archives)?;
+                }
+                Ok(())
+            }
+            Self::Validated(reports) => {
+                for (name, report) in reports {
+                    for invalid in &report.invalid {
+                        writeln!(
+                            f,
+                            "{}: {}:{}: {}",
+                            name,
+                            invalid.path.display(),
+                            invalid.line,
+                            invalid.error
+                        )?;
+                        writeln!(
+                            f,
+                            "    {}",
+                            ui::dim(&ui::truncate(&invalid.content, MAX_FIELD_WIDTH))
+                        )?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventsError {
+    #[error(transparent)]
+    Core(#[from] jig_core::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("specify a worker or --all")]
+    NoWorker,
+    #[error("no event log for worker {0}")]
+    NoLog(String),
+}
+
+impl Op for Events {
+    type Error = EventsError;
+    type Output = EventsOutput;
+
+    fn run(&self, ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
+        match &self.subcommand {
+            Some(EventsCommands::Compact { worker }) => return run_compact(ctx, worker.as_deref()),
+            Some(EventsCommands::Validate { worker }) => {
+                return run_validate(ctx, worker.as_deref())
+            }
+            None => {}
+        }
+
+        let mut sources: BTreeMap<String, Source> = BTreeMap::new();
+        if self.all {
+            for (name, log) in EventLog::all()? {
+                sources.insert(name, Source::new(log));
+            }
+        } else {
+            let worker = self.worker.as_deref().ok_or(EventsError::NoWorker)?;
+            let log = worker_log(ctx, worker)?;
+            // A worker that hasn't logged yet is fine to follow, not to show.
+            if !self.follow && !log.exists() && log.archives()?.is_empty() {
+                return Err(EventsError::NoLog(worker.to_string()));
+            }
+            sources.insert(worker.to_string(), Source::new(log));
+        }
+
+        let filter = Filter {
+            types: self.types.clone(),
+            since: self
+                .since
+                .map(|secs| chrono::Utc::now().timestamp() - secs as i64),
+        };
+
+        let mut history = Vec::new();
+        for (name, source) in sources.iter_mut() {
+            let (events, offset) = source.log.read_history()?;
+            source.offset = offset;
+            for event in events {
+                let transition = source.state.advance(&event);
+                history.push((name.clone(), event, transition));
+            }
+        }
+        // Stable, so each worker's events keep their log order on ties.
+        history.sort_by_key(|(_, event, _)| event.ts);
+
+        if self.summary {
+            return self.summarize(sources);
+        }
+
+        let mut lines = Vec::new();
+        for (name, event, transition) in &history {
+            if filter.matches(event) {
+                lines.push(self.render(name, event, *transition)?);
+            }
+        }
+
+        if !self.follow {
+            if lines.is_empty() {
+                eprintln!("{}", ui::dim("No events"));
+            }
+            return Ok(EventsOutput::Lines(lines));
+        }
+
+        for line in lines {
+            println!("{}", line);
+        }
+        loop {
+            std::thread::sleep(FOLLOW_POLL);
+            if self.all {
+                for (name, log) in EventLog::all()? {
+                    sources.entry(name).or_insert_with(|| Source::new(log));
+                }
+            }
+            let mut new = Vec::new();
+            for (name, source) in sources.iter_mut() {
+                let (events, offset) = source.log.read_from(source.offset)?;
+                source.offset = offset;
+                for event in events {
+                    let transition = source.state.advance(&event);
+                    new.push((name.clone(), event, transition));
+                }
+            }
+            new.sort_by_key(|(_, event, _)| event.ts);
+            for (name, event, transition) in &new {
+                if filter.matches(event) {
+                    println!("{}", self.render(name, event, *transition)?);
+                }
+            }
+        }
+    }
+}
+
+impl Events {
+    fn render(
+        &self,
+        name: &str,
+        event: &Event,
+        transition: Option<WorkerStatus>,
+    ) -> Result<String, EventsError> {
+        let worker = self.all.then_some(name);
+        if self.json {
+            return Ok(format_json(worker, event, transition)?);
+        }
+        Ok(format_event(worker, event, transition))
+    }
+
+    fn summarize(&self, sources: BTreeMap<String, Source>) -> Result<EventsOutput, EventsError> {
+        let summaries: Vec<_> = sources
+            .into_iter()
+            .map(|(name, source)| (name, source.state.telemetry))
+            .collect();
+        if !self.json {
+            return Ok(EventsOutput::Summary(summaries));
+        }
+        let mut lines = Vec::new();
+        for (name, telemetry) in &summaries {
+            let mut value = serde_json::to_value(telemetry)?;
+            if let Some(map) = value.as_object_mut() {
+                // Tools in flight only matter for folding more events.
+                map.remove("pending");
+                map.insert("worker".into(), name.as_str().into());
+                map.insert("usage".into(), serde_json::to_value(telemetry.usage())?);
+            }
+            lines.push(serde_json::to_string(&value)?);
+        }
+        Ok(EventsOutput::Lines(lines))
+    }
+}
+
+/// A worker log being replayed, with the state folded so far.
+struct Source {
+    log: EventLog,
+    state: WorkerState,
+    offset: u64,
+}
+
+impl Source {
+    fn new(log: EventLog) -> Self {
+        Self {
+            log,
+            state: WorkerState::default(),
+            offset: 0,
+        }
+    }
+}
+
+struct Filter {
+    types: Vec<EventType>,
+    since: Option<i64>,
+}
+
+impl Filter {
+    fn matches(&self, event: &Event) -> bool {
+        (self.types.is_empty() || self.types.contains(&event.event_type))
+            && self.since.is_none_or(|cutoff| event.ts >= cutoff)
+    }
+}
+
+/// The event log of a worker in the current repo.
+fn worker_log(ctx: &RepoCtx, worker: &str) -> Result<EventLog, EventsError> {
+    let repo_name = ctx
+        .repo()?
+        .repo_root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    Ok(EventLog::for_worker(&repo_name, worker)?)
+}
+
+/// One line per event: time, type, fields, and the status it moved the worker to.
+fn format_event(worker: Option<&str>, event: &Event, transition: Option<WorkerStatus>) -> String {
+    let time = chrono::DateTime::from_timestamp(event.ts, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| event.ts.to_string());
+
+    let mut line = ui::dim(&time);
+    if let Some(worker) = worker {
+        line.push_str(&format!(" {}", ui::highlight(worker)));
+    }
+    line.push_str(&format!(" {:<14}", event.event_type.as_str()));
+
+    if let Some(fields) = event.data.as_object() {
+        for (key, value) in fields {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            let value = ui::truncate(&value.replace('\n', " "), MAX_FIELD_WIDTH);
+            line.push_str(&format!(" {}={}", key, value));
+        }
+    }
+
+    if let Some(status) = transition {
+        line.push_str(&format!(
+            " {}",
+            ui::warn_text(&format!("-> {}", status.as_str()))
+        ));
+    }
+    line.trim_end().to_string()
+}
+
+/// A worker's telemetry: totals, token usage, then one row per tool.
+fn write_summary(f: &mut fmt::Formatter<'_>, name: &str, telemetry: &Telemetry) -> fmt::Result {
+    writeln!(
+        f,
+        "{}: {} tool calls, {} files, {} commands",
+        ui::bold(name),
+        telemetry.tool_calls(),
+        telemetry.files.len(),
+        telemetry.commands
+    )?;
+
+    if !telemetry.sessions.is_empty() {
+        let usage = telemetry.usage();
+        let mut tokens = format!(
+            "  tokens: {} in, {} out, {} cache read, {} cache write",
+            ui::format_count(usage.input_tokens),
+            ui::format_count(usage.output_tokens),
+            ui::format_count(usage.cache_read_tokens),
+            ui::format_count(usage.cache_creation_tokens)
+        );
+        if let Some(cost) = usage.cost_usd {
+            tokens.push_str(&format!(" (${:.2})", cost));
+        }
+        writeln!(f, "{}", tokens)?;
+    }
+    if let Some(command) = &telemetry.last_command {
+        let command = ui::truncate(&command.replace('\n', " "), MAX_FIELD_WIDTH);
+        writeln!(f, "  last command: {}", ui::dim(&command))?;
+    }
+
+    if telemetry.tools.is_empty() {
+        return Ok(());
+    }
+    let mut tools: Vec<_> = telemetry.tools.iter().collect();
+    tools.sort_by_key(|(_, t)| std::cmp::Reverse((t.total_seconds, t.calls)));
+    let mut table = ui::new_table(&["TOOL", "CALLS", "TOTAL", "AVG", "MAX"]);
+    for (tool, stats) in tools {
+        let (total, avg, max) = match stats.timed_calls {
+            0 => ("-".to_string(), "-".to_string(), "-".to_string()),
+            timed => (
+                ui::format_duration_short(stats.total_seconds),
+                ui::format_duration_short(stats.total_seconds / timed as u64),
+                ui::format_duration_short(stats.max_seconds),
+            ),
+        };
+        table.add_row(vec![tool.clone(), stats.calls.to_string(), total, avg, max]);
+    }
+    writeln!(f, "{}", table)
+}
+
+/// The event as a JSON line, tagged with its worker (in `--all` mode) and the
+/// status it moved the worker to.
+fn format_json(
+    worker: Option<&str>,
+    event: &Event,
+    transition: Option<WorkerStatus>,
+) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(event)?;
+    if let Some(map) = value.as_object_mut() {
+        if let Some(worker) = worker {
+            map.insert("worker".into(), worker.into());
+        }
+        if let Some(status) = transition {
+            map.insert("worker_status".into(), status.as_str().into());
+        }
+    }
+    serde_json::to_string(&value)
+}
+
+/// One worker's log, or every worker's.
+fn selected_logs(
+    ctx: &RepoCtx,
+    worker: Option<&str>,
+) -> Result<Vec<(String, EventLog)>, EventsError> {
+    match worker {
+        Some(worker) => {
+            let log = worker_log(ctx, worker)?;
+            if !log.exists() && log.archives()?.is_empty() {
+                return Err(EventsError::NoLog(worker.to_string()));
+            }
+            Ok(vec![(worker.to_string(), log)])
+        }
+        None => Ok(EventLog::all()?),
+    }
+}
+
+fn run_validate(ctx: &RepoCtx, worker: Option<&str>) -> Result<EventsOutput, EventsError> {
+    let mut reports = Vec::new();
+    for (name, log) in selected_logs(ctx, worker)? {
+        reports.push((name, log.validate()?));
+    }
+
+    let invalid: usize = reports.iter().map(|(_, r)| r.invalid.len()).sum();
+    let unversioned: usize = reports.iter().map(|(_, r)| r.unversioned).sum();
+    if invalid == 0 {
+        ui::success(&format!("{} event log(s) valid", reports.len()));
+    } else {
+        ui::failure(&format!(
+            "{} invalid line(s) in {} event log(s)",
+            invalid,
+            reports
+                .iter()
+                .filter(|(_, r)| !r.invalid.is_empty())
+                .count()
+        ));
+    }
+    if unversioned > 0 {
+        ui::detail(&format!(
+            "{} line(s) predate schema versioning (read as v0)",
+            unversioned
+        ));
+    }
+    Ok(EventsOutput::Validated(reports))
+}
+
+fn run_compact(ctx: &RepoCtx, worker: Option<&str>) -> Result<EventsOutput, EventsError> {
+    let logs = selected_logs(ctx, worker)?;
+
+    let mut compacted = Vec::new();
+    for (name, log) in logs {
+        compacted.push((name, log.compact()?));
+    }
+    if compacted.is_empty() {
+        eprintln!("{}", ui::dim("No event logs found"));
+    } else {
+        let rotated = compacted
+            .iter()
+            .filter(|(_, s)| s.rotated_bytes > 0)
+            .count();
+        ui::success(&format!(
+            "Compacted {} event log(s), {} rotated",
+            compacted.len(),
+            rotated
+        ));
+    }
+    Ok(EventsOutput::Compacted(compacted))
+}
@@ -1,8 +1,12 @@
 //! Hooks command — manage hook integrations
 
+use std::io::Read;
+use std::path::Path;
+
 use clap::{Args, Subcommand};
 
 use jig_core::config::JigToml;
+use jig_core::events::{Event, EventType, Usage};
 
 use crate::op::{NoOutput, Op, RepoCtx};
 use crate::ui;
@@ -27,6 +31,21 @@ pub enum HooksCommands {
         /// Specific hook to uninstall (e.g. post-commit). Omit to uninstall all.
         hook: Option<String>,
     },
+    /// Append an event to the current worker's log (used by agent hooks)
+    Emit {
+        /// Event type (e.g. tool_use_end, notification, stop)
+        event_type: EventType,
+        /// Set a field on the event (repeatable)
+        #[arg(long = "field", value_name = "KEY=VALUE", value_parser = parse_field)]
+        fields: Vec<(String, String)>,
+        /// Copy a field from JSON on stdin, e.g. command=tool_input.command (repeatable)
+        #[arg(long = "stdin-field", value_name = "KEY=STDIN_PATH", value_parser = parse_field)]
+        stdin_fields: Vec<(String, String)>,
+        /// Add the session's token usage, read from the Claude Code transcript
+        /// named by `transcript_path` on stdin
+        #[arg(long)]
+        transcript_usage: bool,
+    },
     /// Git post-commit handler (called by hook wrapper)
     #[command(hide = true)]
     PostCommit {
@@ -63,6 +82,43 @@ pub enum HooksCommands {
 pub enum HooksError {
     #[error(transparent)]
     Core(#[from] jig_core::Error),
+    #[error("failed to read hook input from stdin: {0}")]
+    Stdin(#[from] std::io::Error),
+}
+
+/// Longest string copied from hook input; tool inputs can be whole files.
+const MAX_STDIN_VALUE: usize = 500;
+
+/// Follow a dotted path (`tool_input.command`) into JSON.
+fn lookup<'a>(input: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.')
+        .try_fold(input, |value, key| value.get(key))
+        .filter(|value| !value.is_null())
+}
+
+/// Cap long strings at `MAX_STDIN_VALUE` characters.
+fn clip(value: &serde_json::Value) -> serde_json::Value {
+    match value.as_str() {
+        Some(s) if s.chars().count() > MAX_STDIN_VALUE => {
+            let clipped: String = s.chars().take(MAX_STDIN_VALUE).collect();
+            format!("{}…", clipped).into()
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Parse `KEY=VALUE`, rejecting keys the event line format reserves.
+fn parse_field(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{}'", s))?;
+    if key.is_empty() {
+        return Err(format!("empty field name in '{}'", s));
+    }
+    if matches!(key, "v" | "ts" | "type") {
+        return Err(format!("field name '{}' is reserved", key));
+    }
+    Ok((key.to_string(), value.to_string()))
 }
 
 impl Op for Hooks {
@@ -116,7 +172,9 @@ impl Op for Hooks {
 
                 // Install agent-specific hooks based on config
                 let jig_toml = JigToml::load(repo_path)?.unwrap_or_default();
-                let adapter = jig_core::adapter::get_adapter(&jig_toml.agent.agent_type);
+                let global = jig_core::global::GlobalConfig::load().unwrap_or_default();
+                let adapter = jig_core::AgentRegistry::from_config(&jig_toml, &global)
+                    .get(&jig_toml.agent.agent_type);
 
                 if let Some(adapter) = adapter {
                     if matches!(adapter.agent_type, jig_core::adapter::AgentType::Claude) {
@@ -178,6 +236,44 @@ impl Op for Hooks {
 
                 Ok(NoOutput)
             }
+            HooksCommands::Emit {
+                event_type,
+                fields,
+                stdin_fields,
+                transcript_usage,
+            } => {
+                // Hooks also fire outside repos; there's no worker to log for.
+                let Ok(repo) = ctx.repo() else {
+                    return Ok(NoOutput);
+                };
+
+                let mut event = Event::new(event_type.clone());
+                if !stdin_fields.is_empty() || *transcript_usage {
+                    let mut input = String::new();
+                    std::io::stdin().read_to_string(&mut input)?;
+                    let input: serde_json::Value = serde_json::from_str(&input).unwrap_or_default();
+                    for (key, source) in stdin_fields {
+                        if let Some(value) = lookup(&input, source) {
+                            event = event.with_field(key, clip(value));
+                        }
+                    }
+                    let transcript = input["transcript_path"].as_str();
+                    if let (true, Some(path)) = (*transcript_usage, transcript) {
+                        // Usage is best-effort; the event matters more.
+                        if let Ok(usage) = Usage::from_claude_transcript(Path::new(path)) {
+                            for (key, value) in usage.to_fields() {
+                                event = event.with_field(key, value);
+                            }
+                        }
+                    }
+                }
+                for (key, value) in fields {
+                    event = event.with_field(key, value.as_str());
+                }
+
+                jig_core::hooks::handle_emit(repo, &event)?;
+                Ok(NoOutput)
+            }
             HooksCommands::PostCommit { .. } => {
                 let repo = ctx.repo()?;
                 jig_core::hooks::handle_post_commit(&repo.repo_root)?;
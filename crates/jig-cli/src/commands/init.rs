@@ -6,7 +6,7 @@ use std::path::Path;
 
 use jig_core::config::{JIG_DIR, JIG_LOCAL_TOML};
 use jig_core::git::Repo;
-use jig_core::{adapter, session, terminal, Error, JigToml};
+use jig_core::{adapter, session, terminal, AgentRegistry, Error, JigToml};
 
 use crate::op::{NoOutput, Op, RepoCtx};
 use crate::ui;
@@ -45,7 +45,7 @@ const CLAUDE_SETTINGS_JSON: &str =
 /// Initialize repository for jig
 #[derive(Args, Debug, Clone)]
 pub struct Init {
-    /// Agent framework to initialize (claude, cursor)
+    /// Agent framework to initialize (claude, codex, aider, gemini, or an `[agents.<name>]` entry)
     #[arg(value_name = "AGENT")]
     pub agent: Option<String>,
 
@@ -97,16 +97,14 @@ impl Op for Init {
             }
         };
 
-        // Validate agent argument
-        let adapter = adapter::get_adapter(agent_name).ok_or_else(|| {
-            InitError::UnknownAgent(
-                agent_name.to_string(),
-                adapter::supported_agents().join(", "),
-            )
+        // Validate agent argument against built-in and configured adapters
+        let registry = AgentRegistry::load(&repo_root)?;
+        let adapter = &registry.get(agent_name).ok_or_else(|| {
+            InitError::UnknownAgent(agent_name.to_string(), registry.names().join(", "))
         })?;
 
         // Check if agent is installed
-        if !terminal::command_exists(adapter.command) {
+        if !terminal::command_exists(&adapter.command) {
             ui::warning(&format!(
                 "'{}' not found in PATH. Install it before running agents.",
                 adapter.command
@@ -169,7 +167,7 @@ impl Op for Init {
             "spawn",
         ];
         for skill in skill_names {
-            let dir = repo_root.join(adapter.skills_dir).join(skill);
+            let dir = repo_root.join(adapter.skills_dir.as_ref()).join(skill);
             if !dir.exists() {
                 fs::create_dir_all(&dir)?;
                 eprintln!("  {} Created {}/{}/", ui::SYM_OK, adapter.skills_dir, skill);
@@ -190,7 +188,7 @@ type = "{}"
 
 # Issue configuration
 # [issues]
-# provider = "file"                    # or "linear"
+# provider = "file"                    # or "linear", "github"
 # auto_spawn_labels = []               # [] = all issues, ["x"] = filtered, omit = disabled
 "#,
             agent_name
@@ -269,15 +267,16 @@ type = "{}"
         // Write adapter-specific project file (CLAUDE.md, .cursorrules, etc.)
         write_file(
             &repo_root,
-            adapter.project_file,
+            &adapter.project_file,
             PROJECT_MD_TEMPLATE,
             self.force,
             backup_dir_opt,
         )?;
 
         // Write adapter-specific settings file if applicable
-        if let Some(settings_path) = adapter.settings_file {
-            let settings_content = get_settings_content(adapter);
+        if let (Some(settings_path), Some(settings_content)) =
+            (&adapter.settings_file, get_settings_content(adapter))
+        {
             write_file(
                 &repo_root,
                 settings_path,
@@ -389,10 +388,11 @@ Remove HTML comment placeholders as you fill in actual content. Commit when done
     )
 }
 
-/// Get settings file content for an adapter
-fn get_settings_content(adapter: &adapter::AgentAdapter) -> &'static str {
+/// Get settings file content for an adapter, if jig ships one
+fn get_settings_content(adapter: &adapter::AgentAdapter) -> Option<&'static str> {
     match adapter.agent_type {
-        adapter::AgentType::Claude => CLAUDE_SETTINGS_JSON,
+        adapter::AgentType::Claude => Some(CLAUDE_SETTINGS_JSON),
+        _ => None,
     }
 }
 
@@ -404,7 +404,7 @@ fn launch_audit(
     extra: Option<&str>,
 ) -> Result<(), InitError> {
     let prompt = audit_prompt(adapter, has_backup, extra);
-    let cmd = adapter::build_spawn_command(adapter, Some(&prompt), &[]);
+    let cmd = adapter::build_spawn_command(adapter, Some(&prompt), None, &[]);
 
     let session_name = "jig-init";
     let window_name = repo_root
@@ -414,6 +414,9 @@ fn launch_audit(
 
     session::create_window(session_name, window_name, repo_root)?;
     session::send_keys(session_name, window_name, &cmd)?;
+    if adapter.send_prompt {
+        session::send_prompt(session_name, window_name, &prompt)?;
+    }
 
     eprintln!();
     ui::progress(&format!(
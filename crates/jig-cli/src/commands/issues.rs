@@ -8,7 +8,9 @@ use comfy_table::{Cell, Color};
 use crossterm::event::{self, Event, KeyCode, KeyModifiers};
 use crossterm::terminal;
 
-use jig_core::issues::{self, Issue as CoreIssue, IssueFilter, IssuePriority, IssueStatus};
+use jig_core::issues::{
+    self, Issue as CoreIssue, IssueFilter, IssuePriority, IssueProvider, IssueStatus,
+};
 
 use crate::op::{GlobalCtx, Op, RepoCtx};
 use crate::ui;
@@ -380,6 +382,12 @@ fn run_create(
                 Some(&initial_status),
             )?
         }
+        "github" => {
+            return Err(IssuesError::Usage(
+                "creating issues is not supported by the GitHub provider (use `gh issue create`)"
+                    .to_string(),
+            ));
+        }
         _ => {
             let cat = category.unwrap_or("features");
             let file_provider = repo.file_provider();
@@ -536,6 +544,12 @@ fn run_update(
                 linear_provider.remove_blocked_by(id, blocker)?;
             }
         }
+        "github" => {
+            return Err(IssuesError::Usage(
+                "updating issue fields is not supported by the GitHub provider (use `gh issue edit`)"
+                    .to_string(),
+            ));
+        }
         _ => {
             if assignee.is_some() {
                 return Err(IssuesError::Usage(
@@ -582,6 +596,10 @@ fn run_status_update(
             let linear_provider = repo.linear_provider()?;
             linear_provider.update_status(id, &status)?;
         }
+        "github" => {
+            let github_provider = repo.github_provider()?;
+            github_provider.update_status(id, &status)?;
+        }
         _ => {
             let file_provider = repo.file_provider();
             file_provider.update_status(id, &status)?;
@@ -602,6 +620,10 @@ fn run_complete(ctx: &RepoCtx, id: &str, delete: bool) -> Result<IssuesOutput, I
             let linear_provider = repo.linear_provider()?;
             linear_provider.update_status(id, &IssueStatus::Complete)?;
         }
+        "github" => {
+            let github_provider = repo.github_provider()?;
+            github_provider.update_status(id, &IssueStatus::Complete)?;
+        }
         _ => {
             let file_provider = repo.file_provider();
             file_provider.update_status(id, &IssueStatus::Complete)?;
@@ -968,10 +990,8 @@ fn interactive_loop(
             }
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Char('j') | KeyCode::Down => {
-                    if cursor + 1 < issues.len() {
-                        cursor += 1;
-                    }
+                KeyCode::Char('j') | KeyCode::Down if cursor + 1 < issues.len() => {
+                    cursor += 1;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
                     cursor = cursor.saturating_sub(1);
@@ -1024,11 +1044,9 @@ fn view_issue(issue: &CoreIssue, w: &mut impl Write) -> Result<(), IssuesError>
 
         // Footer
         let total = lines.len();
-        let pct = if total == 0 {
-            100
-        } else {
-            ((scroll + visible).min(total) * 100) / total
-        };
+        let pct = ((scroll + visible).min(total) * 100)
+            .checked_div(total)
+            .unwrap_or(100);
         write!(w, "\x1B[2m— {}% (j/k scroll, q back) —\x1B[0m", pct)?;
         w.flush()?;
 
@@ -1038,10 +1056,8 @@ fn view_issue(issue: &CoreIssue, w: &mut impl Write) -> Result<(), IssuesError>
             }
             match key.code {
                 KeyCode::Char('q') | KeyCode::Esc => break,
-                KeyCode::Char('j') | KeyCode::Down => {
-                    if scroll + visible < lines.len() {
-                        scroll += 1;
-                    }
+                KeyCode::Char('j') | KeyCode::Down if scroll + visible < lines.len() => {
+                    scroll += 1;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
                     scroll = scroll.saturating_sub(1);
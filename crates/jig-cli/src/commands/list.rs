@@ -5,7 +5,7 @@ use std::path::Path;
 use clap::Args;
 use comfy_table::{Cell, CellAlignment, Color};
 
-use jig_core::events::{EventLog, WorkerState};
+use jig_core::events::EventLog;
 use jig_core::git::{self, Repo};
 use jig_core::global::GlobalConfig;
 use jig_core::worker::WorkerStatus;
@@ -163,12 +163,9 @@ fn worktree_event_status(repo_root: &Path, name: &str) -> Option<WorkerStatus> {
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| "unknown".to_string());
     let event_log = EventLog::for_worker(&repo_name, name).ok()?;
-    let events = event_log.read_all().ok()?;
-    if events.is_empty() {
-        return None;
-    }
     let config = GlobalConfig::load().ok()?.health;
-    Some(WorkerState::reduce(&events, &config).status)
+    let state = event_log.reduce(&config).ok()?;
+    state.last_event_at.map(|_| state.status)
 }
 
 fn build_worktree_table(
@@ -1,9 +1,9 @@
 //! Merge command - merge reviewed worktree into current branch
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 
 use jig_core::git::Repo;
-use jig_core::{spawn, Error};
+use jig_core::land::{self, LandError, Landing, Note};
 
 use crate::op::{NoOutput, Op, RepoCtx};
 use crate::ui;
@@ -13,55 +13,71 @@ use crate::ui;
 pub struct Merge {
     /// Worktree name
     pub name: String,
+
+    /// How to land the branch
+    #[arg(long, short, value_enum, default_value_t = Strategy::Merge)]
+    pub strategy: Strategy,
+
+    /// Squash commit message (defaults to the branch's first commit)
+    #[arg(long, short)]
+    pub message: Option<String>,
+
+    /// Also remove the worktree, archive the event log and complete the linked issue
+    #[arg(long)]
+    pub cleanup: bool,
 }
 
-#[derive(Debug, thiserror::Error)]
-pub enum MergeError {
-    #[error(transparent)]
-    Core(#[from] Error),
+/// How `jig merge` lands a worker branch.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Fast-forward, or a merge commit if the branches diverged
+    Merge,
+    /// One commit with a validated conventional message
+    Squash,
+    /// Rebase the branch onto the current branch, then fast-forward
+    Rebase,
+    /// Merge the branch's open PR through the forge API
+    Pr,
+}
+
+impl From<Strategy> for land::Strategy {
+    fn from(strategy: Strategy) -> Self {
+        match strategy {
+            Strategy::Merge => Self::Merge,
+            Strategy::Squash => Self::Squash,
+            Strategy::Rebase => Self::Rebase,
+            Strategy::Pr => Self::Pr,
+        }
+    }
 }
 
 impl Op for Merge {
-    type Error = MergeError;
+    type Error = LandError;
     type Output = NoOutput;
 
     fn run(&self, ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
         let repo = ctx.repo()?;
-        let worktree_path = repo.worktrees_dir.join(&self.name);
-
-        if !worktree_path.exists() {
-            return Err(Error::WorktreeNotFound(self.name.clone()).into());
+        let landing = Landing {
+            name: self.name.clone(),
+            strategy: self.strategy.into(),
+            message: self.message.clone(),
+            cleanup: self.cleanup,
+        };
+        for note in landing.land(repo, &Repo::discover()?)? {
+            match note {
+                Note::Success(message) => ui::success(&message),
+                Note::Warning(message) => ui::warning(&message),
+            }
         }
 
-        // Check for uncommitted changes
-        if Repo::has_uncommitted_changes(&worktree_path)? {
-            return Err(Error::UncommittedChanges.into());
+        if !self.cleanup {
+            eprintln!();
+            ui::detail(&format!(
+                "Remove worktree with: {}",
+                ui::highlight(&format!("jig remove {}", self.name))
+            ));
         }
 
-        // Get branch name
-        let branch = Repo::worktree_branch(&worktree_path)?;
-
-        // Merge the branch
-        let git_repo = Repo::discover()?;
-        git_repo.merge_branch(&branch)?;
-
-        ui::success(&format!(
-            "Merged branch '{}' into current branch",
-            ui::highlight(&branch)
-        ));
-
-        // Unregister from spawn state
-        spawn::unregister(repo, &self.name)?;
-
-        // Kill tmux window if running
-        spawn::kill_window(repo, &self.name)?;
-
-        eprintln!();
-        ui::detail(&format!(
-            "Remove worktree with: {}",
-            ui::highlight(&format!("jig remove {}", self.name))
-        ));
-
         Ok(NoOutput)
     }
 }
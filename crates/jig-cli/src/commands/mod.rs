@@ -3,8 +3,10 @@
 pub mod attach;
 pub mod commit;
 pub mod config;
+pub mod conflicts;
 pub mod create;
 pub mod daemon;
+pub mod events;
 pub mod exit;
 pub mod health;
 pub mod home;
@@ -17,8 +19,10 @@ pub mod merge;
 pub mod notify;
 pub mod nuke;
 pub mod open;
+pub mod peek;
 pub mod pr;
 pub mod ps;
+pub mod queue;
 pub mod remove;
 pub mod repos;
 pub mod resume;
@@ -26,6 +30,7 @@ pub mod review;
 pub mod shell_init;
 pub mod shell_setup;
 pub mod spawn;
+pub mod stats;
 pub mod update;
 pub mod version;
 pub mod which;
@@ -34,8 +39,10 @@ pub mod which;
 pub use attach::Attach;
 pub use commit::Commit;
 pub use config::Config;
+pub use conflicts::Conflicts;
 pub use create::Create;
 pub use daemon::Daemon;
+pub use events::Events;
 pub use exit::Exit;
 pub use health::Health;
 pub use home::Home;
@@ -48,8 +55,10 @@ pub use merge::Merge;
 pub use notify::Notify;
 pub use nuke::Nuke;
 pub use open::Open;
+pub use peek::Peek;
 pub use pr::Pr;
 pub use ps::Ps;
+pub use queue::Queue;
 pub use remove::Remove;
 pub use repos::Repos;
 pub use resume::Resume;
@@ -57,6 +66,7 @@ pub use review::Review;
 pub use shell_init::ShellInit;
 pub use shell_setup::ShellSetup;
 pub use spawn::Spawn;
+pub use stats::Stats;
 pub use update::Update;
 pub use version::Version;
 pub use which::Which;
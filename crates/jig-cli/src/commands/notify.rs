@@ -4,8 +4,11 @@ use std::fmt;
 
 use clap::{Args, Subcommand};
 
-use jig_core::global::{GlobalConfig, NotifyConfig};
-use jig_core::notify::{NotificationEvent, NotificationQueue, Notifier};
+use jig_core::global::{GlobalConfig, NotifyConfig, NotifyRule};
+use jig_core::notify::{
+    Digest, NotificationEvent, NotificationQueue, Notifier, RegistryContext,
+    DEFAULT_WEBHOOK_RETRIES,
+};
 
 use crate::op::{Op, RepoCtx};
 use crate::ui;
@@ -29,6 +32,18 @@ pub enum NotifyCommands {
         #[arg(short, long, default_value = "10")]
         n: usize,
     },
+    /// Summarize queued notifications (what the next digest would send)
+    Digest {
+        /// Cover the last N minutes instead of everything since the last digest
+        #[arg(short, long)]
+        minutes: Option<u64>,
+        /// Deliver the summary through the configured targets
+        #[arg(long)]
+        send: bool,
+        /// Print the digest as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Emit a notification event (agent-facing)
     Send {
         #[command(subcommand)]
@@ -178,6 +193,7 @@ pub enum NotifyOutput {
     Doctor,
     Test,
     Tail(TailOutput),
+    Digest(String),
     Send,
 }
 
@@ -196,6 +212,7 @@ impl fmt::Display for NotifyOutput {
                 }
                 Ok(())
             }
+            Self::Digest(text) => writeln!(f, "{}", text),
         }
     }
 }
@@ -215,11 +232,62 @@ impl Op for Notify {
             NotifyCommands::Doctor => run_doctor(),
             NotifyCommands::Test => run_test(),
             NotifyCommands::Tail { n } => run_tail(*n),
+            NotifyCommands::Digest {
+                minutes,
+                send,
+                json,
+            } => run_digest(*minutes, *send, *json),
             NotifyCommands::Send { kind } => run_send(kind.clone()),
         }
     }
 }
 
+/// One-line summary of a routing rule: `match → targets`.
+fn describe_rule(rule: &NotifyRule) -> String {
+    let mut matches = Vec::new();
+    for (name, values) in [
+        ("repos", &rule.repos),
+        ("workers", &rule.workers),
+        ("events", &rule.events),
+        ("labels", &rule.labels),
+    ] {
+        if !values.is_empty() {
+            matches.push(format!("{}={}", name, values.join(",")));
+        }
+    }
+    let mut targets = Vec::new();
+    if let Some(exec) = &rule.exec {
+        targets.push(format!("exec {}", exec));
+    }
+    if let Some(webhook) = &rule.webhook {
+        targets.push(format!("webhook {}", webhook));
+    }
+    if rule.desktop {
+        targets.push("desktop".to_string());
+    }
+    format!(
+        "{} → {}{}",
+        if matches.is_empty() {
+            "*".to_string()
+        } else {
+            matches.join(" ")
+        },
+        if targets.is_empty() {
+            "<none>".to_string()
+        } else {
+            targets.join(", ")
+        },
+        if rule.continue_matching {
+            " (continue)"
+        } else {
+            ""
+        }
+    )
+}
+
+/// Most recent delivery failures shown by `jig notify doctor`.
+const DOCTOR_FAILURES: usize = 5;
+
 fn run_doctor() -> Result<NotifyOutput, NotifyError> {
     let config_path = GlobalConfig::default_path()?;
     let config_path_str = config_path.display().to_string();
@@ -269,6 +337,26 @@ fn run_doctor() -> Result<NotifyOutput, NotifyError> {
         "webhook: {}",
         notify_config.webhook.as_deref().unwrap_or("<unset>")
     ));
+    if notify_config.webhook.is_some() {
+        ui::detail(&format!(
+            "webhook format: {}",
+            notify_config.webhook_format.as_str()
+        ));
+        ui::detail(&format!(
+            "webhook signing: {}",
+            if notify_config.webhook_secret.is_some() {
+                "on"
+            } else {
+                "off"
+            }
+        ));
+        ui::detail(&format!(
+            "webhook retries: {}",
+            notify_config
+                .webhook_retries
+                .unwrap_or(DEFAULT_WEBHOOK_RETRIES)
+        ));
+    }
     ui::detail(&format!(
         "events: {}",
         if notify_config.events.is_empty() {
@@ -277,6 +365,32 @@ fn run_doctor() -> Result<NotifyOutput, NotifyError> {
             notify_config.events.join(", ")
         }
     ));
+    ui::detail(&format!(
+        "desktop: {}",
+        if notify_config.desktop { "on" } else { "off" }
+    ));
+    ui::detail(&format!(
+        "quiet hours: {}",
+        notify_config.quiet_hours.as_deref().unwrap_or("<unset>")
+    ));
+    ui::detail(&format!(
+        "dedup window: {}",
+        notify_config
+            .dedup_seconds
+            .map(|s| format!("{}s", s))
+            .unwrap_or_else(|| "<off>".to_string())
+    ));
+    ui::detail(&format!(
+        "digest: {}",
+        match &notify_config.digest {
+            Some(d) if d.events.is_empty() => format!("every {}s, all events", d.window_seconds),
+            Some(d) => format!("every {}s, {}", d.window_seconds, d.events.join(", ")),
+            None => "<off>".to_string(),
+        }
+    ));
+    for (i, rule) in notify_config.rules.iter().enumerate() {
+        ui::detail(&format!("rule {}: {}", i + 1, describe_rule(rule)));
+    }
 
     eprintln!();
     ui::header("Queue");
@@ -296,13 +410,29 @@ fn run_doctor() -> Result<NotifyOutput, NotifyError> {
         ui::detail("status: no queue file (no events emitted yet)");
     }
 
+    let failures = queue.failures(DOCTOR_FAILURES)?;
+    if !failures.is_empty() {
+        eprintln!();
+        ui::header("Delivery failures");
+        ui::detail(&format!("log: {}", queue.failures_path().display()));
+        for f in &failures {
+            let dt = chrono::DateTime::from_timestamp(f.ts, 0)
+                .map(|d| d.to_rfc3339())
+                .unwrap_or_else(|| f.ts.to_string());
+            ui::failure(&format!(
+                "{} {} ({} attempt(s)): {}",
+                dt, f.target, f.attempts, f.error
+            ));
+        }
+    }
+
     Ok(NotifyOutput::Doctor)
 }
 
 fn run_test() -> Result<NotifyOutput, NotifyError> {
     let config = GlobalConfig::load()?;
     let queue = NotificationQueue::global()?;
-    let notifier = Notifier::new(config.notify, queue);
+    let notifier = Notifier::new(config.notify, queue).with_context(Box::new(RegistryContext));
 
     let event = NotificationEvent::NeedsIntervention {
         repo: "jig".into(),
@@ -329,10 +459,48 @@ fn run_tail(n: usize) -> Result<NotifyOutput, NotifyError> {
     Ok(NotifyOutput::Tail(TailOutput { lines }))
 }
 
+/// Window used by `jig notify digest` when digest mode has never run.
+const DEFAULT_DIGEST_MINUTES: u64 = 60;
+
+fn run_digest(minutes: Option<u64>, send: bool, json: bool) -> Result<NotifyOutput, NotifyError> {
+    let config = GlobalConfig::load()?;
+    let queue = NotificationQueue::global()?;
+    let now = chrono::Utc::now().timestamp();
+
+    let digest_config = config.notify.digest.clone();
+    let marker = match (&digest_config, minutes) {
+        (Some(_), None) => queue.last_digest()?,
+        _ => None,
+    };
+    let since = marker.unwrap_or_else(|| {
+        let minutes = minutes.unwrap_or(DEFAULT_DIGEST_MINUTES);
+        now - (minutes * 60) as i64
+    });
+    let events = digest_config.map(|d| d.events).unwrap_or_default();
+    // Include notifications from the current second
+    let digest = Digest::collect(&queue, since, now + 1, &events)?;
+
+    if send {
+        let notifier = Notifier::new(config.notify, NotificationQueue::global()?);
+        notifier.send_digest(&digest)?;
+        if marker.is_some() {
+            queue.set_last_digest(now + 1)?;
+        }
+        ui::success(&format!("sent digest: {}", digest.summary));
+    }
+
+    let output = if json {
+        digest.to_json()?
+    } else {
+        digest.to_text()
+    };
+    Ok(NotifyOutput::Digest(output))
+}
+
 fn run_send(kind: SendKind) -> Result<NotifyOutput, NotifyError> {
     let config = GlobalConfig::load()?;
     let queue = NotificationQueue::global()?;
-    let notifier = Notifier::new(config.notify, queue);
+    let notifier = Notifier::new(config.notify, queue).with_context(Box::new(RegistryContext));
 
     let event = kind.into_event();
     let type_name = event.type_name();
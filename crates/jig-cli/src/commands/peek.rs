@@ -0,0 +1,129 @@
+//! Peek command — show the recent output of a worker's tmux pane
+
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use clap::Args;
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::terminal;
+
+use jig_core::{session, RepoContext, TmuxClient, TmuxTarget};
+
+use crate::op::{GlobalCtx, Op, RepoCtx};
+use crate::ui;
+
+/// Show the recent output of a worker's tmux pane
+#[derive(Args, Debug, Clone)]
+pub struct Peek {
+    /// Worker name
+    pub name: String,
+
+    /// Number of lines to show
+    #[arg(long, short = 'n', default_value_t = 40)]
+    pub lines: usize,
+
+    /// Keep refreshing until q is pressed
+    #[arg(long, short)]
+    pub follow: bool,
+}
+
+#[derive(Debug)]
+pub enum PeekOutput {
+    Lines(Vec<String>),
+    /// Followed in the alternate screen; nothing left to print.
+    Followed,
+}
+
+impl fmt::Display for PeekOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lines(lines) => {
+                for line in lines {
+                    writeln!(f, "{}", line)?;
+                }
+                Ok(())
+            }
+            Self::Followed => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PeekError {
+    #[error(transparent)]
+    Core(#[from] jig_core::Error),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl Op for Peek {
+    type Error = PeekError;
+    type Output = PeekOutput;
+
+    fn run(&self, ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
+        self.peek(ctx.repo()?)
+    }
+
+    fn run_global(&self, ctx: &GlobalCtx) -> Result<Self::Output, Self::Error> {
+        self.peek(ctx.repo_for_worktree(&self.name)?)
+    }
+}
+
+impl Peek {
+    fn peek(&self, repo: &RepoContext) -> Result<PeekOutput, PeekError> {
+        if !session::window_exists(&repo.session_name, &self.name) {
+            return Err(jig_core::Error::WorkerNotFound(self.name.clone()).into());
+        }
+        let target = TmuxTarget::new(&repo.session_name, &self.name);
+        let tmux = TmuxClient::new();
+
+        if !self.follow {
+            return Ok(PeekOutput::Lines(tmux.capture_pane(&target, self.lines)?));
+        }
+        ui::with_alternate_screen(|w| follow(w, &tmux, &target, self.lines))?;
+        Ok(PeekOutput::Followed)
+    }
+}
+
+/// Redraw the pane every second until q, Esc or Ctrl-C.
+fn follow(
+    w: &mut io::Stderr,
+    tmux: &TmuxClient,
+    target: &TmuxTarget,
+    lines: usize,
+) -> Result<(), PeekError> {
+    let mut shown = None;
+    loop {
+        let (cols, rows) = terminal::size().unwrap_or((80, 24));
+        let height = lines.min((rows as usize).saturating_sub(2));
+        let captured = tmux.capture_pane(target, height)?;
+
+        if shown.as_ref() != Some(&captured) {
+            write!(w, "\x1B[2J\x1B[H")?;
+            write!(
+                w,
+                "{} {}  {}\r\n\r\n",
+                ui::bold("jig peek"),
+                ui::highlight(&target.window),
+                ui::dim("(q quit)")
+            )?;
+            for line in &captured {
+                write!(w, "{}\r\n", ui::truncate(line, cols as usize))?;
+            }
+            w.flush()?;
+            shown = Some(captured);
+        }
+
+        if event::poll(Duration::from_secs(1))? {
+            if let Event::Key(key) = event::read()? {
+                if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                    return Ok(());
+                }
+                if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
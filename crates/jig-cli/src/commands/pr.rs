@@ -4,10 +4,12 @@ use std::process::Command;
 
 use clap::{Args, Subcommand};
 
+use jig_core::events::EventLog;
 use jig_core::git;
 use jig_core::github::{GitHubClient, PrFeedback, ReviewState};
+use jig_core::global::GlobalConfig;
 use jig_core::state::OrchestratorState;
-use jig_core::Error;
+use jig_core::{forge, stack, Error};
 
 use crate::op::{Op, RepoCtx};
 use crate::ui;
@@ -18,11 +20,11 @@ pub struct Pr {
     #[command(subcommand)]
     pub command: Option<PrCommand>,
 
-    /// PR title (defaults to --fill behavior)
+    /// PR title (defaults to the commit subject, or the branch name)
     #[arg(short, long)]
     pub title: Option<String>,
 
-    /// PR body/description
+    /// PR body/description (defaults to the commit body, or a list of commits)
     #[arg(short, long)]
     pub body: Option<String>,
 }
@@ -47,8 +49,6 @@ pub enum PrError {
     Core(#[from] Error),
     #[error("git push failed: {0}")]
     PushFailed(String),
-    #[error("gh pr create failed: {0}")]
-    GhFailed(String),
     #[error("could not determine current branch")]
     NoBranch,
     #[error("no open PR found for branch '{0}'")]
@@ -86,8 +86,14 @@ impl Pr {
         let git_repo = jig_core::git::Repo::discover()?;
         let branch = git_repo.current_branch().map_err(|_| PrError::NoBranch)?;
 
-        // 2. Resolve base branch
-        let base = resolve_base(&repo.worktrees_dir, &repo.repo_root, repo)?;
+        // 2. Resolve base branch. A worker stacked on another in-flight
+        // worker targets that worker's branch.
+        let lower_branch = git::get_current_worktree_name(&repo.worktrees_dir)?
+            .and_then(|name| stacked_base(&repo.repo_root, &name));
+        let base = match lower_branch {
+            Some(ref lower) => lower.clone(),
+            None => resolve_base(&repo.worktrees_dir, &repo.repo_root, repo)?,
+        };
         let base_for_gh = base.strip_prefix("origin/").unwrap_or(&base);
 
         ui::detail(&format!(
@@ -96,59 +102,84 @@ impl Pr {
             ui::highlight(base_for_gh)
         ));
 
-        // 3. Push
-        ui::detail("Pushing...");
-        let push = Command::new("git")
-            .args(["push", "-u", "origin", &branch])
-            .output()
-            .map_err(|e| PrError::PushFailed(e.to_string()))?;
-
-        if !push.status.success() {
-            let stderr = String::from_utf8_lossy(&push.stderr);
-            return Err(PrError::PushFailed(stderr.to_string()));
-        }
-
-        // 4. Create PR
-        let mut gh_args = vec![
-            "pr".to_string(),
-            "create".to_string(),
-            "--draft".to_string(),
-            "--base".to_string(),
-            base_for_gh.to_string(),
-        ];
-
-        if let Some(ref title) = self.title {
-            gh_args.push("--title".to_string());
-            gh_args.push(title.clone());
+        // 3. Push (the lower branch first, if a stacked PR's base isn't on
+        // origin yet)
+        if let Some(ref lower) = lower_branch {
+            if !on_origin(lower) {
+                ui::detail(&format!("Pushing {}...", ui::highlight(lower)));
+                push(lower)?;
+            }
         }
+        ui::detail("Pushing...");
+        push(&branch)?;
 
-        if let Some(ref body) = self.body {
-            gh_args.push("--body".to_string());
-            gh_args.push(body.clone());
-        }
+        // 4. Create PR, filling missing fields from the commits like
+        // `gh pr create --fill`
+        let (fill_title, fill_body) = fill(&base, &branch);
+        let title = self.title.clone().unwrap_or(fill_title);
+        let body = self.body.clone().unwrap_or(fill_body);
+        let forge = forge::for_repo(&repo.repo_root)?;
+        let pr = forge.create_pr(&branch, base_for_gh, &title, &body, true)?;
 
-        // Use --fill for any fields not explicitly provided
-        if self.title.is_none() {
-            gh_args.push("--fill".to_string());
-        }
+        let url = pr.url;
+        ui::success(&format!("Draft PR created: {}", ui::highlight(&url)));
 
-        let gh = Command::new("gh")
-            .args(&gh_args)
-            .output()
-            .map_err(|e| PrError::GhFailed(e.to_string()))?;
+        Ok(PrOutput(url))
+    }
+}
 
-        if !gh.status.success() {
-            let stderr = String::from_utf8_lossy(&gh.stderr);
-            return Err(PrError::GhFailed(stderr.to_string()));
-        }
+fn push(branch: &str) -> Result<(), PrError> {
+    let push = Command::new("git")
+        .args(["push", "-u", "origin", branch])
+        .output()
+        .map_err(|e| PrError::PushFailed(e.to_string()))?;
 
-        let url = String::from_utf8_lossy(&gh.stdout).trim().to_string();
-        ui::success(&format!("Draft PR created: {}", ui::highlight(&url)));
+    if !push.status.success() {
+        let stderr = String::from_utf8_lossy(&push.stderr);
+        return Err(PrError::PushFailed(stderr.to_string()));
+    }
+    Ok(())
+}
 
-        Ok(PrOutput(url))
+/// Default PR title and body from the commits `branch` adds over `base`:
+/// the commit message for a single commit, otherwise the branch name and a
+/// list of commit subjects.
+fn fill(base: &str, branch: &str) -> (String, String) {
+    let log = Command::new("git")
+        .args(["log", "--reverse", "--format=%s%x1f%b%x1e"])
+        .arg(format!("{}..{}", base, branch))
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+        .unwrap_or_default();
+    let commits: Vec<(&str, &str)> = log
+        .split('\x1e')
+        .filter_map(|c| c.trim_start_matches('\n').split_once('\x1f'))
+        .collect();
+
+    match commits.as_slice() {
+        [(subject, body)] => (subject.to_string(), body.trim().to_string()),
+        _ => (
+            branch.to_string(),
+            commits
+                .iter()
+                .map(|(subject, _)| format!("- {}", subject))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
     }
 }
 
+/// Whether `branch` has a remote-tracking ref on origin.
+fn on_origin(branch: &str) -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("refs/remotes/origin/{}", branch))
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
 fn run_comments(
     _ctx: &RepoCtx,
     pr_number: Option<u64>,
@@ -313,3 +344,15 @@ fn resolve_base(
 
     Ok(repo.base_branch.clone())
 }
+
+/// Branch of the worker `name` is stacked on, while that worker is in flight.
+fn stacked_base(repo_root: &std::path::Path, name: &str) -> Option<String> {
+    let repo_name = repo_root.file_name()?.to_string_lossy().to_string();
+    let config = GlobalConfig::load().ok()?.health;
+    let lower = EventLog::for_worker(&repo_name, name)
+        .ok()?
+        .reduce(&config)
+        .ok()?
+        .stacked_on?;
+    stack::branch_of(repo_root, &lower)
+}
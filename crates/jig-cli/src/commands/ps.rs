@@ -1,20 +1,17 @@
 //! Ps command — show status of spawned sessions
 
-use std::collections::VecDeque;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
-
 use clap::Args;
-use crossterm::event::{self, Event, KeyCode, KeyEvent};
-use crossterm::terminal::{self, disable_raw_mode};
 
 use jig_core::config::JigToml;
-use jig_core::daemon::{DaemonConfig, RuntimeConfig, TickResult, TimerInfo};
+use jig_core::daemon::control::ControlClient;
+use jig_core::daemon::{service, DaemonConfig, RuntimeConfig, TickResult};
+use jig_core::RepoContext;
 
 use crate::op::{GlobalCtx, NoOutput, Op, RepoCtx};
 use crate::ui;
 
+mod dashboard;
+
 /// Show status of spawned sessions
 #[derive(Args, Debug, Clone)]
 pub struct Ps {
@@ -44,11 +41,12 @@ impl Op for Ps {
             .file_name()
             .map(|n| n.to_string_lossy().to_string());
         let runtime_config = self.build_runtime_config(&repo.repo_root);
-        self.execute_ps(repo_filter, runtime_config, false)
+        self.execute_ps(repo_filter, runtime_config, &[repo], false)
     }
 
-    fn run_global(&self, _ctx: &GlobalCtx) -> Result<Self::Output, Self::Error> {
-        self.execute_ps(None, RuntimeConfig::default(), true)
+    fn run_global(&self, ctx: &GlobalCtx) -> Result<Self::Output, Self::Error> {
+        let repos: Vec<&RepoContext> = ctx.repos.iter().collect();
+        self.execute_ps(None, RuntimeConfig::default(), &repos, true)
     }
 }
 
@@ -57,28 +55,35 @@ impl Ps {
         &self,
         repo_filter: Option<String>,
         runtime_config: RuntimeConfig,
+        repos: &[&RepoContext],
         global: bool,
     ) -> Result<NoOutput, PsError> {
         if let Some(interval) = self.watch {
             let interval = if interval == 0 { 2 } else { interval };
-            run_watch(interval, runtime_config, repo_filter, global);
+            dashboard::run(interval, runtime_config, repo_filter, global, repos);
             return Ok(NoOutput);
         }
 
-        let daemon_config = DaemonConfig {
-            once: true,
-            skip_sync: true,
-            repo_filter,
-            ..Default::default()
+        let mut tick = match running_daemon() {
+            Some(daemon) => daemon.tick_now()?,
+            None => {
+                let daemon_config = DaemonConfig {
+                    once: true,
+                    skip_sync: true,
+                    repo_filter: repo_filter.clone(),
+                    ..Default::default()
+                };
+                let mut result = TickResult::default();
+                jig_core::daemon::run_with(&daemon_config, runtime_config, |tick, _| {
+                    result.worker_display.clone_from(&tick.worker_display);
+                    result.triage_display.clone_from(&tick.triage_display);
+                    false
+                })?;
+                result
+            }
         };
-
-        let mut display = vec![];
-        let mut triage_display = vec![];
-        jig_core::daemon::run_with(&daemon_config, runtime_config, |tick, _| {
-            display.clone_from(&tick.worker_display);
-            triage_display.clone_from(&tick.triage_display);
-            false
-        })?;
+        retain_repo(&mut tick, repo_filter.as_deref());
+        let (display, triage_display) = (tick.worker_display, tick.triage_display);
 
         if display.is_empty() && triage_display.is_empty() {
             eprintln!("No spawned sessions");
@@ -129,317 +134,18 @@ impl Ps {
     }
 }
 
-/// View mode for the watch display.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ViewMode {
-    Table,
-    Logs,
-}
-
-impl ViewMode {
-    fn toggle(&mut self) {
-        *self = match self {
-            ViewMode::Table => ViewMode::Logs,
-            ViewMode::Logs => ViewMode::Table,
-        };
-    }
-}
-
-const LOG_BUFFER_SIZE: usize = 50;
-
-/// Format structured log lines from a TickResult.
-fn format_tick_log(tick: &TickResult) -> Vec<String> {
-    let now = chrono::Local::now().format("%H:%M:%S");
-    let mut lines = vec![];
-
-    lines.push(format!(
-        "[{}] tick: {} workers, {} actions, {} nudges, {} errors",
-        now,
-        tick.workers_checked,
-        tick.actions_dispatched,
-        tick.nudges_sent,
-        tick.errors.len(),
-    ));
-
-    for (key, info) in &tick.worker_info {
-        if !info.has_pr {
-            continue;
-        }
-        if let Some(err) = &info.pr_error {
-            lines.push(format!("[{}]   {} PR: {}", now, key, err));
-        } else if !info.pr_checks.is_empty() {
-            let problems: Vec<&str> = info
-                .pr_checks
-                .iter()
-                .filter(|(_, bad)| *bad)
-                .map(|(name, _)| name.as_str())
-                .collect();
-            if problems.is_empty() {
-                lines.push(format!("[{}]   {} PR: ok", now, key));
-            } else {
-                lines.push(format!("[{}]   {} PR: {}", now, key, problems.join(", ")));
-            }
-        }
-    }
-
-    for spawned in &tick.auto_spawned {
-        lines.push(format!("[{}]   auto-spawned: {}", now, spawned));
-    }
-
-    for pruned in &tick.pruned {
-        lines.push(format!("[{}]   pruned: {}", now, pruned));
-    }
-
-    for err in &tick.errors {
-        lines.push(format!("[{}]   error: {}", now, err));
-    }
-
-    lines
-}
-
-/// Run the watch loop: display + orchestrate via daemon::run_with.
-fn run_watch(
-    interval: u64,
-    runtime_config: RuntimeConfig,
-    repo_filter: Option<String>,
-    global: bool,
-) {
-    let daemon_config = DaemonConfig {
-        interval_seconds: interval,
-        once: false,
-        skip_sync: false,
-        repo_filter,
-        ..Default::default()
-    };
-
-    // Shared state for the callback
-    let mut view_mode = ViewMode::Table;
-    let mut log_buffer: VecDeque<String> = VecDeque::with_capacity(LOG_BUFFER_SIZE);
-
-    // Enable raw mode for keypress detection
-    terminal::enable_raw_mode().ok();
-
-    // Clear screen once on first render
-    eprint!("\x1B[2J");
-
-    // Spawn a dedicated key-polling thread. It continuously reads crossterm events
-    // and sets `quit` when q/Esc/Ctrl-C is pressed. This runs DURING ticks too,
-    // so 'q' pressed mid-tick is caught immediately rather than after the tick finishes.
-    // Toggle keys (l/t) are stored in a separate flag for the callback to pick up.
-    let quit_for_thread = Arc::new(AtomicBool::new(false));
-    let toggle_flag = Arc::new(AtomicBool::new(false));
-    {
-        let quit_bg = Arc::clone(&quit_for_thread);
-        let toggle_bg = Arc::clone(&toggle_flag);
-        std::thread::spawn(move || {
-            while !quit_bg.load(Ordering::Relaxed) {
-                if !event::poll(std::time::Duration::from_millis(50)).unwrap_or(false) {
-                    continue;
-                }
-                if let Ok(Event::Key(KeyEvent {
-                    code, modifiers, ..
-                })) = event::read()
-                {
-                    match code {
-                        KeyCode::Char('q') | KeyCode::Esc => {
-                            quit_bg.store(true, Ordering::Relaxed);
-                            return;
-                        }
-                        KeyCode::Char('c')
-                            if modifiers.contains(crossterm::event::KeyModifiers::CONTROL) =>
-                        {
-                            quit_bg.store(true, Ordering::Relaxed);
-                            return;
-                        }
-                        KeyCode::Char('l') | KeyCode::Char('t') => {
-                            toggle_bg.store(true, Ordering::Relaxed);
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        });
-    }
-
-    let result = jig_core::daemon::run_with(&daemon_config, runtime_config, |tick, quit| {
-        // The background thread sets quit_for_thread; propagate to the daemon's quit flag
-        if quit_for_thread.load(Ordering::Relaxed) {
-            quit.store(true, Ordering::Relaxed);
-            return false;
-        }
-
-        // Check for toggle
-        if toggle_flag.swap(false, Ordering::Relaxed) {
-            view_mode.toggle();
-        }
-
-        // Append log entries
-        for line in format_tick_log(tick) {
-            if log_buffer.len() >= LOG_BUFFER_SIZE {
-                log_buffer.pop_front();
-            }
-            log_buffer.push_back(line);
-        }
-
-        let render = |view: &ViewMode, tick: &TickResult, logs: &VecDeque<String>| {
-            eprint!("\x1B[H");
-            match view {
-                ViewMode::Table => {
-                    let table_output = if global {
-                        ui::render_worker_table_grouped(&tick.worker_display, true)
-                    } else {
-                        ui::render_worker_table(&tick.worker_display, true).to_string()
-                    };
-                    let triage_output = if global {
-                        ui::render_triage_section_grouped(&tick.triage_display, true)
-                    } else {
-                        ui::render_triage_section(&tick.triage_display, true)
-                    };
-                    let status_line = format_tick_status(&Some(tick));
-                    let triage_count = if tick.triage_display.is_empty() {
-                        String::new()
-                    } else {
-                        format!(", {} triages", tick.triage_display.len())
-                    };
-                    let spawning_section = if tick.spawning.is_empty() {
-                        String::new()
-                    } else {
-                        let names: Vec<&str> = tick.spawning.iter().map(|s| s.as_str()).collect();
-                        format!(
-                            "\n\x1B[2mspawning:\x1B[0m \x1B[33m{}\x1B[0m\n",
-                            names.join(", ")
-                        )
-                    };
-                    let nudge_section = format_nudge_messages(&tick.nudge_messages);
-                    let timer_section = format_timer_info(&tick.timer_info);
-                    let triage_section = if triage_output.is_empty() {
-                        String::new()
-                    } else {
-                        format!("\n{triage_output}\n")
-                    };
-                    let output = format!(
-                        "\x1B[1mjig ps --watch\x1B[0m — {} workers{triage_count}  \x1B[2m(every {}s)\x1B[0m{status_line}\n\n{table_output}{triage_section}{spawning_section}{nudge_section}\n\x1B[2m[l]ogs  [q]uit{timer_section}\x1B[0m",
-                        tick.worker_display.len(), interval,
-                    );
-                    for line in output.lines() {
-                        eprint!("{}\x1B[K\r\n", line);
-                    }
-                }
-                ViewMode::Logs => {
-                    eprint!(
-                        "\x1B[1mjig ps --watch\x1B[0m — logs  \x1B[2m(every {}s)\x1B[0m\x1B[K\r\n",
-                        interval
-                    );
-                    eprint!("\x1B[K\r\n");
-                    for line in logs {
-                        eprint!("{}\x1B[K\r\n", line);
-                    }
-                    eprint!("\x1B[K\r\n");
-                    eprint!("\x1B[2m[t]able  [q]uit\x1B[0m\x1B[K\r\n");
-                }
-            }
-            eprint!("\x1B[J");
-        };
-
-        render(&view_mode, tick, &log_buffer);
-
-        // Sleep interval — the background thread handles all key polling,
-        // so we just need to wait and check for quit/toggle periodically.
-        let sleep_end = Instant::now() + std::time::Duration::from_secs(interval);
-        while Instant::now() < sleep_end {
-            if quit_for_thread.load(Ordering::Relaxed) {
-                quit.store(true, Ordering::Relaxed);
-                return false;
-            }
-            if toggle_flag.swap(false, Ordering::Relaxed) {
-                view_mode.toggle();
-                render(&view_mode, tick, &log_buffer);
-                continue;
-            }
-            std::thread::sleep(std::time::Duration::from_millis(50));
-        }
-
-        true // keep looping
-    });
-
-    disable_raw_mode().ok();
-
-    match result {
-        Ok(_) => {}
-        Err(e) => eprintln!("daemon error: {}", e),
-    }
-}
-
-/// Format the daemon tick result as a compact status suffix.
-fn format_tick_status(tick: &Option<&TickResult>) -> String {
-    let Some(tick) = tick else {
-        return String::new();
-    };
-    let mut parts = vec![];
-    if tick.nudges_sent > 0 {
-        parts.push(format!(
-            "{} nudge{}",
-            tick.nudges_sent,
-            if tick.nudges_sent == 1 { "" } else { "s" }
-        ));
-    }
-    if tick.notifications_sent > 0 {
-        parts.push(format!("{} notify", tick.notifications_sent));
-    }
-    if !tick.errors.is_empty() {
-        parts.push(format!(
-            "{} err{}",
-            tick.errors.len(),
-            if tick.errors.len() == 1 { "" } else { "s" }
-        ));
-    }
-    if !tick.auto_spawned.is_empty() {
-        parts.push(format!("{} spawned", tick.auto_spawned.len()));
-    }
-    if !tick.spawning.is_empty() {
-        parts.push(format!("spawning {}", tick.spawning.len()));
-    }
-    if !tick.pruned.is_empty() {
-        parts.push(format!("{} pruned", tick.pruned.len()));
-    }
-    if parts.is_empty() {
-        String::new()
-    } else {
-        format!("  \x1B[2m[{}]\x1B[0m", parts.join(", "))
-    }
-}
-
-/// Format nudge messages delivered this tick for display below the worker table.
-fn format_nudge_messages(messages: &[(String, String, String)]) -> String {
-    if messages.is_empty() {
-        return String::new();
-    }
-
-    let term_width = terminal::size().map(|(w, _)| w as usize).unwrap_or(120);
-    let mut lines = Vec::new();
-    for (worker, ntype, msg) in messages {
-        let prefix = format!("  \u{21b3} {} [{}]: ", worker, ntype);
-        let max_msg_len = term_width.saturating_sub(prefix.len() + 1);
-        let truncated = ui::truncate(msg, max_msg_len);
-        lines.push(format!(
-            "\x1B[2m  \u{21b3} {} [{}]:\x1B[0m {}",
-            worker, ntype, truncated
-        ));
-    }
-    format!("\n{}\n", lines.join("\n"))
+/// The running daemon, if one is serving its control socket. `jig ps` follows
+/// it rather than orchestrating the same workers alongside it.
+fn running_daemon() -> Option<ControlClient> {
+    service::running_pid(&jig_core::global::daemon_pid_path().ok()?)?;
+    let client = ControlClient::global().ok()?;
+    client.is_running().then_some(client)
 }
 
-/// Format timer info for the footer line.
-fn format_timer_info(timer: &Option<TimerInfo>) -> String {
-    let Some(timer) = timer else {
-        return String::new();
-    };
-    let mut parts = vec![format!(
-        "sync: {}",
-        ui::format_duration_short(timer.sync_remaining)
-    )];
-    if let Some(poll) = timer.poll_remaining {
-        parts.push(format!("poll: {}", ui::format_duration_short(poll)));
+/// Drop workers and triages outside `repo`. A daemon's ticks cover every repo.
+fn retain_repo(tick: &mut TickResult, repo: Option<&str>) {
+    if let Some(repo) = repo {
+        tick.worker_display.retain(|w| w.repo == repo);
+        tick.triage_display.retain(|t| t.repo_name == repo);
     }
-    format!("  {}", parts.join("  "))
 }
@@ -0,0 +1,1303 @@
+//! Dashboard — the full-screen `jig ps --watch` view
+//!
+//! Each daemon tick refreshes the worker list. Between ticks the dashboard
+//! reads keys: move the selection, see the selected worker's recent events
+//! and latest review, and attach, message, kill, resume, merge or open its PR.
+
+use std::collections::VecDeque;
+use std::io;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event as TermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::{Frame, Terminal};
+
+use jig_core::daemon::control::{ControlClient, ControlRequest};
+use jig_core::daemon::{
+    DaemonConfig, RuntimeConfig, TickResult, TimerInfo, TriageDisplayInfo, WorkerDisplayInfo,
+};
+use jig_core::git::Repo;
+use jig_core::land::{LandError, Landing, Note, Strategy};
+use jig_core::review::{load_reviews, Review, ReviewStatus, ReviewVerdict};
+use jig_core::{
+    session, spawn, Error, Event, EventLog, RepoContext, TmuxClient, TmuxTarget, Worktree,
+};
+
+use crate::ui;
+
+const LOG_BUFFER_SIZE: usize = 50;
+
+/// Events shown for the selected worker.
+const RECENT_EVENTS: usize = 20;
+
+/// Run the dashboard until the user quits.
+///
+/// With `jig daemon` running, the dashboard follows its ticks over the
+/// control socket and sends actions to it; otherwise it orchestrates itself
+/// via `daemon::run_with`. `repos` are the repos whose workers can be acted
+/// on. Attaching from outside tmux leaves the dashboard, since `tmux attach`
+/// takes over the terminal.
+pub(super) fn run(
+    interval: u64,
+    runtime_config: RuntimeConfig,
+    repo_filter: Option<String>,
+    global: bool,
+    repos: &[&RepoContext],
+) {
+    let daemon = super::running_daemon();
+    // The list refreshes on the daemon's ticks, not ours.
+    let interval = daemon
+        .as_ref()
+        .and_then(|d| d.request(&ControlRequest::Status).ok())
+        .and_then(|r| r.status)
+        .map(|s| s.interval_seconds)
+        .unwrap_or(interval);
+    let mut dashboard = Dashboard::new(interval, global);
+
+    let result = ui::with_alternate_screen(|w| -> jig_core::Result<Option<WorkerKey>> {
+        let mut terminal = Terminal::new(CrosstermBackend::new(w))?;
+        terminal.hide_cursor()?;
+
+        // Keys are read on their own thread so presses made mid-tick are
+        // queued rather than lost.
+        let stop = Arc::new(AtomicBool::new(false));
+        let (tx, inputs) = mpsc::channel();
+        read_keys(Arc::clone(&stop), tx.clone());
+
+        let mut screen = Screen {
+            terminal,
+            inputs,
+            repos,
+            daemon: daemon.as_ref(),
+            attach_to: None,
+        };
+        let result = match daemon {
+            Some(ref daemon) => {
+                follow_ticks(daemon, repo_filter, tx)?;
+                match screen.interact(&mut dashboard, None) {
+                    Flow::Closed => Err(Error::Custom("daemon stopped".to_string())),
+                    _ => Ok(()),
+                }
+            }
+            None => {
+                drop(tx);
+                let daemon_config = DaemonConfig {
+                    interval_seconds: interval,
+                    once: false,
+                    skip_sync: false,
+                    repo_filter,
+                    ..Default::default()
+                };
+                jig_core::daemon::run_with(&daemon_config, runtime_config, |tick, quit| {
+                    dashboard.update(tick);
+                    let deadline = Instant::now() + Duration::from_secs(interval);
+                    match screen.interact(&mut dashboard, Some(deadline)) {
+                        Flow::Tick => true,
+                        Flow::Quit | Flow::Closed => {
+                            quit.store(true, Ordering::Relaxed);
+                            false
+                        }
+                    }
+                })
+                .map(|_| ())
+            }
+        };
+
+        stop.store(true, Ordering::Relaxed);
+        screen.terminal.show_cursor()?;
+        result.map(|_| screen.attach_to)
+    });
+
+    let attach_to = match result {
+        Ok(attach_to) => attach_to,
+        Err(e) => {
+            eprintln!("daemon error: {}", e);
+            return;
+        }
+    };
+
+    if let Some(worker) = attach_to {
+        let attached =
+            find_repo(repos, &worker.repo).and_then(|repo| spawn::attach(repo, Some(&worker.name)));
+        if let Err(e) = attached {
+            ui::failure(&e.to_string());
+        }
+    }
+}
+
+/// What the dashboard waits on: key presses, and the daemon's ticks when
+/// following one.
+enum Input {
+    Term(TermEvent),
+    Tick(Box<TickResult>),
+    /// The followed daemon closed the connection.
+    Closed,
+}
+
+/// Why the dashboard stopped waiting for input.
+#[derive(Debug, PartialEq, Eq)]
+enum Flow {
+    /// The refresh interval is up.
+    Tick,
+    Quit,
+    Closed,
+}
+
+/// Forward terminal events to `tx` until `stop` is set.
+fn read_keys(stop: Arc<AtomicBool>, tx: mpsc::Sender<Input>) {
+    std::thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) {
+            if !event::poll(Duration::from_millis(50)).unwrap_or(false) {
+                continue;
+            }
+            if let Ok(ev) = event::read() {
+                if tx.send(Input::Term(ev)).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// Forward the daemon's ticks to `tx`, starting with one run now.
+fn follow_ticks(
+    daemon: &ControlClient,
+    repo_filter: Option<String>,
+    tx: mpsc::Sender<Input>,
+) -> jig_core::Result<()> {
+    let mut subscription = daemon.open_subscription()?;
+    daemon.request(&ControlRequest::Tick)?.into_result()?;
+    std::thread::spawn(move || {
+        while let Ok(Some(mut tick)) = subscription.next_tick() {
+            super::retain_repo(&mut tick, repo_filter.as_deref());
+            if tx.send(Input::Tick(Box::new(tick))).is_err() {
+                return;
+            }
+        }
+        let _ = tx.send(Input::Closed);
+    });
+    Ok(())
+}
+
+/// The terminal and what the dashboard's keys act through.
+struct Screen<'a, B: Backend> {
+    terminal: Terminal<B>,
+    inputs: mpsc::Receiver<Input>,
+    repos: &'a [&'a RepoContext],
+    /// The daemon being followed, if any.
+    daemon: Option<&'a ControlClient>,
+    /// Worker to attach to once the dashboard has left the screen.
+    attach_to: Option<WorkerKey>,
+}
+
+impl<B: Backend> Screen<'_, B> {
+    /// Draw and handle input until `deadline`, or with none until the user
+    /// quits. Ticks from a followed daemon refresh the list as they arrive.
+    fn interact(&mut self, dashboard: &mut Dashboard, deadline: Option<Instant>) -> Flow {
+        loop {
+            dashboard.load_detail(self.repos);
+            if self.terminal.draw(|frame| dashboard.draw(frame)).is_err() {
+                return Flow::Quit;
+            }
+
+            let input = match deadline {
+                Some(deadline) => self
+                    .inputs
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now())),
+                None => self
+                    .inputs
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            let key = match input {
+                Ok(Input::Term(TermEvent::Key(key))) if key.kind == KeyEventKind::Press => key,
+                Ok(Input::Term(_)) => continue,
+                Ok(Input::Tick(tick)) => {
+                    dashboard.update(&tick);
+                    continue;
+                }
+                Ok(Input::Closed) => return Flow::Closed,
+                Err(RecvTimeoutError::Timeout) => return Flow::Tick,
+                Err(RecvTimeoutError::Disconnected) => return Flow::Quit,
+            };
+
+            match dashboard.handle_key(key) {
+                Step::Continue => {}
+                Step::Quit => return Flow::Quit,
+                Step::Act(Action::Attach(worker)) if std::env::var_os("TMUX").is_none() => {
+                    self.attach_to = Some(worker);
+                    return Flow::Quit;
+                }
+                Step::Act(action) => {
+                    let outcome = perform(&action, self.repos, self.daemon);
+                    dashboard.finish(outcome);
+                    // Subprocesses may have written over the screen.
+                    let _ = self.terminal.clear();
+                }
+            }
+        }
+    }
+}
+
+/// A worker, as the daemon names it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WorkerKey {
+    repo: String,
+    name: String,
+}
+
+impl WorkerKey {
+    fn of(worker: &WorkerDisplayInfo) -> Self {
+        Self {
+            repo: worker.repo.clone(),
+            name: worker.name.clone(),
+        }
+    }
+
+    /// `repo/worker`, as control requests name it.
+    fn spec(&self) -> String {
+        format!("{}/{}", self.repo, self.name)
+    }
+}
+
+/// Something to do to a worker, chosen from the keyboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Attach(WorkerKey),
+    Message(WorkerKey, String),
+    Kill(WorkerKey),
+    Resume(WorkerKey),
+    Merge {
+        worker: WorkerKey,
+        strategy: Strategy,
+        cleanup: bool,
+    },
+    OpenPr(String),
+}
+
+/// What the loop does after a key press.
+#[derive(Debug, PartialEq, Eq)]
+enum Step {
+    Continue,
+    Quit,
+    Act(Action),
+}
+
+/// What the keyboard is currently doing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    /// Typing a message for the worker.
+    Message(WorkerKey, String),
+    /// Waiting for `y` before a destructive action.
+    Confirm(Action),
+    /// Choosing how to merge the worker, and whether to clean it up.
+    Merge(WorkerKey, bool),
+}
+
+/// View mode for the watch display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewMode {
+    Table,
+    Logs,
+}
+
+impl ViewMode {
+    fn toggle(&mut self) {
+        *self = match self {
+            ViewMode::Table => ViewMode::Logs,
+            ViewMode::Logs => ViewMode::Table,
+        };
+    }
+}
+
+/// Recent history of the selected worker, read from disk on selection.
+#[derive(Debug, Default)]
+struct Detail {
+    worker: Option<WorkerKey>,
+    events: Vec<Event>,
+    review: Option<(usize, Review)>,
+}
+
+struct Dashboard {
+    interval: u64,
+    global: bool,
+    view: ViewMode,
+    mode: Mode,
+    workers: Vec<WorkerDisplayInfo>,
+    triages: Vec<TriageDisplayInfo>,
+    spawning: Vec<String>,
+    nudges: Vec<(String, String, String)>,
+    timer: Option<TimerInfo>,
+    status: String,
+    table: TableState,
+    detail: Detail,
+    logs: VecDeque<String>,
+    /// Result of the last action: (message, is_error).
+    flash: Option<(String, bool)>,
+}
+
+impl Dashboard {
+    fn new(interval: u64, global: bool) -> Self {
+        Self {
+            interval,
+            global,
+            view: ViewMode::Table,
+            mode: Mode::Normal,
+            workers: vec![],
+            triages: vec![],
+            spawning: vec![],
+            nudges: vec![],
+            timer: None,
+            status: String::new(),
+            table: TableState::default(),
+            detail: Detail::default(),
+            logs: VecDeque::with_capacity(LOG_BUFFER_SIZE),
+            flash: None,
+        }
+    }
+
+    /// Take in a tick, keeping the same worker selected if it is still there.
+    fn update(&mut self, tick: &TickResult) {
+        let selected = self.selected().map(WorkerKey::of);
+        self.workers.clone_from(&tick.worker_display);
+        self.triages.clone_from(&tick.triage_display);
+        self.spawning.clone_from(&tick.spawning);
+        self.nudges.clone_from(&tick.nudge_messages);
+        self.timer.clone_from(&tick.timer_info);
+        self.status = format_tick_status(tick);
+
+        let index = selected
+            .and_then(|key| self.workers.iter().position(|w| WorkerKey::of(w) == key))
+            .or_else(|| self.table.selected())
+            .map(|i| i.min(self.workers.len().saturating_sub(1)));
+        self.table.select(if self.workers.is_empty() {
+            None
+        } else {
+            index.or(Some(0))
+        });
+        // Events and reviews may have moved on since the last read.
+        self.detail.worker = None;
+
+        for line in format_tick_log(tick) {
+            if self.logs.len() >= LOG_BUFFER_SIZE {
+                self.logs.pop_front();
+            }
+            self.logs.push_back(line);
+        }
+    }
+
+    fn selected(&self) -> Option<&WorkerDisplayInfo> {
+        self.table.selected().and_then(|i| self.workers.get(i))
+    }
+
+    fn select(&mut self, index: usize) {
+        if !self.workers.is_empty() {
+            self.table.select(Some(index.min(self.workers.len() - 1)));
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Step {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Step::Quit;
+        }
+        self.flash = None;
+
+        match std::mem::replace(&mut self.mode, Mode::Normal) {
+            Mode::Normal => self.handle_normal_key(key),
+            Mode::Message(worker, mut text) => {
+                match key.code {
+                    KeyCode::Esc => {}
+                    KeyCode::Enter if !text.trim().is_empty() => {
+                        return Step::Act(Action::Message(worker, text.trim().to_string()));
+                    }
+                    KeyCode::Enter => {}
+                    KeyCode::Backspace => {
+                        text.pop();
+                        self.mode = Mode::Message(worker, text);
+                    }
+                    KeyCode::Char(c) => {
+                        text.push(c);
+                        self.mode = Mode::Message(worker, text);
+                    }
+                    _ => self.mode = Mode::Message(worker, text),
+                }
+                Step::Continue
+            }
+            Mode::Confirm(action) => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => Step::Act(action),
+                _ => Step::Continue,
+            },
+            Mode::Merge(worker, cleanup) => {
+                let strategy = match key.code {
+                    KeyCode::Char('m') | KeyCode::Char('y') => Strategy::Merge,
+                    KeyCode::Char('s') => Strategy::Squash,
+                    KeyCode::Char('r') => Strategy::Rebase,
+                    KeyCode::Char('p') => Strategy::Pr,
+                    KeyCode::Char('c') => {
+                        self.mode = Mode::Merge(worker, !cleanup);
+                        return Step::Continue;
+                    }
+                    _ => return Step::Continue,
+                };
+                Step::Act(Action::Merge {
+                    worker,
+                    strategy,
+                    cleanup,
+                })
+            }
+        }
+    }
+
+    fn handle_normal_key(&mut self, key: KeyEvent) -> Step {
+        let selected = self.table.selected().unwrap_or(0);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Step::Quit,
+            KeyCode::Char('j') | KeyCode::Down => self.select(selected + 1),
+            KeyCode::Char('k') | KeyCode::Up => self.select(selected.saturating_sub(1)),
+            KeyCode::Char('g') | KeyCode::Home => self.select(0),
+            KeyCode::Char('G') | KeyCode::End => self.select(usize::MAX),
+            KeyCode::Char('l') | KeyCode::Char('t') => self.view.toggle(),
+            _ => {}
+        }
+
+        let Some(worker) = self.selected() else {
+            return Step::Continue;
+        };
+        let key_of = WorkerKey::of(worker);
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('a') => Step::Act(Action::Attach(key_of)),
+            KeyCode::Char('m') => {
+                self.mode = Mode::Message(key_of, String::new());
+                Step::Continue
+            }
+            KeyCode::Char('x') => {
+                self.mode = Mode::Confirm(Action::Kill(key_of));
+                Step::Continue
+            }
+            KeyCode::Char('r') => Step::Act(Action::Resume(key_of)),
+            KeyCode::Char('M') => {
+                self.mode = Mode::Merge(key_of, false);
+                Step::Continue
+            }
+            KeyCode::Char('o') => match &worker.pr_url {
+                Some(url) => Step::Act(Action::OpenPr(url.clone())),
+                None => {
+                    self.flash = Some((format!("'{}' has no PR", key_of.name), true));
+                    Step::Continue
+                }
+            },
+            _ => Step::Continue,
+        }
+    }
+
+    /// Show the outcome of an action until the next key press.
+    fn finish(&mut self, outcome: jig_core::Result<String>) {
+        self.flash = Some(match outcome {
+            Ok(message) => (message, false),
+            Err(e) => (e.to_string(), true),
+        });
+        self.detail.worker = None;
+    }
+
+    /// Read the selected worker's events and reviews, if not already loaded.
+    fn load_detail(&mut self, repos: &[&RepoContext]) {
+        let selected = self.selected().map(WorkerKey::of);
+        if selected == self.detail.worker {
+            return;
+        }
+        self.detail = Detail::default();
+        let Some(worker) = selected else {
+            return;
+        };
+
+        if let Ok(log) = EventLog::for_worker(&worker.repo, &worker.name) {
+            let events = log.read_all().unwrap_or_default();
+            let skip = events.len().saturating_sub(RECENT_EVENTS);
+            self.detail.events = events.into_iter().skip(skip).collect();
+        }
+        if let Ok(repo) = find_repo(repos, &worker.repo) {
+            let reviews = load_reviews(&repo.worktrees_dir.join(&worker.name));
+            let round = reviews.len();
+            self.detail.review = reviews.into_iter().last().map(|r| (round, r));
+        }
+        self.detail.worker = Some(worker);
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(Paragraph::new(self.header_line()), header);
+        match self.view {
+            ViewMode::Table => self.draw_table_view(frame, body),
+            ViewMode::Logs => {
+                let height = body.height.saturating_sub(2) as usize;
+                let skip = self.logs.len().saturating_sub(height);
+                let lines: Vec<Line> = self
+                    .logs
+                    .iter()
+                    .skip(skip)
+                    .map(|l| Line::raw(l.as_str()))
+                    .collect();
+                frame.render_widget(
+                    Paragraph::new(lines).block(Block::bordered().title(" logs ")),
+                    body,
+                );
+            }
+        }
+        frame.render_widget(Paragraph::new(self.footer_line()), footer);
+    }
+
+    fn header_line(&self) -> Line<'_> {
+        let mut title = format!("{} workers", self.workers.len());
+        if !self.triages.is_empty() {
+            title.push_str(&format!(", {} triages", self.triages.len()));
+        }
+        let mut spans = vec![
+            Span::styled("jig ps --watch", Style::new().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" — {}  ", title)),
+            Span::styled(format!("(every {}s)", self.interval), dim()),
+        ];
+        if !self.status.is_empty() {
+            spans.push(Span::styled(format!("  [{}]", self.status), dim()));
+        }
+        Line::from(spans)
+    }
+
+    fn footer_line(&self) -> Line<'_> {
+        match &self.mode {
+            Mode::Message(worker, text) => Line::from(vec![
+                Span::styled(format!("message {}: ", worker.name), bold()),
+                Span::raw(format!("{}█", text)),
+                Span::styled("  (enter send, esc cancel)", dim()),
+            ]),
+            Mode::Confirm(action) => Line::from(vec![
+                Span::styled(format!("{}? ", describe(action)), bold()),
+                Span::styled("(y/n)", dim()),
+            ]),
+            Mode::Merge(worker, cleanup) => Line::from(vec![
+                Span::styled(format!("merge {}: ", worker.name), bold()),
+                Span::raw("[m]erge  [s]quash  [r]ebase  [p]r  "),
+                Span::raw(format!("[c]leanup {}", if *cleanup { "on" } else { "off" })),
+                Span::styled("  (esc cancel)", dim()),
+            ]),
+            Mode::Normal => match &self.flash {
+                Some((message, true)) => Line::styled(message.as_str(), Color::Red),
+                Some((message, false)) => Line::styled(message.as_str(), Color::Green),
+                None => {
+                    let keys = match self.view {
+                        ViewMode::Table => {
+                            "[↑↓] select  [⏎] attach  [m]essage  [x] kill  [r]esume  [M]erge  [o]pen PR  [l]ogs  [q]uit"
+                        }
+                        ViewMode::Logs => "[t]able  [q]uit",
+                    };
+                    Line::styled(format!("{}{}", keys, format_timer_info(&self.timer)), dim())
+                }
+            },
+        }
+    }
+
+    fn draw_table_view(&mut self, frame: &mut Frame, area: Rect) {
+        // Rendering updates the scroll offset, so hold the state apart from
+        // the rows that borrow `self`.
+        let mut state = std::mem::take(&mut self.table);
+        let extra = self.extra_lines();
+        let table_height = (self.workers.len().max(1) as u16 + 3)
+            .min(area.height / 2)
+            .max(4);
+        let [table_area, detail_area, extra_area] = Layout::vertical([
+            Constraint::Length(table_height),
+            Constraint::Min(0),
+            Constraint::Length(extra.len() as u16),
+        ])
+        .areas(area);
+
+        if self.workers.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Line::styled("No spawned sessions", dim())).block(Block::bordered()),
+                table_area,
+            );
+        } else {
+            let table = self.worker_table();
+            frame.render_stateful_widget(table, table_area, &mut state);
+        }
+
+        let [events_area, review_area] =
+            Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+                .areas(detail_area);
+        let name = self
+            .detail
+            .worker
+            .as_ref()
+            .map(|w| w.name.as_str())
+            .unwrap_or("-");
+        frame.render_widget(
+            Paragraph::new(self.event_lines(events_area.height.saturating_sub(2) as usize))
+                .block(Block::bordered().title(format!(" {} — events ", name))),
+            events_area,
+        );
+        frame.render_widget(
+            Paragraph::new(self.review_lines())
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered().title(" review ")),
+            review_area,
+        );
+
+        frame.render_widget(Paragraph::new(extra), extra_area);
+        self.table = state;
+    }
+
+    fn worker_table(&self) -> Table<'_> {
+        let mut headers: Vec<&str> = ui::WORKER_COLUMNS.to_vec();
+        if self.global {
+            headers.insert(0, "REPO");
+        }
+        let rows: Vec<Vec<(String, Color)>> = self
+            .workers
+            .iter()
+            .map(|w| {
+                let mut columns: Vec<(String, Color)> = ui::worker_columns(w)
+                    .into_iter()
+                    .map(|(text, color)| (text, tui_color(color)))
+                    .collect();
+                if self.global {
+                    columns.insert(0, (w.repo.clone(), Color::Reset));
+                }
+                columns
+            })
+            .collect();
+
+        let widths: Vec<Constraint> = headers
+            .iter()
+            .enumerate()
+            .map(|(i, h)| {
+                let width = rows
+                    .iter()
+                    .map(|r| r[i].0.chars().count())
+                    .chain([h.len()])
+                    .max()
+                    .unwrap_or(0);
+                Constraint::Length(width as u16)
+            })
+            .collect();
+
+        Table::new(
+            rows.into_iter().map(|r| {
+                Row::new(
+                    r.into_iter()
+                        .map(|(text, color)| Span::styled(text, Style::new().fg(color))),
+                )
+            }),
+            widths,
+        )
+        .header(Row::new(headers).style(bold()))
+        .column_spacing(2)
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .block(Block::bordered())
+    }
+
+    fn event_lines(&self, height: usize) -> Vec<Line<'_>> {
+        if self.detail.events.is_empty() {
+            return vec![Line::styled("No events", dim())];
+        }
+        let skip = self.detail.events.len().saturating_sub(height);
+        self.detail
+            .events
+            .iter()
+            .skip(skip)
+            .map(|event| {
+                Line::from(vec![
+                    Span::styled(format_time(event.ts), dim()),
+                    Span::raw(format!(" {:<14}", event.event_type.as_str())),
+                    Span::styled(format_fields(event), dim()),
+                ])
+            })
+            .collect()
+    }
+
+    fn review_lines(&self) -> Vec<Line<'_>> {
+        let Some((round, review)) = &self.detail.review else {
+            return vec![Line::styled("No reviews yet", dim())];
+        };
+        let verdict_color = match review.verdict {
+            ReviewVerdict::Approve => Color::Green,
+            ReviewVerdict::ChangesRequested => Color::Yellow,
+        };
+        let mut lines = vec![Line::from(vec![
+            Span::raw(format!("#{} ", round)),
+            Span::styled(review.verdict.to_string(), verdict_color),
+            Span::styled(format!("  {}", format_time(review.timestamp)), dim()),
+        ])];
+
+        for section in &review.sections {
+            for finding in &section.findings {
+                if finding.severity == ReviewStatus::Pass {
+                    continue;
+                }
+                let color = match finding.severity {
+                    ReviewStatus::Fail => Color::Red,
+                    _ => Color::Yellow,
+                };
+                let location = match (&finding.file, finding.line) {
+                    (Some(file), Some(line)) => format!(" {}:{}", file, line),
+                    (Some(file), None) => format!(" {}", file),
+                    _ => String::new(),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("[{}]", finding.severity.tag()), color),
+                    Span::styled(format!(" {}{}", section.category.as_str(), location), dim()),
+                    Span::raw(format!(" {}", finding.message)),
+                ]));
+            }
+        }
+
+        if !review.summary.is_empty() {
+            lines.push(Line::raw(""));
+            lines.extend(review.summary.lines().map(Line::raw));
+        }
+        lines
+    }
+
+    /// Triages, spawning workers and this tick's nudges, below the detail pane.
+    fn extra_lines(&self) -> Vec<Line<'_>> {
+        let mut lines = vec![];
+        for t in &self.triages {
+            let mut text = format!(
+                "triage {} ({}) {}",
+                t.issue_id,
+                t.model,
+                ui::format_duration_short(t.elapsed_secs)
+            );
+            if self.global {
+                text.push_str(&format!("  {}", t.repo_name));
+            }
+            lines.push(Line::styled(text, Color::Cyan));
+        }
+        if !self.spawning.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("spawning: ", dim()),
+                Span::styled(self.spawning.join(", "), Color::Yellow),
+            ]));
+        }
+        for (worker, nudge_type, message) in &self.nudges {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  \u{21b3} {} [{}]: ", worker, nudge_type), dim()),
+                Span::raw(message.replace('\n', " ")),
+            ]));
+        }
+        lines
+    }
+}
+
+fn describe(action: &Action) -> String {
+    match action {
+        Action::Kill(w) => format!("Kill '{}'", w.name),
+        Action::Merge { worker, .. } => format!("Merge '{}'", worker.name),
+        Action::Attach(w) => format!("Attach to '{}'", w.name),
+        Action::Message(w, _) => format!("Message '{}'", w.name),
+        Action::Resume(w) => format!("Resume '{}'", w.name),
+        Action::OpenPr(url) => format!("Open {}", url),
+    }
+}
+
+/// Carry out an action, returning a line for the footer.
+fn perform(
+    action: &Action,
+    repos: &[&RepoContext],
+    daemon: Option<&ControlClient>,
+) -> jig_core::Result<String> {
+    // A followed daemon acts on its workers itself.
+    if let (Some(daemon), Some(request)) = (daemon, control_request(action)) {
+        let response = daemon.request(&request)?.into_result()?;
+        return Ok(response.message.unwrap_or_default());
+    }
+    match action {
+        Action::OpenPr(url) => {
+            open_url(url)?;
+            Ok(format!("Opened {}", url))
+        }
+        Action::Attach(w) => {
+            let repo = find_repo(repos, &w.repo)?;
+            session::switch_client(&repo.session_name, &w.name)?;
+            Ok(format!("Switched to '{}'", w.name))
+        }
+        Action::Message(w, text) => {
+            let repo = find_repo(repos, &w.repo)?;
+            TmuxClient::new().send_message(&TmuxTarget::new(&repo.session_name, &w.name), text)?;
+            Ok(format!("Sent message to '{}'", w.name))
+        }
+        Action::Kill(w) => {
+            let repo = find_repo(repos, &w.repo)?;
+            spawn::kill_window(repo, &w.name)?;
+            spawn::unregister(repo, &w.name)?;
+            Ok(format!("Killed '{}'", w.name))
+        }
+        Action::Resume(w) => {
+            let repo = find_repo(repos, &w.repo)?;
+            let wt = Worktree::open(&repo.repo_root, &repo.worktrees_dir, &w.name)?;
+            if wt.has_tmux_window() {
+                return Err(Error::Custom(format!(
+                    "Worker '{}' already running — attach instead",
+                    w.name
+                )));
+            }
+            let context =
+                jig_core::daemon::recovery::RecoveryScanner::read_spawn_context(&w.repo, &w.name);
+            wt.resume(context.as_deref())?;
+            Ok(format!("Resumed '{}'", w.name))
+        }
+        Action::Merge {
+            worker,
+            strategy,
+            cleanup,
+        } => {
+            let repo = find_repo(repos, &worker.repo)?;
+            let landing = Landing {
+                name: worker.name.clone(),
+                strategy: *strategy,
+                message: None,
+                cleanup: *cleanup,
+            };
+            let notes = landing
+                .land(repo, &Repo::open(&repo.repo_root)?)
+                .map_err(|e| match e {
+                    LandError::Core(e) => e,
+                    e => Error::Custom(e.to_string()),
+                })?;
+            let lines: Vec<String> = notes
+                .into_iter()
+                .map(|note| match note {
+                    Note::Success(line) | Note::Warning(line) => line,
+                })
+                .collect();
+            Ok(lines.join("; "))
+        }
+    }
+}
+
+/// The control request that has a followed daemon carry out `action`, for
+/// actions that change its workers.
+fn control_request(action: &Action) -> Option<ControlRequest> {
+    match action {
+        Action::Message(w, text) => Some(ControlRequest::Nudge {
+            worker: w.spec(),
+            message: Some(text.clone()),
+        }),
+        Action::Kill(w) => Some(ControlRequest::Kill { worker: w.spec() }),
+        Action::Resume(w) => Some(ControlRequest::ResumeWorker { worker: w.spec() }),
+        Action::Merge {
+            worker,
+            strategy,
+            cleanup,
+        } => Some(ControlRequest::Merge {
+            worker: worker.spec(),
+            strategy: *strategy,
+            cleanup: *cleanup,
+        }),
+        Action::Attach(_) | Action::OpenPr(_) => None,
+    }
+}
+
+/// The repo a worker belongs to, by the name the daemon reports.
+fn find_repo<'a>(repos: &[&'a RepoContext], name: &str) -> jig_core::Result<&'a RepoContext> {
+    repos
+        .iter()
+        .find(|r| {
+            r.repo_root
+                .file_name()
+                .is_some_and(|n| n.to_string_lossy() == name)
+        })
+        .copied()
+        .ok_or_else(|| Error::Custom(format!("repo '{}' is not tracked", name)))
+}
+
+/// Open a URL in the default browser.
+fn open_url(url: &str) -> io::Result<()> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    Command::new(opener)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+fn dim() -> Style {
+    Style::new().fg(Color::DarkGray)
+}
+
+fn bold() -> Style {
+    Style::new().add_modifier(Modifier::BOLD)
+}
+
+/// The ratatui equivalent of a worker table color.
+fn tui_color(color: comfy_table::Color) -> Color {
+    use comfy_table::Color as C;
+    match color {
+        C::Black => Color::Black,
+        C::DarkGrey => Color::DarkGray,
+        C::Red => Color::LightRed,
+        C::DarkRed => Color::Red,
+        C::Green => Color::LightGreen,
+        C::DarkGreen => Color::Green,
+        C::Yellow => Color::LightYellow,
+        C::DarkYellow => Color::Yellow,
+        C::Blue => Color::LightBlue,
+        C::DarkBlue => Color::Blue,
+        C::Magenta => Color::LightMagenta,
+        C::DarkMagenta => Color::Magenta,
+        C::Cyan => Color::LightCyan,
+        C::DarkCyan => Color::Cyan,
+        C::White => Color::White,
+        C::Grey => Color::Gray,
+        C::Rgb { r, g, b } => Color::Rgb(r, g, b),
+        C::AnsiValue(v) => Color::Indexed(v),
+        C::Reset => Color::Reset,
+    }
+}
+
+fn format_time(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| ts.to_string())
+}
+
+/// `key=value` pairs of an event's fields, single line.
+fn format_fields(event: &Event) -> String {
+    let Some(fields) = event.data.as_object() else {
+        return String::new();
+    };
+    fields
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            format!("{}={}", key, ui::truncate(&value.replace('\n', " "), 60))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Format structured log lines from a TickResult.
+fn format_tick_log(tick: &TickResult) -> Vec<String> {
+    let now = chrono::Local::now().format("%H:%M:%S");
+    let mut lines = vec![];
+
+    lines.push(format!(
+        "[{}] tick: {} workers, {} actions, {} nudges, {} errors",
+        now,
+        tick.workers_checked,
+        tick.actions_dispatched,
+        tick.nudges_sent,
+        tick.errors.len(),
+    ));
+
+    for (key, info) in &tick.worker_info {
+        if !info.has_pr {
+            continue;
+        }
+        if let Some(err) = &info.pr_error {
+            lines.push(format!("[{}]   {} PR: {}", now, key, err));
+        } else if !info.pr_checks.is_empty() {
+            let problems: Vec<&str> = info
+                .pr_checks
+                .iter()
+                .filter(|(_, bad)| *bad)
+                .map(|(name, _)| name.as_str())
+                .collect();
+            if problems.is_empty() {
+                lines.push(format!("[{}]   {} PR: ok", now, key));
+            } else {
+                lines.push(format!("[{}]   {} PR: {}", now, key, problems.join(", ")));
+            }
+        }
+    }
+
+    for spawned in &tick.auto_spawned {
+        lines.push(format!("[{}]   auto-spawned: {}", now, spawned));
+    }
+
+    for pruned in &tick.pruned {
+        lines.push(format!("[{}]   pruned: {}", now, pruned));
+    }
+
+    for err in &tick.errors {
+        lines.push(format!("[{}]   error: {}", now, err));
+    }
+
+    lines
+}
+
+/// Format the daemon tick result as a compact status summary.
+fn format_tick_status(tick: &TickResult) -> String {
+    let plural = |n: usize| if n == 1 { "" } else { "s" };
+    let mut parts = vec![];
+    if tick.nudges_sent > 0 {
+        parts.push(format!(
+            "{} nudge{}",
+            tick.nudges_sent,
+            plural(tick.nudges_sent)
+        ));
+    }
+    if tick.notifications_sent > 0 {
+        parts.push(format!("{} notify", tick.notifications_sent));
+    }
+    if !tick.errors.is_empty() {
+        parts.push(format!(
+            "{} err{}",
+            tick.errors.len(),
+            plural(tick.errors.len())
+        ));
+    }
+    if !tick.auto_spawned.is_empty() {
+        parts.push(format!("{} spawned", tick.auto_spawned.len()));
+    }
+    if !tick.spawning.is_empty() {
+        parts.push(format!("spawning {}", tick.spawning.len()));
+    }
+    if !tick.pruned.is_empty() {
+        parts.push(format!("{} pruned", tick.pruned.len()));
+    }
+    parts.join(", ")
+}
+
+/// Format timer info for the footer line.
+fn format_timer_info(timer: &Option<TimerInfo>) -> String {
+    let Some(timer) = timer else {
+        return String::new();
+    };
+    let mut parts = vec![format!(
+        "sync: {}",
+        ui::format_duration_short(timer.sync_remaining)
+    )];
+    if let Some(poll) = timer.poll_remaining {
+        parts.push(format!("poll: {}", ui::format_duration_short(poll)));
+    }
+    format!("  {}", parts.join("  "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use jig_core::daemon::WorkerTickInfo;
+    use jig_core::spawn::TaskStatus;
+    use jig_core::WorkerStatus;
+    use ratatui::backend::TestBackend;
+
+    fn worker(name: &str, pr_url: Option<&str>) -> WorkerDisplayInfo {
+        WorkerDisplayInfo {
+            repo: "app".to_string(),
+            name: name.to_string(),
+            branch: name.to_string(),
+            tmux_status: TaskStatus::Running,
+            worker_status: Some(WorkerStatus::Running),
+            nudge_count: 0,
+            max_nudges: 3,
+            commits_ahead: 1,
+            is_dirty: false,
+            pr_url: pr_url.map(String::from),
+            issue_ref: None,
+            pr_health: WorkerTickInfo::default(),
+            is_draft: false,
+            nudge_cooldown_remaining: None,
+            agent: None,
+            model: None,
+            pane_preview: Some("Running tests…".to_string()),
+        }
+    }
+
+    fn tick(workers: Vec<WorkerDisplayInfo>) -> TickResult {
+        TickResult {
+            worker_display: workers,
+            ..Default::default()
+        }
+    }
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn key(name: &str) -> WorkerKey {
+        WorkerKey {
+            repo: "app".to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn selection_follows_the_worker_across_ticks() {
+        let mut dash = Dashboard::new(2, false);
+        dash.update(&tick(vec![
+            worker("a", None),
+            worker("b", None),
+            worker("c", None),
+        ]));
+        assert_eq!(dash.selected().unwrap().name, "a");
+
+        dash.handle_key(press(KeyCode::Down));
+        dash.handle_key(press(KeyCode::Char('j')));
+        dash.handle_key(press(KeyCode::Down));
+        assert_eq!(dash.selected().unwrap().name, "c");
+
+        dash.update(&tick(vec![worker("c", None), worker("a", None)]));
+        assert_eq!(dash.selected().unwrap().name, "c");
+
+        // The selected worker went away: stay in range.
+        dash.handle_key(press(KeyCode::Down));
+        dash.update(&tick(vec![worker("c", None)]));
+        assert_eq!(dash.selected().unwrap().name, "c");
+
+        dash.update(&tick(vec![]));
+        assert!(dash.selected().is_none());
+        assert_eq!(dash.handle_key(press(KeyCode::Char('x'))), Step::Continue);
+        assert_eq!(dash.mode, Mode::Normal);
+    }
+
+    #[test]
+    fn message_mode_sends_what_was_typed() {
+        let mut dash = Dashboard::new(2, false);
+        dash.update(&tick(vec![worker("a", None)]));
+
+        dash.handle_key(press(KeyCode::Char('m')));
+        for c in "rebase pls".chars() {
+            assert_eq!(dash.handle_key(press(KeyCode::Char(c))), Step::Continue);
+        }
+        dash.handle_key(press(KeyCode::Backspace));
+        dash.handle_key(press(KeyCode::Backspace));
+        dash.handle_key(press(KeyCode::Backspace));
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Enter)),
+            Step::Act(Action::Message(key("a"), "rebase".to_string()))
+        );
+        assert_eq!(dash.mode, Mode::Normal);
+
+        // Esc cancels, and `q` while typing is text, not quit.
+        dash.handle_key(press(KeyCode::Char('m')));
+        assert_eq!(dash.handle_key(press(KeyCode::Char('q'))), Step::Continue);
+        dash.handle_key(press(KeyCode::Esc));
+        assert_eq!(dash.mode, Mode::Normal);
+    }
+
+    #[test]
+    fn kill_and_merge_wait_for_confirmation() {
+        let mut dash = Dashboard::new(2, false);
+        dash.update(&tick(vec![worker("a", None)]));
+
+        assert_eq!(dash.handle_key(press(KeyCode::Char('x'))), Step::Continue);
+        assert_eq!(dash.handle_key(press(KeyCode::Char('n'))), Step::Continue);
+        assert_eq!(dash.mode, Mode::Normal);
+
+        dash.handle_key(press(KeyCode::Char('x')));
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Char('y'))),
+            Step::Act(Action::Kill(key("a")))
+        );
+
+        dash.handle_key(press(KeyCode::Char('M')));
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Char('y'))),
+            Step::Act(Action::Merge {
+                worker: key("a"),
+                strategy: Strategy::Merge,
+                cleanup: false,
+            })
+        );
+
+        // The merge prompt picks the strategy and toggles cleanup.
+        dash.handle_key(press(KeyCode::Char('M')));
+        assert_eq!(dash.handle_key(press(KeyCode::Char('c'))), Step::Continue);
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Char('s'))),
+            Step::Act(Action::Merge {
+                worker: key("a"),
+                strategy: Strategy::Squash,
+                cleanup: true,
+            })
+        );
+        dash.handle_key(press(KeyCode::Char('M')));
+        assert_eq!(dash.handle_key(press(KeyCode::Esc)), Step::Continue);
+        assert_eq!(dash.mode, Mode::Normal);
+
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Char('r'))),
+            Step::Act(Action::Resume(key("a")))
+        );
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Enter)),
+            Step::Act(Action::Attach(key("a")))
+        );
+        assert_eq!(dash.handle_key(press(KeyCode::Char('q'))), Step::Quit);
+    }
+
+    #[test]
+    fn open_pr_needs_a_pr() {
+        let mut dash = Dashboard::new(2, false);
+        dash.update(&tick(vec![
+            worker("a", None),
+            worker("b", Some("https://github.com/acme/app/pull/7")),
+        ]));
+
+        assert_eq!(dash.handle_key(press(KeyCode::Char('o'))), Step::Continue);
+        assert_eq!(dash.flash, Some(("'a' has no PR".to_string(), true)));
+
+        dash.handle_key(press(KeyCode::Down));
+        assert_eq!(dash.flash, None);
+        assert_eq!(
+            dash.handle_key(press(KeyCode::Char('o'))),
+            Step::Act(Action::OpenPr(
+                "https://github.com/acme/app/pull/7".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn draws_workers_detail_and_keys() {
+        let mut dash = Dashboard::new(2, true);
+        dash.update(&tick(vec![worker("feat-login", Some("https://x/pull/12"))]));
+        dash.detail.worker = Some(key("feat-login"));
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 20)).unwrap();
+        terminal.draw(|frame| dash.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(screen.contains("1 workers"));
+        assert!(screen.contains("REPO"));
+        assert!(screen.contains("feat-login"));
+        assert!(screen.contains("#12"));
+        assert!(screen.contains("Running tests…"));
+        assert!(screen.contains("feat-login — events"));
+        assert!(screen.contains("No reviews yet"));
+        assert!(screen.contains("[M]erge"));
+    }
+}
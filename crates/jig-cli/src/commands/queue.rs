@@ -0,0 +1,128 @@
+//! Queue command — show the daemon's merge queue
+
+use std::fmt;
+
+use clap::Args;
+use comfy_table::{Cell, Color};
+
+use jig_core::merge_queue::{EntryState, MergeQueue, QueueEntry};
+use jig_core::RepoContext;
+
+use crate::op::{GlobalCtx, Op, RepoCtx};
+use crate::ui;
+
+/// Show approved workers waiting to land, in merge order
+#[derive(Args, Debug, Clone)]
+pub struct Queue {}
+
+#[derive(Debug, thiserror::Error)]
+pub enum QueueError {
+    #[error(transparent)]
+    Core(#[from] jig_core::Error),
+}
+
+#[derive(Debug)]
+pub struct QueueOutput {
+    entries: Vec<QueueEntry>,
+    show_repo: bool,
+}
+
+impl Op for Queue {
+    type Error = QueueError;
+    type Output = QueueOutput;
+
+    fn run(&self, ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
+        let name = repo_name(ctx.repo()?);
+        let queue = MergeQueue::load()?;
+        Ok(QueueOutput {
+            entries: queue.for_repo(&name).cloned().collect(),
+            show_repo: false,
+        })
+    }
+
+    fn run_global(&self, _ctx: &GlobalCtx) -> Result<Self::Output, Self::Error> {
+        Ok(QueueOutput {
+            entries: MergeQueue::load()?.entries,
+            show_repo: true,
+        })
+    }
+}
+
+fn repo_name(repo: &RepoContext) -> String {
+    repo.repo_root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+impl fmt::Display for QueueOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.entries.is_empty() {
+            return write!(f, "{}", ui::dim("No workers queued"));
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        if ui::is_plain() {
+            for (i, entry) in self.entries.iter().enumerate() {
+                let mut fields = vec![(i + 1).to_string()];
+                if self.show_repo {
+                    fields.push(entry.repo.clone());
+                }
+                fields.extend([
+                    entry.worker.clone(),
+                    entry.state.as_str().to_string(),
+                    priority(entry),
+                    entry.attempts.to_string(),
+                    waiting(entry, now),
+                    entry.last_error.clone().unwrap_or_default(),
+                ]);
+                writeln!(f, "{}", fields.join("\t"))?;
+            }
+            return Ok(());
+        }
+
+        let mut headers = vec!["#"];
+        if self.show_repo {
+            headers.push("REPO");
+        }
+        headers.extend([
+            "WORKER", "STATE", "PRIORITY", "ATTEMPTS", "WAITING", "ERROR",
+        ]);
+        let mut table = ui::new_table(&headers);
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            let mut row = vec![Cell::new(i + 1)];
+            if self.show_repo {
+                row.push(Cell::new(&entry.repo));
+            }
+            let state = match entry.state {
+                EntryState::Queued => Cell::new(entry.state.as_str()),
+                EntryState::Landing => Cell::new(entry.state.as_str()).fg(Color::Green),
+                EntryState::Failed => Cell::new(entry.state.as_str()).fg(Color::Red),
+            };
+            row.extend([
+                Cell::new(&entry.worker).fg(Color::Cyan),
+                state,
+                Cell::new(priority(entry)),
+                Cell::new(entry.attempts),
+                Cell::new(waiting(entry, now)),
+                Cell::new(ui::truncate(entry.last_error.as_deref().unwrap_or("-"), 60)),
+            ]);
+            table.add_row(row);
+        }
+
+        write!(f, "{table}")
+    }
+}
+
+fn priority(entry: &QueueEntry) -> String {
+    entry
+        .priority
+        .as_ref()
+        .map_or("-", |p| p.as_str())
+        .to_string()
+}
+
+fn waiting(entry: &QueueEntry, now: i64) -> String {
+    ui::format_duration_short((now - entry.enqueued_at).max(0) as u64)
+}
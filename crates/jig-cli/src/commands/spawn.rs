@@ -1,15 +1,16 @@
-//! Spawn command - create worktree and launch Claude in tmux
+//! Spawn command - create worktree and launch an agent in tmux
 
 use clap::Args;
 
+use jig_core::global::GlobalConfig;
 use jig_core::issues::naming::{derive_worker_name, extract_linear_identifier};
 use jig_core::worktree::Worktree;
-use jig_core::{config, terminal, Error};
+use jig_core::{config, stack, terminal, AgentRegistry, Error, JigToml, CLAUDE_CODE};
 
 use crate::op::{NoOutput, Op, RepoCtx};
 use crate::ui;
 
-/// Create worktree and launch Claude in tmux
+/// Create worktree and launch an agent in tmux
 #[derive(Args, Debug, Clone)]
 pub struct Spawn {
     /// Worktree name (derived from --issue if omitted)
@@ -26,6 +27,18 @@ pub struct Spawn {
     /// Base branch to create worktree from (overrides jig.toml default)
     #[arg(long, short = 'b')]
     pub base: Option<String>,
+
+    /// Stack on another in-flight worker: fork from its branch, restack when it moves
+    #[arg(long, conflicts_with = "base")]
+    pub on: Option<String>,
+
+    /// Agent to launch (built-in or `[agents.<name>]`; defaults to `[agent] type`)
+    #[arg(long, short = 'a')]
+    pub agent: Option<String>,
+
+    /// Model for the agent (overrides the issue's `model:` label)
+    #[arg(long, short = 'm')]
+    pub model: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -48,11 +61,6 @@ impl Op for Spawn {
             return Err(Error::MissingDependency("tmux".to_string()).into());
         }
 
-        // Check for claude
-        if !terminal::command_exists("claude") {
-            return Err(Error::MissingDependency("claude".to_string()).into());
-        }
-
         // Resolve issue early so we can derive the name if needed
         let issue_ref = self.issue.as_deref();
 
@@ -72,6 +80,31 @@ impl Op for Spawn {
             None
         };
 
+        // Resolve the agent: --agent > issue override > repo default. An
+        // explicit choice must exist; the repo default falls back to Claude.
+        let agent = self
+            .agent
+            .clone()
+            .or_else(|| issue.as_ref().and_then(|i| i.agent.clone()));
+        let model = self
+            .model
+            .clone()
+            .or_else(|| issue.as_ref().and_then(|i| i.model.clone()));
+        let jig_toml = JigToml::load(&repo.repo_root)?.unwrap_or_default();
+        let registry = AgentRegistry::from_config(&jig_toml, &GlobalConfig::load()?);
+        let adapter = match agent.as_deref() {
+            Some(agent) => registry.resolve(agent)?,
+            None => registry
+                .get(&jig_toml.agent.agent_type)
+                .unwrap_or(CLAUDE_CODE),
+        };
+
+        // Check for the agent binary
+        let agent_bin = adapter.command.split_whitespace().next().unwrap_or("");
+        if !terminal::command_exists(agent_bin) {
+            return Err(Error::MissingDependency(agent_bin.to_string()).into());
+        }
+
         // Resolve the worktree name: explicit > derived from issue > error
         let name = if let Some(ref explicit) = self.name {
             explicit.clone()
@@ -84,11 +117,39 @@ impl Op for Spawn {
             .into());
         };
 
+        // Resolve the lower worker: --on must name an in-flight worker; an
+        // issue's Stacked-On falls back to the repo base if it isn't running.
+        let stacked_on = match (&self.on, issue.as_ref().and_then(|i| i.stacked_on.as_ref())) {
+            (Some(on), _) => Some(stack::lower_worker(&repo.repo_root, on).ok_or_else(|| {
+                Error::Custom(format!("no in-flight worker to stack on: {}", on))
+            })?),
+            (None, Some(reference)) => {
+                let lower = stack::lower_worker(&repo.repo_root, reference);
+                if lower.is_none() {
+                    ui::warning(&format!(
+                        "'{}' is not in flight; spawning from the base branch",
+                        reference
+                    ));
+                }
+                lower
+            }
+            (None, None) => None,
+        };
+        let stack_base = stacked_on
+            .as_deref()
+            .map(|lower| {
+                stack::branch_of(&repo.repo_root, lower).ok_or_else(|| {
+                    Error::Custom(format!("worker '{}' has no branch checked out", lower))
+                })
+            })
+            .transpose()?;
+
         let worktree_path = repo.worktrees_dir.join(&name);
 
         // Create worktree if needed using Worktree::create
-        let wt = if !worktree_path.exists() {
-            // Resolve base branch: explicit --base > parent branch > repo default
+        let mut wt = if !worktree_path.exists() {
+            // Resolve base branch: explicit --base > lower worker > parent
+            // branch > repo default
             let parent_base = issue
                 .as_ref()
                 .and_then(|i| i.parent.as_ref())
@@ -97,6 +158,7 @@ impl Op for Spawn {
             let base = self
                 .base
                 .as_deref()
+                .or(stack_base.as_deref())
                 .or(parent_base.as_deref())
                 .unwrap_or(&repo.base_branch);
             let copy_files = config::get_copy_files(&repo.repo_root)?;
@@ -137,6 +199,9 @@ impl Op for Spawn {
         };
 
         // Register and launch using Worktree methods
+        wt.agent = agent;
+        wt.model = model;
+        wt.stacked_on = stacked_on;
         wt.register(effective_context.as_deref(), issue_ref)?;
         wt.launch(effective_context.as_deref())?;
 
@@ -146,7 +211,8 @@ impl Op for Spawn {
         }
 
         ui::success(&format!(
-            "Launched Claude in tmux window '{}'",
+            "Launched {} in tmux window '{}'",
+            adapter.name,
             ui::highlight(&name)
         ));
 
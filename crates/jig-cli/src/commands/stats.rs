@@ -0,0 +1,262 @@
+//! Stats command — how well agents do the work, from worker history
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use clap::Args;
+
+use jig_core::events::EventLog;
+use jig_core::notify::{NotificationEvent, NotificationQueue};
+use jig_core::review::load_reviews;
+use jig_core::stats::{
+    Report, ReviewRecord, Stats as CoreStats, StatsLedger, Timing, WorkerRecord,
+};
+use jig_core::worktree::Worktree;
+use jig_core::RepoContext;
+
+use crate::op::{GlobalCtx, Op, RepoCtx};
+use crate::ui;
+
+/// Report time to PR and merge, nudges, escalations and review outcomes
+#[derive(Args, Debug, Clone)]
+pub struct Stats {
+    /// Only count work from the last duration (e.g. 7d, 30d)
+    #[arg(long, value_parser = ui::parse_duration)]
+    since: Option<u64>,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Debug)]
+pub enum StatsOutput {
+    Report(Box<Report>),
+    Json(String),
+}
+
+impl fmt::Display for StatsOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(text) => writeln!(f, "{}", text),
+            Self::Report(report) => {
+                write_stats(f, &report.total)?;
+                if report.by_repo.len() > 1 {
+                    writeln!(f)?;
+                    write_breakdown(f, "REPO", &report.by_repo)?;
+                }
+                if !report.by_week.is_empty() {
+                    writeln!(f)?;
+                    write_breakdown(f, "WEEK", &report.by_week)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StatsError {
+    #[error(transparent)]
+    Core(#[from] jig_core::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl Op for Stats {
+    type Error = StatsError;
+    type Output = StatsOutput;
+
+    fn run(&self, ctx: &RepoCtx) -> Result<Self::Output, Self::Error> {
+        self.report(&[ctx.repo()?], true)
+    }
+
+    fn run_global(&self, ctx: &GlobalCtx) -> Result<Self::Output, Self::Error> {
+        self.report(&ctx.repos.iter().collect::<Vec<_>>(), false)
+    }
+}
+
+impl Stats {
+    /// Gather history for `repos`. With `scoped`, workers and escalations
+    /// from other repos are left out.
+    fn report(&self, repos: &[&RepoContext], scoped: bool) -> Result<StatsOutput, StatsError> {
+        let cutoff = self
+            .since
+            .map(|secs| chrono::Utc::now().timestamp() - secs as i64);
+        let in_window = |ts: Option<i64>| cutoff.is_none_or(|c| ts.is_some_and(|ts| ts >= c));
+        let names: Vec<String> = repos.iter().map(|r| repo_name(r)).collect();
+        let in_scope = |repo: &str| !scoped || names.iter().any(|n| n == repo);
+
+        let mut workers = Vec::new();
+        for (log_name, log) in EventLog::all()? {
+            let (events, _) = log.read_history()?;
+            workers.push(WorkerRecord::from_events(&log_name, &events, &names));
+        }
+        // Workers whose logs were cleaned up.
+        workers.extend(StatsLedger::global()?.records()?);
+        workers.retain(|record| in_scope(&record.repo) && in_window(record.spawned_at));
+
+        let mut reviews = Vec::new();
+        for (repo, name) in repos.iter().zip(&names) {
+            // Reviews live in worktrees, so they go when the worktree does.
+            let worktrees =
+                Worktree::list(&repo.repo_root, &repo.worktrees_dir).unwrap_or_default();
+            for worktree in worktrees {
+                for review in load_reviews(&worktree.path) {
+                    if in_window(Some(review.timestamp)) {
+                        reviews.push(ReviewRecord {
+                            repo: name.clone(),
+                            review,
+                        });
+                    }
+                }
+            }
+        }
+
+        let escalations: Vec<(String, i64)> = NotificationQueue::global()?
+            .read_since(cutoff.unwrap_or(0) - 1)?
+            .into_iter()
+            .filter_map(|n| match n.event {
+                NotificationEvent::NeedsIntervention { repo, .. } => Some((repo, n.ts)),
+                _ => None,
+            })
+            .filter(|(repo, _)| in_scope(repo))
+            .collect();
+
+        let report = Report::build(&workers, &reviews, &escalations);
+        if self.json {
+            return Ok(StatsOutput::Json(serde_json::to_string_pretty(&report)?));
+        }
+        if report.total.workers == 0 && report.total.review_rounds == 0 {
+            eprintln!("{}", ui::dim("No worker history"));
+        }
+        Ok(StatsOutput::Report(Box::new(report)))
+    }
+}
+
+fn repo_name(repo: &RepoContext) -> String {
+    repo.repo_root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// `idle 10, ci 8`: counts, largest first.
+fn format_counts<N: Copy + Ord + fmt::Display>(counts: &BTreeMap<String, N>) -> String {
+    let mut counts: Vec<_> = counts.iter().collect();
+    counts.sort_by_key(|(_, n)| std::cmp::Reverse(**n));
+    counts
+        .iter()
+        .map(|(name, n)| format!("{} {}", name, n))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_timing(timing: &Option<Timing>) -> String {
+    match timing {
+        Some(t) => format!(
+            "median {}, mean {} ({})",
+            ui::format_duration_short(t.median_secs),
+            ui::format_duration_short(t.mean_secs),
+            t.count
+        ),
+        None => "-".to_string(),
+    }
+}
+
+fn format_rate(stats: &CoreStats) -> String {
+    stats
+        .changes_requested_rate()
+        .map(|rate| format!("{:.0}%", rate * 100.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// A label, padded to line up the values, and its value.
+fn write_row(f: &mut fmt::Formatter<'_>, label: &str, value: &str) -> fmt::Result {
+    writeln!(f, "{} {}", ui::bold(&format!("{:<12}", label)), value)
+}
+
+fn write_stats(f: &mut fmt::Formatter<'_>, stats: &CoreStats) -> fmt::Result {
+    write_row(
+        f,
+        "Workers",
+        &format!(
+            "{} across {} issues ({} PRs, {} merged, {} failed)",
+            stats.workers, stats.issues, stats.prs, stats.merged, stats.failed
+        ),
+    )?;
+    write_row(f, "Spawn → PR", &format_timing(&stats.spawn_to_pr))?;
+    write_row(f, "PR → merge", &format_timing(&stats.pr_to_merge))?;
+
+    let mut nudges = format!("{:.1} per worker", stats.nudges_per_worker());
+    if !stats.nudges.is_empty() {
+        nudges.push_str(&format!(": {}", format_counts(&stats.nudges)));
+    }
+    write_row(f, "Nudges", &nudges)?;
+    write_row(f, "Escalations", &stats.escalations.to_string())?;
+    write_row(
+        f,
+        "Reviews",
+        &format!(
+            "{} rounds, {} changes requested ({})",
+            stats.review_rounds,
+            stats.changes_requested,
+            format_rate(stats)
+        ),
+    )?;
+    if !stats.findings.is_empty() {
+        write_row(f, "Findings", &format_counts(&stats.findings))?;
+    }
+    if stats.failed > 0 {
+        let nudges = if stats.nudges_before_failure.is_empty() {
+            "none".to_string()
+        } else {
+            format_counts(&stats.nudges_before_failure)
+        };
+        write_row(
+            f,
+            "Failures",
+            &format!("{} failed, after nudges: {}", stats.failed, nudges),
+        )?;
+    }
+    Ok(())
+}
+
+/// One row per repo or week.
+fn write_breakdown(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    rows: &BTreeMap<String, CoreStats>,
+) -> fmt::Result {
+    let mut table = ui::new_table(&[
+        label,
+        "WORKERS",
+        "MERGED",
+        "FAILED",
+        "SPAWN→PR",
+        "PR→MERGE",
+        "NUDGES/W",
+        "ESCALATIONS",
+        "CHANGES REQ",
+    ]);
+    let median = |timing: &Option<Timing>| {
+        timing
+            .as_ref()
+            .map(|t| ui::format_duration_short(t.median_secs))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    for (key, stats) in rows {
+        table.add_row(vec![
+            key.clone(),
+            stats.workers.to_string(),
+            stats.merged.to_string(),
+            stats.failed.to_string(),
+            median(&stats.spawn_to_pr),
+            median(&stats.pr_to_merge),
+            format!("{:.1}", stats.nudges_per_worker()),
+            stats.escalations.to_string(),
+            format_rate(stats),
+        ]);
+    }
+    writeln!(f, "{}", table)
+}
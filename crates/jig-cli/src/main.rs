@@ -115,6 +115,10 @@ fn print_help() {
         ui::highlight("ps")
     );
     eprintln!("  {}      Attach to tmux session", ui::highlight("attach"));
+    eprintln!(
+        "  {}        Show a worker's recent pane output",
+        ui::highlight("peek")
+    );
     eprintln!(
         "  {}      Show diff for parent review",
         ui::highlight("review")
@@ -123,6 +127,14 @@ fn print_help() {
         "  {}       Merge reviewed worktree into current branch",
         ui::highlight("merge")
     );
+    eprintln!(
+        "  {}       Show approved workers waiting to land",
+        ui::highlight("queue")
+    );
+    eprintln!(
+        "  {}   Show workers editing the same files",
+        ui::highlight("conflicts")
+    );
     eprintln!(
         "  {}        Kill a running tmux window",
         ui::highlight("kill")
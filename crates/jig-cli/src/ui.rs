@@ -177,6 +177,9 @@ pub fn new_table(headers: &[&str]) -> Table {
 /// Maximum display width for worker names.
 pub const NAME_MAX: usize = 36;
 
+/// Maximum display width for the last line of a worker's pane.
+pub const PREVIEW_MAX: usize = 40;
+
 /// Truncate a string to `max` characters, appending ellipsis if needed (UTF-8 safe).
 pub fn truncate(s: &str, max: usize) -> String {
     if s.chars().count() <= max {
@@ -218,6 +221,50 @@ pub fn format_duration_short(secs: u64) -> String {
     }
 }
 
+/// Parse a short duration (`45s`, `30m`, `1h`, `2d`; bare numbers are
+/// seconds) into seconds. The inverse of [`format_duration_short`] for a
+/// single unit.
+pub fn parse_duration(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (digits, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let n: u64 = digits
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (e.g. 30m, 1h, 2d)", s))?;
+    let scale = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => return Err(format!("invalid duration '{}' (e.g. 30m, 1h, 2d)", s)),
+    };
+    Ok(n * scale)
+}
+
+/// Format a byte count: `512B`, `3.4K`, `1.2M`.
+pub fn format_bytes(bytes: u64) -> String {
+    const K: f64 = 1024.0;
+    let b = bytes as f64;
+    if b < K {
+        format!("{}B", bytes)
+    } else if b < K * K {
+        format!("{:.1}K", b / K)
+    } else {
+        format!("{:.1}M", b / (K * K))
+    }
+}
+
+/// Format a count compactly: `950`, `12.3K`, `4.5M`.
+pub fn format_count(n: u64) -> String {
+    let f = n as f64;
+    if n < 1_000 {
+        n.to_string()
+    } else if n < 1_000_000 {
+        format!("{:.1}K", f / 1e3)
+    } else {
+        format!("{:.1}M", f / 1e6)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Error display
 // ---------------------------------------------------------------------------
@@ -305,8 +352,13 @@ pub fn format_health(info: &WorkerTickInfo) -> (String, Color) {
     }
 }
 
-/// Build a row of cells for a single worker.
-fn worker_row(w: &WorkerDisplayInfo) -> Vec<Cell> {
+/// Worker table column headers, in display order.
+pub const WORKER_COLUMNS: [&str; 9] = [
+    "WORKER", "STATE", "NUDGE", "COMMITS", "PR", "HEALTH", "AGENT", "ISSUE", "OUTPUT",
+];
+
+/// The text and color of each worker table column, in `WORKER_COLUMNS` order.
+pub fn worker_columns(w: &WorkerDisplayInfo) -> Vec<(String, Color)> {
     let tmux_indicator = match w.tmux_status {
         TaskStatus::Running => "●",
         TaskStatus::Exited => "○",
@@ -391,34 +443,61 @@ fn worker_row(w: &WorkerDisplayInfo) -> Vec<Cell> {
 
     let (health_text, health_color) = format_health(&w.pr_health);
 
+    let agent = match (&w.agent, &w.model) {
+        (Some(agent), Some(model)) => format!("{} ({})", agent, model),
+        (Some(agent), None) => agent.clone(),
+        (None, _) => "-".to_string(),
+    };
+    let agent_color = if agent == "-" {
+        Color::DarkGrey
+    } else {
+        Color::White
+    };
+
     let name = format!("{} {}", tmux_indicator, truncate(&w.name, NAME_MAX));
 
     vec![
-        Cell::new(&name).fg(tmux_color),
-        Cell::new(state_text).fg(state_color),
-        Cell::new(&nudge_text)
-            .fg(nudge_color)
-            .set_alignment(CellAlignment::Right),
-        Cell::new(&commits)
-            .fg(commit_color)
-            .set_alignment(CellAlignment::Right),
-        Cell::new(&pr).fg(pr_color),
-        Cell::new(&health_text).fg(health_color),
-        Cell::new(&issue).fg(issue_color),
+        (name, tmux_color),
+        (state_text.to_string(), state_color),
+        (nudge_text, nudge_color),
+        (commits, commit_color),
+        (pr, pr_color),
+        (health_text, health_color),
+        (agent, agent_color),
+        (issue, issue_color),
+        (
+            w.pane_preview
+                .as_deref()
+                .map(|line| truncate(line, PREVIEW_MAX))
+                .unwrap_or_else(|| "-".to_string()),
+            Color::DarkGrey,
+        ),
     ]
 }
 
+/// Build a row of cells for a single worker.
+fn worker_row(w: &WorkerDisplayInfo) -> Vec<Cell> {
+    worker_columns(w)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (text, color))| {
+            let cell = Cell::new(text).fg(color);
+            // NUDGE and COMMITS are counts
+            if matches!(i, 2 | 3) {
+                cell.set_alignment(CellAlignment::Right)
+            } else {
+                cell
+            }
+        })
+        .collect()
+}
+
 /// Standard table header cells.
 fn table_header() -> Vec<Cell> {
-    vec![
-        Cell::new("WORKER").add_attribute(Attribute::Bold),
-        Cell::new("STATE").add_attribute(Attribute::Bold),
-        Cell::new("NUDGE").add_attribute(Attribute::Bold),
-        Cell::new("COMMITS").add_attribute(Attribute::Bold),
-        Cell::new("PR").add_attribute(Attribute::Bold),
-        Cell::new("HEALTH").add_attribute(Attribute::Bold),
-        Cell::new("ISSUE").add_attribute(Attribute::Bold),
-    ]
+    WORKER_COLUMNS
+        .iter()
+        .map(|h| Cell::new(h).add_attribute(Attribute::Bold))
+        .collect()
 }
 
 /// Render a worker table from display info.
@@ -663,6 +742,24 @@ mod tests {
         assert_eq!(format_duration_short(7200), "2h");
     }
 
+    #[test]
+    fn parse_duration_units() {
+        assert_eq!(parse_duration("45"), Ok(45));
+        assert_eq!(parse_duration("45s"), Ok(45));
+        assert_eq!(parse_duration("30m"), Ok(1800));
+        assert_eq!(parse_duration("1h"), Ok(3600));
+        assert_eq!(parse_duration("2d"), Ok(172800));
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("1w").is_err());
+    }
+
+    #[test]
+    fn format_count_scales() {
+        assert_eq!(format_count(950), "950");
+        assert_eq!(format_count(12_345), "12.3K");
+        assert_eq!(format_count(4_500_000), "4.5M");
+    }
+
     #[test]
     fn render_triage_section_empty_returns_empty() {
         let section = render_triage_section(&[], false);
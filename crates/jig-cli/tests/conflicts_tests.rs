@@ -0,0 +1,109 @@
+#![allow(deprecated)]
+//! Integration tests for `jig conflicts`.
+
+use std::path::Path;
+use std::process::Command as StdCommand;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = StdCommand::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Test User")
+        .env("GIT_AUTHOR_EMAIL", "test@test.com")
+        .env("GIT_COMMITTER_NAME", "Test User")
+        .env("GIT_COMMITTER_EMAIL", "test@test.com")
+        .output()
+        .expect("run git");
+    assert!(output.status.success(), "git {:?} failed", args);
+}
+
+struct TestEnv {
+    config_dir: TempDir,
+    repo: TempDir,
+}
+
+impl TestEnv {
+    /// A repo on `main` with base `main` and no worktrees yet.
+    fn new() -> Self {
+        let repo = TempDir::new().unwrap();
+        git(repo.path(), &["init", "-q", "-b", "main"]);
+        std::fs::write(
+            repo.path().join("jig.toml"),
+            "[worktree]\nbase = \"main\"\n",
+        )
+        .unwrap();
+        std::fs::write(repo.path().join("lib.rs"), "").unwrap();
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-q", "-m", "init"]);
+        Self {
+            config_dir: TempDir::new().unwrap(),
+            repo,
+        }
+    }
+
+    /// A worktree whose branch commits `files`.
+    fn worker(&self, name: &str, files: &[&str]) {
+        let path = format!(".jig/{}", name);
+        git(
+            self.repo.path(),
+            &["worktree", "add", "-q", &path, "-b", name],
+        );
+        let worktree = self.repo.path().join(&path);
+        for file in files {
+            std::fs::write(worktree.join(file), name).unwrap();
+        }
+        git(&worktree, &["add", "."]);
+        git(&worktree, &["commit", "-q", "-m", name]);
+    }
+
+    fn jig(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd.current_dir(self.repo.path());
+        cmd
+    }
+}
+
+#[test]
+fn no_overlap() {
+    let env = TestEnv::new();
+    env.worker("feat-a", &["a.rs"]);
+    env.worker("feat-b", &["b.rs"]);
+    env.jig(&["conflicts"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No overlapping workers"));
+}
+
+#[test]
+fn reports_shared_files() {
+    let env = TestEnv::new();
+    env.worker("feat-a", &["lib.rs", "a.rs", "Cargo.lock"]);
+    env.worker("feat-b", &["lib.rs", "b.rs", "Cargo.lock"]);
+    env.worker("feat-c", &["c.rs"]);
+    env.jig(&["--plain", "conflicts"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("feat-a\tfeat-b\tlib.rs\n"))
+        .stdout(predicate::str::contains("Cargo.lock").not())
+        .stdout(predicate::str::contains("feat-c").not());
+}
+
+#[test]
+fn matrix_lists_overlapping_workers() {
+    let env = TestEnv::new();
+    env.worker("feat-a", &["lib.rs", "a.rs"]);
+    env.worker("feat-b", &["lib.rs"]);
+    env.worker("feat-c", &["c.rs"]);
+    env.jig(&["conflicts"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("feat-a"))
+        .stdout(predicate::str::contains("lib.rs"))
+        .stdout(predicate::str::contains("feat-c").not());
+}
@@ -0,0 +1,165 @@
+#![allow(deprecated)]
+//! Integration tests for `jig daemon ctl` against a running daemon.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::PathBuf;
+use std::process::{Child, Command as StdCommand, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+struct TestDaemon {
+    config_dir: TempDir,
+    child: Child,
+}
+
+impl TestDaemon {
+    /// Start `jig daemon` with a long interval so only requested ticks run.
+    fn start() -> Self {
+        let config_dir = TempDir::new().expect("create config dir");
+        let child = StdCommand::new(assert_cmd::cargo::cargo_bin("jig"))
+            .args(["daemon", "--interval", "3600"])
+            .env("XDG_CONFIG_HOME", config_dir.path())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("spawn daemon");
+        let daemon = Self { config_dir, child };
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !daemon.socket_path().exists() {
+            assert!(Instant::now() < deadline, "daemon socket never appeared");
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        daemon
+    }
+
+    fn socket_path(&self) -> PathBuf {
+        self.config_dir
+            .path()
+            .join("jig")
+            .join("state")
+            .join("daemon.sock")
+    }
+
+    fn ctl(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(["daemon", "ctl"]).args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd
+    }
+}
+
+impl Drop for TestDaemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn ctl_without_daemon_fails() {
+    let config_dir = TempDir::new().unwrap();
+    Command::cargo_bin("jig")
+        .unwrap()
+        .args(["daemon", "ctl", "status"])
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("daemon is not running"));
+}
+
+#[test]
+fn ctl_pause_resume_and_status() {
+    let daemon = TestDaemon::start();
+
+    daemon
+        .ctl(&["status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("auto-spawn: active"));
+
+    daemon
+        .ctl(&["pause"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("auto-spawn paused"));
+
+    daemon
+        .ctl(&["status", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"paused\": true"));
+
+    daemon.ctl(&["resume"]).assert().success();
+    daemon
+        .ctl(&["status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("auto-spawn: active"));
+}
+
+#[test]
+fn ctl_tick_runs_immediately() {
+    let daemon = TestDaemon::start();
+
+    daemon
+        .ctl(&["tick"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("tick started"));
+
+    // The first tick ran at startup; the requested one follows it.
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let out = daemon.ctl(&["status", "--json"]).output().unwrap();
+        if String::from_utf8_lossy(&out.stdout).contains("\"ticks\": 2") {
+            break;
+        }
+        assert!(Instant::now() < deadline, "requested tick never ran");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn ctl_nudge_unknown_worker_fails() {
+    let daemon = TestDaemon::start();
+
+    daemon
+        .ctl(&["nudge", "nobody"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no worker named nobody"));
+}
+
+#[test]
+fn ctl_kill_unknown_worker_fails() {
+    let daemon = TestDaemon::start();
+
+    daemon
+        .ctl(&["kill", "nobody"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no worker named nobody"));
+}
+
+#[test]
+fn ps_follows_running_daemon() {
+    let daemon = TestDaemon::start();
+
+    // The daemon holds the pidfile lock; ps reads its tick instead.
+    let mut cmd = Command::cargo_bin("jig").unwrap();
+    cmd.args(["ps", "-g"])
+        .env("XDG_CONFIG_HOME", daemon.config_dir.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No spawned sessions"))
+        .stderr(predicate::str::contains("already running").not());
+
+    // The requested tick ran in the daemon.
+    daemon
+        .ctl(&["status", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"ticks\": 2"));
+}
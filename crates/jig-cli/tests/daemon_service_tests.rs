@@ -0,0 +1,118 @@
+#![allow(deprecated)]
+//! Integration tests for `jig daemon start|stop|status|restart`.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+struct TestEnv {
+    config_dir: TempDir,
+}
+
+impl TestEnv {
+    fn new() -> Self {
+        Self {
+            config_dir: TempDir::new().expect("create config dir"),
+        }
+    }
+
+    fn jig(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd
+    }
+}
+
+impl Drop for TestEnv {
+    fn drop(&mut self) {
+        let _ = self.jig(&["daemon", "stop"]).output();
+    }
+}
+
+#[test]
+fn status_when_not_running() {
+    let env = TestEnv::new();
+    env.jig(&["daemon", "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("not running"));
+}
+
+#[test]
+fn start_status_stop() {
+    let env = TestEnv::new();
+
+    env.jig(&["daemon", "start", "--interval", "3600"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Daemon started (pid"));
+
+    env.jig(&["daemon", "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("running (pid"));
+
+    // A second daemon, background or foreground, is refused.
+    env.jig(&["daemon", "start"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("daemon already running"));
+    env.jig(&["daemon", "--once"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("daemon already running"));
+
+    env.jig(&["daemon", "stop"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Daemon stopped"));
+
+    env.jig(&["daemon", "status", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"pid\": null"))
+        .stdout(predicate::str::contains("\"stop_reason\": \"normal\""));
+}
+
+#[test]
+fn restart_replaces_daemon() {
+    let env = TestEnv::new();
+
+    env.jig(&["daemon", "restart", "--interval", "3600"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Daemon started"));
+    env.jig(&["daemon", "restart", "--interval", "3600"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Daemon stopped"))
+        .stderr(predicate::str::contains("Daemon started"));
+}
+
+#[test]
+fn status_reports_crashed_run() {
+    let env = TestEnv::new();
+    let state = env.config_dir.path().join("jig").join("state");
+    std::fs::create_dir_all(&state).unwrap();
+    std::fs::write(
+        state.join("daemon.jsonl"),
+        "{\"event\":\"Started\",\"ts\":1700000000,\"pid\":999999}\n",
+    )
+    .unwrap();
+
+    env.jig(&["daemon", "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("previous run crashed"));
+}
+
+#[test]
+fn unit_prints_systemd_service() {
+    let env = TestEnv::new();
+    env.jig(&["daemon", "unit"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ExecStart="))
+        .stdout(predicate::str::contains(" daemon\n"));
+}
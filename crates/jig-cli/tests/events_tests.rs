@@ -0,0 +1,368 @@
+#![allow(deprecated)]
+//! Integration tests for `jig events`.
+
+use std::path::PathBuf;
+use std::process::Command as StdCommand;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+struct TestEnv {
+    config_dir: TempDir,
+}
+
+impl TestEnv {
+    fn new() -> Self {
+        Self {
+            config_dir: TempDir::new().expect("create config dir"),
+        }
+    }
+
+    fn jig(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd
+    }
+
+    /// Write a worker event log with a run of tool-use events.
+    fn write_log(&self, dir_name: &str) -> PathBuf {
+        let dir = self
+            .config_dir
+            .path()
+            .join("jig/state/events")
+            .join(dir_name);
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut log = String::from("{\"ts\":1700000000,\"type\":\"spawn\",\"branch\":\"feat\"}\n");
+        for i in 0..20 {
+            log.push_str(&format!(
+                "{{\"ts\":{},\"type\":\"tool_use_end\"}}\n",
+                1700000001 + i
+            ));
+        }
+        std::fs::write(dir.join("events.jsonl"), log).unwrap();
+        dir
+    }
+}
+
+fn write_raw(env: &TestEnv, dir_name: &str, lines: &[String]) {
+    let dir = env
+        .config_dir
+        .path()
+        .join("jig/state/events")
+        .join(dir_name);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("events.jsonl"), lines.join("\n") + "\n").unwrap();
+}
+
+#[test]
+fn all_merges_logs_in_time_order() {
+    let env = TestEnv::new();
+    write_raw(
+        &env,
+        "api-a",
+        &[
+            r#"{"ts":1700000000,"type":"spawn","branch":"a"}"#.to_string(),
+            r#"{"ts":1700000020,"type":"commit","sha":"abc"}"#.to_string(),
+        ],
+    );
+    write_raw(
+        &env,
+        "api-b",
+        &[r#"{"ts":1700000010,"type":"spawn","branch":"b"}"#.to_string()],
+    );
+
+    let out = env
+        .jig(&["--plain", "events", "--all"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().filter(|l| !l.is_empty()).collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].contains("api-a spawn") && lines[0].contains("-> spawned"));
+    assert!(lines[1].contains("api-b spawn"));
+    assert!(lines[2].contains("commit") && lines[2].contains("sha=abc"));
+    assert!(lines[2].contains("-> running"));
+}
+
+#[test]
+fn filters_by_type_and_since() {
+    let env = TestEnv::new();
+    let now = chrono::Utc::now().timestamp();
+    write_raw(
+        &env,
+        "api-a",
+        &[
+            r#"{"ts":1700000000,"type":"spawn"}"#.to_string(),
+            r#"{"ts":1700000001,"type":"nudge","nudge_type":"idle"}"#.to_string(),
+            format!(r#"{{"ts":{},"type":"nudge","nudge_type":"stuck"}}"#, now),
+            format!(r#"{{"ts":{},"type":"stop"}}"#, now),
+        ],
+    );
+
+    env.jig(&["events", "--all", "--type", "nudge,commit", "--since", "1h"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("nudge_type=stuck"))
+        .stdout(predicate::str::contains("idle").not())
+        .stdout(predicate::str::contains("stop").not());
+}
+
+#[test]
+fn json_output_tags_worker_and_status() {
+    let env = TestEnv::new();
+    write_raw(
+        &env,
+        "api-a",
+        &[r#"{"ts":1700000000,"type":"spawn","branch":"a"}"#.to_string()],
+    );
+
+    let out = env
+        .jig(&["events", "--all", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let event: serde_json::Value =
+        serde_json::from_str(String::from_utf8(out).unwrap().trim()).unwrap();
+    assert_eq!(event["type"], "spawn");
+    assert_eq!(event["branch"], "a");
+    assert_eq!(event["worker"], "api-a");
+    assert_eq!(event["worker_status"], "spawned");
+}
+
+#[test]
+fn requires_worker_or_all() {
+    let env = TestEnv::new();
+    env.jig(&["events"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("specify a worker or --all"));
+}
+
+#[test]
+fn compact_rotates_active_log() {
+    let env = TestEnv::new();
+    let dir = env.write_log("api-feat");
+
+    env.jig(&["events", "compact"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("api-feat:"))
+        .stdout(predicate::str::contains("archived"))
+        .stdout(predicate::str::contains("(1 archives)"));
+
+    assert!(dir.join("snapshot.json").exists());
+    assert_eq!(
+        std::fs::read_to_string(dir.join("events.jsonl")).unwrap(),
+        ""
+    );
+
+    // Nothing left to do the second time around.
+    env.jig(&["events", "compact"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("nothing to rotate (1 archives)"));
+}
+
+#[test]
+fn compact_with_no_logs() {
+    let env = TestEnv::new();
+    env.jig(&["events", "compact"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No event logs found"));
+}
+
+#[test]
+fn validate_reports_corrupt_lines() {
+    let env = TestEnv::new();
+    write_raw(
+        &env,
+        "api-a",
+        &[
+            r#"{"v":1,"ts":1700000000,"type":"spawn"}"#.to_string(),
+            r#"{"ts":1700000001,"type":"tool_use_end","tool":"say "hi""}"#.to_string(),
+            r#"{"ts":1700000002,"type":"stop"}"#.to_string(),
+        ],
+    );
+
+    env.jig(&["events", "validate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("api-a:"))
+        .stdout(predicate::str::contains("events.jsonl:2:"))
+        .stderr(predicate::str::contains(
+            "1 invalid line(s) in 1 event log(s)",
+        ))
+        .stderr(predicate::str::contains(
+            "1 line(s) predate schema versioning",
+        ));
+
+    // The corrupt line is skipped, not fatal.
+    env.jig(&["events", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("stop"));
+}
+
+/// A git repo with a jig worktree at `.jig/feat-a`.
+fn worktree_repo() -> TempDir {
+    let repo = TempDir::new().unwrap();
+    let git = |args: &[&str], dir: &std::path::Path| {
+        StdCommand::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", "Test User")
+            .env("GIT_AUTHOR_EMAIL", "test@test.com")
+            .env("GIT_COMMITTER_NAME", "Test User")
+            .env("GIT_COMMITTER_EMAIL", "test@test.com")
+            .output()
+            .expect("run git");
+    };
+    git(&["init", "-q", "-b", "main"], repo.path());
+    git(
+        &["commit", "--allow-empty", "-q", "-m", "init"],
+        repo.path(),
+    );
+    git(
+        &["worktree", "add", "-q", ".jig/feat-a", "-b", "feat-a"],
+        repo.path(),
+    );
+    repo
+}
+
+/// The events written to the `feat-a` worker's log.
+fn worker_events(env: &TestEnv, repo: &TempDir) -> Vec<serde_json::Value> {
+    let repo_name = repo.path().file_name().unwrap().to_string_lossy();
+    let log = env
+        .config_dir
+        .path()
+        .join("jig/state/events")
+        .join(format!("{}-feat-a", repo_name))
+        .join("events.jsonl");
+    std::fs::read_to_string(log)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn hooks_emit_writes_escaped_versioned_event() {
+    let env = TestEnv::new();
+    let repo = worktree_repo();
+
+    env.jig(&[
+        "hooks",
+        "emit",
+        "tool_use_end",
+        "--stdin-field",
+        "tool=tool_name",
+        "--field",
+        "note=a \"quoted\" value",
+    ])
+    .current_dir(repo.path().join(".jig/feat-a"))
+    .write_stdin(r#"{"tool_name":"Bash \"x\"","cwd":"/tmp"}"#)
+    .assert()
+    .success();
+
+    let event = &worker_events(&env, &repo)[0];
+    assert_eq!(event["v"], 1);
+    assert_eq!(event["type"], "tool_use_end");
+    assert_eq!(event["tool"], "Bash \"x\"");
+    assert_eq!(event["note"], "a \"quoted\" value");
+    assert!(event.get("cwd").is_none());
+}
+
+#[test]
+fn hooks_emit_copies_tool_input_and_transcript_usage() {
+    let env = TestEnv::new();
+    let repo = worktree_repo();
+    let transcript = repo.path().join("session.jsonl");
+    std::fs::write(
+        &transcript,
+        r#"{"message":{"id":"m1","usage":{"input_tokens":12,"output_tokens":3,"cache_read_input_tokens":40}}}"#,
+    )
+    .unwrap();
+    let input = serde_json::json!({
+        "tool_name": "Bash",
+        "tool_input": { "command": "x".repeat(600) },
+        "transcript_path": transcript,
+    });
+
+    env.jig(&[
+        "hooks",
+        "emit",
+        "stop",
+        "--stdin-field",
+        "command=tool_input.command",
+        "--stdin-field",
+        "path=tool_input.file_path",
+        "--transcript-usage",
+    ])
+    .current_dir(repo.path().join(".jig/feat-a"))
+    .write_stdin(input.to_string())
+    .assert()
+    .success();
+
+    let event = &worker_events(&env, &repo)[0];
+    assert_eq!(event["command"].as_str().unwrap().chars().count(), 501);
+    assert!(event.get("path").is_none());
+    assert_eq!(event["input_tokens"], 12);
+    assert_eq!(event["output_tokens"], 3);
+    assert_eq!(event["cache_read_tokens"], 40);
+}
+
+#[test]
+fn summary_reports_tool_durations_and_usage() {
+    let env = TestEnv::new();
+    write_raw(
+        &env,
+        "api-a",
+        &[
+            r#"{"v":1,"ts":1700000000,"type":"tool_use_start","tool":"Bash","tool_use_id":"t1"}"#.to_string(),
+            r#"{"v":1,"ts":1700000042,"type":"tool_use_end","tool":"Bash","tool_use_id":"t1","command":"cargo test"}"#.to_string(),
+            r#"{"v":1,"ts":1700000043,"type":"tool_use_end","tool":"Edit","path":"src/lib.rs"}"#.to_string(),
+            r#"{"v":1,"ts":1700000050,"type":"stop","session":"s1","input_tokens":1500,"output_tokens":20,"cost_usd":0.25}"#.to_string(),
+        ],
+    );
+
+    env.jig(&["--plain", "events", "--all", "--summary"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "api-a: 2 tool calls, 1 files, 1 commands",
+        ))
+        .stdout(predicate::str::contains("tokens: 1.5K in, 20 out"))
+        .stdout(predicate::str::contains("($0.25)"))
+        .stdout(predicate::str::contains("last command: cargo test"))
+        .stdout(predicate::str::is_match(r"Bash\s+1\s+42s\s+42s\s+42s").unwrap());
+
+    let out = env
+        .jig(&["events", "--all", "--summary", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let summary: serde_json::Value =
+        serde_json::from_str(String::from_utf8(out).unwrap().trim()).unwrap();
+    assert_eq!(summary["worker"], "api-a");
+    assert_eq!(summary["tools"]["Bash"]["total_seconds"], 42);
+    assert_eq!(summary["usage"]["input_tokens"], 1500);
+}
+
+#[test]
+fn hooks_emit_rejects_reserved_fields() {
+    let env = TestEnv::new();
+    env.jig(&["hooks", "emit", "stop", "--field", "ts=1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("reserved"));
+}
@@ -0,0 +1,304 @@
+#![allow(deprecated)] // Command::cargo_bin is deprecated but used across tests
+//! Integration tests for the GitHub Issues provider, run against a stubbed
+//! `gh` binary placed first on PATH.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Canned `gh api` responses. Issue #9 is a pull request and must be ignored.
+const ISSUES_JSON: &str = r#"[
+  {"number": 12, "title": "Fix login redirect", "body": "Redirect loops.", "state": "open",
+   "html_url": "https://github.com/acme/app/issues/12",
+   "labels": [{"name": "status:planned"}, {"name": "priority:high"}, {"name": "auth"}]},
+  {"number": 14, "title": "Dark mode", "body": null, "state": "open",
+   "html_url": "https://github.com/acme/app/issues/14",
+   "labels": [{"name": "status:in-progress"}]},
+  {"number": 9, "title": "Some PR", "body": null, "state": "open",
+   "html_url": "https://github.com/acme/app/pull/9", "labels": [],
+   "pull_request": {"url": "https://api.github.com/repos/acme/app/pulls/9"}}
+]"#;
+
+const ISSUE_12_JSON: &str = r#"{"number": 12, "title": "Fix login redirect", "body": "Redirect loops.",
+  "state": "open", "html_url": "https://github.com/acme/app/issues/12",
+  "labels": [{"name": "status:planned"}, {"name": "priority:high"}, {"name": "auth"}]}"#;
+
+struct TestRepo {
+    dir: TempDir,
+    config_dir: TempDir,
+    bin_dir: TempDir,
+}
+
+impl TestRepo {
+    fn new() -> Self {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let config_dir = TempDir::new().expect("Failed to create config dir");
+        let bin_dir = TempDir::new().expect("Failed to create bin dir");
+
+        for args in [
+            vec!["init", "-q", "-b", "main"],
+            vec!["config", "user.email", "test@test.com"],
+            vec!["config", "user.name", "Test User"],
+            vec!["config", "commit.gpgsign", "false"],
+            vec!["commit", "--allow-empty", "-m", "init", "-q"],
+        ] {
+            StdCommand::new("git")
+                .args(&args)
+                .current_dir(dir.path())
+                .output()
+                .expect("Failed to run git");
+        }
+
+        let config_file = config_dir.path().join("jig").join("config");
+        fs::create_dir_all(config_file.parent().unwrap()).unwrap();
+        fs::write(&config_file, "_default=main\n").expect("Failed to write config");
+
+        fs::write(
+            dir.path().join("jig.toml"),
+            "[issues]\nprovider = \"github\"\n\n[issues.github]\nrepo = \"acme/app\"\n",
+        )
+        .unwrap();
+
+        fs::write(bin_dir.path().join("issues.json"), ISSUES_JSON).unwrap();
+        fs::write(bin_dir.path().join("issue-12.json"), ISSUE_12_JSON).unwrap();
+
+        // Stub `gh`: log every invocation (and any request body on stdin),
+        // then answer from the canned JSON files.
+        let script = format!(
+            r#"#!/bin/sh
+dir="{dir}"
+echo "$*" >> "$dir/calls.log"
+case "$*" in
+  *--input*) cat >> "$dir/calls.log"; echo >> "$dir/calls.log"; echo '{{}}' ;;
+  *"repos/acme/app/issues?"*"page=1 "*) cat "$dir/issues.json" ;;
+  *"repos/acme/app/issues?"*) cat "$dir/issues-page-2.json" 2>/dev/null || echo '[]' ;;
+  *"--include repos/acme/app/issues/12") printf 'HTTP/2.0 200 OK\r\n\r\n'; cat "$dir/issue-12.json" ;;
+  *--include*) printf 'HTTP/2.0 404 Not Found\r\n\r\n{{"message":"Not Found"}}'; echo "gh: Not Found (HTTP 404)" >&2; exit 1 ;;
+  *) echo "gh: Not Found (HTTP 404)" >&2; exit 1 ;;
+esac
+"#,
+            dir = bin_dir.path().display()
+        );
+        let gh = bin_dir.path().join("gh");
+        fs::write(&gh, script).unwrap();
+        fs::set_permissions(&gh, fs::Permissions::from_mode(0o755)).unwrap();
+
+        TestRepo {
+            dir,
+            config_dir,
+            bin_dir,
+        }
+    }
+
+    #[allow(deprecated)]
+    fn jig(&self) -> Command {
+        let mut cmd = Command::cargo_bin("jig").expect("Failed to find jig binary");
+        let path = std::env::var("PATH").unwrap_or_default();
+        cmd.current_dir(self.dir.path());
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd.env_remove("GITHUB_TOKEN");
+        cmd.env(
+            "PATH",
+            format!("{}:{}", self.bin_dir.path().display(), path),
+        );
+        cmd
+    }
+
+    fn calls_log(&self) -> PathBuf {
+        self.bin_dir.path().join("calls.log")
+    }
+}
+
+#[test]
+fn list_maps_labels_and_skips_pull_requests() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fix login redirect"))
+        .stdout(predicate::str::contains("Dark mode"))
+        .stdout(predicate::str::contains("Some PR").not());
+}
+
+#[test]
+fn auto_lists_only_planned_issues() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues", "--auto", "--ids"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("12"))
+        .stdout(predicate::str::contains("14").not());
+}
+
+#[test]
+fn detail_fetches_single_issue() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues", "#12"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fix login redirect"))
+        .stdout(predicate::str::contains("Redirect loops."));
+}
+
+#[test]
+fn list_follows_pages() {
+    let repo = TestRepo::new();
+    let page: Vec<String> = (100..200)
+        .map(|n| {
+            format!(
+                r#"{{"number": {n}, "title": "Issue {n}", "body": null, "state": "open", "html_url": "", "labels": []}}"#
+            )
+        })
+        .collect();
+    fs::write(
+        repo.bin_dir.path().join("issues.json"),
+        format!("[{}]", page.join(",")),
+    )
+    .unwrap();
+    fs::write(
+        repo.bin_dir.path().join("issues-page-2.json"),
+        r#"[{"number": 500, "title": "Last page issue", "body": null, "state": "open", "html_url": "", "labels": []}]"#,
+    )
+    .unwrap();
+
+    repo.jig()
+        .args(["issues", "--ids"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("199"))
+        .stdout(predicate::str::contains("500"));
+
+    let log = fs::read_to_string(repo.calls_log()).unwrap();
+    assert!(log.contains("page=2"));
+    assert!(!log.contains("page=3"));
+}
+
+#[test]
+fn detail_of_missing_issue_is_not_found() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues", "#77"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("issue not found: #77"));
+}
+
+#[test]
+fn status_update_replaces_status_label() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues", "status", "12", "--status", "in-progress"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated 12 -> In Progress"));
+
+    let log = fs::read_to_string(repo.calls_log()).unwrap();
+    // The labels being rewritten are read fresh, never from the cache.
+    assert!(log.contains("api --include repos/acme/app/issues/12\n"));
+    assert!(!log.contains("issues/12 --cache"));
+    assert!(log.contains("api -X PUT repos/acme/app/issues/12/labels --input -"));
+    assert!(log.contains(r#"{"labels":["priority:high","auth","status:in-progress"]}"#));
+    assert!(!log.contains("-X PATCH"));
+}
+
+#[test]
+fn complete_closes_issue() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues", "complete", "12"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Completed: 12"));
+
+    let log = fs::read_to_string(repo.calls_log()).unwrap();
+    assert!(log.contains(r#"{"labels":["priority:high","auth"]}"#));
+    assert!(log.contains("api -X PATCH repos/acme/app/issues/12 --input -"));
+    assert!(log.contains(r#""state":"closed""#));
+}
+
+#[test]
+fn create_is_rejected() {
+    let repo = TestRepo::new();
+
+    repo.jig()
+        .args(["issues", "create", "New thing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("gh issue create"));
+}
+
+/// Serve `body` once on a local port; returns the base URL and a receiver
+/// for the request line and `Authorization` header.
+fn serve_once(body: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut seen = String::new();
+        reader.read_line(&mut seen).unwrap();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+            let lower = line.to_ascii_lowercase();
+            if let Some(v) = lower.strip_prefix("content-length:") {
+                content_length = v.trim().parse().unwrap();
+            }
+            if lower.starts_with("authorization:") {
+                seen.push_str(&line);
+            }
+        }
+        let mut discard = vec![0; content_length];
+        reader.read_exact(&mut discard).unwrap();
+        tx.send(seen).unwrap();
+        let mut stream = reader.into_inner();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+    });
+    (base, rx)
+}
+
+#[test]
+fn token_uses_native_http_api_instead_of_gh() {
+    let repo = TestRepo::new();
+    let (base, rx) = serve_once(ISSUES_JSON);
+
+    repo.jig()
+        .env("GITHUB_TOKEN", "test-token")
+        .env("GITHUB_API_URL", &base)
+        .args(["issues"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fix login redirect"))
+        .stdout(predicate::str::contains("Some PR").not());
+
+    let request = rx.recv().unwrap();
+    assert!(request.starts_with("GET /repos/acme/app/issues?"));
+    assert!(request.contains("Bearer test-token"));
+    assert!(!repo.calls_log().exists(), "gh should not be invoked");
+}
@@ -0,0 +1,155 @@
+#![allow(deprecated)]
+//! Integration tests for `jig merge` strategies and `--cleanup`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command as StdCommand;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = StdCommand::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Test User")
+        .env("GIT_AUTHOR_EMAIL", "test@test.com")
+        .env("GIT_COMMITTER_NAME", "Test User")
+        .env("GIT_COMMITTER_EMAIL", "test@test.com")
+        .output()
+        .expect("run git");
+    assert!(output.status.success(), "git {:?} failed", args);
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+struct TestEnv {
+    config_dir: TempDir,
+    repo: TempDir,
+}
+
+impl TestEnv {
+    /// A repo on `main` with a `feat-a` worktree two commits ahead.
+    fn new() -> Self {
+        let repo = TempDir::new().unwrap();
+        git(repo.path(), &["init", "-q", "-b", "main"]);
+        std::fs::write(
+            repo.path().join("jig.toml"),
+            "[worktree]\nbase = \"main\"\n",
+        )
+        .unwrap();
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-q", "-m", "init"]);
+
+        git(
+            repo.path(),
+            &["worktree", "add", "-q", ".jig/feat-a", "-b", "feat-a"],
+        );
+        let worktree = repo.path().join(".jig/feat-a");
+        for (file, message) in [("a.rs", "feat: add a"), ("b.rs", "wip")] {
+            std::fs::write(worktree.join(file), file).unwrap();
+            git(&worktree, &["add", "."]);
+            git(&worktree, &["commit", "-q", "-m", message]);
+        }
+
+        Self {
+            config_dir: TempDir::new().unwrap(),
+            repo,
+        }
+    }
+
+    fn jig(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd.env("GIT_COMMITTER_NAME", "Test User");
+        cmd.env("GIT_COMMITTER_EMAIL", "test@test.com");
+        cmd.current_dir(self.repo.path());
+        cmd
+    }
+
+    fn log(&self) -> String {
+        git(self.repo.path(), &["log", "--format=%s", "main"])
+    }
+
+    fn events_dir(&self) -> PathBuf {
+        let repo = self.repo.path().file_name().unwrap().to_string_lossy();
+        self.config_dir
+            .path()
+            .join(format!("jig/state/events/{}-feat-a", repo))
+    }
+}
+
+#[test]
+fn squash_rejects_invalid_message() {
+    let env = TestEnv::new();
+    env.jig(&[
+        "merge",
+        "feat-a",
+        "--strategy",
+        "squash",
+        "-m",
+        "Added stuff",
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("not a valid conventional commit"));
+    assert_eq!(env.log(), "init");
+}
+
+#[test]
+fn squash_lands_one_commit() {
+    let env = TestEnv::new();
+    env.jig(&["merge", "feat-a", "--strategy", "squash"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Squashed branch 'feat-a'"));
+    assert_eq!(env.log(), "feat: add a\ninit");
+    assert!(env.repo.path().join("b.rs").exists());
+}
+
+#[test]
+fn rebase_fast_forwards_onto_current_branch() {
+    let env = TestEnv::new();
+    std::fs::write(env.repo.path().join("main.rs"), "main").unwrap();
+    git(env.repo.path(), &["add", "main.rs"]);
+    git(env.repo.path(), &["commit", "-q", "-m", "main"]);
+
+    env.jig(&["merge", "feat-a", "--strategy", "rebase"])
+        .assert()
+        .success();
+    assert_eq!(env.log(), "wip\nfeat: add a\nmain\ninit");
+}
+
+#[test]
+fn cleanup_removes_worktree_and_archives_events() {
+    let env = TestEnv::new();
+    let events = env.events_dir();
+    std::fs::create_dir_all(&events).unwrap();
+    std::fs::write(
+        events.join("events.jsonl"),
+        "{\"ts\":1,\"type\":\"spawn\"}\n",
+    )
+    .unwrap();
+
+    env.jig(&["merge", "feat-a", "--cleanup"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed worktree 'feat-a'"))
+        .stderr(predicate::str::contains("jig remove").not());
+
+    assert!(!env.repo.path().join(".jig/feat-a").exists());
+    let archived: String = std::fs::read_dir(&events)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| {
+            p.file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("events.")
+        })
+        .filter(|p| p.file_name().unwrap() != "events.jsonl")
+        .map(|p| std::fs::read_to_string(p).unwrap())
+        .collect();
+    assert!(archived.contains("\"spawn\""));
+    assert!(archived.contains("\"terminal\":\"merged\""));
+}
@@ -285,3 +285,94 @@ fn send_pr_opened_writes_to_queue() {
     assert!(content.contains("pr_opened"));
     assert!(content.contains("https://github.com/test/pr/1"));
 }
+
+// --- digest ---
+
+fn send_pr_opened(env: &TestEnv, worker: &str) {
+    env.jig()
+        .args([
+            "notify",
+            "send",
+            "pr-opened",
+            "--repo",
+            "r",
+            "--worker",
+            worker,
+            "--pr-url",
+            "https://github.com/o/r/pull/1",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn digest_summarizes_recent_events() {
+    let env = TestEnv::new();
+    send_pr_opened(&env, "w1");
+    send_pr_opened(&env, "w2");
+    env.jig()
+        .args([
+            "notify",
+            "send",
+            "needs-intervention",
+            "--repo",
+            "r",
+            "--worker",
+            "w3",
+            "--reason",
+            "stuck",
+        ])
+        .assert()
+        .success();
+
+    env.jig()
+        .args(["notify", "digest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2 PRs opened, 1 needs intervention",
+        ))
+        .stdout(predicate::str::contains("r/w3: needs intervention: stuck"));
+}
+
+#[test]
+fn digest_ignores_events_outside_window() {
+    let env = TestEnv::new();
+    env.seed_queue(&[
+        r#"{"ts":1000,"id":"aaa","type":"work_started","repo":"r","worker":"w1","issue":null}"#,
+    ]);
+
+    env.jig()
+        .args(["notify", "digest", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""type":"digest""#))
+        .stdout(predicate::str::contains(r#""summary":"no notifications""#));
+}
+
+#[test]
+fn digest_mode_holds_events_and_send_delivers_summary() {
+    let env = TestEnv::new();
+    let out = env.dir.path().join("hook-output.json");
+    let env = env.with_notify_config(&format!(
+        "[notify]\nexec = \"cat >> {}\"\n\n[notify.digest]\nwindow_seconds = 3600\nevents = [\"pr_opened\"]\n",
+        out.display()
+    ));
+
+    send_pr_opened(&env, "w1");
+    send_pr_opened(&env, "w2");
+    assert!(
+        !out.exists(),
+        "digest events should not be delivered one by one"
+    );
+
+    env.jig()
+        .args(["notify", "digest", "--minutes", "5", "--send"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 PRs opened"));
+
+    let sent = fs::read_to_string(&out).unwrap();
+    assert!(sent.contains(r#""type":"digest""#), "got: {sent}");
+    assert!(sent.contains(r#""pr_opened":2"#), "got: {sent}");
+}
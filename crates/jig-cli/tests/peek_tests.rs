@@ -0,0 +1,28 @@
+#![allow(deprecated)] // Command::cargo_bin is deprecated but used across tests
+
+use std::process::Command as StdCommand;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_peek_unknown_worker() {
+    let repo = TempDir::new().expect("Failed to create temp dir");
+    let config_dir = TempDir::new().expect("Failed to create config dir");
+    StdCommand::new("git")
+        .args(["init", "-q"])
+        .current_dir(repo.path())
+        .output()
+        .expect("Failed to init repo");
+
+    let mut cmd = Command::cargo_bin("jig").expect("Failed to find jig binary");
+    cmd.current_dir(repo.path());
+    cmd.env("XDG_CONFIG_HOME", config_dir.path());
+    cmd.args(["peek", "nonexistent-worker", "-n", "5"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Worker 'nonexistent-worker' not found",
+        ));
+}
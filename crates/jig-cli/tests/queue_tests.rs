@@ -0,0 +1,92 @@
+#![allow(deprecated)]
+//! Integration tests for `jig queue`.
+
+use std::process::Command as StdCommand;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+struct TestEnv {
+    config_dir: TempDir,
+    repo: TempDir,
+}
+
+impl TestEnv {
+    fn new() -> Self {
+        let repo = TempDir::new().unwrap();
+        StdCommand::new("git")
+            .args(["init", "-q", "-b", "main"])
+            .current_dir(repo.path())
+            .output()
+            .expect("run git");
+        Self {
+            config_dir: TempDir::new().unwrap(),
+            repo,
+        }
+    }
+
+    fn repo_name(&self) -> String {
+        self.repo
+            .path()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string()
+    }
+
+    fn jig(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd.current_dir(self.repo.path());
+        cmd
+    }
+
+    /// One landing and one failed worker in this repo, one queued elsewhere.
+    fn write_queue(&self) {
+        let repo = self.repo_name();
+        let state = self.config_dir.path().join("jig/state");
+        std::fs::create_dir_all(&state).unwrap();
+        let queue = format!(
+            r#"{{"entries":[
+                {{"repo":"{repo}","worker":"feat-a","branch":"feat-a","priority":"High","enqueued_at":0,"state":"landing"}},
+                {{"repo":"other","worker":"feat-c","branch":"feat-c","enqueued_at":0}},
+                {{"repo":"{repo}","worker":"feat-b","branch":"feat-b","enqueued_at":0,"state":"failed","attempts":2,"last_error":"verify failed"}}
+            ]}}"#
+        );
+        std::fs::write(state.join("merge_queue.json"), queue).unwrap();
+    }
+}
+
+#[test]
+fn empty_queue() {
+    let env = TestEnv::new();
+    env.jig(&["queue"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No workers queued"));
+}
+
+#[test]
+fn lists_this_repos_entries_in_order() {
+    let env = TestEnv::new();
+    env.write_queue();
+    env.jig(&["--plain", "queue"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match("^1\tfeat-a\tlanding\tHigh\t0\t").unwrap())
+        .stdout(predicate::str::contains("2\tfeat-b\tfailed\t-\t2\t"))
+        .stdout(predicate::str::contains("verify failed"))
+        .stdout(predicate::str::contains("feat-c").not());
+}
+
+#[test]
+fn global_lists_every_repo() {
+    let env = TestEnv::new();
+    env.write_queue();
+    env.jig(&["--plain", "-g", "queue"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2\tother\tfeat-c\tqueued"));
+}
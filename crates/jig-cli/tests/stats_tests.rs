@@ -0,0 +1,205 @@
+#![allow(deprecated)]
+//! Integration tests for `jig stats`.
+
+use std::process::Command as StdCommand;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+const REVIEW: &str = "\
+# Review 001
+Reviewed: abc123 | 2026-04-04T12:00:00Z
+
+## Correctness
+- [FAIL] `src/foo.rs:42` — missing null check
+
+## Conventions
+- [PASS] No issues found
+
+## Error Handling
+- [PASS] Appropriate for context
+
+## Security
+- [PASS] No issues found
+
+## Test Coverage
+- [WARN] `src/foo.rs` — new public function `bar()` has no test
+
+## Documentation
+- [PASS] No updates needed
+
+## Summary
+VERDICT: changes_requested
+
+Missing null check is a blocker.
+";
+
+struct TestEnv {
+    config_dir: TempDir,
+    repo: TempDir,
+}
+
+impl TestEnv {
+    /// A repo with one worktree (`feat-a`) holding a changes-requested review.
+    fn new() -> Self {
+        let repo = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            StdCommand::new("git")
+                .args(args)
+                .current_dir(repo.path())
+                .env("GIT_AUTHOR_NAME", "Test User")
+                .env("GIT_AUTHOR_EMAIL", "test@test.com")
+                .env("GIT_COMMITTER_NAME", "Test User")
+                .env("GIT_COMMITTER_EMAIL", "test@test.com")
+                .output()
+                .expect("run git");
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["commit", "--allow-empty", "-q", "-m", "init"]);
+        git(&["worktree", "add", "-q", ".jig/feat-a", "-b", "feat-a"]);
+
+        let reviews = repo.path().join(".jig/feat-a/.jig/reviews");
+        std::fs::create_dir_all(&reviews).unwrap();
+        std::fs::write(reviews.join("001.md"), REVIEW).unwrap();
+
+        Self {
+            config_dir: TempDir::new().unwrap(),
+            repo,
+        }
+    }
+
+    fn repo_name(&self) -> String {
+        self.repo
+            .path()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string()
+    }
+
+    fn jig(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("jig").unwrap();
+        cmd.args(args);
+        cmd.env("XDG_CONFIG_HOME", self.config_dir.path());
+        cmd.current_dir(self.repo.path());
+        cmd
+    }
+
+    fn write_log(&self, dir_name: &str, lines: &[String]) {
+        let dir = self
+            .config_dir
+            .path()
+            .join("jig/state/events")
+            .join(dir_name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("events.jsonl"), lines.join("\n") + "\n").unwrap();
+    }
+
+    /// A merged worker (1h to PR, 2h to merge) and a failed one that was
+    /// nudged as stuck, plus a worker from another repo.
+    fn write_history(&self, start: i64) {
+        let repo = self.repo_name();
+        self.write_log(
+            &format!("{}-feat-a", repo),
+            &[
+                format!(
+                    r#"{{"ts":{},"type":"spawn","repo":"{}","issue":"JIG-1"}}"#,
+                    start, repo
+                ),
+                format!(
+                    r#"{{"ts":{},"type":"nudge","nudge_type":"idle"}}"#,
+                    start + 60
+                ),
+                format!(
+                    r#"{{"ts":{},"type":"pr_opened","pr_url":"https://x/1"}}"#,
+                    start + 3600
+                ),
+                format!(
+                    r#"{{"ts":{},"type":"terminal","terminal":"merged"}}"#,
+                    start + 3 * 3600
+                ),
+            ],
+        );
+        self.write_log(
+            &format!("{}-feat-b", repo),
+            &[
+                format!(
+                    r#"{{"ts":{},"type":"spawn","repo":"{}","issue":"JIG-1"}}"#,
+                    start, repo
+                ),
+                format!(
+                    r#"{{"ts":{},"type":"nudge","nudge_type":"stuck"}}"#,
+                    start + 60
+                ),
+                format!(
+                    r#"{{"ts":{},"type":"nudge","nudge_type":"stuck"}}"#,
+                    start + 120
+                ),
+                format!(
+                    r#"{{"ts":{},"type":"terminal","terminal":"failed"}}"#,
+                    start + 180
+                ),
+            ],
+        );
+        self.write_log(
+            "other-feat-c",
+            &[format!(
+                r#"{{"ts":{},"type":"spawn","repo":"other"}}"#,
+                start
+            )],
+        );
+    }
+}
+
+#[test]
+fn reports_outcomes_nudges_and_reviews_for_the_repo() {
+    let env = TestEnv::new();
+    env.write_history(chrono::Utc::now().timestamp() - 4 * 3600);
+
+    env.jig(&["--plain", "stats"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2 across 1 issues (1 PRs, 1 merged, 1 failed)",
+        ))
+        .stdout(predicate::str::contains("median 1h, mean 1h (1)"))
+        .stdout(predicate::str::contains("median 2h, mean 2h (1)"))
+        .stdout(predicate::str::contains("1.5 per worker: stuck 2, idle 1"))
+        .stdout(predicate::str::contains(
+            "1 rounds, 1 changes requested (100%)",
+        ))
+        .stdout(predicate::str::contains("correctness 1, test_coverage 1"))
+        .stdout(predicate::str::contains("1 failed, after nudges: stuck 2"))
+        .stdout(predicate::str::contains("WEEK"));
+}
+
+#[test]
+fn json_report_and_since_window() {
+    let env = TestEnv::new();
+    env.write_history(1_700_000_000);
+
+    let out = env
+        .jig(&["stats", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(report["total"]["workers"], 2);
+    assert_eq!(report["total"]["spawn_to_pr"]["median_secs"], 3600);
+    assert_eq!(report["by_repo"][env.repo_name()]["failed"], 1);
+    assert!(report["by_repo"].get("other").is_none());
+
+    // The workers are from 2023 and the review from April 2026.
+    let out = env
+        .jig(&["stats", "--json", "--since", "1d"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(report["total"]["workers"], 0);
+}
@@ -1,67 +1,156 @@
 //! Agent adapters for different AI coding assistants
 //!
-//! Each adapter knows how to lay out files for a specific agent (Claude Code, Cursor, etc.)
+//! Each adapter knows how to lay out files for a specific agent and how to
+//! build its command lines. Built-in adapters cover Claude Code, Codex, Aider
+//! and Gemini; `AgentRegistry` layers `[agents.<name>]` definitions from
+//! jig.toml and the global config on top of them.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::config::{AgentDefinition, JigToml};
+use crate::error::{Error, Result};
+use crate::global::GlobalConfig;
 
 /// Agent type enum for compile-time safe matching
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AgentType {
     Claude,
-    // Cursor,  // Future
+    Codex,
+    Aider,
+    Gemini,
+    /// Adapter defined entirely in config
+    Custom,
 }
 
 /// Agent adapter containing all agent-specific configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AgentAdapter {
     /// Agent type for pattern matching
     pub agent_type: AgentType,
-    /// Agent name (e.g., "claude", "cursor")
-    pub name: &'static str,
+    /// Agent name (e.g., "claude", "codex")
+    pub name: Cow<'static, str>,
     /// Command to invoke the agent
-    pub command: &'static str,
+    pub command: Cow<'static, str>,
     /// Directory for skills (relative to repo root)
-    pub skills_dir: &'static str,
+    pub skills_dir: Cow<'static, str>,
     /// Skill file name (e.g., "SKILL.md", "rule.mdc")
-    pub skill_file: &'static str,
+    pub skill_file: Cow<'static, str>,
     /// Settings file path (relative to repo root), if any
-    pub settings_file: Option<&'static str>,
-    /// Project context file (e.g., "CLAUDE.md", ".cursorrules")
-    pub project_file: &'static str,
+    pub settings_file: Option<Cow<'static, str>>,
+    /// Project context file (e.g., "CLAUDE.md", "AGENTS.md")
+    pub project_file: Cow<'static, str>,
     /// Flag to run in auto mode
-    pub auto_flag: &'static str,
+    pub auto_flag: Cow<'static, str>,
     /// Flags for ephemeral (one-shot, non-interactive) execution mode.
     /// Empty string means ephemeral mode is unsupported for this adapter.
-    pub ephemeral_flags: &'static str,
+    pub ephemeral_flags: Cow<'static, str>,
+    /// Flag preceding the prompt in ephemeral mode.
+    /// Empty string means the prompt is positional (or read from stdin).
+    pub ephemeral_prompt_flag: Cow<'static, str>,
     /// Flag to continue the most recent session in the current cwd.
     /// Empty string means session continuation is unsupported.
-    pub continue_flag: &'static str,
+    pub continue_flag: Cow<'static, str>,
+    /// Flag preceding the initial prompt of an interactive session.
+    /// Empty string means the prompt is passed as a positional argument.
+    pub prompt_flag: Cow<'static, str>,
+    /// Type the initial prompt into the pane once the agent is running,
+    /// instead of passing it on the command line. For agents that treat a
+    /// command-line prompt as one-shot and exit after replying.
+    pub send_prompt: bool,
+    /// Flag used to select a model. Empty string means unsupported.
+    pub model_flag: Cow<'static, str>,
+    /// Flag used to pass a tool allowlist. Empty string means unsupported.
+    pub allowed_tools_flag: Cow<'static, str>,
+    /// Flag used to pass a tool denylist. Empty string means unsupported.
+    pub disallowed_tools_flag: Cow<'static, str>,
 }
 
 /// Claude Code adapter
 pub const CLAUDE_CODE: AgentAdapter = AgentAdapter {
     agent_type: AgentType::Claude,
-    name: "claude",
-    command: "claude",
-    skills_dir: ".claude/skills",
-    skill_file: "SKILL.md",
-    settings_file: Some(".claude/settings.json"),
-    project_file: "CLAUDE.md",
-    auto_flag: "--dangerously-skip-permissions",
-    ephemeral_flags: "--print --no-session-persistence --dangerously-skip-permissions",
-    continue_flag: "-c",
+    name: Cow::Borrowed("claude"),
+    command: Cow::Borrowed("claude"),
+    skills_dir: Cow::Borrowed(".claude/skills"),
+    skill_file: Cow::Borrowed("SKILL.md"),
+    settings_file: Some(Cow::Borrowed(".claude/settings.json")),
+    project_file: Cow::Borrowed("CLAUDE.md"),
+    auto_flag: Cow::Borrowed("--dangerously-skip-permissions"),
+    ephemeral_flags: Cow::Borrowed(
+        "--print --no-session-persistence --dangerously-skip-permissions",
+    ),
+    ephemeral_prompt_flag: Cow::Borrowed(""),
+    continue_flag: Cow::Borrowed("-c"),
+    prompt_flag: Cow::Borrowed(""),
+    send_prompt: false,
+    model_flag: Cow::Borrowed("--model"),
+    allowed_tools_flag: Cow::Borrowed("--allowed-tools"),
+    disallowed_tools_flag: Cow::Borrowed("--disallowedTools"),
+};
+
+/// OpenAI Codex CLI adapter
+pub const CODEX: AgentAdapter = AgentAdapter {
+    agent_type: AgentType::Codex,
+    name: Cow::Borrowed("codex"),
+    command: Cow::Borrowed("codex"),
+    skills_dir: Cow::Borrowed(".codex/skills"),
+    skill_file: Cow::Borrowed("SKILL.md"),
+    settings_file: None,
+    project_file: Cow::Borrowed("AGENTS.md"),
+    auto_flag: Cow::Borrowed("--dangerously-bypass-approvals-and-sandbox"),
+    ephemeral_flags: Cow::Borrowed("exec --dangerously-bypass-approvals-and-sandbox"),
+    ephemeral_prompt_flag: Cow::Borrowed(""),
+    continue_flag: Cow::Borrowed("resume --last"),
+    prompt_flag: Cow::Borrowed(""),
+    send_prompt: false,
+    model_flag: Cow::Borrowed("--model"),
+    allowed_tools_flag: Cow::Borrowed(""),
+    disallowed_tools_flag: Cow::Borrowed(""),
+};
+
+/// Aider adapter
+pub const AIDER: AgentAdapter = AgentAdapter {
+    agent_type: AgentType::Aider,
+    name: Cow::Borrowed("aider"),
+    command: Cow::Borrowed("aider"),
+    skills_dir: Cow::Borrowed(".aider/skills"),
+    skill_file: Cow::Borrowed("SKILL.md"),
+    settings_file: None,
+    project_file: Cow::Borrowed("CONVENTIONS.md"),
+    auto_flag: Cow::Borrowed("--yes-always"),
+    ephemeral_flags: Cow::Borrowed("--yes-always --no-pretty"),
+    ephemeral_prompt_flag: Cow::Borrowed("--message"),
+    continue_flag: Cow::Borrowed("--restore-chat-history"),
+    prompt_flag: Cow::Borrowed(""),
+    send_prompt: true,
+    model_flag: Cow::Borrowed("--model"),
+    allowed_tools_flag: Cow::Borrowed(""),
+    disallowed_tools_flag: Cow::Borrowed(""),
+};
+
+/// Gemini CLI adapter
+pub const GEMINI: AgentAdapter = AgentAdapter {
+    agent_type: AgentType::Gemini,
+    name: Cow::Borrowed("gemini"),
+    command: Cow::Borrowed("gemini"),
+    skills_dir: Cow::Borrowed(".gemini/skills"),
+    skill_file: Cow::Borrowed("SKILL.md"),
+    settings_file: None,
+    project_file: Cow::Borrowed("GEMINI.md"),
+    auto_flag: Cow::Borrowed("--yolo"),
+    ephemeral_flags: Cow::Borrowed("--yolo"),
+    ephemeral_prompt_flag: Cow::Borrowed(""),
+    continue_flag: Cow::Borrowed(""),
+    prompt_flag: Cow::Borrowed("--prompt-interactive"),
+    send_prompt: false,
+    model_flag: Cow::Borrowed("--model"),
+    allowed_tools_flag: Cow::Borrowed(""),
+    disallowed_tools_flag: Cow::Borrowed(""),
 };
 
-// Future adapters:
-// pub const CURSOR: AgentAdapter = AgentAdapter {
-//     name: "cursor",
-//     command: "cursor",
-//     skills_dir: ".cursor/rules",
-//     skill_file: "rule.mdc",
-//     settings_file: None,
-//     project_file: ".cursorrules",
-//     auto_flag: "",
-//     ephemeral_flags: "",
-//     continue_flag: "",
-// };
+/// All built-in adapters, in display order.
+pub const BUILTIN_ADAPTERS: &[AgentAdapter] = &[CLAUDE_CODE, CODEX, AIDER, GEMINI];
 
 impl AgentAdapter {
     /// Returns true if this adapter supports ephemeral (one-shot) execution mode.
@@ -76,60 +165,224 @@ impl AgentAdapter {
 
     /// Build a command string for ephemeral (one-shot, non-interactive) execution.
     ///
-    /// The command includes the adapter's ephemeral flags, optional allowed-tools,
-    /// and the prompt as a single-quoted shell argument.
+    /// The command includes the adapter's ephemeral flags, optional allowed-tools
+    /// (when the adapter has an allowlist flag), and the prompt as a
+    /// single-quoted shell argument, after `ephemeral_prompt_flag` if set.
     pub fn build_ephemeral_command(&self, prompt: &str, allowed_tools: &[&str]) -> String {
         let mut cmd = format!("{} {}", self.command, self.ephemeral_flags);
 
-        if !allowed_tools.is_empty() {
+        if !allowed_tools.is_empty() && !self.allowed_tools_flag.is_empty() {
             let tools = allowed_tools.join(",");
-            cmd = format!("{} --allowed-tools \"{}\"", cmd, tools);
+            cmd = format!("{} {} \"{}\"", cmd, self.allowed_tools_flag, tools);
+        }
+
+        if !self.ephemeral_prompt_flag.is_empty() {
+            cmd = format!("{} {}", cmd, self.ephemeral_prompt_flag);
         }
 
-        // Escape single quotes in prompt
-        let escaped = prompt.replace('\'', "'\\''");
-        format!("{} '{}'", cmd, escaped)
+        format!("{} {}", cmd, shell_quote(prompt))
+    }
+
+    /// Apply an `[agents.<name>]` definition on top of this adapter.
+    fn with_definition(mut self, def: &AgentDefinition) -> Self {
+        let fields = [
+            (&mut self.command, &def.command),
+            (&mut self.auto_flag, &def.auto_flag),
+            (&mut self.ephemeral_flags, &def.ephemeral_flags),
+            (&mut self.ephemeral_prompt_flag, &def.ephemeral_prompt_flag),
+            (&mut self.continue_flag, &def.continue_flag),
+            (&mut self.prompt_flag, &def.prompt_flag),
+            (&mut self.model_flag, &def.model_flag),
+            (&mut self.allowed_tools_flag, &def.allowed_tools_flag),
+            (&mut self.disallowed_tools_flag, &def.disallowed_tools_flag),
+            (&mut self.skills_dir, &def.skills_dir),
+            (&mut self.skill_file, &def.skill_file),
+            (&mut self.project_file, &def.project_file),
+        ];
+        for (field, value) in fields {
+            if let Some(value) = value {
+                *field = Cow::Owned(value.clone());
+            }
+        }
+        if let Some(send_prompt) = def.send_prompt {
+            self.send_prompt = send_prompt;
+        }
+        self
+    }
+
+    /// Base for a config-only adapter: every flag unsupported, generic file layout.
+    fn custom(name: &str) -> Self {
+        Self {
+            agent_type: AgentType::Custom,
+            name: Cow::Owned(name.to_string()),
+            command: Cow::Owned(name.to_string()),
+            skills_dir: Cow::Borrowed(".agents/skills"),
+            skill_file: Cow::Borrowed("SKILL.md"),
+            settings_file: None,
+            project_file: Cow::Borrowed("AGENTS.md"),
+            auto_flag: Cow::Borrowed(""),
+            ephemeral_flags: Cow::Borrowed(""),
+            ephemeral_prompt_flag: Cow::Borrowed(""),
+            continue_flag: Cow::Borrowed(""),
+            prompt_flag: Cow::Borrowed(""),
+            send_prompt: false,
+            model_flag: Cow::Borrowed(""),
+            allowed_tools_flag: Cow::Borrowed(""),
+            disallowed_tools_flag: Cow::Borrowed(""),
+        }
     }
 }
 
-/// Get an adapter by name
+/// Get a built-in adapter by name
 pub fn get_adapter(name: &str) -> Option<&'static AgentAdapter> {
-    match name {
-        "claude" => Some(&CLAUDE_CODE),
-        // "cursor" => Some(&CURSOR),
-        _ => None,
-    }
+    BUILTIN_ADAPTERS.iter().find(|a| a.name == name)
 }
 
-/// Get list of supported agent names
+/// Get list of built-in agent names
 pub fn supported_agents() -> &'static [&'static str] {
-    &["claude"]
+    &["claude", "codex", "aider", "gemini"]
+}
+
+/// Resolves agent names to adapters.
+///
+/// Lookup order for a name is repo `[agents.<name>]` > global
+/// `[agents.<name>]` > built-in. Definitions are merged field by field, so a
+/// repo can override a single flag of a globally defined or built-in agent.
+#[derive(Debug, Clone, Default)]
+pub struct AgentRegistry {
+    definitions: HashMap<String, AgentDefinition>,
+}
+
+impl AgentRegistry {
+    /// Build a registry from repo and global `[agents]` tables.
+    pub fn new(
+        repo: &HashMap<String, AgentDefinition>,
+        global: &HashMap<String, AgentDefinition>,
+    ) -> Self {
+        let mut definitions = global.clone();
+        for (name, def) in repo {
+            let merged = match definitions.remove(name) {
+                Some(base) => merge_definitions(&base, def),
+                None => def.clone(),
+            };
+            definitions.insert(name.clone(), merged);
+        }
+        Self { definitions }
+    }
+
+    /// Build a registry from already-loaded repo and global config.
+    pub fn from_config(jig_toml: &JigToml, global: &GlobalConfig) -> Self {
+        Self::new(&jig_toml.agents, &global.agents)
+    }
+
+    /// Load jig.toml and the global config and build a registry from them.
+    pub fn load(repo_root: &Path) -> Result<Self> {
+        let jig_toml = JigToml::load(repo_root)?.unwrap_or_default();
+        let global = GlobalConfig::load()?;
+        Ok(Self::from_config(&jig_toml, &global))
+    }
+
+    /// Look up an adapter by name, returning `None` if it is neither built in
+    /// nor defined in config.
+    pub fn get(&self, name: &str) -> Option<AgentAdapter> {
+        let builtin = get_adapter(name).cloned();
+        match (builtin, self.definitions.get(name)) {
+            (Some(adapter), Some(def)) => Some(adapter.with_definition(def)),
+            (Some(adapter), None) => Some(adapter),
+            (None, Some(def)) => Some(AgentAdapter::custom(name).with_definition(def)),
+            (None, None) => None,
+        }
+    }
+
+    /// Look up an adapter by name, erroring if it is unknown.
+    pub fn resolve(&self, name: &str) -> Result<AgentAdapter> {
+        self.get(name)
+            .ok_or_else(|| Error::UnknownAgent(name.to_string(), self.names().join(", ")))
+    }
+
+    /// All known agent names: built-ins first, then custom names sorted.
+    pub fn names(&self) -> Vec<String> {
+        let mut custom: Vec<String> = self
+            .definitions
+            .keys()
+            .filter(|name| get_adapter(name).is_none())
+            .cloned()
+            .collect();
+        custom.sort();
+        supported_agents()
+            .iter()
+            .map(|s| s.to_string())
+            .chain(custom)
+            .collect()
+    }
+}
+
+/// Merge two definitions, with fields set in `over` taking precedence.
+fn merge_definitions(base: &AgentDefinition, over: &AgentDefinition) -> AgentDefinition {
+    fn pick(over: &Option<String>, base: &Option<String>) -> Option<String> {
+        over.clone().or_else(|| base.clone())
+    }
+    AgentDefinition {
+        command: pick(&over.command, &base.command),
+        auto_flag: pick(&over.auto_flag, &base.auto_flag),
+        ephemeral_flags: pick(&over.ephemeral_flags, &base.ephemeral_flags),
+        ephemeral_prompt_flag: pick(&over.ephemeral_prompt_flag, &base.ephemeral_prompt_flag),
+        continue_flag: pick(&over.continue_flag, &base.continue_flag),
+        prompt_flag: pick(&over.prompt_flag, &base.prompt_flag),
+        send_prompt: over.send_prompt.or(base.send_prompt),
+        model_flag: pick(&over.model_flag, &base.model_flag),
+        allowed_tools_flag: pick(&over.allowed_tools_flag, &base.allowed_tools_flag),
+        disallowed_tools_flag: pick(&over.disallowed_tools_flag, &base.disallowed_tools_flag),
+        skills_dir: pick(&over.skills_dir, &base.skills_dir),
+        skill_file: pick(&over.skill_file, &base.skill_file),
+        project_file: pick(&over.project_file, &base.project_file),
+    }
+}
+
+/// Single-quote a string for the shell, escaping embedded single quotes.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
 }
 
 /// Build a triage command as an argv vector for direct subprocess execution.
 ///
 /// Returns a `Vec<String>` suitable for `std::process::Command`. The
-/// prompt is piped to stdin by the caller.
+/// adapter's command is split into words the way the shell would for a
+/// spawn, so `npx foo` works in both. The prompt is passed after the
+/// adapter's `ephemeral_prompt_flag` when it has one, and is otherwise piped
+/// to stdin by the caller. The model and allowed tools are only passed when
+/// given and the adapter has a flag for them.
 pub fn build_triage_argv(
     adapter: &AgentAdapter,
-    model: &str,
+    prompt: &str,
+    model: Option<&str>,
     allowed_tools: &[&str],
 ) -> Vec<String> {
-    let mut argv: Vec<String> = vec![adapter.command.to_string()];
+    let mut argv: Vec<String> =
+        shlex::split(&adapter.command).unwrap_or_else(|| vec![adapter.command.to_string()]);
 
     // Add ephemeral flags as individual arguments
     for flag in adapter.ephemeral_flags.split_whitespace() {
         argv.push(flag.to_string());
     }
 
-    argv.push("--model".to_string());
-    argv.push(model.to_string());
+    if let Some(model) = model {
+        if !adapter.model_flag.is_empty() {
+            argv.push(adapter.model_flag.to_string());
+            argv.push(model.to_string());
+        }
+    }
 
-    if !allowed_tools.is_empty() {
-        argv.push("--allowed-tools".to_string());
+    if !allowed_tools.is_empty() && !adapter.allowed_tools_flag.is_empty() {
+        argv.push(adapter.allowed_tools_flag.to_string());
         argv.push(allowed_tools.join(","));
     }
 
+    if !adapter.ephemeral_prompt_flag.is_empty() {
+        argv.push(adapter.ephemeral_prompt_flag.to_string());
+        argv.push(prompt.to_string());
+    }
+
     argv
 }
 
@@ -137,43 +390,65 @@ pub fn build_triage_argv(
 ///
 /// Uses the adapter's `continue_flag` (e.g. `-c`) instead of a prompt argument,
 /// so the agent picks up its prior session transcript rather than starting fresh.
-pub fn build_resume_command(adapter: &AgentAdapter) -> String {
+pub fn build_resume_command(adapter: &AgentAdapter, model: Option<&str>) -> String {
     let mut cmd = adapter.command.to_string();
     if !adapter.continue_flag.is_empty() {
         cmd.push(' ');
-        cmd.push_str(adapter.continue_flag);
+        cmd.push_str(&adapter.continue_flag);
     }
     if !adapter.auto_flag.is_empty() {
         cmd.push(' ');
-        cmd.push_str(adapter.auto_flag);
+        cmd.push_str(&adapter.auto_flag);
     }
+    push_model(&mut cmd, adapter, model);
     cmd
 }
 
+/// Append the adapter's model flag and a model, if both are present.
+fn push_model(cmd: &mut String, adapter: &AgentAdapter, model: Option<&str>) {
+    if let Some(model) = model {
+        if !adapter.model_flag.is_empty() {
+            cmd.push_str(&format!(" {} {}", adapter.model_flag, shell_quote(model)));
+        }
+    }
+}
+
 /// Tools that are always blocked for spawned workers.
 /// Workers must use `jig pr` instead of raw `gh` PR commands.
 pub const DEFAULT_DISALLOWED_TOOLS: &[&str] = &["Bash(gh pr create:*)", "Bash(gh pr merge:*)"];
 
 /// Build the spawn command for an agent (always appends auto_flag).
 ///
+/// `context` is left off for adapters with `send_prompt`; the caller types
+/// it into the pane once the agent is up. `model` is passed via the adapter's `model_flag` when both are set.
 /// Merges `DEFAULT_DISALLOWED_TOOLS` with any extra `disallowed_tools`
-/// from config, then passes the combined list via `--disallowedTools`.
+/// from config, then passes the combined list via the adapter's
+/// `disallowed_tools_flag`. Adapters without that flag get no denylist.
 pub fn build_spawn_command(
     adapter: &AgentAdapter,
     context: Option<&str>,
+    model: Option<&str>,
     disallowed_tools: &[String],
 ) -> String {
     let mut cmd = adapter.command.to_string();
 
-    if let Some(ctx) = context {
-        // Escape single quotes in context
-        let escaped = ctx.replace('\'', "'\\''");
-        cmd = format!("{} '{}'", cmd, escaped);
+    if let Some(ctx) = context.filter(|_| !adapter.send_prompt) {
+        if !adapter.prompt_flag.is_empty() {
+            cmd.push(' ');
+            cmd.push_str(&adapter.prompt_flag);
+        }
+        cmd = format!("{} {}", cmd, shell_quote(ctx));
     }
 
     if !adapter.auto_flag.is_empty() {
         cmd.push(' ');
-        cmd.push_str(adapter.auto_flag);
+        cmd.push_str(&adapter.auto_flag);
+    }
+
+    push_model(&mut cmd, adapter, model);
+
+    if adapter.disallowed_tools_flag.is_empty() {
+        return cmd;
     }
 
     // Merge hardcoded defaults with config extras
@@ -185,7 +460,7 @@ pub fn build_spawn_command(
     }
     if !all_tools.is_empty() {
         let tools = all_tools.join(",");
-        cmd = format!("{} --disallowedTools \"{}\"", cmd, tools);
+        cmd = format!("{} {} \"{}\"", cmd, adapter.disallowed_tools_flag, tools);
     }
 
     cmd
@@ -213,7 +488,7 @@ mod tests {
     #[test]
     fn test_build_spawn_command_no_context() {
         let adapter = &CLAUDE_CODE;
-        let cmd = build_spawn_command(adapter, None, &[]);
+        let cmd = build_spawn_command(adapter, None, None, &[]);
         assert_eq!(
             cmd,
             "claude --dangerously-skip-permissions \
@@ -224,7 +499,7 @@ mod tests {
     #[test]
     fn test_build_spawn_command_with_context() {
         let adapter = &CLAUDE_CODE;
-        let cmd = build_spawn_command(adapter, Some("hello world"), &[]);
+        let cmd = build_spawn_command(adapter, Some("hello world"), None, &[]);
         assert_eq!(
             cmd,
             "claude 'hello world' --dangerously-skip-permissions \
@@ -235,7 +510,7 @@ mod tests {
     #[test]
     fn test_build_spawn_command_escapes_quotes() {
         let adapter = &CLAUDE_CODE;
-        let cmd = build_spawn_command(adapter, Some("it's a test"), &[]);
+        let cmd = build_spawn_command(adapter, Some("it's a test"), None, &[]);
         assert_eq!(
             cmd,
             "claude 'it'\\''s a test' --dangerously-skip-permissions \
@@ -247,7 +522,7 @@ mod tests {
     fn test_build_spawn_command_with_extra_disallowed_tools() {
         let adapter = &CLAUDE_CODE;
         let disallowed = vec!["Bash(rm -rf:*)".to_string()];
-        let cmd = build_spawn_command(adapter, Some("do work"), &disallowed);
+        let cmd = build_spawn_command(adapter, Some("do work"), None, &disallowed);
         assert_eq!(
             cmd,
             "claude 'do work' --dangerously-skip-permissions \
@@ -260,7 +535,7 @@ mod tests {
         let adapter = &CLAUDE_CODE;
         // Config duplicates a default — should not appear twice
         let disallowed = vec!["Bash(gh pr create:*)".to_string()];
-        let cmd = build_spawn_command(adapter, Some("work"), &disallowed);
+        let cmd = build_spawn_command(adapter, Some("work"), None, &disallowed);
         assert_eq!(
             cmd,
             "claude 'work' --dangerously-skip-permissions \
@@ -276,41 +551,27 @@ mod tests {
     #[test]
     fn test_supports_ephemeral_false_when_empty() {
         let adapter = AgentAdapter {
-            agent_type: AgentType::Claude,
-            name: "test",
-            command: "test",
-            skills_dir: "",
-            skill_file: "",
-            settings_file: None,
-            project_file: "",
-            auto_flag: "",
-            ephemeral_flags: "",
-            continue_flag: "",
+            ephemeral_flags: Cow::Borrowed(""),
+            ..CLAUDE_CODE
         };
         assert!(!adapter.supports_ephemeral());
     }
 
     #[test]
     fn test_build_resume_command_claude() {
-        let cmd = build_resume_command(&CLAUDE_CODE);
+        let cmd = build_resume_command(&CLAUDE_CODE, None);
         assert_eq!(cmd, "claude -c --dangerously-skip-permissions");
     }
 
     #[test]
     fn test_build_resume_command_no_continue_flag() {
         let adapter = AgentAdapter {
-            agent_type: AgentType::Claude,
-            name: "test",
-            command: "test-agent",
-            skills_dir: "",
-            skill_file: "",
-            settings_file: None,
-            project_file: "",
-            auto_flag: "--auto",
-            ephemeral_flags: "",
-            continue_flag: "",
+            command: Cow::Borrowed("test-agent"),
+            auto_flag: Cow::Borrowed("--auto"),
+            continue_flag: Cow::Borrowed(""),
+            ..CLAUDE_CODE
         };
-        let cmd = build_resume_command(&adapter);
+        let cmd = build_resume_command(&adapter, None);
         assert_eq!(cmd, "test-agent --auto");
     }
 
@@ -318,16 +579,8 @@ mod tests {
     fn test_supports_continue() {
         assert!(CLAUDE_CODE.supports_continue());
         let adapter = AgentAdapter {
-            agent_type: AgentType::Claude,
-            name: "test",
-            command: "test",
-            skills_dir: "",
-            skill_file: "",
-            settings_file: None,
-            project_file: "",
-            auto_flag: "",
-            ephemeral_flags: "",
-            continue_flag: "",
+            continue_flag: Cow::Borrowed(""),
+            ..CLAUDE_CODE
         };
         assert!(!adapter.supports_continue());
     }
@@ -366,7 +619,8 @@ mod tests {
     fn test_build_triage_argv() {
         let argv = build_triage_argv(
             &CLAUDE_CODE,
-            "sonnet",
+            "triage",
+            Some("sonnet"),
             &["Read", "Glob", "Grep", "Bash(jig *)"],
         );
         assert_eq!(
@@ -386,7 +640,7 @@ mod tests {
 
     #[test]
     fn test_build_triage_argv_no_tools() {
-        let argv = build_triage_argv(&CLAUDE_CODE, "opus", &[]);
+        let argv = build_triage_argv(&CLAUDE_CODE, "triage", Some("opus"), &[]);
         assert_eq!(
             argv,
             vec![
@@ -399,4 +653,188 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_builtin_adapters_resolve_by_name() {
+        for name in supported_agents() {
+            assert_eq!(get_adapter(name).unwrap().name, *name);
+        }
+        assert_eq!(get_adapter("codex").unwrap().project_file, "AGENTS.md");
+    }
+
+    #[test]
+    fn test_build_spawn_command_without_denylist_flag() {
+        let cmd = build_spawn_command(&CODEX, Some("do work"), None, &["Bash(rm:*)".to_string()]);
+        assert_eq!(
+            cmd,
+            "codex 'do work' --dangerously-bypass-approvals-and-sandbox"
+        );
+    }
+
+    #[test]
+    fn test_build_spawn_command_with_prompt_flag() {
+        let cmd = build_spawn_command(&GEMINI, Some("do work"), None, &[]);
+        assert_eq!(cmd, "gemini --prompt-interactive 'do work' --yolo");
+    }
+
+    #[test]
+    fn test_aider_prompt_is_sent_after_launch() {
+        assert_eq!(
+            build_spawn_command(&AIDER, Some("do work"), None, &[]),
+            "aider --yes-always"
+        );
+    }
+
+    #[test]
+    fn test_aider_one_shot_prompt_uses_message_flag() {
+        assert_eq!(
+            AIDER.build_ephemeral_command("review this", &["Read"]),
+            "aider --yes-always --no-pretty --message 'review this'"
+        );
+        assert_eq!(
+            build_triage_argv(&AIDER, "triage this", None, &["Read"]),
+            vec![
+                "aider",
+                "--yes-always",
+                "--no-pretty",
+                "--message",
+                "triage this",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_triage_argv_splits_command() {
+        let adapter = AgentAdapter {
+            command: Cow::Borrowed("npx 'my agent'"),
+            ..CODEX
+        };
+        let argv = build_triage_argv(&adapter, "triage", None, &[]);
+        assert_eq!(
+            argv,
+            vec![
+                "npx",
+                "my agent",
+                "exec",
+                "--dangerously-bypass-approvals-and-sandbox"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_triage_argv_without_model_flag() {
+        let adapter = AgentAdapter {
+            model_flag: Cow::Borrowed(""),
+            allowed_tools_flag: Cow::Borrowed(""),
+            ..CODEX
+        };
+        let argv = build_triage_argv(&adapter, "triage", Some("sonnet"), &["Read"]);
+        assert_eq!(
+            argv,
+            vec![
+                "codex",
+                "exec",
+                "--dangerously-bypass-approvals-and-sandbox"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_resume_command_multiword_continue_flag() {
+        assert_eq!(
+            build_resume_command(&CODEX, None),
+            "codex resume --last --dangerously-bypass-approvals-and-sandbox"
+        );
+    }
+
+    fn def(command: Option<&str>, auto_flag: Option<&str>) -> AgentDefinition {
+        AgentDefinition {
+            command: command.map(String::from),
+            auto_flag: auto_flag.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_registry_builtin_without_config() {
+        let registry = AgentRegistry::default();
+        assert_eq!(registry.get("claude").unwrap(), CLAUDE_CODE);
+        assert!(registry.get("opencode").is_none());
+    }
+
+    #[test]
+    fn test_registry_custom_agent() {
+        let repo = HashMap::from([("opencode".to_string(), def(None, Some("--auto")))]);
+        let registry = AgentRegistry::new(&repo, &HashMap::new());
+
+        let adapter = registry.get("opencode").unwrap();
+        assert_eq!(adapter.agent_type, AgentType::Custom);
+        assert_eq!(adapter.command, "opencode");
+        assert_eq!(adapter.project_file, "AGENTS.md");
+        assert!(!adapter.supports_continue());
+        assert_eq!(
+            build_spawn_command(&adapter, Some("hi"), None, &[]),
+            "opencode 'hi' --auto"
+        );
+        assert_eq!(
+            registry.names(),
+            vec!["claude", "codex", "aider", "gemini", "opencode"]
+        );
+    }
+
+    #[test]
+    fn test_registry_overrides_builtin_fields() {
+        let repo = HashMap::from([("claude".to_string(), def(Some("/opt/claude"), None))]);
+        let registry = AgentRegistry::new(&repo, &HashMap::new());
+
+        let adapter = registry.get("claude").unwrap();
+        assert_eq!(adapter.agent_type, AgentType::Claude);
+        assert_eq!(adapter.command, "/opt/claude");
+        assert_eq!(adapter.auto_flag, CLAUDE_CODE.auto_flag);
+        assert_eq!(registry.names(), supported_agents());
+    }
+
+    #[test]
+    fn test_registry_repo_definition_wins_over_global() {
+        let global = HashMap::from([("mine".to_string(), def(Some("global-bin"), Some("--yes")))]);
+        let repo = HashMap::from([("mine".to_string(), def(Some("repo-bin"), None))]);
+        let registry = AgentRegistry::new(&repo, &global);
+
+        let adapter = registry.get("mine").unwrap();
+        assert_eq!(adapter.command, "repo-bin");
+        assert_eq!(adapter.auto_flag, "--yes");
+    }
+
+    #[test]
+    fn test_registry_resolve_unknown() {
+        let err = AgentRegistry::default().resolve("nope").unwrap_err();
+        assert!(err.to_string().contains("Unknown agent 'nope'"));
+        assert!(err.to_string().contains("claude, codex"));
+    }
+
+    #[test]
+    fn test_build_spawn_command_with_model() {
+        let cmd = build_spawn_command(&CLAUDE_CODE, Some("work"), Some("opus"), &[]);
+        assert_eq!(
+            cmd,
+            "claude 'work' --dangerously-skip-permissions --model 'opus' \
+             --disallowedTools \"Bash(gh pr create:*),Bash(gh pr merge:*)\""
+        );
+    }
+
+    #[test]
+    fn test_model_skipped_without_model_flag() {
+        let adapter = AgentAdapter {
+            model_flag: Cow::Borrowed(""),
+            ..CODEX
+        };
+        assert_eq!(
+            build_resume_command(&adapter, Some("o3")),
+            "codex resume --last --dangerously-bypass-approvals-and-sandbox"
+        );
+        assert_eq!(
+            build_resume_command(&CODEX, Some("o3")),
+            "codex resume --last --dangerously-bypass-approvals-and-sandbox --model 'o3'"
+        );
+    }
 }
@@ -0,0 +1,456 @@
+//! Automatic rebase of a conflicted worker branch.
+//!
+//! Before the daemon nudges a worker about merge conflicts it rebases the
+//! branch onto its base in a scratch worktree. A clean result is pushed and
+//! the worker's worktree moved onto it; otherwise the conflicting hunks are
+//! collected so the nudge can show the agent exactly what to fix. Only a
+//! push reaches the PR, so every other outcome still leaves the nudge to go
+//! out.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::error::{Error, Result};
+use crate::git::Repo;
+
+/// Most conflicted files reported in a nudge.
+const MAX_FILES: usize = 10;
+/// Most hunks reported per file.
+const MAX_HUNKS: usize = 5;
+/// Most lines kept per hunk, markers included.
+const MAX_HUNK_LINES: usize = 40;
+
+/// A file the rebase stopped on, with its conflict hunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub path: String,
+    /// Each hunk from `<<<<<<<` to `>>>>>>>`, prefixed with its line number.
+    pub hunks: Vec<String>,
+}
+
+impl Conflict {
+    /// The file and its hunks, as shown in the conflict nudge.
+    pub fn render(&self) -> String {
+        let mut out = format!("{}:", self.path);
+        for hunk in &self.hunks {
+            out.push('\n');
+            out.push_str(hunk);
+        }
+        out
+    }
+}
+
+/// What an automatic rebase did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Rebased cleanly and force-pushed; the worktree now points at `head`.
+    Pushed { head: String },
+    /// Rebased cleanly, but the branch isn't on origin, so only the local
+    /// branch and worktree moved to `head`.
+    RebasedLocally { head: String },
+    /// The branch already sits on top of its target. Nothing was changed.
+    Unchanged,
+    /// The rebase stopped on conflicts. Nothing was changed.
+    Conflicts(Vec<Conflict>),
+    /// The worktree has uncommitted changes, or got new commits during the
+    /// rebase, so the agent is mid-edit.
+    Dirty,
+}
+
+/// Rebase `branch`, checked out at `worktree`, onto `onto`.
+///
+/// The rebase runs in a detached scratch worktree so the worker's checkout is
+/// never left mid-rebase. A clean result is force-pushed with a lease on the
+/// branch's last known remote commit (if it was ever pushed), then the
+/// worktree is reset onto it.
+pub fn attempt(repo_path: &Path, worktree: &Path, branch: &str, onto: &str) -> Result<Outcome> {
+    if Repo::has_uncommitted_changes(worktree)? {
+        return Ok(Outcome::Dirty);
+    }
+    if is_ancestor(worktree, onto, "HEAD") {
+        return Ok(Outcome::Unchanged);
+    }
+    rebase(repo_path, worktree, branch, &[onto])
+}
+
+/// Like [`attempt`], but only the commits after `upstream` are moved onto
+/// `onto` (`git rebase --onto`). Used to restack a branch whose lower branch
+/// was rewritten or has landed.
+pub fn attempt_onto(
+    repo_path: &Path,
+    worktree: &Path,
+    branch: &str,
+    onto: &str,
+    upstream: &str,
+) -> Result<Outcome> {
+    if Repo::has_uncommitted_changes(worktree)? {
+        return Ok(Outcome::Dirty);
+    }
+    if git(worktree, &["rev-parse", onto])? == git(worktree, &["rev-parse", upstream])? {
+        return Ok(Outcome::Unchanged);
+    }
+    rebase(repo_path, worktree, branch, &["--onto", onto, upstream])
+}
+
+fn rebase(repo_path: &Path, worktree: &Path, branch: &str, args: &[&str]) -> Result<Outcome> {
+    let old_head = git(worktree, &["rev-parse", "HEAD"])?;
+    let remote_ref = format!("refs/remotes/origin/{}", branch);
+    let remote_head = git(worktree, &["rev-parse", "--verify", "-q", &remote_ref]).ok();
+
+    let scratch = scratch_path(worktree);
+    remove_scratch(repo_path, &scratch);
+    git(
+        repo_path,
+        &[
+            "worktree",
+            "add",
+            "--detach",
+            &scratch.to_string_lossy(),
+            &old_head,
+        ],
+    )?;
+    let result = rebase_in(&scratch, args);
+    remove_scratch(repo_path, &scratch);
+
+    let new_head = match result? {
+        Ok(head) => head,
+        Err(conflicts) => return Ok(Outcome::Conflicts(conflicts)),
+    };
+    if new_head == old_head {
+        return Ok(Outcome::Unchanged);
+    }
+    // The agent may have committed or started editing while the rebase
+    // ran; the rebased head would drop that work.
+    if !still_at(worktree, &old_head)? {
+        return Ok(Outcome::Dirty);
+    }
+    let pushed = match remote_head {
+        Some(remote_head) => {
+            let lease = format!("--force-with-lease=refs/heads/{}:{}", branch, remote_head);
+            let refspec = format!("{}:refs/heads/{}", new_head, branch);
+            git(worktree, &["push", &lease, "origin", &refspec])?;
+            true
+        }
+        None => false,
+    };
+    if !still_at(worktree, &old_head)? {
+        return Ok(Outcome::Dirty);
+    }
+    git(worktree, &["reset", "--hard", "-q", &new_head])?;
+    Ok(if pushed {
+        Outcome::Pushed { head: new_head }
+    } else {
+        Outcome::RebasedLocally { head: new_head }
+    })
+}
+
+/// Whether `ancestor` is reachable from `descendant`.
+fn is_ancestor(dir: &Path, ancestor: &str, descendant: &str) -> bool {
+    git(dir, &["merge-base", "--is-ancestor", ancestor, descendant]).is_ok()
+}
+
+/// Whether the worktree is clean and still at `head`.
+fn still_at(worktree: &Path, head: &str) -> Result<bool> {
+    Ok(git(worktree, &["rev-parse", "HEAD"])? == head && !Repo::has_uncommitted_changes(worktree)?)
+}
+
+/// Rebase the scratch worktree. Returns the new head, or the conflicts after
+/// aborting.
+fn rebase_in(scratch: &Path, args: &[&str]) -> Result<std::result::Result<String, Vec<Conflict>>> {
+    let output = Command::new("git")
+        .arg("rebase")
+        .args(args)
+        .current_dir(scratch)
+        .stdin(Stdio::null())
+        .output()?;
+    if output.status.success() {
+        return Ok(Ok(git(scratch, &["rev-parse", "HEAD"])?));
+    }
+
+    let unmerged = git(scratch, &["diff", "--name-only", "--diff-filter=U"]).unwrap_or_default();
+    let conflicts: Vec<Conflict> = unmerged
+        .lines()
+        .take(MAX_FILES)
+        .map(|path| Conflict {
+            path: path.to_string(),
+            hunks: std::fs::read_to_string(scratch.join(path))
+                .map(|content| conflict_hunks(&content))
+                .unwrap_or_default(),
+        })
+        .collect();
+    let _ = git(scratch, &["rebase", "--abort"]);
+
+    if conflicts.is_empty() {
+        return Err(Error::Custom(format!(
+            "git rebase {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(Err(conflicts))
+}
+
+/// Blocks between conflict markers, each prefixed with its starting line.
+fn conflict_hunks(content: &str) -> Vec<String> {
+    let mut hunks = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    for (i, line) in content.lines().enumerate() {
+        if line.starts_with("<<<<<<<") {
+            current = Some((i + 1, vec![line]));
+        } else if let Some((start, lines)) = current.as_mut() {
+            lines.push(line);
+            if line.starts_with(">>>>>>>") {
+                let mut kept: Vec<&str> = lines.clone();
+                if kept.len() > MAX_HUNK_LINES {
+                    let last = kept[kept.len() - 1];
+                    kept.truncate(MAX_HUNK_LINES - 2);
+                    kept.extend(["...", last]);
+                }
+                hunks.push(format!("line {}:\n{}", start, kept.join("\n")));
+                current = None;
+                if hunks.len() == MAX_HUNKS {
+                    break;
+                }
+            }
+        }
+    }
+    hunks
+}
+
+/// A scratch directory unique to the worktree being rebased and to this
+/// process, so two jig processes never share one.
+fn scratch_path(worktree: &Path) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    worktree.hash(&mut hasher);
+    std::env::temp_dir().join(format!(
+        "jig-rebase-{:016x}-{}",
+        hasher.finish(),
+        std::process::id()
+    ))
+}
+
+fn remove_scratch(repo_path: &Path, scratch: &Path) {
+    if scratch.exists() {
+        let _ = git(
+            repo_path,
+            &["worktree", "remove", "--force", &scratch.to_string_lossy()],
+        );
+        let _ = std::fs::remove_dir_all(scratch);
+    }
+    let _ = git(repo_path, &["worktree", "prune"]);
+}
+
+/// Run git, returning trimmed stdout.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(Error::Custom(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run(dir: &Path, args: &[&str]) {
+        git(dir, args).unwrap();
+    }
+
+    fn commit_file(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+        run(dir, &["add", name]);
+        run(dir, &["commit", "-q", "-m", name]);
+    }
+
+    /// A clone of a bare origin with a pushed `feature` worktree, and
+    /// `origin/main` moved on by `main_file` with `main_content`.
+    fn setup(main_file: &str, main_content: &str) -> (TempDir, PathBuf, PathBuf) {
+        let tmp = TempDir::new().unwrap();
+        let repo = tmp.path().join("repo");
+        run(
+            tmp.path(),
+            &["init", "-q", "--bare", "-b", "main", "origin.git"],
+        );
+        run(tmp.path(), &["clone", "-q", "origin.git", "repo"]);
+        run(&repo, &["config", "user.email", "test@test.com"]);
+        run(&repo, &["config", "user.name", "Test"]);
+        run(&repo, &["config", "commit.gpgsign", "false"]);
+        commit_file(&repo, "shared.txt", "base\n");
+        run(&repo, &["push", "-q", "origin", "main"]);
+
+        let worktree = tmp.path().join("feature");
+        run(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                &worktree.to_string_lossy(),
+                "-b",
+                "feature",
+            ],
+        );
+        commit_file(&worktree, "shared.txt", "feature\n");
+        run(&worktree, &["push", "-q", "origin", "feature"]);
+
+        commit_file(&repo, main_file, main_content);
+        run(&repo, &["push", "-q", "origin", "main"]);
+        run(&repo, &["fetch", "-q", "origin"]);
+        (tmp, repo, worktree)
+    }
+
+    #[test]
+    fn clean_rebase_is_pushed_and_checked_out() {
+        let (tmp, repo, worktree) = setup("other.txt", "main\n");
+
+        let outcome = attempt(&repo, &worktree, "feature", "origin/main").unwrap();
+        let Outcome::Pushed { head } = outcome else {
+            panic!("expected push, got {:?}", outcome);
+        };
+        assert_eq!(git(&worktree, &["rev-parse", "HEAD"]).unwrap(), head);
+        assert!(worktree.join("other.txt").exists());
+        let remote = git(&tmp.path().join("origin.git"), &["rev-parse", "feature"]).unwrap();
+        assert_eq!(remote, head);
+        assert!(!scratch_path(&worktree).exists());
+    }
+
+    #[test]
+    fn conflicts_are_reported_and_nothing_changes() {
+        let (_tmp, repo, worktree) = setup("shared.txt", "main\n");
+        let before = git(&worktree, &["rev-parse", "HEAD"]).unwrap();
+
+        let outcome = attempt(&repo, &worktree, "feature", "origin/main").unwrap();
+        let Outcome::Conflicts(conflicts) = outcome else {
+            panic!("expected conflicts, got {:?}", outcome);
+        };
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "shared.txt");
+        assert!(conflicts[0].hunks[0].starts_with("line 1:\n<<<<<<<"));
+        assert!(conflicts[0].hunks[0].contains("feature"));
+        assert_eq!(git(&worktree, &["rev-parse", "HEAD"]).unwrap(), before);
+        assert!(!scratch_path(&worktree).exists());
+    }
+
+    #[test]
+    fn dirty_worktree_is_left_alone() {
+        let (_tmp, repo, worktree) = setup("other.txt", "main\n");
+        std::fs::write(worktree.join("shared.txt"), "editing\n").unwrap();
+        assert_eq!(
+            attempt(&repo, &worktree, "feature", "origin/main").unwrap(),
+            Outcome::Dirty
+        );
+    }
+
+    #[test]
+    fn moved_worktree_is_not_reset() {
+        let (_tmp, _repo, worktree) = setup("other.txt", "main\n");
+        let head = git(&worktree, &["rev-parse", "HEAD"]).unwrap();
+        assert!(still_at(&worktree, &head).unwrap());
+
+        commit_file(&worktree, "late.txt", "late\n");
+        assert!(!still_at(&worktree, &head).unwrap());
+        let late = git(&worktree, &["rev-parse", "HEAD"]).unwrap();
+        std::fs::write(worktree.join("late.txt"), "editing\n").unwrap();
+        assert!(!still_at(&worktree, &late).unwrap());
+    }
+
+    #[test]
+    fn onto_moves_only_later_commits_and_skips_unpushed_branches() {
+        let (tmp, repo, lower) = setup("other.txt", "main\n");
+        let fork = git(&lower, &["rev-parse", "HEAD"]).unwrap();
+        let upper = tmp.path().join("upper");
+        run(
+            &lower,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                &upper.to_string_lossy(),
+                "-b",
+                "upper",
+            ],
+        );
+        commit_file(&upper, "upper.txt", "upper\n");
+
+        // The lower branch is rewritten onto main, so its old commit is gone.
+        run(&lower, &["rebase", "-q", "origin/main"]);
+        let lower_head = git(&lower, &["rev-parse", "HEAD"]).unwrap();
+
+        let outcome = attempt_onto(&repo, &upper, "upper", "feature", &fork).unwrap();
+        let Outcome::RebasedLocally { head } = outcome else {
+            panic!("expected a local rebase, got {:?}", outcome);
+        };
+        assert_eq!(git(&upper, &["rev-parse", "HEAD~1"]).unwrap(), lower_head);
+        assert_eq!(git(&upper, &["rev-parse", "HEAD"]).unwrap(), head);
+        assert!(git(
+            &tmp.path().join("origin.git"),
+            &["rev-parse", "--verify", "upper"]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn branch_already_on_base_is_unchanged() {
+        let (tmp, repo, worktree) = setup("other.txt", "main\n");
+        assert!(matches!(
+            attempt(&repo, &worktree, "feature", "origin/main").unwrap(),
+            Outcome::Pushed { .. }
+        ));
+        let head = git(&worktree, &["rev-parse", "HEAD"]).unwrap();
+
+        assert_eq!(
+            attempt(&repo, &worktree, "feature", "origin/main").unwrap(),
+            Outcome::Unchanged
+        );
+        assert_eq!(git(&worktree, &["rev-parse", "HEAD"]).unwrap(), head);
+        let remote = git(&tmp.path().join("origin.git"), &["rev-parse", "feature"]).unwrap();
+        assert_eq!(remote, head);
+    }
+
+    #[test]
+    fn unpushed_branch_is_only_rebased_locally() {
+        let (tmp, repo, worktree) = setup("other.txt", "main\n");
+        let pushed = git(&tmp.path().join("origin.git"), &["rev-parse", "feature"]).unwrap();
+        run(&repo, &["update-ref", "-d", "refs/remotes/origin/feature"]);
+
+        let outcome = attempt(&repo, &worktree, "feature", "origin/main").unwrap();
+        let Outcome::RebasedLocally { head } = outcome else {
+            panic!("expected a local rebase, got {:?}", outcome);
+        };
+        assert_eq!(git(&worktree, &["rev-parse", "HEAD"]).unwrap(), head);
+        let remote = git(&tmp.path().join("origin.git"), &["rev-parse", "feature"]).unwrap();
+        assert_eq!(remote, pushed);
+    }
+
+    #[test]
+    fn hunks_are_numbered_and_capped() {
+        let mut content = String::from("a\n<<<<<<< HEAD\nours\n=======\ntheirs\n>>>>>>> x\nb\n");
+        content.push_str("<<<<<<< HEAD\n");
+        for i in 0..100 {
+            content.push_str(&format!("{}\n", i));
+        }
+        content.push_str(">>>>>>> y\n");
+
+        let hunks = conflict_hunks(&content);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(
+            hunks[0],
+            "line 2:\n<<<<<<< HEAD\nours\n=======\ntheirs\n>>>>>>> x"
+        );
+        assert!(hunks[1].starts_with("line 8:\n"));
+        assert!(hunks[1].ends_with("...\n>>>>>>> y"));
+        assert_eq!(hunks[1].lines().count(), MAX_HUNK_LINES + 1);
+    }
+}
@@ -0,0 +1,343 @@
+//! Spending budgets for workers and repos.
+//!
+//! Spend is derived from the event stream: tokens and cost from the usage
+//! the agent's stop hook records, hours from the worker's first event.
+//! A repo's total is kept in a [`SpendLedger`] so it survives worker cleanup.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::BudgetLimits;
+use crate::error::Result;
+use crate::events::WorkerState;
+use crate::global::global_state_dir;
+
+/// What a worker (or a repo's workers together) has used.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Spend {
+    pub tokens: u64,
+    pub dollars: f64,
+    /// Wall-clock hours; for a repo, the sum over its workers.
+    pub hours: f64,
+}
+
+impl Spend {
+    /// A worker's spend as of `now`. Hours stop counting once it is terminal.
+    ///
+    /// Tokens leave out cache reads: they re-read context already counted
+    /// when it was written, and would otherwise dominate long sessions. Their
+    /// cost still counts toward the dollar limit.
+    pub fn of(state: &WorkerState, now: i64) -> Self {
+        let usage = state.telemetry.usage();
+        let end = if state.status.is_terminal() {
+            state.last_event_at.unwrap_or(now)
+        } else {
+            now
+        };
+        let hours = state
+            .started_at
+            .map(|start| (end - start).max(0) as f64 / 3600.0)
+            .unwrap_or(0.0);
+        Self {
+            tokens: usage.input_tokens + usage.output_tokens + usage.cache_creation_tokens,
+            dollars: usage.cost_usd.unwrap_or(0.0),
+            hours,
+        }
+    }
+
+    pub fn add(&mut self, other: &Spend) {
+        self.tokens += other.tokens;
+        self.dollars += other.dollars;
+        self.hours += other.hours;
+    }
+}
+
+/// Last known spend of every worker each repo has run, so a repo's total
+/// keeps counting workers after they are cleaned up
+/// (`~/.config/jig/state/spend.json`).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SpendLedger {
+    #[serde(skip)]
+    path: PathBuf,
+    /// Repo name → worker key (`<worker>@<started_at>`) → spend.
+    repos: BTreeMap<String, BTreeMap<String, LedgerEntry>>,
+}
+
+/// A worker's line in the [`SpendLedger`]. Hours are kept up to the
+/// worker's last event, so the entry only changes when its log does.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct LedgerEntry {
+    #[serde(flatten)]
+    spend: Spend,
+    /// When a worker that is still running started; its hours count up to
+    /// now instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    live_since: Option<i64>,
+}
+
+impl LedgerEntry {
+    fn spend_at(&self, now: i64) -> Spend {
+        let mut spend = self.spend.clone();
+        if let Some(start) = self.live_since {
+            spend.hours = (now - start).max(0) as f64 / 3600.0;
+        }
+        spend
+    }
+}
+
+impl SpendLedger {
+    /// Load the global ledger. Returns an empty ledger if missing.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&global_state_dir()?.join("spend.json"))
+    }
+
+    /// Load from a specific path. Returns an empty ledger if missing.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let mut ledger = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(path)?)?
+        } else {
+            Self::default()
+        };
+        ledger.path = path.to_path_buf();
+        Ok(ledger)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Record a worker's current spend, returning whether the ledger
+    /// changed. A respawned worker with the same name starts a new entry.
+    pub fn record(&mut self, repo: &str, worker: &str, state: &WorkerState) -> bool {
+        let key = format!("{}@{}", worker, state.started_at.unwrap_or(0));
+        let entry = LedgerEntry {
+            spend: Spend::of(state, state.last_event_at.unwrap_or(0)),
+            live_since: state.started_at.filter(|_| !state.status.is_terminal()),
+        };
+        let workers = self.repos.entry(repo.to_string()).or_default();
+        if workers.get(&key) == Some(&entry) {
+            return false;
+        }
+        // An earlier run under the same name is over.
+        let prefix = format!("{}@", worker);
+        for (other, entry) in workers.iter_mut() {
+            if *other != key && other.starts_with(&prefix) {
+                entry.live_since = None;
+            }
+        }
+        workers.insert(key, entry);
+        true
+    }
+
+    /// Stop counting hours for a repo's workers that are no longer running,
+    /// returning whether the ledger changed.
+    pub fn retire_missing(&mut self, repo: &str, running: &HashSet<&str>) -> bool {
+        let mut changed = false;
+        for (key, entry) in self.repos.get_mut(repo).into_iter().flatten() {
+            let worker = key.rsplit_once('@').map_or(key.as_str(), |(w, _)| w);
+            if entry.live_since.is_some() && !running.contains(worker) {
+                entry.live_since = None;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Everything a repo's workers have spent as of `now`, cleaned-up ones
+    /// included.
+    pub fn total(&self, repo: &str, now: i64) -> Spend {
+        let mut total = Spend::default();
+        for entry in self.repos.get(repo).into_iter().flat_map(|w| w.values()) {
+            total.add(&entry.spend_at(now));
+        }
+        total
+    }
+}
+
+/// Where spend stands against a set of limits. `Near` and `Over` describe
+/// the limit that tripped, e.g. `4200000 of 5000000 tokens`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BudgetStatus {
+    Within,
+    Near(String),
+    Over(String),
+}
+
+/// Check spend against every limit that is set, reporting the one furthest
+/// along. `warn_at` is the fraction of a limit that counts as near.
+pub fn check(limits: &BudgetLimits, spend: &Spend, warn_at: f64) -> BudgetStatus {
+    let mut used: Vec<(f64, String)> = Vec::new();
+    if let Some(limit) = limits.tokens {
+        used.push((
+            spend.tokens as f64 / limit.max(1) as f64,
+            format!("{} of {} tokens", spend.tokens, limit),
+        ));
+    }
+    if let Some(limit) = limits.dollars {
+        used.push((
+            spend.dollars / limit,
+            format!("${:.2} of ${:.2}", spend.dollars, limit),
+        ));
+    }
+    if let Some(limit) = limits.hours {
+        used.push((
+            spend.hours / limit,
+            format!("{:.1} of {:.1} hours", spend.hours, limit),
+        ));
+    }
+
+    let Some((fraction, detail)) = used.into_iter().max_by(|a, b| a.0.total_cmp(&b.0)) else {
+        return BudgetStatus::Within;
+    };
+    if fraction >= 1.0 {
+        BudgetStatus::Over(detail)
+    } else if fraction >= warn_at {
+        BudgetStatus::Near(detail)
+    } else {
+        BudgetStatus::Within
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event, EventType};
+    use crate::global::HealthConfig;
+    use crate::worker::WorkerStatus;
+
+    fn limits(tokens: Option<u64>, dollars: Option<f64>, hours: Option<f64>) -> BudgetLimits {
+        BudgetLimits {
+            tokens,
+            dollars,
+            hours,
+        }
+    }
+
+    #[test]
+    fn no_limits_is_within() {
+        let spend = Spend {
+            tokens: 10_000_000,
+            dollars: 100.0,
+            hours: 24.0,
+        };
+        assert_eq!(
+            check(&BudgetLimits::default(), &spend, 0.8),
+            BudgetStatus::Within
+        );
+    }
+
+    #[test]
+    fn reports_the_limit_furthest_along() {
+        let spend = Spend {
+            tokens: 850,
+            dollars: 2.0,
+            hours: 0.5,
+        };
+        assert_eq!(
+            check(&limits(Some(1000), Some(10.0), None), &spend, 0.8),
+            BudgetStatus::Near("850 of 1000 tokens".to_string())
+        );
+        assert_eq!(
+            check(&limits(Some(1000), None, Some(0.5)), &spend, 0.8),
+            BudgetStatus::Over("0.5 of 0.5 hours".to_string())
+        );
+        assert_eq!(
+            check(&limits(Some(10_000), Some(10.0), None), &spend, 0.8),
+            BudgetStatus::Within
+        );
+    }
+
+    #[test]
+    fn spend_from_worker_events() {
+        let at = |event: Event, ts: i64| Event { ts, ..event };
+        let events = vec![
+            at(Event::new(EventType::Spawn), 1000),
+            at(
+                Event::new(EventType::Stop)
+                    .with_field("session", "s1")
+                    .with_field("input_tokens", 300)
+                    .with_field("output_tokens", 200)
+                    .with_field("cache_read_tokens", 9000)
+                    .with_field("cost_usd", 1.5),
+                2000,
+            ),
+            at(
+                Event::new(EventType::Terminal).with_field("terminal", "merged"),
+                8200,
+            ),
+        ];
+        let state = WorkerState::reduce(&events, &HealthConfig::default());
+        assert_eq!(state.status, WorkerStatus::Merged);
+
+        // Terminal: hours stop at the last event, not now.
+        let spend = Spend::of(&state, 100_000);
+        assert_eq!(spend.tokens, 500);
+        assert_eq!(spend.dollars, 1.5);
+        assert_eq!(spend.hours, 2.0);
+
+        let mut total = Spend::default();
+        total.add(&spend);
+        total.add(&spend);
+        assert_eq!(total.tokens, 1000);
+        assert_eq!(total.hours, 4.0);
+    }
+
+    #[test]
+    fn ledger_keeps_cleaned_up_workers() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("spend.json");
+        let state = |started: i64, tokens: u64| {
+            let spawn = Event {
+                ts: started,
+                ..Event::new(EventType::Spawn)
+            };
+            let stop = Event {
+                ts: started + 10,
+                ..Event::new(EventType::Stop)
+                    .with_field("session", "s1")
+                    .with_field("output_tokens", tokens)
+            };
+            WorkerState::reduce(&[spawn, stop], &HealthConfig::default())
+        };
+
+        let mut ledger = SpendLedger::load_from(&path).unwrap();
+        assert!(ledger.record("app", "a", &state(100, 10)));
+        assert!(ledger.record("app", "a", &state(100, 30)));
+        assert!(!ledger.record("app", "a", &state(100, 30)));
+        assert!(ledger.record("app", "b", &state(100, 5)));
+        ledger.save().unwrap();
+
+        // `b` is gone, and `a` was respawned later under the same name.
+        let mut ledger = SpendLedger::load_from(&path).unwrap();
+        ledger.record("app", "a", &state(500, 7));
+        assert_eq!(ledger.total("app", 600).tokens, 42);
+        assert_eq!(ledger.total("other", 600), Spend::default());
+    }
+
+    #[test]
+    fn ledger_counts_live_hours_until_the_worker_goes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spawn = Event {
+            ts: 0,
+            ..Event::new(EventType::Spawn)
+        };
+        let state = WorkerState::reduce(&[spawn], &HealthConfig::default());
+
+        let mut ledger = SpendLedger::load_from(&tmp.path().join("spend.json")).unwrap();
+        assert!(ledger.record("app", "a", &state));
+        assert_eq!(ledger.total("app", 7200).hours, 2.0);
+        assert_eq!(ledger.total("app", 10800).hours, 3.0);
+
+        let running = HashSet::from(["a"]);
+        assert!(!ledger.retire_missing("app", &running));
+        assert!(ledger.retire_missing("app", &HashSet::new()));
+        assert_eq!(ledger.total("app", 10800).hours, 0.0);
+    }
+}
@@ -311,6 +311,9 @@ pub struct JigToml {
     pub spawn: SpawnConfig,
     #[serde(default)]
     pub agent: AgentConfig,
+    /// Custom or overridden agent adapters, keyed by name.
+    #[serde(default)]
+    pub agents: HashMap<String, AgentDefinition>,
     #[serde(default)]
     pub issues: IssuesConfig,
     #[serde(default)]
@@ -321,6 +324,14 @@ pub struct JigToml {
     pub review: ReviewConfig,
     #[serde(default)]
     pub triage: TriageConfig,
+    #[serde(default)]
+    pub forge: ForgeConfig,
+    #[serde(default)]
+    pub notify: RepoNotifyConfig,
+    #[serde(default)]
+    pub budget: BudgetConfig,
+    #[serde(default)]
+    pub merge_queue: MergeQueueConfig,
     /// Whether a jig.local.toml overlay was merged into this config.
     #[serde(skip)]
     pub has_local_overlay: bool,
@@ -332,6 +343,82 @@ pub struct JigToml {
     pub local_keys: Vec<String>,
 }
 
+/// Code forge configuration in jig.toml `[forge]`.
+///
+/// Everything is optional — the forge is detected from the `origin` remote.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgeConfig {
+    /// "github" or "gitlab". Detected from the remote host when omitted.
+    pub provider: Option<String>,
+    /// GitLab project path (`group/subgroup/project`). Detected from the
+    /// remote URL when omitted.
+    pub project: Option<String>,
+    /// GitLab API base URL (e.g. `https://gitlab.example.com/api/v4`).
+    /// Overrides the global `[gitlab] api_url`.
+    pub api_url: Option<String>,
+}
+
+/// Per-repo notification overrides in jig.toml `[notify]`.
+///
+/// Set fields replace the global `[notify]` value. Rules are checked before
+/// the global rules, so a repo rule wins over a global one for the same event.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepoNotifyConfig {
+    pub exec: Option<String>,
+    pub webhook: Option<String>,
+    pub webhook_format: Option<crate::notify::WebhookFormat>,
+    pub webhook_secret: Option<String>,
+    pub webhook_retries: Option<u32>,
+    pub desktop: Option<bool>,
+    pub events: Option<Vec<String>>,
+    pub rules: Vec<crate::global::NotifyRule>,
+    pub quiet_hours: Option<String>,
+    pub dedup_seconds: Option<u64>,
+}
+
+impl RepoNotifyConfig {
+    /// Merge these overrides over the global `[notify]` config.
+    pub fn resolve(&self, global: &crate::global::NotifyConfig) -> crate::global::NotifyConfig {
+        let mut merged = global.clone();
+        if let Some(exec) = &self.exec {
+            merged.exec = Some(exec.clone());
+        }
+        if let Some(webhook) = &self.webhook {
+            merged.webhook = Some(webhook.clone());
+        }
+        if let Some(format) = self.webhook_format {
+            merged.webhook_format = format;
+        }
+        if let Some(secret) = &self.webhook_secret {
+            merged.webhook_secret = Some(secret.clone());
+        }
+        if let Some(retries) = self.webhook_retries {
+            merged.webhook_retries = Some(retries);
+        }
+        if let Some(desktop) = self.desktop {
+            merged.desktop = desktop;
+        }
+        if let Some(events) = &self.events {
+            merged.events = events.clone();
+        }
+        if let Some(quiet_hours) = &self.quiet_hours {
+            merged.quiet_hours = Some(quiet_hours.clone());
+        }
+        if let Some(dedup) = self.dedup_seconds {
+            merged.dedup_seconds = Some(dedup);
+        }
+        merged.rules = self
+            .rules
+            .iter()
+            .chain(global.rules.iter())
+            .cloned()
+            .collect();
+        merged
+    }
+}
+
 /// Per-repo health/nudge configuration in jig.toml `[health]`.
 ///
 /// All fields are optional — when absent, the global config is used.
@@ -345,6 +432,60 @@ pub struct RepoHealthConfig {
     /// Per-nudge-type overrides.
     #[serde(default)]
     pub nudge: NudgeTypeConfigs,
+    /// Rules matched against a stuck worker's pane (`[[health.patterns]]`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<PanePattern>,
+    /// Whether the built-in pane patterns apply (default true).
+    pub builtin_patterns: Option<bool>,
+    /// Whether to try rebasing a conflicted PR before nudging (default true).
+    pub auto_rebase: Option<bool>,
+}
+
+/// A rule for a stuck worker: when `regex` matches the pane, take `action`
+/// instead of sending the generic nudge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PanePattern {
+    /// Rule name, recorded in `pane_match` events. A repo rule with the name
+    /// of a built-in replaces it.
+    pub name: String,
+    /// Regex matched against the last lines of the pane.
+    pub regex: String,
+    pub action: PatternAction,
+    /// Keys for `send_keys`, as tmux `send-keys` arguments (`["y", "Enter"]`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<String>,
+    /// Minimum seconds between firings for one worker.
+    #[serde(default = "default_pattern_cooldown")]
+    pub cooldown_seconds: u64,
+}
+
+fn default_pattern_cooldown() -> u64 {
+    300
+}
+
+/// What the daemon does when a pane pattern matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternAction {
+    /// Type the rule's `keys` into the pane.
+    SendKeys,
+    /// Send Ctrl-C.
+    Interrupt,
+    /// Kill the tmux window and resume the worker.
+    Restart,
+    /// Escalate with a `needs_intervention` notification.
+    Notify,
+}
+
+impl PatternAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SendKeys => "send_keys",
+            Self::Interrupt => "interrupt",
+            Self::Restart => "restart",
+            Self::Notify => "notify",
+        }
+    }
 }
 
 /// Per-nudge-type configuration overrides.
@@ -421,12 +562,153 @@ impl RepoHealthConfig {
             cooldown_seconds,
         }
     }
+
+    /// Pane patterns in match order: the repo's rules, then the built-ins
+    /// they don't replace (unless `builtin_patterns = false`).
+    pub fn resolve_patterns(&self) -> Vec<PanePattern> {
+        let mut patterns = self.patterns.clone();
+        if self.builtin_patterns.unwrap_or(true) {
+            patterns.extend(
+                crate::patterns::builtin()
+                    .into_iter()
+                    .filter(|b| !self.patterns.iter().any(|p| p.name == b.name)),
+            );
+        }
+        patterns
+    }
+}
+
+/// Spending limits in jig.toml `[budget]`.
+///
+/// Limits are off unless set. Tokens and hours are always tracked; dollars
+/// only when the agent reports cost.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BudgetConfig {
+    /// Limits for each worker.
+    pub worker: BudgetLimits,
+    /// Limits for all of the repo's workers combined.
+    pub repo: BudgetLimits,
+    /// Fraction of a limit at which the worker is warned.
+    pub warn_at: f64,
+    /// What to do with a worker once it is over budget.
+    pub on_exceed: BudgetAction,
+}
+
+impl Default for BudgetConfig {
+    fn default() -> Self {
+        Self {
+            worker: BudgetLimits::default(),
+            repo: BudgetLimits::default(),
+            warn_at: 0.8,
+            on_exceed: BudgetAction::default(),
+        }
+    }
+}
+
+impl BudgetConfig {
+    /// Whether any limit is set.
+    pub fn is_enabled(&self) -> bool {
+        self.worker.is_set() || self.repo.is_set()
+    }
+}
+
+/// Token, dollar and wall-clock hour limits.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BudgetLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dollars: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<f64>,
+}
+
+impl BudgetLimits {
+    pub fn is_set(&self) -> bool {
+        self.tokens.is_some() || self.dollars.is_some() || self.hours.is_some()
+    }
+}
+
+/// How the daemon stops a worker that went over budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetAction {
+    /// Send Ctrl-C, leaving the session open for a human to pick up.
+    #[default]
+    Interrupt,
+    /// Kill the tmux window and mark the worker failed.
+    Stop,
+}
+
+impl BudgetAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Interrupt => "interrupt",
+            Self::Stop => "stop",
+        }
+    }
+}
+
+/// Merge queue in jig.toml `[merge_queue]`.
+///
+/// When enabled, the daemon lands `Approved` workers one at a time: rebase
+/// onto the base branch, run `verify` in the worktree, then merge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MergeQueueConfig {
+    pub enabled: bool,
+    /// Shell command run in the rebased worktree. A non-zero exit fails the
+    /// attempt.
+    pub verify: Option<String>,
+    /// Seconds before the verify command is killed.
+    pub verify_timeout_seconds: u64,
+    /// Where the branch is merged.
+    pub method: MergeMethod,
+    /// Seconds to wait for CI on the pushed head before a `forge` merge.
+    pub checks_timeout_seconds: u64,
+    /// Attempts before a worker is taken out of the queue and escalated.
+    pub max_attempts: u32,
+}
+
+impl Default for MergeQueueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            verify: None,
+            verify_timeout_seconds: 1800,
+            method: MergeMethod::default(),
+            checks_timeout_seconds: 3600,
+            max_attempts: 2,
+        }
+    }
+}
+
+/// How the merge queue lands a branch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMethod {
+    /// Merge into the local base branch checked out in the repo root.
+    #[default]
+    Local,
+    /// Push the rebased branch and merge its PR through the forge.
+    Forge,
+}
+
+impl MergeMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Forge => "forge",
+        }
+    }
 }
 
 /// Issue tracking configuration in jig.toml
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssuesConfig {
-    /// Provider type ("file" or "linear").
+    /// Provider type ("file", "linear", or "github").
     #[serde(default = "default_issues_provider")]
     pub provider: String,
     /// Directory containing issue files (relative to repo root).
@@ -435,6 +717,9 @@ pub struct IssuesConfig {
     /// Linear-specific configuration (required when provider = "linear").
     #[serde(default)]
     pub linear: Option<LinearIssuesConfig>,
+    /// GitHub Issues configuration (optional when provider = "github").
+    #[serde(default)]
+    pub github: Option<GitHubIssuesConfig>,
     /// Labels required for auto-spawn. Controls whether the daemon auto-spawns
     /// workers for eligible issues in this repo.
     ///
@@ -468,6 +753,18 @@ pub struct LinearIssuesConfig {
     pub labels: Vec<String>,
 }
 
+/// GitHub Issues provider configuration in jig.toml.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitHubIssuesConfig {
+    /// Repository in `owner/repo` format. Detected from the git remote via
+    /// `gh repo view` when omitted.
+    #[serde(default)]
+    pub repo: Option<String>,
+    /// Optional label filter. Issues must carry all listed labels.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
 fn default_issues_provider() -> String {
     "file".to_string()
 }
@@ -482,6 +779,7 @@ impl Default for IssuesConfig {
             provider: default_issues_provider(),
             directory: default_issues_directory(),
             linear: None,
+            github: None,
             auto_spawn_labels: None,
             auto_complete_on_merge: false,
         }
@@ -515,6 +813,25 @@ pub struct SpawnConfig {
     /// Override global auto_spawn_interval for this repo.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_spawn_interval: Option<u64>,
+    /// What to do when in-flight workers edit the same files.
+    pub overlap: OverlapAction,
+    /// Globs for files whose overlap doesn't count (default: lockfiles and
+    /// changelogs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlap_ignore: Option<Vec<String>>,
+}
+
+/// What the daemon does about workers editing the same files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapAction {
+    /// Log and notify, but spawn anyway.
+    #[default]
+    Warn,
+    /// Don't auto-spawn an issue whose files an in-flight worker is editing.
+    Hold,
+    /// Don't check.
+    Off,
 }
 
 impl SpawnConfig {
@@ -532,6 +849,15 @@ impl SpawnConfig {
         self.auto_spawn_interval
             .unwrap_or(global.auto_spawn_interval)
     }
+
+    /// Overlap ignore globs, or the defaults when unset.
+    pub fn resolve_overlap_ignore(&self) -> Vec<String> {
+        self.overlap_ignore.clone().unwrap_or_else(|| {
+            ["*.lock", "package-lock.json", "CHANGELOG.md"]
+                .map(String::from)
+                .to_vec()
+        })
+    }
 }
 
 /// Agent configuration in jig.toml
@@ -559,6 +885,56 @@ impl Default for AgentConfig {
     }
 }
 
+/// Agent adapter definition in `[agents.<name>]` (jig.toml or global config).
+///
+/// When `<name>` matches a built-in adapter, set fields override the built-in
+/// values. Otherwise a new adapter is defined; `command` defaults to `<name>`.
+/// Flags set to `""` are treated as unsupported.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentDefinition {
+    /// Command to invoke the agent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Flag to run without permission prompts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_flag: Option<String>,
+    /// Flags for one-shot, non-interactive execution (prompt on stdin)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ephemeral_flags: Option<String>,
+    /// Flag preceding the one-shot prompt (unset/empty: stdin or positional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ephemeral_prompt_flag: Option<String>,
+    /// Flag to continue the most recent session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continue_flag: Option<String>,
+    /// Flag preceding the initial prompt (unset/empty: positional argument)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_flag: Option<String>,
+    /// Type the initial prompt into the pane after launch instead of
+    /// passing it on the command line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_prompt: Option<bool>,
+    /// Flag used to select a model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_flag: Option<String>,
+    /// Flag used to pass the tool allowlist
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_tools_flag: Option<String>,
+    /// Flag used to pass the tool denylist
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disallowed_tools_flag: Option<String>,
+    /// Directory for skills (relative to repo root)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills_dir: Option<String>,
+    /// Skill file name inside each skill directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_file: Option<String>,
+    /// Project context file (e.g. "AGENTS.md")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_file: Option<String>,
+}
+
 /// Review configuration in jig.toml `[review]`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ReviewConfig {
@@ -585,33 +961,45 @@ impl Default for ReviewConfig {
     }
 }
 
+/// Triage model for Claude when `[triage] model` is unset.
+pub const DEFAULT_TRIAGE_MODEL: &str = "sonnet";
+
 /// Per-repo triage configuration in jig.toml `[triage]`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TriageConfig {
     /// Whether triage auto-spawn is enabled for this repo.
     #[serde(default)]
     pub enabled: bool,
-    /// Model for triage agents (default "sonnet").
-    #[serde(default = "default_triage_model")]
-    pub model: String,
+    /// Model for triage agents. Unset uses "sonnet" for Claude and the
+    /// agent's own default otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
     /// Max time in seconds for a triage worker before it's considered stuck.
     #[serde(default = "default_triage_timeout")]
     pub timeout_seconds: i64,
 }
 
-fn default_triage_model() -> String {
-    "sonnet".to_string()
-}
-
 fn default_triage_timeout() -> i64 {
     600
 }
 
+impl TriageConfig {
+    /// The model to pass to a triage run of `agent`, if any. A Claude model
+    /// name is only a default for Claude itself.
+    pub fn model_for(&self, agent: &str) -> Option<&str> {
+        match &self.model {
+            Some(model) => Some(model),
+            None if agent == "claude" => Some(DEFAULT_TRIAGE_MODEL),
+            None => None,
+        }
+    }
+}
+
 impl Default for TriageConfig {
     fn default() -> Self {
         Self {
             enabled: false,
-            model: default_triage_model(),
+            model: None,
             timeout_seconds: default_triage_timeout(),
         }
     }
@@ -748,6 +1136,8 @@ pub struct ConfigDisplay {
     pub issues_source: String,
     /// Linear config (populated when provider = "linear")
     pub linear: Option<LinearIssuesConfig>,
+    /// GitHub Issues config (populated when `[issues.github]` is present)
+    pub github: Option<GitHubIssuesConfig>,
     // Auto-spawn fields
     pub auto_spawn_labels: Option<Vec<String>>,
     pub max_concurrent_workers: usize,
@@ -893,6 +1283,7 @@ impl ConfigDisplay {
             issues_directory: jig_toml.issues.directory,
             issues_source,
             linear: jig_toml.issues.linear,
+            github: jig_toml.issues.github,
             auto_spawn_labels: jig_toml.issues.auto_spawn_labels,
             max_concurrent_workers,
             max_concurrent_workers_source,
@@ -1066,6 +1457,110 @@ profile = "work"
         assert!(linear.labels.is_empty());
     }
 
+    #[test]
+    fn parse_jig_toml_github_issues() {
+        let toml_str = r#"
+[issues]
+provider = "github"
+
+[issues.github]
+repo = "acme/widgets"
+labels = ["jig"]
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.issues.provider, "github");
+        let github = config.issues.github.unwrap();
+        assert_eq!(github.repo.as_deref(), Some("acme/widgets"));
+        assert_eq!(github.labels, vec!["jig"]);
+    }
+
+    #[test]
+    fn parse_jig_toml_forge() {
+        let toml_str = r#"
+[forge]
+provider = "gitlab"
+project = "platform/widgets"
+api_url = "https://gitlab.example.com/api/v4"
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.forge.provider.as_deref(), Some("gitlab"));
+        assert_eq!(config.forge.project.as_deref(), Some("platform/widgets"));
+        assert_eq!(
+            config.forge.api_url.as_deref(),
+            Some("https://gitlab.example.com/api/v4")
+        );
+
+        let empty: JigToml = toml::from_str("").unwrap();
+        assert!(empty.forge.provider.is_none());
+    }
+
+    #[test]
+    fn repo_notify_merges_over_global() {
+        let toml_str = r#"
+[notify]
+webhook = "https://hooks.example/api-team"
+quiet_hours = "22:00-07:00"
+
+[[notify.rules]]
+events = ["needs_intervention"]
+exec = "page-api-oncall"
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        let global = crate::global::NotifyConfig {
+            exec: Some("notify-send".to_string()),
+            webhook: Some("https://hooks.example/everyone".to_string()),
+            rules: vec![crate::global::NotifyRule {
+                events: vec!["needs_intervention".to_string()],
+                webhook: Some("https://hooks.example/pager".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let merged = config.notify.resolve(&global);
+        assert_eq!(merged.exec.as_deref(), Some("notify-send"));
+        assert_eq!(
+            merged.webhook.as_deref(),
+            Some("https://hooks.example/api-team")
+        );
+        assert_eq!(merged.quiet_hours.as_deref(), Some("22:00-07:00"));
+        assert_eq!(merged.rules.len(), 2);
+        assert_eq!(merged.rules[0].exec.as_deref(), Some("page-api-oncall"));
+
+        let empty: JigToml = toml::from_str("").unwrap();
+        let merged = empty.notify.resolve(&global);
+        assert_eq!(
+            merged.webhook.as_deref(),
+            Some("https://hooks.example/everyone")
+        );
+        assert_eq!(merged.rules.len(), 1);
+    }
+
+    #[test]
+    fn parse_jig_toml_agents() {
+        let toml_str = r#"
+[agent]
+type = "opencode"
+
+[agents.opencode]
+auto_flag = "--auto"
+continue_flag = "--continue"
+
+[agents.claude]
+command = "/opt/bin/claude"
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.agent.agent_type, "opencode");
+        let opencode = &config.agents["opencode"];
+        assert_eq!(opencode.command, None);
+        assert_eq!(opencode.auto_flag.as_deref(), Some("--auto"));
+        assert_eq!(opencode.continue_flag.as_deref(), Some("--continue"));
+        assert_eq!(
+            config.agents["claude"].command.as_deref(),
+            Some("/opt/bin/claude")
+        );
+    }
+
     #[test]
     fn parse_jig_toml_with_health() {
         let toml_str = r#"
@@ -1093,6 +1588,94 @@ cooldown_seconds = 60
         assert!(config.health.nudge.review.is_none());
     }
 
+    #[test]
+    fn parse_jig_toml_with_patterns() {
+        let toml_str = r#"
+[[health.patterns]]
+name = "yes-no"
+regex = "Continue\\? \\[Y/n\\]"
+action = "send_keys"
+keys = ["Y", "Enter"]
+
+[[health.patterns]]
+name = "quota"
+regex = "(?i)quota exceeded"
+action = "notify"
+cooldown_seconds = 3600
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        let patterns = &config.health.patterns;
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns[0].action, PatternAction::SendKeys);
+        assert_eq!(patterns[0].keys, vec!["Y", "Enter"]);
+        assert_eq!(patterns[0].cooldown_seconds, 300);
+        assert_eq!(patterns[1].action, PatternAction::Notify);
+
+        // Repo rules come first and replace the built-in of the same name.
+        let resolved = config.health.resolve_patterns();
+        assert_eq!(resolved[0].name, "yes-no");
+        assert_eq!(resolved[1].name, "quota");
+        assert_eq!(resolved.iter().filter(|p| p.name == "yes-no").count(), 1);
+        assert!(resolved.len() > 2);
+
+        let config: JigToml = toml::from_str("[health]\nbuiltin_patterns = false\n").unwrap();
+        assert!(config.health.resolve_patterns().is_empty());
+    }
+
+    #[test]
+    fn parse_jig_toml_with_budget() {
+        let toml_str = r#"
+[budget]
+on_exceed = "stop"
+
+[budget.worker]
+tokens = 5000000
+hours = 4
+
+[budget.repo]
+dollars = 50.0
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        assert!(config.budget.is_enabled());
+        assert_eq!(config.budget.worker.tokens, Some(5_000_000));
+        assert_eq!(config.budget.worker.hours, Some(4.0));
+        assert_eq!(config.budget.repo.dollars, Some(50.0));
+        assert_eq!(config.budget.warn_at, 0.8);
+        assert_eq!(config.budget.on_exceed, BudgetAction::Stop);
+        assert!(!JigToml::default().budget.is_enabled());
+    }
+
+    #[test]
+    fn parse_jig_toml_with_merge_queue() {
+        let toml_str = r#"
+[merge_queue]
+enabled = true
+verify = "cargo test"
+method = "forge"
+"#;
+        let config: JigToml = toml::from_str(toml_str).unwrap();
+        assert!(config.merge_queue.enabled);
+        assert_eq!(config.merge_queue.verify.as_deref(), Some("cargo test"));
+        assert_eq!(config.merge_queue.method, MergeMethod::Forge);
+        assert_eq!(config.merge_queue.max_attempts, 2);
+        assert_eq!(config.merge_queue.verify_timeout_seconds, 1800);
+        assert!(!JigToml::default().merge_queue.enabled);
+    }
+
+    #[test]
+    fn parse_jig_toml_with_spawn_overlap() {
+        let config: JigToml =
+            toml::from_str("[spawn]\noverlap = \"hold\"\noverlap_ignore = [\"docs/*\"]\n").unwrap();
+        assert_eq!(config.spawn.overlap, OverlapAction::Hold);
+        assert_eq!(config.spawn.resolve_overlap_ignore(), vec!["docs/*"]);
+
+        let default = JigToml::default().spawn;
+        assert_eq!(default.overlap, OverlapAction::Warn);
+        assert!(default
+            .resolve_overlap_ignore()
+            .contains(&"*.lock".to_string()));
+    }
+
     #[test]
     fn parse_jig_toml_without_health() {
         let toml_str = r#"
@@ -1140,6 +1723,7 @@ base = "origin/main"
                 }),
                 ..Default::default()
             },
+            ..Default::default()
         };
         let global = global_health();
 
@@ -1370,7 +1954,7 @@ auto_spawn_labels = []
     fn test_triage_config_defaults() {
         let config = TriageConfig::default();
         assert!(!config.enabled);
-        assert_eq!(config.model, "sonnet");
+        assert_eq!(config.model_for("claude"), Some("sonnet"));
         assert_eq!(config.timeout_seconds, 600);
     }
 
@@ -1389,7 +1973,8 @@ timeout_seconds = 300
         .unwrap();
         let toml = JigToml::load(dir.path()).unwrap().unwrap();
         assert!(!toml.triage.enabled);
-        assert_eq!(toml.triage.model, "opus");
+        assert_eq!(toml.triage.model.as_deref(), Some("opus"));
+        assert_eq!(toml.triage.model_for("codex"), Some("opus"));
         assert_eq!(toml.triage.timeout_seconds, 300);
     }
 
@@ -1399,7 +1984,9 @@ timeout_seconds = 300
         fs::write(dir.path().join(JIG_TOML), "[worktree]\n").unwrap();
         let toml = JigToml::load(dir.path()).unwrap().unwrap();
         assert!(!toml.triage.enabled);
-        assert_eq!(toml.triage.model, "sonnet");
+        assert_eq!(toml.triage.model, None);
+        assert_eq!(toml.triage.model_for("claude"), Some("sonnet"));
+        assert_eq!(toml.triage.model_for("codex"), None);
         assert_eq!(toml.triage.timeout_seconds, 600);
     }
 }
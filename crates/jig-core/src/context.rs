@@ -6,7 +6,7 @@ use crate::config::{Config, JigToml, JIG_DIR};
 use crate::error::Result;
 use crate::git::Repo;
 use crate::global::GlobalConfig;
-use crate::issues::{self, FileProvider, IssueProvider, LinearProvider};
+use crate::issues::{self, FileProvider, GitHubProvider, IssueProvider, LinearProvider};
 
 /// All repo-derived state needed by jig operations.
 /// Created once at startup to avoid redundant git subprocess calls.
@@ -135,6 +135,11 @@ impl RepoContext {
     pub fn linear_provider(&self) -> Result<LinearProvider> {
         issues::make_linear_provider(&self.jig_toml, &self.global_config)
     }
+
+    /// Create a GitHub Issues provider (for mutation operations).
+    pub fn github_provider(&self) -> Result<GitHubProvider> {
+        issues::make_github_provider(&self.repo_root, &self.jig_toml)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,610 @@
+//! Control socket — a local API for the running daemon.
+//!
+//! `jig daemon` listens on `~/.config/jig/state/daemon.sock`. Each connection
+//! sends one JSON request line and reads one JSON response line, except
+//! `subscribe`, which keeps the connection open and streams every
+//! `TickResult` as a JSON line.
+//!
+//! Requests are handed to the daemon loop over a channel and answered between
+//! ticks, so the loop stays the only thing touching daemon state.
+
+use std::io::{BufRead, BufReader, Lines, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::global::daemon_socket_path;
+use crate::land::Strategy;
+
+use super::TickResult;
+
+/// How long a connection waits for the loop to answer. A request that
+/// arrives mid-tick is answered once the tick finishes.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(300);
+
+type Subscribers = Arc<Mutex<Vec<flume::Sender<String>>>>;
+
+/// A request sent to the daemon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum ControlRequest {
+    /// Run a tick now instead of waiting for the interval.
+    Tick,
+    /// Stop auto-spawning workers for issues (workers are still monitored).
+    Pause,
+    /// Resume auto-spawn.
+    Resume,
+    /// Nudge a worker now. `worker` is `repo/worker` or a unique worker name;
+    /// without `message` the worker's usual nudge template is used.
+    Nudge {
+        worker: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Stop a worker's window and unregister it.
+    Kill { worker: String },
+    /// Relaunch a worker whose window is gone (`resume` is for auto-spawn).
+    ResumeWorker { worker: String },
+    /// Land a worker's branch into its repo (see [`crate::land`]).
+    Merge {
+        worker: String,
+        strategy: Strategy,
+        #[serde(default)]
+        cleanup: bool,
+    },
+    /// Re-read the global config.
+    Reload,
+    /// Report daemon status.
+    Status,
+    /// Stream every tick result until the client disconnects.
+    Subscribe,
+}
+
+/// The daemon's answer to a request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<DaemonStatus>,
+}
+
+impl ControlResponse {
+    pub fn ok(message: impl Into<String>) -> Self {
+        Self {
+            ok: true,
+            message: Some(message.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn error(error: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            error: Some(error.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn status(status: DaemonStatus) -> Self {
+        Self {
+            ok: true,
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
+    /// Turn an error response into an `Err`.
+    pub fn into_result(self) -> Result<Self> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(Error::Custom(
+                self.error
+                    .unwrap_or_else(|| "daemon request failed".to_string()),
+            ))
+        }
+    }
+}
+
+/// Snapshot of the daemon loop, returned by `status`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    pub pid: u32,
+    pub started_at: i64,
+    pub interval_seconds: u64,
+    /// Whether auto-spawn is paused.
+    pub paused: bool,
+    pub ticks: u64,
+    pub last_tick_at: Option<i64>,
+    /// Workers checked on the last tick.
+    pub workers: usize,
+}
+
+impl DaemonStatus {
+    pub fn new(interval_seconds: u64) -> Self {
+        Self {
+            pid: std::process::id(),
+            started_at: chrono::Utc::now().timestamp(),
+            interval_seconds,
+            ..Default::default()
+        }
+    }
+
+    pub fn record_tick(&mut self, tick: &TickResult) {
+        self.ticks += 1;
+        self.last_tick_at = Some(chrono::Utc::now().timestamp());
+        self.workers = tick.workers_checked;
+    }
+}
+
+/// A request waiting for the daemon loop to answer it.
+#[derive(Debug)]
+pub struct ControlCommand {
+    pub request: ControlRequest,
+    reply: flume::Sender<ControlResponse>,
+}
+
+impl ControlCommand {
+    pub fn reply(self, response: ControlResponse) {
+        // The client may have given up; nothing to do then.
+        let _ = self.reply.send(response);
+    }
+}
+
+/// The listening side of the control socket.
+///
+/// Connections are accepted on background threads; requests come out of
+/// [`ControlServer::recv_timeout`]. The socket file is removed on drop.
+pub struct ControlServer {
+    path: PathBuf,
+    commands: flume::Receiver<ControlCommand>,
+    subscribers: Subscribers,
+    closed: Arc<AtomicBool>,
+}
+
+impl ControlServer {
+    /// Listen on the global socket path.
+    pub fn bind_global() -> Result<Self> {
+        Self::bind(&daemon_socket_path()?)
+    }
+
+    /// Listen on `path`, replacing a stale socket left by a dead daemon.
+    pub fn bind(path: &Path) -> Result<Self> {
+        if path.exists() {
+            if UnixStream::connect(path).is_ok() {
+                return Err(Error::Custom(format!(
+                    "a daemon is already listening on {}",
+                    path.display()
+                )));
+            }
+            std::fs::remove_file(path)?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let listener = UnixListener::bind(path)?;
+
+        let (tx, commands) = flume::unbounded();
+        let subscribers = Subscribers::default();
+        let closed = Arc::new(AtomicBool::new(false));
+
+        let subs = Arc::clone(&subscribers);
+        let closed_flag = Arc::clone(&closed);
+        std::thread::Builder::new()
+            .name("jig-control".into())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if closed_flag.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let stream = match stream {
+                        Ok(s) => s,
+                        Err(e) => {
+                            tracing::warn!("control socket accept failed: {}", e);
+                            continue;
+                        }
+                    };
+                    let tx = tx.clone();
+                    let subs = Arc::clone(&subs);
+                    std::thread::spawn(move || {
+                        if let Err(e) = handle_connection(stream, &tx, &subs) {
+                            tracing::debug!("control connection ended: {}", e);
+                        }
+                    });
+                }
+            })?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            commands,
+            subscribers,
+            closed,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Wait up to `timeout` for the next request.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<ControlCommand> {
+        self.commands.recv_timeout(timeout).ok()
+    }
+
+    /// Send a tick result to every subscriber, dropping disconnected ones.
+    pub fn publish(&self, tick: &TickResult) {
+        let mut subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        if subscribers.is_empty() {
+            return;
+        }
+        let line = match serde_json::to_string(tick) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!("failed to serialize tick result: {}", e);
+                return;
+            }
+        };
+        subscribers.retain(|s| s.send(line.clone()).is_ok());
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::Relaxed);
+        // Wake the accept loop so it sees the flag and exits.
+        let _ = UnixStream::connect(&self.path);
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Serve one connection: read a request line, answer it (or stream ticks).
+fn handle_connection(
+    stream: UnixStream,
+    commands: &flume::Sender<ControlCommand>,
+    subscribers: &Subscribers,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(());
+    }
+    let request: ControlRequest = match serde_json::from_str(line.trim()) {
+        Ok(r) => r,
+        Err(e) => {
+            return write_line(
+                &mut writer,
+                &ControlResponse::error(format!("invalid request: {}", e)),
+            )
+        }
+    };
+
+    if request == ControlRequest::Subscribe {
+        let (tx, rx) = flume::unbounded();
+        subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(tx);
+        write_line(&mut writer, &ControlResponse::ok("subscribed"))?;
+        for tick in rx.iter() {
+            writer.write_all(tick.as_bytes())?;
+            writer.write_all(b"\n")?;
+            writer.flush()?;
+        }
+        return Ok(());
+    }
+
+    let (reply, replies) = flume::bounded(1);
+    let response = if commands.send(ControlCommand { request, reply }).is_err() {
+        ControlResponse::error("daemon is shutting down")
+    } else {
+        replies
+            .recv_timeout(REPLY_TIMEOUT)
+            .unwrap_or_else(|_| ControlResponse::error("timed out waiting for the daemon"))
+    };
+    write_line(&mut writer, &response)
+}
+
+fn write_line<T: Serialize>(writer: &mut UnixStream, value: &T) -> std::io::Result<()> {
+    let mut line = serde_json::to_string(value)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes())?;
+    writer.flush()
+}
+
+/// Client for a daemon's control socket.
+#[derive(Debug, Clone)]
+pub struct ControlClient {
+    path: PathBuf,
+}
+
+impl ControlClient {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Client for the global socket path.
+    pub fn global() -> Result<Self> {
+        Ok(Self::new(daemon_socket_path()?))
+    }
+
+    /// Whether a daemon is listening.
+    pub fn is_running(&self) -> bool {
+        UnixStream::connect(&self.path).is_ok()
+    }
+
+    /// Send a request and wait for the response.
+    pub fn request(&self, request: &ControlRequest) -> Result<ControlResponse> {
+        let mut stream = self.connect()?;
+        stream.set_read_timeout(Some(REPLY_TIMEOUT + Duration::from_secs(5)))?;
+        write_line(&mut stream, request)?;
+
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line)?;
+        if line.trim().is_empty() {
+            return Err(Error::Custom("daemon closed the connection".to_string()));
+        }
+        Ok(serde_json::from_str(line.trim())?)
+    }
+
+    /// Stream tick results. `on_tick` gets each tick as JSON and returns
+    /// `false` to stop.
+    pub fn subscribe<F>(&self, mut on_tick: F) -> Result<()>
+    where
+        F: FnMut(serde_json::Value) -> bool,
+    {
+        let mut subscription = self.open_subscription()?;
+        while let Some(tick) = subscription.next_value()? {
+            if !on_tick(tick) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Subscribe to tick results. Every tick published after this returns
+    /// is delivered.
+    pub fn open_subscription(&self) -> Result<Subscription> {
+        let mut stream = self.connect()?;
+        write_line(&mut stream, &ControlRequest::Subscribe)?;
+
+        let mut lines = BufReader::new(stream).lines();
+        let ack: ControlResponse = match lines.next() {
+            Some(line) => serde_json::from_str(&line?)?,
+            None => return Err(Error::Custom("daemon closed the connection".to_string())),
+        };
+        ack.into_result()?;
+        Ok(Subscription { lines })
+    }
+
+    /// Ask for a tick now and wait for its result.
+    pub fn tick_now(&self) -> Result<TickResult> {
+        let mut subscription = self.open_subscription()?;
+        self.request(&ControlRequest::Tick)?.into_result()?;
+        subscription
+            .next_tick()?
+            .ok_or_else(|| Error::Custom("daemon closed the connection".to_string()))
+    }
+
+    fn connect(&self) -> Result<UnixStream> {
+        UnixStream::connect(&self.path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused => {
+                Error::Custom(format!(
+                    "daemon is not running (no control socket at {})",
+                    self.path.display()
+                ))
+            }
+            _ => e.into(),
+        })
+    }
+}
+
+/// An open `subscribe` connection.
+#[derive(Debug)]
+pub struct Subscription {
+    lines: Lines<BufReader<UnixStream>>,
+}
+
+impl Subscription {
+    /// The next tick as JSON, or `None` once the daemon closes the connection.
+    pub fn next_value(&mut self) -> Result<Option<serde_json::Value>> {
+        match self.lines.next() {
+            Some(line) => Ok(Some(serde_json::from_str(&line?)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// The next tick, or `None` once the daemon closes the connection.
+    pub fn next_tick(&mut self) -> Result<Option<TickResult>> {
+        match self.lines.next() {
+            Some(line) => Ok(Some(serde_json::from_str(&line?)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answer requests on a background thread the way the daemon loop would.
+    fn serve(server: ControlServer, n: usize) -> std::thread::JoinHandle<ControlServer> {
+        std::thread::spawn(move || {
+            let mut status = DaemonStatus::new(30);
+            for _ in 0..n {
+                let cmd = server.recv_timeout(Duration::from_secs(5)).unwrap();
+                let response = match &cmd.request {
+                    ControlRequest::Pause => {
+                        status.paused = true;
+                        ControlResponse::ok("paused")
+                    }
+                    ControlRequest::Status => ControlResponse::status(status.clone()),
+                    ControlRequest::Nudge { worker, .. } => {
+                        ControlResponse::error(format!("unknown worker {}", worker))
+                    }
+                    _ => ControlResponse::ok("ok"),
+                };
+                cmd.reply(response);
+            }
+            server
+        })
+    }
+
+    #[test]
+    fn request_wire_format() {
+        let json = serde_json::to_string(&ControlRequest::Nudge {
+            worker: "api/fix".into(),
+            message: None,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"cmd":"nudge","worker":"api/fix"}"#);
+
+        let req: ControlRequest = serde_json::from_str(r#"{"cmd":"pause"}"#).unwrap();
+        assert_eq!(req, ControlRequest::Pause);
+
+        let req: ControlRequest =
+            serde_json::from_str(r#"{"cmd":"merge","worker":"fix","strategy":"squash"}"#).unwrap();
+        assert_eq!(
+            req,
+            ControlRequest::Merge {
+                worker: "fix".into(),
+                strategy: Strategy::Squash,
+                cleanup: false,
+            }
+        );
+    }
+
+    #[test]
+    fn request_response_round_trip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.sock");
+        let server = ControlServer::bind(&path).unwrap();
+        let client = ControlClient::new(&path);
+        assert!(client.is_running());
+
+        let handle = serve(server, 3);
+        let paused = client.request(&ControlRequest::Pause).unwrap();
+        assert_eq!(paused.message.as_deref(), Some("paused"));
+
+        let status = client.request(&ControlRequest::Status).unwrap();
+        assert!(status.status.unwrap().paused);
+
+        let err = client
+            .request(&ControlRequest::Nudge {
+                worker: "nope".into(),
+                message: None,
+            })
+            .unwrap()
+            .into_result()
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown worker nope"));
+
+        drop(handle.join().unwrap());
+        assert!(!path.exists());
+        assert!(!client.is_running());
+    }
+
+    #[test]
+    fn invalid_request_is_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.sock");
+        let _server = ControlServer::bind(&path).unwrap();
+
+        let mut stream = UnixStream::connect(&path).unwrap();
+        stream.write_all(b"{\"cmd\":\"explode\"}\n").unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        let response: ControlResponse = serde_json::from_str(&line).unwrap();
+        assert!(!response.ok);
+        assert!(response.error.unwrap().contains("invalid request"));
+    }
+
+    #[test]
+    fn subscribers_receive_ticks() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.sock");
+        let server = ControlServer::bind(&path).unwrap();
+        let client = ControlClient::new(&path);
+
+        let (tx, rx) = flume::unbounded();
+        let sub = std::thread::spawn(move || {
+            client
+                .subscribe(|tick| {
+                    tx.send(tick).unwrap();
+                    false
+                })
+                .unwrap();
+        });
+
+        // Publish until the subscriber has registered and received a tick.
+        let tick = TickResult {
+            workers_checked: 2,
+            ..Default::default()
+        };
+        let received = loop {
+            server.publish(&tick);
+            if let Ok(v) = rx.recv_timeout(Duration::from_millis(50)) {
+                break v;
+            }
+        };
+        assert_eq!(received["workers_checked"], 2);
+        sub.join().unwrap();
+    }
+
+    #[test]
+    fn tick_now_waits_for_the_requested_tick() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.sock");
+        let server = ControlServer::bind(&path).unwrap();
+        let client = ControlClient::new(&path);
+
+        let daemon = std::thread::spawn(move || {
+            let cmd = server.recv_timeout(Duration::from_secs(5)).unwrap();
+            assert_eq!(cmd.request, ControlRequest::Tick);
+            cmd.reply(ControlResponse::ok("tick started"));
+            server.publish(&TickResult {
+                workers_checked: 3,
+                ..Default::default()
+            });
+            server
+        });
+
+        let tick = client.tick_now().unwrap();
+        assert_eq!(tick.workers_checked, 3);
+        drop(daemon.join().unwrap());
+    }
+
+    #[test]
+    fn bind_refuses_live_socket_and_replaces_stale_one() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.sock");
+
+        let server = ControlServer::bind(&path).unwrap();
+        let err = ControlServer::bind(&path).err().unwrap();
+        assert!(err.to_string().contains("already listening"));
+        drop(server);
+
+        // A socket file nobody listens on is stale.
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+        let _server = ControlServer::bind(&path).unwrap();
+    }
+
+    #[test]
+    fn client_reports_missing_daemon() {
+        let tmp = tempfile::tempdir().unwrap();
+        let client = ControlClient::new(tmp.path().join("daemon.sock"));
+        let err = client.request(&ControlRequest::Status).unwrap_err();
+        assert!(err.to_string().contains("daemon is not running"));
+    }
+}
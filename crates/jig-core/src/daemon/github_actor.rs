@@ -1,23 +1,29 @@
 //! GitHub actor — runs PR discovery and lifecycle checks in a background thread.
+//!
+//! Despite the name it serves any [`Forge`]; GitLab repos go through the same path.
 
-use crate::github::{self, GitHubClient};
+use std::time::Instant;
+
+use crate::forge::Forge;
+use crate::github::{self, PrLookup, PrSnapshot};
 use crate::registry::RepoRegistry;
 
-use super::messages::{GitHubRequest, GitHubResponse};
+use super::messages::{GitHubBatch, GitHubRequest, GitHubResponse};
+use super::pr::make_forge;
 
 /// Spawn the GitHub actor thread. Returns immediately.
 ///
-/// Processes one `GitHubRequest` at a time (sequential to respect API rate limits).
+/// Processes one batch at a time (sequential to respect API rate limits).
+/// Each batch costs one GraphQL query per repo.
 pub fn spawn(
-    rx: flume::Receiver<GitHubRequest>,
-    tx: flume::Sender<GitHubResponse>,
+    rx: flume::Receiver<Vec<GitHubRequest>>,
+    tx: flume::Sender<GitHubBatch>,
 ) -> std::thread::JoinHandle<()> {
     std::thread::Builder::new()
         .name("jig-github".into())
         .spawn(move || {
-            while let Ok(req) = rx.recv() {
-                let response = process_request(&req);
-                if tx.send(response).is_err() {
+            while let Ok(batch) = rx.recv() {
+                if tx.send(process_batch(&batch)).is_err() {
                     break;
                 }
             }
@@ -25,24 +31,172 @@ pub fn spawn(
         .expect("failed to spawn github actor thread")
 }
 
-fn process_request(req: &GitHubRequest) -> GitHubResponse {
+fn process_batch(batch: &[GitHubRequest]) -> GitHubBatch {
     let registry = RepoRegistry::load().unwrap_or_default();
-    let client = match make_client(&req.repo_name, &registry) {
-        Some(c) => c,
+
+    // Group by repo, keeping first-seen order.
+    let mut repos: Vec<(&str, Vec<&GitHubRequest>)> = Vec::new();
+    for req in batch {
+        match repos.iter_mut().find(|(name, _)| *name == req.repo_name) {
+            Some((_, reqs)) => reqs.push(req),
+            None => repos.push((&req.repo_name, vec![req])),
+        }
+    }
+
+    let mut responses = Vec::with_capacity(batch.len());
+    let mut latencies = Vec::with_capacity(repos.len());
+    for (repo_name, reqs) in repos {
+        let Some(client) = make_forge(repo_name, &registry) else {
+            responses.extend(
+                reqs.iter()
+                    .map(|req| error_response(req, "forge client unavailable")),
+            );
+            continue;
+        };
+        let started = Instant::now();
+        responses.extend(process_repo(client.as_ref(), &reqs));
+        latencies.push(started.elapsed());
+    }
+    GitHubBatch {
+        responses,
+        latencies,
+    }
+}
+
+/// Check every request for one repo with a single batched snapshot query,
+/// falling back to per-worker REST calls if the query fails.
+fn process_repo(client: &dyn Forge, reqs: &[&GitHubRequest]) -> Vec<GitHubResponse> {
+    let mut responses = Vec::with_capacity(reqs.len());
+    let mut lookups = Vec::new();
+    let mut pending = Vec::new();
+
+    for req in reqs {
+        match &req.pr_url {
+            Some(url) => match pr_number_from_url(url) {
+                Some(n) => {
+                    lookups.push(PrLookup::Number(n));
+                    pending.push(*req);
+                }
+                None => responses.push(error_response(req, "invalid PR URL")),
+            },
+            None => {
+                lookups.push(PrLookup::Branch(req.branch.clone()));
+                pending.push(*req);
+            }
+        }
+    }
+
+    match client.get_pr_snapshots(&lookups) {
+        Ok(snapshots) => {
+            responses.extend(
+                pending
+                    .into_iter()
+                    .zip(snapshots)
+                    .map(|(req, snapshot)| snapshot_response(req, snapshot)),
+            );
+        }
+        Err(e) => {
+            tracing::debug!(
+                repo = client.repo(),
+                error = %e,
+                "batched PR query failed, falling back to per-worker checks"
+            );
+            responses.extend(pending.into_iter().map(|req| process_request(client, req)));
+        }
+    }
+    responses
+}
+
+/// Build a response from a batched snapshot.
+fn snapshot_response(req: &GitHubRequest, snapshot: Option<PrSnapshot>) -> GitHubResponse {
+    let Some(snapshot) = snapshot else {
+        if req.pr_url.is_some() {
+            return error_response(req, "PR not found");
+        }
+        return no_pr_response(req);
+    };
+
+    let pr_url = match &req.pr_url {
+        Some(url) => url.clone(),
         None => {
-            return GitHubResponse {
+            tracing::info!(
+                worker = %req.worker_key,
+                pr_url = %snapshot.url,
+                "discovered PR for branch"
+            );
+            snapshot.url.clone()
+        }
+    };
+
+    match snapshot.state {
+        github::PrState::Merged | github::PrState::Closed => GitHubResponse {
+            worker_key: req.worker_key.clone(),
+            pr_url: Some(pr_url),
+            pr_checks: vec![],
+            pr_error: None,
+            pr_merged: snapshot.state == github::PrState::Merged,
+            pr_closed: snapshot.state == github::PrState::Closed,
+            is_draft: false,
+            review_feedback_count: None,
+        },
+        github::PrState::Open => {
+            let mut pr_checks = Vec::new();
+            let mut review_feedback_count = None;
+            for (name, check) in github::check_snapshot(&snapshot) {
+                if name == "reviews" {
+                    let comments = check.review_comment_count.unwrap_or(0);
+                    let changes_req = check.changes_requested_count.unwrap_or(0);
+                    review_feedback_count = Some(comments + changes_req);
+                }
+                pr_checks.push((name.to_string(), check.nudge.is_some()));
+            }
+
+            GitHubResponse {
                 worker_key: req.worker_key.clone(),
-                pr_url: req.pr_url.clone(),
-                pr_checks: vec![],
-                pr_error: Some("GitHub client unavailable".to_string()),
+                pr_url: Some(pr_url),
+                pr_checks,
+                pr_error: None,
                 pr_merged: false,
                 pr_closed: false,
-                is_draft: req.previous_is_draft,
-                review_feedback_count: None,
-            };
+                is_draft: snapshot.is_draft,
+                review_feedback_count,
+            }
         }
-    };
+    }
+}
 
+fn error_response(req: &GitHubRequest, error: &str) -> GitHubResponse {
+    GitHubResponse {
+        worker_key: req.worker_key.clone(),
+        pr_url: req.pr_url.clone(),
+        pr_checks: vec![],
+        pr_error: Some(error.to_string()),
+        pr_merged: false,
+        pr_closed: false,
+        is_draft: req.previous_is_draft,
+        review_feedback_count: None,
+    }
+}
+
+fn no_pr_response(req: &GitHubRequest) -> GitHubResponse {
+    GitHubResponse {
+        worker_key: req.worker_key.clone(),
+        pr_url: None,
+        pr_checks: vec![],
+        pr_error: None,
+        pr_merged: false,
+        pr_closed: false,
+        is_draft: false,
+        review_feedback_count: None,
+    }
+}
+
+pub(super) fn pr_number_from_url(url: &str) -> Option<u64> {
+    url.rsplit('/').next().and_then(|s| s.parse::<u64>().ok())
+}
+
+/// Check a single worker with individual REST calls.
+fn process_request(client: &dyn Forge, req: &GitHubRequest) -> GitHubResponse {
     // PR discovery if no URL known
     let pr_url = match &req.pr_url {
         Some(url) => Some(url.clone()),
@@ -64,24 +218,11 @@ fn process_request(req: &GitHubRequest) -> GitHubResponse {
     };
 
     let Some(pr_url) = &pr_url else {
-        return GitHubResponse {
-            worker_key: req.worker_key.clone(),
-            pr_url: None,
-            pr_checks: vec![],
-            pr_error: None,
-            pr_merged: false,
-            pr_closed: false,
-            is_draft: false,
-            review_feedback_count: None,
-        };
+        return no_pr_response(req);
     };
 
     // Extract PR number
-    let pr_number = match pr_url
-        .rsplit('/')
-        .next()
-        .and_then(|s| s.parse::<u64>().ok())
-    {
+    let pr_number = match pr_number_from_url(pr_url) {
         Some(n) => n,
         None => {
             return GitHubResponse {
@@ -137,10 +278,10 @@ fn process_request(req: &GitHubRequest) -> GitHubResponse {
         },
         github::PrState::Open => {
             let checks: Vec<(&str, Result<github::PrCheck, _>)> = vec![
-                ("ci", github::check_ci(&client, &req.branch)),
-                ("conflicts", github::check_conflicts(&client, pr_number)),
-                ("reviews", github::check_reviews(&client, pr_number)),
-                ("commits", github::check_commits(&client, pr_number)),
+                ("ci", github::check_ci(client, &req.branch)),
+                ("conflicts", github::check_conflicts(client, pr_number)),
+                ("reviews", github::check_reviews(client, pr_number)),
+                ("commits", github::check_commits(client, pr_number)),
             ];
 
             let mut pr_checks: Vec<(String, bool)> = Vec::new();
@@ -177,15 +318,92 @@ fn process_request(req: &GitHubRequest) -> GitHubResponse {
     }
 }
 
-fn make_client(repo_name: &str, registry: &RepoRegistry) -> Option<GitHubClient> {
-    registry
-        .repos()
-        .iter()
-        .find(|e| {
-            e.path
-                .file_name()
-                .map(|n| n.to_string_lossy() == repo_name)
-                .unwrap_or(false)
-        })
-        .and_then(|entry| GitHubClient::from_repo_path(&entry.path).ok())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(pr_url: Option<&str>) -> GitHubRequest {
+        GitHubRequest {
+            worker_key: "app/feat".into(),
+            repo_name: "app".into(),
+            branch: "feat".into(),
+            pr_url: pr_url.map(String::from),
+            previous_is_draft: true,
+        }
+    }
+
+    fn snapshot(state: github::PrState) -> PrSnapshot {
+        PrSnapshot {
+            number: 7,
+            url: "https://github.com/acme/app/pull/7".into(),
+            state,
+            is_draft: false,
+            has_conflicts: true,
+            check_runs: vec![],
+            reviews: vec![],
+            review_comments: vec![],
+            commits: vec![github::PrCommit {
+                sha: "abc1234".into(),
+                message: "feat: thing".into(),
+            }],
+            pushed_after_feedback: false,
+        }
+    }
+
+    #[test]
+    fn snapshot_response_discovers_pr_and_runs_checks() {
+        let resp = snapshot_response(&request(None), Some(snapshot(github::PrState::Open)));
+        assert_eq!(
+            resp.pr_url.as_deref(),
+            Some("https://github.com/acme/app/pull/7")
+        );
+        assert!(!resp.is_draft);
+        assert_eq!(resp.review_feedback_count, Some(0));
+        let checks: Vec<(&str, bool)> = resp
+            .pr_checks
+            .iter()
+            .map(|(n, b)| (n.as_str(), *b))
+            .collect();
+        assert_eq!(
+            checks,
+            vec![
+                ("ci", false),
+                ("conflicts", true),
+                ("reviews", false),
+                ("commits", false)
+            ]
+        );
+    }
+
+    #[test]
+    fn snapshot_response_merged() {
+        let resp = snapshot_response(
+            &request(Some("https://github.com/acme/app/pull/7")),
+            Some(snapshot(github::PrState::Merged)),
+        );
+        assert!(resp.pr_merged);
+        assert!(!resp.pr_closed);
+        assert!(resp.pr_checks.is_empty());
+    }
+
+    #[test]
+    fn snapshot_response_missing_pr() {
+        let resp = snapshot_response(&request(None), None);
+        assert!(resp.pr_url.is_none());
+        assert!(resp.pr_error.is_none());
+
+        // A known PR that vanished keeps the previous draft state.
+        let resp = snapshot_response(&request(Some("https://github.com/acme/app/pull/7")), None);
+        assert_eq!(resp.pr_error.as_deref(), Some("PR not found"));
+        assert!(resp.is_draft);
+    }
+
+    #[test]
+    fn pr_number_parsing() {
+        assert_eq!(
+            pr_number_from_url("https://github.com/acme/app/pull/42"),
+            Some(42)
+        );
+        assert_eq!(pr_number_from_url("https://github.com/acme/app"), None);
+    }
 }
@@ -737,6 +737,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         }
     }
 
@@ -760,6 +763,9 @@ mod tests {
                 status: Some(IssueStatus::InProgress),
                 body: None,
             }),
+            agent: None,
+            model: None,
+            stacked_on: None,
         }
     }
 
@@ -945,6 +951,9 @@ mod tests {
             labels: vec!["auto".to_string()],
             branch_name: None,
             parent: Some(parent_meta(IssueStatus::InProgress)),
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         // Child B: blocked by A
@@ -1060,6 +1069,9 @@ mod tests {
             labels: vec!["auto".to_string()],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         // B — blocked by A
@@ -1185,6 +1197,9 @@ mod tests {
             labels: vec![],
             branch_name: Some(branch.to_string()),
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         }
     }
 
@@ -1440,6 +1455,9 @@ mod tests {
             labels: vec![],
             branch_name: Some(parent_branch.to_string()),
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
         let provider = MockProvider::new(vec![parent]);
 
@@ -1477,6 +1495,9 @@ mod tests {
                 status: Some(IssueStatus::InProgress),
                 body: None,
             }),
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
         assert!(
             is_child_spawnable(&child, &repo_root),
@@ -38,8 +38,13 @@ impl DaemonLifecycleLog {
         Self { path }
     }
 
-    /// Record a daemon start event.
+    /// Record a daemon start event, rotating the log first if it has grown large.
     pub fn record_started(&self) -> Result<()> {
+        super::service::rotate_log(
+            &self.path,
+            super::service::LOG_MAX_BYTES,
+            super::service::LOG_KEEP,
+        )?;
         self.append(&DaemonEvent::Started {
             ts: chrono::Utc::now().timestamp(),
             pid: std::process::id(),
@@ -0,0 +1,353 @@
+//! Merge actor — lands the head of the merge queue in a background thread.
+//!
+//! Each request is rebased onto the base branch, verified in its worktree,
+//! then merged locally or through the forge. Verification can take minutes,
+//! so it runs here rather than on the tick thread; `jig daemon`'s blocking
+//! tick calls [`process`] directly.
+
+use std::fs::File;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::config::MergeMethod;
+use crate::forge::{self, Forge};
+use crate::git::Repo;
+use crate::github::{CheckRun, CheckStatus};
+
+use super::messages::{MergeComplete, MergeRequest};
+
+/// Spawn the merge actor thread. Returns immediately.
+///
+/// The actor blocks on `rx.recv()` waiting for work, lands each worker and
+/// sends `MergeComplete` back.
+pub fn spawn(
+    rx: flume::Receiver<MergeRequest>,
+    tx: flume::Sender<MergeComplete>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::Builder::new()
+        .name("jig-merge".into())
+        .spawn(move || {
+            while let Ok(req) = rx.recv() {
+                if tx.send(process(&req)).is_err() {
+                    break;
+                }
+            }
+        })
+        .expect("failed to spawn merge actor thread")
+}
+
+/// Land one worker on the calling thread.
+pub fn process(req: &MergeRequest) -> MergeComplete {
+    let error = land(req).err();
+    match &error {
+        None => tracing::info!(worker = %req.worker_key, "merge queue landed worker"),
+        Some(e) => tracing::warn!(worker = %req.worker_key, "merge queue: {}", e),
+    }
+    MergeComplete {
+        worker_key: req.worker_key.clone(),
+        error,
+    }
+}
+
+/// Rebase, verify and merge one worker.
+fn land(req: &MergeRequest) -> Result<(), String> {
+    let worktree = &req.worktree_path;
+    if !worktree.exists() {
+        return Err(format!("worktree not found: {}", worktree.display()));
+    }
+    if Repo::has_uncommitted_changes(worktree).map_err(|e| e.to_string())? {
+        return Err("worktree has uncommitted changes".to_string());
+    }
+
+    let (remote, base) = req
+        .base_branch
+        .split_once('/')
+        .unwrap_or(("origin", &req.base_branch));
+    let onto = match req.config.method {
+        MergeMethod::Local => base.to_string(),
+        MergeMethod::Forge => {
+            fetch(worktree, remote, base)?;
+            format!("{}/{}", remote, base)
+        }
+    };
+    Repo::rebase(worktree, &onto).map_err(|e| e.to_string())?;
+
+    if let Some(command) = &req.config.verify {
+        verify(worktree, command, req.config.verify_timeout_seconds)?;
+    }
+
+    match req.config.method {
+        MergeMethod::Local => merge_local(&req.repo_path, base, &req.branch),
+        MergeMethod::Forge => {
+            let pr_number = req.pr_number.ok_or("no PR to merge")?;
+            Repo::force_push_branch(worktree, &req.branch).map_err(|e| e.to_string())?;
+            let head = head_sha(worktree)?;
+            let forge = forge::for_repo(&req.repo_path).map_err(|e| e.to_string())?;
+            wait_for_checks(forge.as_ref(), &head, req.config.checks_timeout_seconds)?;
+            // Pinned to the verified head, so a push since is not merged.
+            forge
+                .merge_pr(pr_number, Some(&head))
+                .map_err(|e| format!("merging PR #{} failed: {}", pr_number, e))
+        }
+    }
+}
+
+/// Seconds between CI polls while waiting on a pushed head.
+const CHECKS_POLL: Duration = Duration::from_secs(30);
+
+/// How long a head with no checks at all waits for CI to register before it
+/// is taken as having none.
+const CHECKS_GRACE: Duration = Duration::from_secs(120);
+
+fn head_sha(worktree: &Path) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(worktree)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("git rev-parse failed: {}", e))?;
+    if !output.status.success() {
+        return Err("git rev-parse HEAD failed".to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Wait for CI on the pushed `sha` to pass.
+fn wait_for_checks(forge: &dyn Forge, sha: &str, timeout_secs: u64) -> Result<(), String> {
+    let started = Instant::now();
+    let deadline = started + Duration::from_secs(timeout_secs);
+    loop {
+        let runs = forge
+            .get_check_runs(sha)
+            .map_err(|e| format!("reading checks on {} failed: {}", sha, e))?;
+        if let Some(outcome) = checks_outcome(&runs, started.elapsed() >= CHECKS_GRACE) {
+            return outcome;
+        }
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "checks on {} did not finish within {}s",
+                sha, timeout_secs
+            ));
+        }
+        std::thread::sleep(CHECKS_POLL);
+    }
+}
+
+/// Whether CI has settled: `None` while checks are pending, or while none
+/// have shown up and `grace_over` is false.
+fn checks_outcome(runs: &[CheckRun], grace_over: bool) -> Option<Result<(), String>> {
+    if let Some(failed) = runs.iter().find(|r| r.is_failure()) {
+        return Some(Err(format!("check '{}' failed", failed.name)));
+    }
+    let pending = runs.iter().any(|r| r.status != CheckStatus::Completed);
+    (!pending && (!runs.is_empty() || grace_over)).then_some(Ok(()))
+}
+
+fn fetch(path: &Path, remote: &str, branch: &str) -> Result<(), String> {
+    let output = Command::new("git")
+        .args(["fetch", remote, branch])
+        .current_dir(path)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("git fetch failed: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git fetch {} {} failed: {}",
+            remote,
+            branch,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Run the verify command in the worktree, logging its output to
+/// `.jig/verify.log`.
+fn verify(worktree: &Path, command: &str, timeout_secs: u64) -> Result<(), String> {
+    let log_path = worktree.join(crate::config::JIG_DIR).join("verify.log");
+    let log = std::fs::create_dir_all(log_path.parent().unwrap_or(worktree))
+        .and_then(|()| File::create(&log_path))
+        .map_err(|e| format!("failed to create {}: {}", log_path.display(), e))?;
+    let stderr = log.try_clone().map_err(|e| e.to_string())?;
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .current_dir(worktree)
+        .stdin(Stdio::null())
+        .stdout(log)
+        .stderr(stderr)
+        .spawn()
+        .map_err(|e| format!("failed to run verify command: {}", e))?;
+
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return Ok(()),
+            Ok(Some(status)) => {
+                return Err(format!(
+                    "verify `{}` exited with {} (see {})",
+                    command,
+                    status,
+                    log_path.display()
+                ));
+            }
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "verify `{}` timed out after {}s",
+                    command, timeout_secs
+                ));
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(200)),
+            Err(e) => return Err(format!("verify command failed: {}", e)),
+        }
+    }
+}
+
+/// Merge `branch` into `base`, which must be checked out and clean in the
+/// repo root.
+fn merge_local(repo_path: &Path, base: &str, branch: &str) -> Result<(), String> {
+    let repo = Repo::open(repo_path).map_err(|e| e.to_string())?;
+    let current = repo.current_branch().map_err(|e| e.to_string())?;
+    if current != base {
+        return Err(format!(
+            "repo root is on '{}', not the base branch '{}'",
+            current, base
+        ));
+    }
+    if Repo::has_uncommitted_changes(repo_path).map_err(|e| e.to_string())? {
+        return Err("repo root has uncommitted changes".to_string());
+    }
+    repo.merge_branch(branch).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MergeQueueConfig;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    fn commit_file(dir: &Path, name: &str) {
+        std::fs::write(dir.join(name), name).unwrap();
+        git(dir, &["add", name]);
+        git(dir, &["commit", "-q", "-m", name]);
+    }
+
+    /// A repo on `main` with a `feature` worktree one commit ahead of an
+    /// older main.
+    fn setup() -> (TempDir, MergeRequest) {
+        let repo = TempDir::new().unwrap();
+        git(repo.path(), &["init", "-q", "-b", "main"]);
+        git(repo.path(), &["config", "user.email", "test@test.com"]);
+        git(repo.path(), &["config", "user.name", "Test"]);
+        git(repo.path(), &["config", "commit.gpgsign", "false"]);
+        commit_file(repo.path(), "init.txt");
+        std::fs::write(repo.path().join(".git/info/exclude"), ".jig/\n").unwrap();
+
+        let worktree = repo.path().join(".jig/feature");
+        git(
+            repo.path(),
+            &["worktree", "add", "-q", ".jig/feature", "-b", "feature"],
+        );
+        commit_file(&worktree, "feature.txt");
+        commit_file(repo.path(), "main.txt");
+
+        let req = MergeRequest {
+            worker_key: "repo/feature".to_string(),
+            repo_path: repo.path().to_path_buf(),
+            worktree_path: worktree,
+            branch: "feature".to_string(),
+            base_branch: "origin/main".to_string(),
+            pr_number: None,
+            config: MergeQueueConfig {
+                enabled: true,
+                verify: Some("test -f main.txt".to_string()),
+                ..Default::default()
+            },
+        };
+        (repo, req)
+    }
+
+    #[test]
+    fn lands_rebased_and_verified_branch_locally() {
+        let (repo, req) = setup();
+        land(&req).unwrap();
+        assert!(repo.path().join("feature.txt").exists());
+        assert!(req.worktree_path.join(".jig/verify.log").exists());
+    }
+
+    #[test]
+    fn failed_verify_leaves_base_untouched() {
+        let (repo, mut req) = setup();
+        req.config.verify = Some("echo boom; exit 3".to_string());
+        let err = land(&req).unwrap_err();
+        assert!(err.contains("exited with"), "{}", err);
+        assert!(!repo.path().join("feature.txt").exists());
+        let log = std::fs::read_to_string(req.worktree_path.join(".jig/verify.log")).unwrap();
+        assert_eq!(log.trim(), "boom");
+    }
+
+    #[test]
+    fn verify_times_out() {
+        let (_repo, mut req) = setup();
+        req.config.verify = Some("sleep 5".to_string());
+        req.config.verify_timeout_seconds = 0;
+        let err = land(&req).unwrap_err();
+        assert!(err.contains("timed out"), "{}", err);
+    }
+
+    #[test]
+    fn local_merge_needs_base_checked_out() {
+        let (repo, req) = setup();
+        git(repo.path(), &["checkout", "-q", "-b", "other"]);
+        let err = land(&req).unwrap_err();
+        assert!(err.contains("not the base branch 'main'"), "{}", err);
+    }
+
+    fn run(status: CheckStatus, conclusion: Option<&str>) -> CheckRun {
+        CheckRun {
+            name: "ci".to_string(),
+            status,
+            conclusion: conclusion.map(str::to_string),
+            details_url: None,
+        }
+    }
+
+    #[test]
+    fn waits_for_checks_on_the_new_head() {
+        let passed = run(CheckStatus::Completed, Some("success"));
+        let pending = run(CheckStatus::InProgress, None);
+        let failed = run(CheckStatus::Completed, Some("failure"));
+
+        assert_eq!(checks_outcome(&[], false), None);
+        assert_eq!(checks_outcome(&[], true), Some(Ok(())));
+        assert_eq!(checks_outcome(&[passed.clone(), pending], true), None);
+        assert_eq!(
+            checks_outcome(std::slice::from_ref(&passed), false),
+            Some(Ok(()))
+        );
+        assert!(matches!(
+            checks_outcome(&[passed, failed], false),
+            Some(Err(e)) if e.contains("'ci' failed")
+        ));
+    }
+
+    #[test]
+    fn missing_worktree_fails() {
+        let (_repo, mut req) = setup();
+        req.worktree_path = req.worktree_path.with_file_name("gone");
+        assert!(land(&req).unwrap_err().contains("worktree not found"));
+    }
+}
@@ -1,7 +1,9 @@
 //! Message types for daemon actor channels.
 
 use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::config::MergeQueueConfig;
 use crate::issues::{Issue, ProviderKind};
 use crate::spawn::SpawnKind;
 
@@ -22,6 +24,9 @@ pub struct SyncComplete {
 }
 
 /// Request sent to the GitHub actor to check PR status for a worker.
+///
+/// The runtime collects a tick's requests and sends them as one batch so the
+/// actor can issue a single GraphQL query per repo.
 pub struct GitHubRequest {
     /// Worker key ("repo/worker").
     pub worker_key: String,
@@ -58,6 +63,14 @@ pub struct GitHubResponse {
     pub review_feedback_count: Option<u32>,
 }
 
+/// A batch of responses from the GitHub actor.
+#[derive(Debug, Clone, Default)]
+pub struct GitHubBatch {
+    pub responses: Vec<GitHubResponse>,
+    /// Time spent on each repo's forge queries, for metrics.
+    pub latencies: Vec<Duration>,
+}
+
 /// Request sent to the issue actor to poll for auto-spawnable issues.
 pub struct IssueRequest {
     /// (repo_root, base_branch) for each registered repo.
@@ -243,3 +256,29 @@ pub struct ReviewComplete {
     /// for the verdict).
     pub error: Option<String>,
 }
+
+/// Request to land the head of the merge queue.
+pub struct MergeRequest {
+    /// Worker key ("repo/worker") for correlation.
+    pub worker_key: String,
+    /// Repo root path (where local merges happen).
+    pub repo_path: PathBuf,
+    /// Absolute path to the worktree directory.
+    pub worktree_path: PathBuf,
+    /// Worker branch to land.
+    pub branch: String,
+    /// Base branch to rebase onto and merge into (e.g. "origin/main").
+    pub base_branch: String,
+    /// PR number, for forge merges.
+    pub pr_number: Option<u64>,
+    /// The repo's `[merge_queue]` settings.
+    pub config: MergeQueueConfig,
+}
+
+/// Result of landing a worker.
+pub struct MergeComplete {
+    /// Worker key ("repo/worker") for correlation.
+    pub worker_key: String,
+    /// Error message if any step failed, None once merged.
+    pub error: Option<String>,
+}
@@ -0,0 +1,576 @@
+//! Prometheus metrics for the daemon.
+//!
+//! The loop records each tick's [`TickResult`] plus the events the tick
+//! doesn't report (nudges by type, escalations, spawn and triage outcomes,
+//! forge latency). [`MetricsExporter`] renders them in the Prometheus text
+//! format to a textfile, an HTTP `/metrics` endpoint, or both, as configured
+//! in `[daemon.metrics]`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::error::Result;
+use crate::global::MetricsConfig;
+
+use super::TickResult;
+
+/// Histogram buckets for tick duration, in seconds.
+const TICK_BUCKETS: &[f64] = &[0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+/// Histogram buckets for forge API calls, in seconds.
+const GITHUB_BUCKETS: &[f64] = &[0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// How long the endpoint waits for a scraper's request line.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+struct Histogram {
+    bounds: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        for (bound, count) in self.bounds.iter().zip(&mut self.counts) {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Counters {
+    ticks: u64,
+    tick_seconds: Histogram,
+    workers_checked: usize,
+    workers_by_status: BTreeMap<String, usize>,
+    actions: u64,
+    nudges: BTreeMap<String, u64>,
+    notifications: u64,
+    escalations: u64,
+    spawns: BTreeMap<&'static str, u64>,
+    spawning: usize,
+    triages_started: u64,
+    triages: BTreeMap<&'static str, u64>,
+    triaging: usize,
+    pruned: u64,
+    errors: u64,
+    auto_spawn_paused: bool,
+    queue_depths: BTreeMap<String, usize>,
+    github_seconds: Histogram,
+}
+
+/// Daemon counters and gauges, shared between the loop and the actors'
+/// result handling. Counters are cumulative since the daemon started.
+#[derive(Debug)]
+pub struct Metrics {
+    inner: Mutex<Counters>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            inner: Mutex::new(Counters {
+                ticks: 0,
+                tick_seconds: Histogram::new(TICK_BUCKETS),
+                workers_checked: 0,
+                workers_by_status: BTreeMap::new(),
+                actions: 0,
+                nudges: BTreeMap::new(),
+                notifications: 0,
+                escalations: 0,
+                spawns: BTreeMap::new(),
+                spawning: 0,
+                triages_started: 0,
+                triages: BTreeMap::new(),
+                triaging: 0,
+                pruned: 0,
+                errors: 0,
+                auto_spawn_paused: false,
+                queue_depths: BTreeMap::new(),
+                github_seconds: Histogram::new(GITHUB_BUCKETS),
+            }),
+        }
+    }
+}
+
+fn result_label(ok: bool) -> &'static str {
+    if ok {
+        "ok"
+    } else {
+        "error"
+    }
+}
+
+impl Metrics {
+    fn with<F: FnOnce(&mut Counters)>(&self, f: F) {
+        f(&mut self.inner.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+
+    /// A nudge sent (or handed to the nudge actor), by `NudgeType::count_key`.
+    pub fn record_nudge(&self, nudge_type: &str) {
+        self.with(|c| *c.nudges.entry(nudge_type.to_string()).or_default() += 1);
+    }
+
+    /// A `needs_intervention` notification.
+    pub fn record_escalation(&self) {
+        self.with(|c| c.escalations += 1);
+    }
+
+    /// An auto-spawn that finished.
+    pub fn record_spawn(&self, ok: bool) {
+        self.with(|c| *c.spawns.entry(result_label(ok)).or_default() += 1);
+    }
+
+    /// Triage workers started.
+    pub fn record_triages_started(&self, count: usize) {
+        self.with(|c| c.triages_started += count as u64);
+    }
+
+    /// A triage that finished.
+    pub fn record_triage(&self, ok: bool) {
+        self.with(|c| *c.triages.entry(result_label(ok)).or_default() += 1);
+    }
+
+    /// Time spent on one forge (GitHub/GitLab) query.
+    pub fn record_github(&self, elapsed: Duration) {
+        self.with(|c| c.github_seconds.observe(elapsed.as_secs_f64()));
+    }
+
+    /// Record a finished tick and how long it took.
+    pub fn record_tick(&self, tick: &TickResult, elapsed: Duration) {
+        self.with(|c| {
+            c.ticks += 1;
+            c.tick_seconds.observe(elapsed.as_secs_f64());
+            c.workers_checked = tick.workers_checked;
+            c.workers_by_status.clone_from(&tick.workers_by_status);
+            c.actions += tick.actions_dispatched as u64;
+            c.notifications += tick.notifications_sent as u64;
+            c.pruned += tick.pruned.len() as u64;
+            c.errors += tick.errors.len() as u64;
+            c.spawning = tick.spawning.len();
+            c.triaging = tick.triage_display.len();
+            c.auto_spawn_paused = tick.auto_spawn_paused;
+            c.queue_depths = tick.queue_depths.iter().cloned().collect();
+        });
+    }
+
+    /// Render in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let c = self.inner.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let mut out = String::new();
+
+        metric(&mut out, "jig_ticks_total", "counter", "Daemon ticks run.");
+        sample(&mut out, "jig_ticks_total", &[], c.ticks);
+        histogram(
+            &mut out,
+            "jig_tick_duration_seconds",
+            "Time taken by each daemon tick.",
+            &c.tick_seconds,
+        );
+
+        metric(
+            &mut out,
+            "jig_workers_checked",
+            "gauge",
+            "Workers processed in the last tick.",
+        );
+        sample(&mut out, "jig_workers_checked", &[], c.workers_checked);
+        metric(
+            &mut out,
+            "jig_workers",
+            "gauge",
+            "Workers by status as of the last tick.",
+        );
+        for (status, count) in &c.workers_by_status {
+            sample(&mut out, "jig_workers", &[("status", status)], count);
+        }
+
+        metric(
+            &mut out,
+            "jig_actions_total",
+            "counter",
+            "Actions dispatched for workers.",
+        );
+        sample(&mut out, "jig_actions_total", &[], c.actions);
+        metric(
+            &mut out,
+            "jig_nudges_total",
+            "counter",
+            "Nudges sent by type.",
+        );
+        for (nudge_type, count) in &c.nudges {
+            sample(&mut out, "jig_nudges_total", &[("type", nudge_type)], count);
+        }
+        metric(
+            &mut out,
+            "jig_notifications_total",
+            "counter",
+            "Notifications sent for workers.",
+        );
+        sample(&mut out, "jig_notifications_total", &[], c.notifications);
+        metric(
+            &mut out,
+            "jig_escalations_total",
+            "counter",
+            "Needs-intervention notifications.",
+        );
+        sample(&mut out, "jig_escalations_total", &[], c.escalations);
+
+        metric(
+            &mut out,
+            "jig_spawns_total",
+            "counter",
+            "Auto-spawns finished, by result.",
+        );
+        for (result, count) in &c.spawns {
+            sample(&mut out, "jig_spawns_total", &[("result", result)], count);
+        }
+        metric(
+            &mut out,
+            "jig_spawning",
+            "gauge",
+            "Workers being spawned in the background.",
+        );
+        sample(&mut out, "jig_spawning", &[], c.spawning);
+        metric(
+            &mut out,
+            "jig_triages_started_total",
+            "counter",
+            "Triage workers started.",
+        );
+        sample(
+            &mut out,
+            "jig_triages_started_total",
+            &[],
+            c.triages_started,
+        );
+        metric(
+            &mut out,
+            "jig_triages_total",
+            "counter",
+            "Triages finished, by result.",
+        );
+        for (result, count) in &c.triages {
+            sample(&mut out, "jig_triages_total", &[("result", result)], count);
+        }
+        metric(&mut out, "jig_triaging", "gauge", "Triages in flight.");
+        sample(&mut out, "jig_triaging", &[], c.triaging);
+        metric(
+            &mut out,
+            "jig_auto_spawn_paused",
+            "gauge",
+            "1 while auto-spawn is paused.",
+        );
+        sample(
+            &mut out,
+            "jig_auto_spawn_paused",
+            &[],
+            u8::from(c.auto_spawn_paused),
+        );
+
+        metric(&mut out, "jig_pruned_total", "counter", "Worktrees pruned.");
+        sample(&mut out, "jig_pruned_total", &[], c.pruned);
+        metric(
+            &mut out,
+            "jig_errors_total",
+            "counter",
+            "Errors reported by ticks.",
+        );
+        sample(&mut out, "jig_errors_total", &[], c.errors);
+
+        if !c.queue_depths.is_empty() {
+            metric(
+                &mut out,
+                "jig_actor_queue_depth",
+                "gauge",
+                "Requests waiting in each actor's queue.",
+            );
+            for (actor, depth) in &c.queue_depths {
+                sample(
+                    &mut out,
+                    "jig_actor_queue_depth",
+                    &[("actor", actor)],
+                    depth,
+                );
+            }
+        }
+        histogram(
+            &mut out,
+            "jig_github_request_duration_seconds",
+            "Time taken by forge API queries.",
+            &c.github_seconds,
+        );
+        out
+    }
+}
+
+fn metric(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{} {}", name, value);
+        return;
+    }
+    let labels: Vec<String> = labels
+        .iter()
+        .map(|(k, v)| format!("{}=\"{}\"", k, escape_label(v)))
+        .collect();
+    let _ = writeln!(out, "{}{{{}}} {}", name, labels.join(","), value);
+}
+
+fn histogram(out: &mut String, name: &str, help: &str, h: &Histogram) {
+    metric(out, name, "histogram", help);
+    let bucket = format!("{}_bucket", name);
+    for (bound, count) in h.bounds.iter().zip(&h.counts) {
+        sample(out, &bucket, &[("le", &bound.to_string())], count);
+    }
+    sample(out, &bucket, &[("le", "+Inf")], h.count);
+    sample(out, &format!("{}_sum", name), &[], h.sum);
+    sample(out, &format!("{}_count", name), &[], h.count);
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Publishes rendered metrics to the outputs set in `[daemon.metrics]`.
+pub struct MetricsExporter {
+    textfile: Option<PathBuf>,
+    /// Latest rendering, served by the HTTP thread.
+    body: Option<Arc<Mutex<String>>>,
+}
+
+impl MetricsExporter {
+    /// Set up the configured outputs, binding the HTTP endpoint if one is set.
+    /// Returns `None` when metrics are off.
+    pub fn from_config(config: &MetricsConfig) -> Result<Option<Self>> {
+        if config.textfile.is_none() && config.listen.is_none() {
+            return Ok(None);
+        }
+        let body = match &config.listen {
+            Some(addr) => Some(serve(TcpListener::bind(addr)?)),
+            None => None,
+        };
+        Ok(Some(Self {
+            textfile: config
+                .textfile
+                .as_deref()
+                .map(|p| PathBuf::from(crate::notify::expand_tilde(p))),
+            body,
+        }))
+    }
+
+    /// Render `metrics` and write it to every output. Failures are logged;
+    /// metrics never stop the daemon.
+    pub fn publish(&self, metrics: &Metrics) {
+        let text = metrics.render();
+        if let Some(path) = &self.textfile {
+            if let Err(e) = write_atomic(path, &text) {
+                tracing::warn!(path = %path.display(), "failed to write metrics textfile: {}", e);
+            }
+        }
+        if let Some(body) = &self.body {
+            *body.lock().unwrap_or_else(|e| e.into_inner()) = text;
+        }
+    }
+}
+
+/// Write via a temp file and rename so collectors never read a partial file.
+fn write_atomic(path: &std::path::Path, text: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("prom.tmp");
+    std::fs::write(&tmp, text)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Serve `GET /metrics` from a background thread, returning the shared body.
+fn serve(listener: TcpListener) -> Arc<Mutex<String>> {
+    let body = Arc::new(Mutex::new(String::new()));
+    let shared = Arc::clone(&body);
+    std::thread::Builder::new()
+        .name("jig-metrics".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        if let Err(e) = handle_scrape(stream, &shared) {
+                            tracing::debug!("metrics request failed: {}", e);
+                        }
+                    }
+                    Err(e) => tracing::warn!("metrics endpoint accept failed: {}", e),
+                }
+            }
+        })
+        .expect("failed to spawn metrics thread");
+    body
+}
+
+fn handle_scrape(stream: TcpStream, body: &Mutex<String>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers; the request has no body we care about.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && header != "\r\n" && header != "\n" {
+        header.clear();
+    }
+
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let (status, content_type, text) = if path == "/metrics" || path.starts_with("/metrics?") {
+        let text = body.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        ("200 OK", "text/plain; version=0.0.4; charset=utf-8", text)
+    } else {
+        ("404 Not Found", "text/plain", "not found\n".to_string())
+    };
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        text.len(),
+        text
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn tick() -> TickResult {
+        TickResult {
+            workers_checked: 3,
+            actions_dispatched: 2,
+            notifications_sent: 1,
+            errors: vec!["api/x: boom".to_string()],
+            workers_by_status: BTreeMap::from([
+                ("running".to_string(), 2),
+                ("idle".to_string(), 1),
+            ]),
+            queue_depths: vec![("nudge".to_string(), 4)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn renders_tick_and_event_counters() {
+        let metrics = Metrics::default();
+        metrics.record_tick(&tick(), Duration::from_millis(300));
+        metrics.record_tick(&tick(), Duration::from_secs(3));
+        metrics.record_nudge("idle");
+        metrics.record_nudge("idle");
+        metrics.record_nudge("ci");
+        metrics.record_escalation();
+        metrics.record_spawn(true);
+        metrics.record_spawn(false);
+        metrics.record_triage(true);
+        metrics.record_github(Duration::from_millis(200));
+
+        let text = metrics.render();
+        for line in [
+            "# TYPE jig_ticks_total counter",
+            "jig_ticks_total 2",
+            "jig_tick_duration_seconds_bucket{le=\"0.5\"} 1",
+            "jig_tick_duration_seconds_bucket{le=\"5\"} 2",
+            "jig_tick_duration_seconds_bucket{le=\"+Inf\"} 2",
+            "jig_tick_duration_seconds_count 2",
+            "jig_workers{status=\"running\"} 2",
+            "jig_workers{status=\"idle\"} 1",
+            "jig_actions_total 4",
+            "jig_nudges_total{type=\"idle\"} 2",
+            "jig_nudges_total{type=\"ci\"} 1",
+            "jig_escalations_total 1",
+            "jig_spawns_total{result=\"ok\"} 1",
+            "jig_spawns_total{result=\"error\"} 1",
+            "jig_triages_total{result=\"ok\"} 1",
+            "jig_errors_total 2",
+            "jig_actor_queue_depth{actor=\"nudge\"} 4",
+            "jig_github_request_duration_seconds_bucket{le=\"0.25\"} 1",
+        ] {
+            assert!(
+                text.lines().any(|l| l == line),
+                "missing {line:?} in:\n{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn escapes_label_values() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn publishes_textfile_and_endpoint() {
+        let tmp = tempfile::tempdir().unwrap();
+        let textfile = tmp.path().join("jig.prom");
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let exporter = MetricsExporter::from_config(&MetricsConfig {
+            textfile: Some(textfile.to_string_lossy().to_string()),
+            listen: Some(addr.to_string()),
+        })
+        .unwrap()
+        .unwrap();
+        let metrics = Metrics::default();
+        metrics.record_tick(&tick(), Duration::from_millis(10));
+        exporter.publish(&metrics);
+
+        let written = std::fs::read_to_string(&textfile).unwrap();
+        assert!(written.contains("jig_ticks_total 1"));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with(&written));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 404"));
+    }
+
+    #[test]
+    fn off_without_outputs() {
+        assert!(MetricsExporter::from_config(&MetricsConfig::default())
+            .unwrap()
+            .is_none());
+    }
+}
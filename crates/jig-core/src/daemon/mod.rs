@@ -9,47 +9,66 @@
 //! 6. Trigger issue poll for auto-spawn
 //! 7. Auto-spawn eligible workers
 
+pub mod control;
 mod discovery;
 pub mod github_actor;
 pub mod issue_actor;
 pub mod lifecycle;
+pub mod merge_actor;
 pub mod messages;
+pub mod metrics;
 pub mod nudge_actor;
 mod pr;
 pub mod prune_actor;
 pub mod recovery;
 pub mod review_actor;
 pub mod runtime;
+pub mod service;
 pub mod spawn_actor;
 pub mod sync_actor;
 pub mod triage_actor;
 pub mod triage_tracker;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use crate::config::{JigToml, RepoHealthConfig, ResolvedNudgeConfig};
+use serde::{Deserialize, Serialize};
+
+use crate::auto_rebase;
+use crate::budget::{self, BudgetStatus, Spend, SpendLedger};
+use crate::config::{
+    BudgetAction, BudgetConfig, JigToml, MergeMethod, MergeQueueConfig, OverlapAction, PanePattern,
+    PatternAction, RepoHealthConfig, ResolvedNudgeConfig,
+};
 use crate::context::RepoContext;
 use crate::dispatch::{dispatch_actions, Action, NotifyKind};
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::events::{Event, EventLog, EventType, WorkerState};
 use crate::global::{GlobalConfig, HealthConfig, WorkerEntry, WorkersState};
+use crate::issues::IssueStatus;
+use crate::land::{LandError, Landing, Note, Strategy};
+use crate::merge_queue::{MergeQueue, QueueEntry};
 use crate::notify::{NotificationEvent, Notifier};
-use crate::nudge::{build_nudge_context, NudgeType};
+use crate::nudge::{add_conflict_context, build_nudge_context, classify_nudge, NudgeType};
+use crate::overlap;
+use crate::patterns;
 use crate::registry::{RepoEntry, RepoRegistry};
 use crate::review::{latest_verdict, review_count, ReviewVerdict};
 use crate::spawn::TaskStatus;
+use crate::stack;
 use crate::templates::TemplateEngine;
 use crate::tmux::{TmuxClient, TmuxTarget};
 use crate::worker::WorkerStatus;
 
 use discovery::discover_workers;
-use pr::{make_github_client, PrMonitor};
+use messages::{MergeComplete, MergeRequest};
+use metrics::{Metrics, MetricsExporter};
+use pr::{make_forge, PrMonitor};
 
 pub use messages::SpawnableIssue;
-pub use runtime::{DaemonRuntime, RuntimeConfig, TimerInfo};
+pub use runtime::{DaemonRuntime, RuntimeConfig, TickMemory, TimerInfo};
 
 /// Get the HEAD SHA for a worktree path via the project's git module.
 fn head_sha_for(worktree_path: &std::path::Path) -> Option<String> {
@@ -58,20 +77,18 @@ fn head_sha_for(worktree_path: &std::path::Path) -> Option<String> {
         .ok()
 }
 
-/// Extract the branch name from a worker's event log (looks for Spawn event),
-/// falling back to worker_name if no Spawn event exists.
-fn extract_branch_name(events: &[Event], worker_name: &str) -> String {
-    events
-        .iter()
-        .find(|e| e.event_type == EventType::Spawn)
-        .and_then(|e| e.data.get("branch").and_then(|v| v.as_str()))
-        .map(|s| s.to_string())
+/// The worker's branch as recorded by its Spawn event, falling back to
+/// worker_name if no Spawn event exists.
+fn worker_branch(state: &WorkerState, worker_name: &str) -> String {
+    state
+        .branch
+        .clone()
         .unwrap_or_else(|| worker_name.to_string())
 }
 
 /// Pre-computed display data for a worker, populated during tick so the render
 /// callback can format output without any subprocess calls or file I/O.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerDisplayInfo {
     pub repo: String,
     pub name: String,
@@ -89,10 +106,16 @@ pub struct WorkerDisplayInfo {
     pub is_draft: bool,
     /// Seconds until the next nudge cooldown expires (min across all active types).
     pub nudge_cooldown_remaining: Option<u64>,
+    /// Agent adapter the worker runs (e.g. "codex").
+    pub agent: Option<String>,
+    /// Model override the worker was launched with.
+    pub model: Option<String>,
+    /// Last non-blank line of the worker's tmux pane.
+    pub pane_preview: Option<String>,
 }
 
 /// Pre-computed display data for an in-flight triage subprocess.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TriageDisplayInfo {
     /// Linear issue identifier (e.g. "JIG-77").
     pub issue_id: String,
@@ -105,7 +128,7 @@ pub struct TriageDisplayInfo {
 }
 
 /// Per-worker PR health info collected during a tick.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorkerTickInfo {
     /// Per-check outcomes: (check_name, has_problem).
     pub pr_checks: Vec<(String, bool)>,
@@ -143,7 +166,7 @@ impl Default for DaemonConfig {
 }
 
 /// Result of a single daemon tick.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct TickResult {
     pub workers_checked: usize,
     pub actions_dispatched: usize,
@@ -166,6 +189,12 @@ pub struct TickResult {
     pub nudge_messages: Vec<(String, String, String)>,
     /// Timer info for the daemon's sync and poll intervals.
     pub timer_info: Option<TimerInfo>,
+    /// Whether auto-spawn was paused for this tick.
+    pub auto_spawn_paused: bool,
+    /// Worker counts by status after this tick.
+    pub workers_by_status: BTreeMap<String, usize>,
+    /// Requests waiting in each actor's queue: (actor, depth).
+    pub queue_depths: Vec<(String, usize)>,
 }
 
 /// The daemon orchestrator — holds references to shared infrastructure.
@@ -175,6 +204,9 @@ pub struct Daemon<'a> {
     engine: &'a TemplateEngine<'a>,
     notifier: &'a Notifier,
     daemon_config: &'a DaemonConfig,
+    auto_spawn_paused: bool,
+    metrics: Option<&'a Metrics>,
+    memory: Option<&'a Mutex<TickMemory>>,
 }
 
 impl<'a> Daemon<'a> {
@@ -191,7 +223,51 @@ impl<'a> Daemon<'a> {
             engine,
             notifier,
             daemon_config,
+            auto_spawn_paused: false,
+            metrics: None,
+            memory: None,
+        }
+    }
+
+    /// Skip issue polling and auto-spawn (workers are still monitored).
+    pub fn with_auto_spawn_paused(mut self, paused: bool) -> Self {
+        self.auto_spawn_paused = paused;
+        self
+    }
+
+    /// Record nudges, escalations, spawns and triages into `metrics`.
+    pub fn with_metrics(mut self, metrics: &'a Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Keep cross-tick check state in `memory`, for [`Self::tick_once`]
+    /// callers that build a new `Daemon` each tick. Without it those checks
+    /// are skipped.
+    pub fn with_memory(mut self, memory: &'a Mutex<TickMemory>) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    fn record(&self, f: impl FnOnce(&Metrics)) {
+        if let Some(metrics) = self.metrics {
+            f(metrics);
+        }
+    }
+
+    /// Count the discovered workers by their recorded status.
+    fn count_by_status(
+        workers_state: &WorkersState,
+        worker_list: &[(String, String)],
+    ) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for (repo_name, worker_name) in worker_list {
+            let key = format!("{}/{}", repo_name, worker_name);
+            if let Some(entry) = workers_state.get_worker(&key) {
+                *counts.entry(entry.status.clone()).or_default() += 1;
+            }
         }
+        counts
     }
 
     /// Load per-repo health config from jig.toml, falling back to defaults.
@@ -223,6 +299,410 @@ impl<'a> Daemon<'a> {
         }
     }
 
+    /// Load per-repo budget config from jig.toml (no limits when absent).
+    fn load_repo_budget_config(registry: &RepoRegistry, repo_name: &str) -> BudgetConfig {
+        Self::find_repo_path(registry, repo_name)
+            .and_then(|entry| JigToml::load(&entry.path).ok().flatten())
+            .map(|toml| toml.budget)
+            .unwrap_or_default()
+    }
+
+    /// Cumulative spend of each repo's workers, cleaned-up ones included, for
+    /// repos with a `[budget.repo]` limit. Live workers whose event log
+    /// changed since the last tick have their spend recorded in the
+    /// [`SpendLedger`] first; it is only written when an entry changed.
+    fn repo_spend(
+        &self,
+        registry: &RepoRegistry,
+        worker_list: &[(String, String)],
+        memory: &mut TickMemory,
+    ) -> HashMap<String, Spend> {
+        let budgeted: HashSet<String> = registry
+            .filtered_repos(self.daemon_config.repo_filter.as_deref())
+            .into_iter()
+            .filter_map(|entry| entry.path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .filter(|name| Self::load_repo_budget_config(registry, name).repo.is_set())
+            .collect();
+        if budgeted.is_empty() {
+            return HashMap::new();
+        }
+        let mut ledger = match SpendLedger::load() {
+            Ok(ledger) => ledger,
+            Err(e) => {
+                tracing::warn!("budget: failed to load spend ledger: {}", e);
+                return HashMap::new();
+            }
+        };
+
+        let mut changed = false;
+        for (repo_name, worker_name) in worker_list {
+            if !budgeted.contains(repo_name) {
+                continue;
+            }
+            let log = match EventLog::for_worker(repo_name, worker_name) {
+                Ok(log) => log,
+                Err(e) => {
+                    tracing::warn!(repo = %repo_name, worker = %worker_name, "budget: {}", e);
+                    continue;
+                }
+            };
+            let key = format!("{}/{}", repo_name, worker_name);
+            if !memory.spend_log_changed(&key, log.path()) {
+                continue;
+            }
+            match log.reduce(&self.config.health) {
+                Ok(state) => changed |= ledger.record(repo_name, worker_name, &state),
+                Err(e) => {
+                    tracing::warn!(repo = %repo_name, worker = %worker_name, "budget: {}", e)
+                }
+            }
+        }
+        for repo in &budgeted {
+            let running: HashSet<&str> = worker_list
+                .iter()
+                .filter(|(r, _)| r == repo)
+                .map(|(_, w)| w.as_str())
+                .collect();
+            changed |= ledger.retire_missing(repo, &running);
+        }
+        if changed {
+            if let Err(e) = ledger.save() {
+                tracing::warn!("budget: failed to save spend ledger: {}", e);
+            }
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        budgeted
+            .into_iter()
+            .map(|repo| {
+                let total = ledger.total(&repo, now);
+                (repo, total)
+            })
+            .collect()
+    }
+
+    /// Whether a repo's workers together are over its `[budget.repo]` limit.
+    fn repo_over_budget(
+        registry: &RepoRegistry,
+        repo_name: &str,
+        repo_spend: &HashMap<String, Spend>,
+    ) -> bool {
+        let Some(spend) = repo_spend.get(repo_name) else {
+            return false;
+        };
+        let config = Self::load_repo_budget_config(registry, repo_name);
+        matches!(
+            budget::check(&config.repo, spend, config.warn_at),
+            BudgetStatus::Over(_)
+        )
+    }
+
+    /// Warn a worker nearing its budget, or stop one that is over it.
+    ///
+    /// The worker's own limits are checked before the repo's. Warnings are
+    /// sent once; a stopped worker is left alone until it is resumed.
+    fn budget_actions(
+        worker_name: &str,
+        state: &WorkerState,
+        config: &BudgetConfig,
+        repo_spend: Option<&Spend>,
+    ) -> Vec<Action> {
+        if !config.is_enabled()
+            || state.status.is_terminal()
+            || state.budget_exceeded.is_some()
+            || matches!(
+                state.status,
+                WorkerStatus::Created | WorkerStatus::Initializing
+            )
+        {
+            return vec![];
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let worker = budget::check(&config.worker, &Spend::of(state, now), config.warn_at);
+        let repo = repo_spend
+            .map(|spend| budget::check(&config.repo, spend, config.warn_at))
+            .unwrap_or(BudgetStatus::Within);
+        let checks = [("worker", worker), ("repo", repo)];
+
+        if let Some((scope, BudgetStatus::Over(detail))) = checks
+            .iter()
+            .find(|(_, status)| matches!(status, BudgetStatus::Over(_)))
+        {
+            let reason = format!("{} budget exceeded: {}", scope, detail);
+            return vec![
+                Action::EnforceBudget {
+                    worker_id: worker_name.to_string(),
+                    reason: reason.clone(),
+                    on_exceed: config.on_exceed,
+                },
+                Action::Notify {
+                    worker_id: worker_name.to_string(),
+                    message: reason,
+                    kind: NotifyKind::NeedsIntervention,
+                },
+            ];
+        }
+
+        let warned = state
+            .nudge_counts
+            .contains_key(NudgeType::Budget.count_key());
+        let near = checks
+            .iter()
+            .any(|(_, status)| matches!(status, BudgetStatus::Near(_)));
+        if near && !warned {
+            return vec![Action::Nudge {
+                worker_id: worker_name.to_string(),
+                nudge_type: NudgeType::Budget,
+            }];
+        }
+        vec![]
+    }
+
+    /// Read a waiting or stalled worker's pane and match it against the
+    /// repo's pattern rules.
+    fn apply_pane_patterns(
+        &self,
+        actions: &mut Vec<Action>,
+        repo_health: &RepoHealthConfig,
+        repo_name: &str,
+        worker_name: &str,
+        branch_name: &str,
+        state: &WorkerState,
+    ) {
+        if !matches!(
+            state.status,
+            WorkerStatus::WaitingInput | WorkerStatus::Stalled
+        ) || state.budget_exceeded.is_some()
+        {
+            return;
+        }
+        let rules = repo_health.resolve_patterns();
+        if rules.is_empty() {
+            return;
+        }
+        let target = TmuxTarget::new(
+            format!("{}{}", self.daemon_config.session_prefix, repo_name),
+            branch_name.to_string(),
+        );
+        let pane = match self.tmux.capture_pane(&target, patterns::PANE_LINES) {
+            Ok(lines) => lines.join("\n"),
+            Err(e) => {
+                tracing::debug!(worker = worker_name, error = %e, "pane capture failed");
+                return;
+            }
+        };
+        let now = chrono::Utc::now().timestamp();
+        Self::pane_rule_actions(actions, worker_name, state, &rules, &pane, now);
+    }
+
+    /// Swap the idle/stuck nudge for the first rule matching the pane. A rule
+    /// still in its cooldown holds the nudge back without firing again.
+    fn pane_rule_actions(
+        actions: &mut Vec<Action>,
+        worker_name: &str,
+        state: &WorkerState,
+        rules: &[PanePattern],
+        pane: &str,
+        now: i64,
+    ) {
+        let Some(rule) = patterns::find_match(rules, pane) else {
+            return;
+        };
+        actions.retain(|a| {
+            !matches!(
+                a,
+                Action::Nudge {
+                    nudge_type: NudgeType::Idle | NudgeType::Stuck,
+                    ..
+                }
+            )
+        });
+        if let Some(remaining) = patterns::cooldown_remaining(rule, state, now) {
+            tracing::debug!(
+                worker = worker_name,
+                rule = %rule.name,
+                remaining,
+                "pane pattern in cooldown"
+            );
+            return;
+        }
+        actions.push(Action::PaneRule {
+            worker_id: worker_name.to_string(),
+            rule: rule.clone(),
+        });
+    }
+
+    /// Whether auto-spawning an issue is held because its repo is over budget.
+    fn spawn_held_for_budget(
+        &self,
+        issue: &SpawnableIssue,
+        registry: &RepoRegistry,
+        repo_spend: &HashMap<String, Spend>,
+    ) -> bool {
+        let repo_name = issue
+            .repo_root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let held = Self::repo_over_budget(registry, &repo_name, repo_spend);
+        if held {
+            tracing::info!(
+                repo = %repo_name,
+                issue = %issue.issue.id,
+                "repo over budget, not auto-spawning"
+            );
+        }
+        held
+    }
+
+    /// Whether auto-spawning an issue is held because in-flight workers are
+    /// already editing files it names. With `overlap = "warn"` the overlap is
+    /// only logged. The worker an issue stacks on doesn't count.
+    fn spawn_held_for_overlap(&self, issue: &SpawnableIssue) -> bool {
+        let spawn = JigToml::load(&issue.repo_root)
+            .ok()
+            .flatten()
+            .map(|toml| toml.spawn)
+            .unwrap_or_default();
+        if spawn.overlap == OverlapAction::Off {
+            return false;
+        }
+        let ignore = spawn.resolve_overlap_ignore();
+        let candidate = overlap::planned(
+            &issue.repo_root,
+            &issue.worker_name,
+            &issue.issue.body,
+            &ignore,
+        );
+        if candidate.files.is_empty() {
+            return false;
+        }
+        let Ok(repo) = RepoContext::from_path(&issue.repo_root) else {
+            return false;
+        };
+        let lower = issue
+            .issue
+            .stacked_on
+            .as_deref()
+            .and_then(|reference| stack::lower_worker(&issue.repo_root, reference));
+        let found: Vec<_> =
+            overlap::overlapping_with(&candidate, &overlap::active_workers(&repo, &ignore))
+                .into_iter()
+                .filter(|o| lower.as_deref() != Some(o.a.as_str()))
+                .collect();
+        if found.is_empty() {
+            return false;
+        }
+
+        let held = spawn.overlap == OverlapAction::Hold;
+        for o in &found {
+            tracing::warn!(
+                issue = %issue.issue.id,
+                worker = %o.a,
+                files = %o.files.join(", "),
+                held,
+                "issue overlaps an in-flight worker"
+            );
+        }
+        held
+    }
+
+    /// Whether auto-spawning a stacked issue is held until the issue it stacks
+    /// on has a worker. Once that issue is complete (or can't be found) the
+    /// issue spawns from the repo base instead.
+    fn spawn_held_for_stack(&self, issue: &SpawnableIssue) -> bool {
+        let Some(reference) = issue.issue.stacked_on.as_deref() else {
+            return false;
+        };
+        if stack::lower_worker(&issue.repo_root, reference).is_some() {
+            return false;
+        }
+        let lower_open = RepoContext::from_path(&issue.repo_root)
+            .and_then(|repo| repo.issue_provider())
+            .and_then(|provider| provider.get(reference))
+            .ok()
+            .flatten()
+            .is_some_and(|lower| lower.status != IssueStatus::Complete);
+        if lower_open {
+            tracing::info!(
+                issue = %issue.issue.id,
+                stacked_on = %reference,
+                "waiting for the issue it stacks on to be spawned"
+            );
+        }
+        lower_open
+    }
+
+    /// Warn once about each pair of in-flight workers editing the same files.
+    /// A stacked worker shares its lower worker's files by design, so that
+    /// pair is skipped.
+    fn check_overlaps(
+        &self,
+        workers_state: &WorkersState,
+        registry: &RepoRegistry,
+        memory: &mut TickMemory,
+    ) {
+        let mut found = Vec::new();
+        for entry in registry.filtered_repos(self.daemon_config.repo_filter.as_deref()) {
+            let spawn = JigToml::load(&entry.path)
+                .ok()
+                .flatten()
+                .map(|toml| toml.spawn)
+                .unwrap_or_default();
+            if spawn.overlap == OverlapAction::Off {
+                continue;
+            }
+            let Ok(repo) = RepoContext::from_path(&entry.path) else {
+                continue;
+            };
+            let repo_name = entry
+                .path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let workers = overlap::active_workers(&repo, &spawn.resolve_overlap_ignore());
+            let stacked_on = |worker: &str| {
+                workers_state
+                    .workers
+                    .get(&format!("{}/{}", repo_name, worker))
+                    .and_then(|e| e.stacked_on.clone())
+            };
+            for o in overlap::overlaps(&workers) {
+                if stacked_on(&o.a).as_deref() == Some(o.b.as_str())
+                    || stacked_on(&o.b).as_deref() == Some(o.a.as_str())
+                {
+                    continue;
+                }
+                found.push((repo_name.clone(), o));
+            }
+        }
+
+        let new = memory.new_overlaps(found.iter().map(|(repo, o)| o.key(repo)).collect());
+        for (repo_name, o) in found {
+            if !new.contains(&o.key(&repo_name)) {
+                continue;
+            }
+            tracing::warn!(
+                repo = %repo_name,
+                files = %o.files.join(", "),
+                "{} and {} are editing the same files", o.a, o.b
+            );
+            // Overlap only predicts a conflict, so it warns rather than
+            // escalating.
+            let event = NotificationEvent::OverlapWarning {
+                repo: repo_name,
+                worker: o.b.clone(),
+                other: o.a.clone(),
+                files: o.summary(),
+            };
+            if let Err(e) = self.notifier.emit(event) {
+                tracing::warn!("OverlapWarning notification failed: {}", e);
+            }
+        }
+    }
+
     /// Look up the repo path from the registry by repo name.
     fn find_repo_path<'r>(registry: &'r RepoRegistry, repo_name: &str) -> Option<&'r RepoEntry> {
         registry.repos().iter().find(|e| {
@@ -392,6 +872,7 @@ impl<'a> Daemon<'a> {
                                 worker_name: worker_name.to_string(),
                                 worker_key: key,
                             });
+                            self.record(|m| m.record_nudge("parent_update"));
                         }
                     }
                     Ok(false) => {
@@ -493,6 +974,9 @@ impl<'a> Daemon<'a> {
         // Parent-update phase: after sync, check if parent worktrees have new
         // remote commits (from child PR merges) and pull them in.
         self.update_parent_worktrees(&workers_state, &registry, runtime);
+        for nudge in self.restack_workers(&workers_state, &registry, runtime.memory()) {
+            runtime.send_nudge(nudge);
+        }
 
         runtime.drain_github();
         let issue_response = runtime.drain_issues();
@@ -534,7 +1018,7 @@ impl<'a> Daemon<'a> {
         // Repo isolation: `filtered_repos` respects `repo_filter`, so when
         // `jig ps -w` runs within a single repo only that repo is polled.
         // Workers are never spawned for repos outside the filter scope.
-        if spawnable.is_empty() && runtime.should_first_poll() {
+        if spawnable.is_empty() && !self.auto_spawn_paused && runtime.should_first_poll() {
             runtime.mark_first_poll_done();
 
             let repos: Vec<(std::path::PathBuf, String)> = registry
@@ -623,6 +1107,7 @@ impl<'a> Daemon<'a> {
                     worker = %worker_name,
                     "triage timed out, emitting NeedsIntervention"
                 );
+                self.record(|m| m.record_escalation());
                 let event = NotificationEvent::NeedsIntervention {
                     repo: repo_name.clone(),
                     worker: worker_name.clone(),
@@ -811,8 +1296,7 @@ impl<'a> Daemon<'a> {
                             Self::effective_health_config(&repo_health, &self.config.health);
 
                         if let Ok(event_log) = event_log_result {
-                            if let Ok(events) = event_log.read_all() {
-                                let state = WorkerState::reduce(&events, &effective_health);
+                            if let Ok(state) = event_log.reduce(&effective_health) {
                                 let mut ctx = build_nudge_context(
                                     NudgeType::AutoReview,
                                     &state,
@@ -860,6 +1344,7 @@ impl<'a> Daemon<'a> {
                                     worker_name: wname.to_string(),
                                     worker_key: worker_key.to_string(),
                                 });
+                                self.record(|m| m.record_nudge(NudgeType::AutoReview.count_key()));
                             }
                         }
                     }
@@ -894,6 +1379,7 @@ impl<'a> Daemon<'a> {
         // Drain spawn results from previous tick
         if let Some(spawn_complete) = runtime.drain_spawn() {
             for sr in spawn_complete.results {
+                self.record(|m| m.record_spawn(sr.error.is_none()));
                 if let Some(err) = sr.error {
                     result
                         .errors
@@ -921,6 +1407,7 @@ impl<'a> Daemon<'a> {
             for tr in triage_complete.results {
                 // Remove from tracker regardless of success/failure
                 runtime.triage_tracker_mut().remove(&tr.issue_id);
+                self.record(|m| m.record_triage(tr.error.is_none()));
 
                 if let Some(err) = tr.error {
                     tracing::warn!(
@@ -929,6 +1416,7 @@ impl<'a> Daemon<'a> {
                         "triage failed: {}", err
                     );
                     // Emit NeedsIntervention for failed triages
+                    self.record(|m| m.record_escalation());
                     let event = NotificationEvent::NeedsIntervention {
                         repo: tr.repo_name.clone(),
                         worker: tr.worker_name.clone(),
@@ -994,6 +1482,7 @@ impl<'a> Daemon<'a> {
         }
 
         // 3. Process each worker
+        let repo_spend = self.repo_spend(&registry, &worker_list, runtime.memory());
         let mut live_prune_targets = Vec::new();
         for (repo_name, worker_name) in &worker_list {
             if quit.load(Ordering::Relaxed) {
@@ -1009,6 +1498,7 @@ impl<'a> Daemon<'a> {
                 &mut workers_state,
                 &registry,
                 runtime,
+                repo_spend.get(repo_name),
             ) {
                 Ok((
                     actions,
@@ -1037,11 +1527,19 @@ impl<'a> Daemon<'a> {
             }
         }
 
+        // Send this tick's PR checks as one batch
+        runtime.flush_pr_checks();
+
         // Live path: send prune targets from Cleanup actions
         if !live_prune_targets.is_empty() {
             runtime.send_prune(live_prune_targets);
         }
 
+        self.update_merge_queue(&workers_state, &registry, runtime, &mut result);
+        if runtime.memory().overlap_check_due() {
+            self.check_overlaps(&workers_state, &registry, runtime.memory());
+        }
+
         // Filter out terminal workers and workers with no tmux session
         result.worker_display.retain(|w| {
             let is_terminal = w
@@ -1058,10 +1556,14 @@ impl<'a> Daemon<'a> {
         {
             let now = chrono::Utc::now().timestamp();
             for entry in runtime.triage_tracker().active_entries() {
-                let model = Self::find_repo_path(&registry, &entry.repo_name)
+                let toml = Self::find_repo_path(&registry, &entry.repo_name)
                     .and_then(|re| JigToml::load(&re.path).ok().flatten())
-                    .map(|toml| toml.triage.model.clone())
-                    .unwrap_or_else(|| "sonnet".to_string());
+                    .unwrap_or_default();
+                let model = toml
+                    .triage
+                    .model_for(&toml.agent.agent_type)
+                    .unwrap_or("default")
+                    .to_string();
                 let elapsed = (now - entry.spawned_at).max(0) as u64;
                 result.triage_display.push(TriageDisplayInfo {
                     issue_id: entry.issue_id.clone(),
@@ -1075,11 +1577,20 @@ impl<'a> Daemon<'a> {
                 .sort_by(|a, b| a.issue_id.cmp(&b.issue_id));
         }
 
+        result.workers_by_status = Self::count_by_status(&workers_state, &worker_list);
+        result.queue_depths = runtime.queue_depths();
+        for elapsed in runtime.take_github_latencies() {
+            self.record(|m| m.record_github(elapsed));
+        }
+
         // Save updated state
         workers_state.save().unwrap_or_else(|e| {
             tracing::warn!("failed to save workers state: {}", e);
         });
 
+        self.flush_notification_digest(&mut result);
+        self.release_held_notifications(&mut result);
+
         // Recovery path: scan github cache for merged/closed PRs with worktrees still on disk.
         // This catches workers whose PRs were merged/closed while the daemon was off.
         if !runtime.prune_pending() {
@@ -1105,12 +1616,20 @@ impl<'a> Daemon<'a> {
             runtime.send_prune(prune_targets);
         }
 
-        // 4. Trigger issue poll if auto-spawn enabled (scoped to repo_filter)
-        runtime.maybe_trigger_issue_poll(
-            &registry,
-            &worker_list,
-            self.daemon_config.repo_filter.as_deref(),
-        );
+        // 4. Trigger issue poll if auto-spawn enabled (scoped to repo_filter).
+        //    While paused, drop anything a poll already in flight returned.
+        result.auto_spawn_paused = self.auto_spawn_paused;
+        if self.auto_spawn_paused {
+            spawnable.clear();
+            wrapup.clear();
+            triageable.clear();
+        } else {
+            runtime.maybe_trigger_issue_poll(
+                &registry,
+                &worker_list,
+                self.daemon_config.repo_filter.as_deref(),
+            );
+        }
 
         // 5. Send spawnable issues to background spawn actor (non-blocking).
         //    Wrap-up parents are dispatched through the same actor. Skip any
@@ -1132,6 +1651,11 @@ impl<'a> Daemon<'a> {
                 spawnable.extend(wrapup);
             }
         }
+        spawnable.retain(|si| {
+            !self.spawn_held_for_budget(si, &registry, &repo_spend)
+                && !self.spawn_held_for_overlap(si)
+                && !self.spawn_held_for_stack(si)
+        });
         if !spawnable.is_empty() {
             runtime.send_spawn(spawnable);
         }
@@ -1160,6 +1684,7 @@ impl<'a> Daemon<'a> {
                     },
                 );
             }
+            self.record(|m| m.record_triages_started(triageable.len()));
             runtime.send_triage(triageable);
         }
 
@@ -1179,6 +1704,12 @@ impl<'a> Daemon<'a> {
         if !self.daemon_config.skip_sync {
             self.sync_repos(&registry);
         }
+        if let Some(memory) = self.memory {
+            let mut memory = memory.lock().unwrap_or_else(|e| e.into_inner());
+            for nudge in self.restack_workers(&workers_state, &registry, &mut memory) {
+                nudge_actor::deliver(self.tmux, nudge);
+            }
+        }
 
         let mut worker_list = discover_workers(&registry);
 
@@ -1188,6 +1719,13 @@ impl<'a> Daemon<'a> {
 
         tracing::debug!(count = worker_list.len(), "discovered workers");
 
+        let repo_spend = match self.memory {
+            Some(memory) => {
+                let mut memory = memory.lock().unwrap_or_else(|e| e.into_inner());
+                self.repo_spend(&registry, &worker_list, &mut memory)
+            }
+            None => self.repo_spend(&registry, &worker_list, &mut TickMemory::default()),
+        };
         for (repo_name, worker_name) in &worker_list {
             result.workers_checked += 1;
             let key = format!("{}/{}", repo_name, worker_name);
@@ -1198,6 +1736,7 @@ impl<'a> Daemon<'a> {
                 &key,
                 &mut workers_state,
                 &registry,
+                repo_spend.get(repo_name),
             ) {
                 Ok((actions, nudges, notifs, worker_tick_info)) => {
                     result.actions_dispatched += actions;
@@ -1210,15 +1749,27 @@ impl<'a> Daemon<'a> {
                 }
             }
         }
+        result.workers_by_status = Self::count_by_status(&workers_state, &worker_list);
 
         workers_state.save().unwrap_or_else(|e| {
             tracing::warn!("failed to save workers state: {}", e);
         });
 
+        self.flush_notification_digest(&mut result);
+        self.release_held_notifications(&mut result);
+        self.land_merge_queue(&workers_state, &registry, &mut result);
+        if let Some(memory) = self.memory {
+            let mut memory = memory.lock().unwrap_or_else(|e| e.into_inner());
+            if memory.overlap_check_due() {
+                self.check_overlaps(&workers_state, &registry, &mut memory);
+            }
+        }
+
         // Auto-spawn: poll repos for spawnable issues (blocking).
         // Each repo's jig.toml controls auto_spawn and max_concurrent_workers.
         // When repo_filter is set, only poll that repo.
-        {
+        result.auto_spawn_paused = self.auto_spawn_paused;
+        if !self.auto_spawn_paused {
             let repos: Vec<(std::path::PathBuf, String)> = registry
                 .filtered_repos(self.daemon_config.repo_filter.as_deref())
                 .into_iter()
@@ -1238,6 +1789,12 @@ impl<'a> Daemon<'a> {
                 let response = issue_actor::process_request(&req);
                 // Spawn normal issues
                 for issue in response.spawnable {
+                    if self.spawn_held_for_budget(&issue, &registry, &repo_spend)
+                        || self.spawn_held_for_overlap(&issue)
+                        || self.spawn_held_for_stack(&issue)
+                    {
+                        continue;
+                    }
                     match self.auto_spawn_worker(&issue) {
                         Ok(()) => {
                             tracing::info!(
@@ -1245,9 +1802,11 @@ impl<'a> Daemon<'a> {
                                 issue = %issue.issue.id,
                                 "auto-spawned worker"
                             );
+                            self.record(|m| m.record_spawn(true));
                             result.auto_spawned.push(issue.worker_name.clone());
                         }
                         Err(e) => {
+                            self.record(|m| m.record_spawn(false));
                             result
                                 .errors
                                 .push(format!("auto-spawn {}: {}", issue.issue.id, e));
@@ -1266,6 +1825,11 @@ impl<'a> Daemon<'a> {
                         );
                         continue;
                     }
+                    if self.spawn_held_for_budget(&issue, &registry, &repo_spend)
+                        || self.spawn_held_for_overlap(&issue)
+                    {
+                        continue;
+                    }
                     match self.auto_spawn_worker(&issue) {
                         Ok(()) => {
                             tracing::info!(
@@ -1273,9 +1837,11 @@ impl<'a> Daemon<'a> {
                                 issue = %issue.issue.id,
                                 "spawned wrap-up worker for parent"
                             );
+                            self.record(|m| m.record_spawn(true));
                             result.auto_spawned.push(issue.worker_name.clone());
                         }
                         Err(e) => {
+                            self.record(|m| m.record_spawn(false));
                             result
                                 .errors
                                 .push(format!("wrapup-spawn {}: {}", issue.issue.id, e));
@@ -1289,7 +1855,11 @@ impl<'a> Daemon<'a> {
                         issue = %issue.issue.id,
                         "running inline triage subprocess"
                     );
-                    match crate::spawn::run_triage_subprocess(&issue.repo_root, &issue.issue) {
+                    self.record(|m| m.record_triages_started(1));
+                    let triage =
+                        crate::spawn::run_triage_subprocess(&issue.repo_root, &issue.issue);
+                    self.record(|m| m.record_triage(triage.is_ok()));
+                    match triage {
                         Ok(()) => {
                             tracing::info!(
                                 worker = %issue.worker_name,
@@ -1311,7 +1881,7 @@ impl<'a> Daemon<'a> {
     }
 
     /// Process a single worker using cached PR data from the runtime.
-    #[allow(clippy::type_complexity)]
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
     fn process_worker(
         &self,
         repo_name: &str,
@@ -1320,6 +1890,7 @@ impl<'a> Daemon<'a> {
         workers_state: &mut WorkersState,
         registry: &RepoRegistry,
         runtime: &mut DaemonRuntime,
+        repo_spend: Option<&Spend>,
     ) -> Result<(
         usize,
         usize,
@@ -1335,9 +1906,8 @@ impl<'a> Daemon<'a> {
         let resolve = Self::make_nudge_resolver(&repo_health, &self.config.health);
 
         let event_log = EventLog::for_worker(repo_name, worker_name)?;
-        let events = event_log.read_all()?;
-        let new_state = WorkerState::reduce(&events, &effective_health);
-        let branch_name = extract_branch_name(&events, worker_name);
+        let new_state = event_log.reduce(&effective_health)?;
+        let branch_name = worker_branch(&new_state, worker_name);
 
         // Use cached GitHub data — request a check for next tick if needed
         let mut worker_tick_info = WorkerTickInfo::default();
@@ -1371,8 +1941,7 @@ impl<'a> Daemon<'a> {
         }
 
         // Re-read state with potential PrOpened event
-        let events = event_log.read_all()?;
-        let mut new_state = WorkerState::reduce(&events, &effective_health);
+        let mut new_state = event_log.reduce(&effective_health)?;
 
         // Created workers (bare worktrees from `jig create`) are discovered for listing
         // but the daemon takes no actions on them.
@@ -1392,6 +1961,9 @@ impl<'a> Daemon<'a> {
                 pr_health: WorkerTickInfo::default(),
                 is_draft: false,
                 nudge_cooldown_remaining: None,
+                agent: new_state.agent.clone(),
+                model: new_state.model.clone(),
+                pane_preview: None,
             };
             return Ok((0, 0, 0, WorkerTickInfo::default(), display, vec![], vec![]));
         }
@@ -1409,11 +1981,23 @@ impl<'a> Daemon<'a> {
         );
 
         let mut actions = dispatch_actions(worker_name, &old_state, &new_state, &resolve);
+        self.apply_pane_patterns(
+            &mut actions,
+            &repo_health,
+            repo_name,
+            worker_name,
+            &branch_name,
+            &new_state,
+        );
 
         // Dead tmux detection: if worker is non-terminal but tmux window is gone,
         // resume instead of sending nudges to a dead window.
         // Skip Initializing workers — they're still running on-create hooks.
-        if !new_state.status.is_terminal() && new_state.status != WorkerStatus::Initializing {
+        // Workers stopped for budget stay down.
+        if !new_state.status.is_terminal()
+            && new_state.status != WorkerStatus::Initializing
+            && new_state.budget_exceeded.is_none()
+        {
             let session = format!("{}{}", self.daemon_config.session_prefix, repo_name);
             let target = TmuxTarget::new(&session, worker_name);
             if !self.tmux.has_window(&target) {
@@ -1631,6 +2215,19 @@ impl<'a> Daemon<'a> {
             }
         }
 
+        // Budget: warn near the limit, stop over it. A stopped worker gets no
+        // further nudges or restarts.
+        let budget_config = Self::load_repo_budget_config(registry, repo_name);
+        actions.extend(Self::budget_actions(
+            worker_name,
+            &new_state,
+            &budget_config,
+            repo_spend,
+        ));
+        if new_state.budget_exceeded.is_some() {
+            actions.retain(|a| !matches!(a, Action::Nudge { .. } | Action::Restart { .. }));
+        }
+
         // Resolve the repo's base branch for nudge templates
         let repo_base_branch = Self::find_repo_path(registry, repo_name)
             .and_then(|entry| RepoContext::resolve_base_branch_for(&entry.path).ok());
@@ -1665,14 +2262,27 @@ impl<'a> Daemon<'a> {
                 nudge_counts: new_state.nudge_counts.clone(),
                 review_feedback_count: current_review_feedback_count,
                 parent_branch: new_state.parent_branch.clone(),
+                stacked_on: new_state.stacked_on.clone(),
+                stack_base: new_state.stack_base.clone(),
                 last_reviewed_sha: workers_state
                     .get_worker(key)
                     .and_then(|e| e.last_reviewed_sha.clone()),
+                agent: new_state.agent.clone(),
+                model: new_state.model.clone(),
+                issue_labels: new_state.issue_labels.clone(),
             },
         );
 
         // Build display info — git checks are fast local ops
         let tmux_status = self.get_tmux_status(repo_name, worker_name);
+        let pane_preview = match tmux_status {
+            TaskStatus::Running | TaskStatus::Exited => {
+                let session = format!("{}{}", self.daemon_config.session_prefix, repo_name);
+                self.tmux
+                    .pane_preview(&TmuxTarget::new(&session, worker_name))
+            }
+            TaskStatus::NoSession | TaskStatus::NoWindow => None,
+        };
         let (commits_ahead, is_dirty) =
             if let Some(entry) = Self::find_repo_path(registry, repo_name) {
                 let worktree_path = crate::config::worktree_path(&entry.path, worker_name);
@@ -1725,6 +2335,9 @@ impl<'a> Daemon<'a> {
             pr_health: worker_tick_info.clone(),
             is_draft,
             nudge_cooldown_remaining,
+            agent: new_state.agent.clone(),
+            model: new_state.model.clone(),
+            pane_preview,
         };
 
         Ok((
@@ -1746,6 +2359,7 @@ impl<'a> Daemon<'a> {
         key: &str,
         workers_state: &mut WorkersState,
         registry: &RepoRegistry,
+        repo_spend: Option<&Spend>,
     ) -> Result<(usize, usize, usize, WorkerTickInfo)> {
         // Load per-repo health config
         let repo_health = Self::load_repo_health_config(registry, repo_name);
@@ -1753,14 +2367,16 @@ impl<'a> Daemon<'a> {
         let resolve = Self::make_nudge_resolver(&repo_health, &self.config.health);
 
         let event_log = EventLog::for_worker(repo_name, worker_name)?;
-        let events = event_log.read_all()?;
-        let mut new_state = WorkerState::reduce(&events, &effective_health);
-        let branch_name = extract_branch_name(&events, worker_name);
+        let mut new_state = event_log.reduce(&effective_health)?;
+        let branch_name = worker_branch(&new_state, worker_name);
 
         // Proactively discover PR if not already known
         if new_state.pr_url.is_none() && !new_state.status.is_terminal() {
-            if let Some(client) = make_github_client(repo_name, registry) {
-                match client.get_pr_for_branch(&branch_name) {
+            if let Some(client) = make_forge(repo_name, registry) {
+                let started = std::time::Instant::now();
+                let lookup = client.get_pr_for_branch(&branch_name);
+                self.record(|m| m.record_github(started.elapsed()));
+                match lookup {
                     Ok(Some(pr_info)) => {
                         let event = Event::new(EventType::PrOpened)
                             .with_field("pr_url", pr_info.url.as_str())
@@ -1769,8 +2385,8 @@ impl<'a> Daemon<'a> {
                             tracing::warn!(worker = key, error = %e, "failed to emit PrOpened event");
                         } else {
                             tracing::info!(worker = key, pr_url = %pr_info.url, "discovered PR for branch");
-                            if let Ok(updated_events) = event_log.read_all() {
-                                new_state = WorkerState::reduce(&updated_events, &effective_health);
+                            if let Ok(updated) = event_log.reduce(&effective_health) {
+                                new_state = updated;
                             }
                         }
                     }
@@ -1797,6 +2413,14 @@ impl<'a> Daemon<'a> {
         );
 
         let mut actions = dispatch_actions(worker_name, &old_state, &new_state, &resolve);
+        self.apply_pane_patterns(
+            &mut actions,
+            &repo_health,
+            repo_name,
+            worker_name,
+            &branch_name,
+            &new_state,
+        );
 
         // Check PR lifecycle
         let mut worker_tick_info = WorkerTickInfo::default();
@@ -1807,9 +2431,10 @@ impl<'a> Daemon<'a> {
                 let stored_review_feedback_count = workers_state
                     .get_worker(key)
                     .and_then(|e| e.review_feedback_count);
-                match make_github_client(repo_name, registry) {
+                match make_forge(repo_name, registry) {
                     Some(client) => {
-                        let monitor = PrMonitor::new(&client, self.config, &resolve);
+                        let monitor = PrMonitor::new(client.as_ref(), self.config, &resolve);
+                        let started = std::time::Instant::now();
                         let pr_result = monitor.check_lifecycle(
                             worker_name,
                             &branch_name,
@@ -1818,6 +2443,7 @@ impl<'a> Daemon<'a> {
                             stored_review_feedback_count,
                             &mut actions,
                         );
+                        self.record(|m| m.record_github(started.elapsed()));
                         current_review_feedback_count = pr_result.review_feedback_count;
                         worker_tick_info.pr_checks = pr_result
                             .checks
@@ -1826,12 +2452,25 @@ impl<'a> Daemon<'a> {
                             .collect();
                     }
                     None => {
-                        worker_tick_info.pr_error = Some("GitHub client unavailable".to_string());
+                        worker_tick_info.pr_error = Some("forge client unavailable".to_string());
                     }
                 }
             }
         }
 
+        // Budget: warn near the limit, stop over it. A stopped worker gets no
+        // further nudges or restarts.
+        let budget_config = Self::load_repo_budget_config(registry, repo_name);
+        actions.extend(Self::budget_actions(
+            worker_name,
+            &new_state,
+            &budget_config,
+            repo_spend,
+        ));
+        if new_state.budget_exceeded.is_some() {
+            actions.retain(|a| !matches!(a, Action::Nudge { .. } | Action::Restart { .. }));
+        }
+
         let repo_base_branch = Self::find_repo_path(registry, repo_name)
             .and_then(|entry| RepoContext::resolve_base_branch_for(&entry.path).ok());
 
@@ -1863,9 +2502,14 @@ impl<'a> Daemon<'a> {
                 nudge_counts: new_state.nudge_counts.clone(),
                 review_feedback_count: current_review_feedback_count,
                 parent_branch: new_state.parent_branch.clone(),
+                stacked_on: new_state.stacked_on.clone(),
+                stack_base: new_state.stack_base.clone(),
                 last_reviewed_sha: workers_state
                     .get_worker(key)
                     .and_then(|e| e.last_reviewed_sha.clone()),
+                agent: new_state.agent.clone(),
+                model: new_state.model.clone(),
+                issue_labels: new_state.issue_labels.clone(),
             },
         );
 
@@ -1915,16 +2559,17 @@ impl<'a> Daemon<'a> {
                     let target = TmuxTarget::new(&session, branch_name.to_string());
 
                     if self.tmux.has_window(&target) {
-                        // PR nudges (review, ci, conflict, bad commits) should always
-                        // be delivered — the agent may be at its idle prompt, which
-                        // tmux reports as a shell/version string (pane_is_running=false).
-                        // Only skip idle/stuck nudges when the pane has no running command.
+                        // PR and budget nudges should always be delivered — the
+                        // agent may be at its idle prompt, which tmux reports as a
+                        // shell/version string (pane_is_running=false). Only skip
+                        // idle/stuck nudges when the pane has no running command.
                         let is_pr_nudge = matches!(
                             nudge_type,
                             NudgeType::Review
                                 | NudgeType::Ci
                                 | NudgeType::Conflict
                                 | NudgeType::BadCommits
+                                | NudgeType::Budget
                         );
                         if !is_pr_nudge && !self.tmux.pane_is_running(&target) {
                             tracing::debug!(
@@ -1936,8 +2581,26 @@ impl<'a> Daemon<'a> {
                         let resolved = resolve(nudge_type.count_key());
 
                         // Render template on the tick thread (TemplateEngine has lifetime)
-                        let ctx =
+                        let mut ctx =
                             build_nudge_context(*nudge_type, new_state, resolved, base_branch);
+                        // Stacked workers are rebased by the restack pass;
+                        // rebasing onto the base would pull in the lower branch.
+                        if *nudge_type == NudgeType::Conflict && new_state.stacked_on.is_none() {
+                            match self.try_auto_rebase(
+                                registry,
+                                repo_name,
+                                worker_name,
+                                branch_name,
+                                base_branch,
+                                event_log,
+                            ) {
+                                Some(auto_rebase::Outcome::Pushed { .. }) => continue,
+                                Some(auto_rebase::Outcome::Conflicts(conflicts)) => {
+                                    add_conflict_context(&mut ctx, &conflicts)
+                                }
+                                _ => {}
+                            }
+                        }
                         let message = match self.engine.render(nudge_type.template_name(), &ctx) {
                             Ok(msg) => msg,
                             Err(e) => {
@@ -1960,6 +2623,7 @@ impl<'a> Daemon<'a> {
                                 worker_name: worker_name.to_string(),
                                 worker_key: key.to_string(),
                             });
+                            self.record(|m| m.record_nudge(nudge_type.count_key()));
                             nudge_count += 1;
                         } else {
                             // Blocking path (tick_once): deliver synchronously
@@ -1989,6 +2653,7 @@ impl<'a> Daemon<'a> {
                                         nudge_type = nudge_type.count_key(),
                                         "nudge delivered"
                                     );
+                                    self.record(|m| m.record_nudge(nudge_type.count_key()));
                                     nudge_count += 1;
                                 }
                                 Err(e) => {
@@ -2013,11 +2678,14 @@ impl<'a> Daemon<'a> {
                 } => {
                     tracing::info!(worker = key, message = %message, "notification sent");
                     let event = match kind {
-                        NotifyKind::NeedsIntervention => NotificationEvent::NeedsIntervention {
-                            repo: repo_name.to_string(),
-                            worker: worker_id.clone(),
-                            reason: message.clone(),
-                        },
+                        NotifyKind::NeedsIntervention => {
+                            self.record(|m| m.record_escalation());
+                            NotificationEvent::NeedsIntervention {
+                                repo: repo_name.to_string(),
+                                worker: worker_id.clone(),
+                                reason: message.clone(),
+                            }
+                        }
                         NotifyKind::PrOpened { pr_url } => NotificationEvent::PrOpened {
                             repo: repo_name.to_string(),
                             worker: worker_id.clone(),
@@ -2107,46 +2775,154 @@ impl<'a> Daemon<'a> {
 
                     tracing::info!("cleaned up worker {}", worker_id);
                 }
-                Action::UpdateIssueStatus {
+                Action::EnforceBudget {
                     worker_id,
-                    issue_id,
+                    reason,
+                    on_exceed,
                 } => {
-                    if let Some(entry) = Self::find_repo_path(registry, repo_name) {
-                        match RepoContext::from_path(&entry.path) {
-                            Ok(ctx) => {
-                                let base = RepoContext::resolve_base_branch_for(&entry.path)
-                                    .unwrap_or_else(|_| {
-                                        crate::config::DEFAULT_BASE_BRANCH.to_string()
-                                    });
-                                match ctx.issue_provider_with_ref(&base) {
-                                    Ok(provider) => {
-                                        // Check current status — skip if already terminal
-                                        let should_update = match provider.get(issue_id) {
-                                            Ok(Some(issue)) => !matches!(
-                                                issue.status,
-                                                crate::issues::types::IssueStatus::Complete
-                                            ),
-                                            Ok(None) => {
-                                                tracing::warn!(
-                                                    worker = %worker_id,
-                                                    issue = %issue_id,
-                                                    "linked issue not found, skipping auto-complete"
-                                                );
-                                                false
-                                            }
-                                            Err(e) => {
-                                                tracing::warn!(
-                                                    worker = %worker_id,
-                                                    issue = %issue_id,
-                                                    error = %e,
-                                                    "failed to fetch issue status, skipping auto-complete"
-                                                );
-                                                false
-                                            }
-                                        };
-                                        if should_update {
-                                            match provider.update_status(
-                                                issue_id,
+                    let target = TmuxTarget::new(
+                        format!("{}{}", self.daemon_config.session_prefix, repo_name),
+                        branch_name.to_string(),
+                    );
+                    tracing::warn!(
+                        worker = key,
+                        reason = %reason,
+                        action = on_exceed.as_str(),
+                        "worker over budget"
+                    );
+
+                    if self.tmux.has_window(&target) {
+                        let stopped = match on_exceed {
+                            BudgetAction::Interrupt => self.tmux.interrupt(&target),
+                            BudgetAction::Stop => self.tmux.kill_window(&target),
+                        };
+                        if let Err(e) = stopped {
+                            tracing::warn!(
+                                "failed to stop over-budget worker {}: {}",
+                                worker_id,
+                                e
+                            );
+                        }
+                    }
+
+                    let mut events = vec![Event::new(EventType::BudgetExceeded)
+                        .with_field("reason", reason.as_str())
+                        .with_field("action", on_exceed.as_str())];
+                    if *on_exceed == BudgetAction::Stop {
+                        events
+                            .push(Event::new(EventType::Terminal).with_field("terminal", "failed"));
+                    }
+                    for event in &events {
+                        if let Err(e) = event_log.append(event) {
+                            tracing::warn!("failed to emit budget event for {}: {}", key, e);
+                        }
+                    }
+                }
+                Action::PaneRule { worker_id, rule } => {
+                    let target = TmuxTarget::new(
+                        format!("{}{}", self.daemon_config.session_prefix, repo_name),
+                        branch_name.to_string(),
+                    );
+                    tracing::info!(
+                        worker = key,
+                        rule = %rule.name,
+                        action = rule.action.as_str(),
+                        "pane matched pattern"
+                    );
+
+                    let applied = match rule.action {
+                        PatternAction::SendKeys => {
+                            let keys: Vec<&str> = rule.keys.iter().map(String::as_str).collect();
+                            self.tmux.send_keys(&target, &keys)
+                        }
+                        PatternAction::Interrupt => self.tmux.interrupt(&target),
+                        PatternAction::Restart => {
+                            self.tmux.kill_window(&target).and_then(
+                                |()| match Self::find_repo_path(registry, repo_name) {
+                                    Some(entry) => recovery::RecoveryScanner::try_resume_worker(
+                                        &entry.path,
+                                        repo_name,
+                                        worker_name,
+                                    )
+                                    .map(|_| ()),
+                                    None => Ok(()),
+                                },
+                            )
+                        }
+                        PatternAction::Notify => {
+                            self.record(|m| m.record_escalation());
+                            notif_count += 1;
+                            self.notifier.emit(NotificationEvent::NeedsIntervention {
+                                repo: repo_name.to_string(),
+                                worker: worker_id.clone(),
+                                reason: format!("Worker matched pane pattern '{}'", rule.name),
+                            })
+                        }
+                    };
+
+                    match applied {
+                        Ok(()) => {
+                            let event = Event::new(EventType::PaneMatch)
+                                .with_field("rule", rule.name.as_str())
+                                .with_field("action", rule.action.as_str());
+                            if let Err(e) = event_log.append(&event) {
+                                tracing::warn!(
+                                    "failed to emit pane match event for {}: {}",
+                                    key,
+                                    e
+                                );
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!(
+                                "pane rule '{}' failed for {}: {}",
+                                rule.name,
+                                worker_id,
+                                e
+                            );
+                        }
+                    }
+                }
+                Action::UpdateIssueStatus {
+                    worker_id,
+                    issue_id,
+                } => {
+                    if let Some(entry) = Self::find_repo_path(registry, repo_name) {
+                        match RepoContext::from_path(&entry.path) {
+                            Ok(ctx) => {
+                                let base = RepoContext::resolve_base_branch_for(&entry.path)
+                                    .unwrap_or_else(|_| {
+                                        crate::config::DEFAULT_BASE_BRANCH.to_string()
+                                    });
+                                match ctx.issue_provider_with_ref(&base) {
+                                    Ok(provider) => {
+                                        // Check current status — skip if already terminal
+                                        let should_update = match provider.get(issue_id) {
+                                            Ok(Some(issue)) => !matches!(
+                                                issue.status,
+                                                crate::issues::types::IssueStatus::Complete
+                                            ),
+                                            Ok(None) => {
+                                                tracing::warn!(
+                                                    worker = %worker_id,
+                                                    issue = %issue_id,
+                                                    "linked issue not found, skipping auto-complete"
+                                                );
+                                                false
+                                            }
+                                            Err(e) => {
+                                                tracing::warn!(
+                                                    worker = %worker_id,
+                                                    issue = %issue_id,
+                                                    error = %e,
+                                                    "failed to fetch issue status, skipping auto-complete"
+                                                );
+                                                false
+                                            }
+                                        };
+                                        if should_update {
+                                            match provider.update_status(
+                                                issue_id,
                                                 &crate::issues::types::IssueStatus::Complete,
                                             ) {
                                                 Ok(()) => {
@@ -2192,6 +2968,663 @@ impl<'a> Daemon<'a> {
         (nudge_count, notif_count, prune_targets, nudge_messages)
     }
 
+    /// Rebase a conflicted worker onto its base before nudging it.
+    ///
+    /// Returns `None` when auto-rebase is off for the repo or the attempt
+    /// failed outright. Only `Pushed` fixes the PR; on anything else the
+    /// nudge goes out as usual.
+    fn try_auto_rebase(
+        &self,
+        registry: &RepoRegistry,
+        repo_name: &str,
+        worker_name: &str,
+        branch_name: &str,
+        base_branch: Option<&str>,
+        event_log: &EventLog,
+    ) -> Option<auto_rebase::Outcome> {
+        let entry = Self::find_repo_path(registry, repo_name)?;
+        let enabled = JigToml::load(&entry.path)
+            .ok()
+            .flatten()
+            .and_then(|toml| toml.health.auto_rebase)
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let worktree = crate::config::worktree_path(&entry.path, worker_name);
+        let base = base_branch.unwrap_or(crate::config::DEFAULT_BASE_BRANCH);
+        let outcome = match auto_rebase::attempt(&entry.path, &worktree, branch_name, base) {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                tracing::warn!(worker = %worker_name, "auto-rebase failed: {}", e);
+                return None;
+            }
+        };
+
+        let event = Event::new(EventType::AutoRebase).with_field("base", base);
+        let event = match &outcome {
+            auto_rebase::Outcome::Pushed { head } => {
+                tracing::info!(worker = %worker_name, base, "auto-rebased and pushed");
+                event
+                    .with_field("result", "pushed")
+                    .with_field("head", head.as_str())
+            }
+            auto_rebase::Outcome::RebasedLocally { head } => {
+                tracing::info!(worker = %worker_name, base, "auto-rebased, branch not on origin");
+                event
+                    .with_field("result", "local")
+                    .with_field("head", head.as_str())
+            }
+            auto_rebase::Outcome::Unchanged => {
+                tracing::debug!(worker = %worker_name, base, "branch already on base, nothing to rebase");
+                return Some(outcome);
+            }
+            auto_rebase::Outcome::Conflicts(conflicts) => {
+                let files: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+                tracing::info!(worker = %worker_name, files = ?files, "auto-rebase hit conflicts");
+                event
+                    .with_field("result", "conflicts")
+                    .with_field("files", files.join(","))
+            }
+            auto_rebase::Outcome::Dirty => {
+                tracing::debug!(worker = %worker_name, "worktree dirty, skipping auto-rebase");
+                return Some(outcome);
+            }
+        };
+        if let Err(e) = event_log.append(&event) {
+            tracing::warn!(worker = %worker_name, "failed to append auto-rebase event: {}", e);
+        }
+        Some(outcome)
+    }
+
+    /// Keep stacked workers on top of their lower worker's branch.
+    ///
+    /// When the lower branch moves, the upper's own commits (those after its
+    /// stack base) are rebased onto the new lower head. Once the lower worker
+    /// has merged, they are rebased onto the repo base instead and the
+    /// upper's PR is retargeted there. A lower worker gone without a merge is
+    /// escalated. Returns the nudges telling restacked workers what changed.
+    fn restack_workers(
+        &self,
+        workers_state: &WorkersState,
+        registry: &RepoRegistry,
+        memory: &mut TickMemory,
+    ) -> Vec<messages::NudgeRequest> {
+        let mut nudges = Vec::new();
+        for (key, entry) in &workers_state.workers {
+            let (Some(lower), Some(stack_base)) = (&entry.stacked_on, &entry.stack_base) else {
+                continue;
+            };
+            if entry.status == "merged" || entry.status == "archived" || entry.status == "failed" {
+                continue;
+            }
+            let Some(repo_entry) = Self::find_repo_path(registry, &entry.repo) else {
+                continue;
+            };
+            let worker_name = key.split_once('/').map(|(_, w)| w).unwrap_or(key);
+            let lower_entry = workers_state
+                .workers
+                .get(&format!("{}/{}", entry.repo, lower));
+            let pr_merged = || Self::pr_merged(&repo_entry.path, lower_entry?);
+
+            let plan = stack::plan(
+                &repo_entry.path,
+                lower,
+                stack_base,
+                lower_entry.map(|e| e.status.as_str()),
+                pr_merged,
+            );
+            let (onto, landed) = match plan {
+                stack::Plan::Current => continue,
+                stack::Plan::Restack { head } => (head, false),
+                stack::Plan::Land => (
+                    RepoContext::resolve_base_branch_for(&repo_entry.path)
+                        .unwrap_or_else(|_| crate::config::DEFAULT_BASE_BRANCH.to_string()),
+                    true,
+                ),
+                stack::Plan::Orphaned => {
+                    if !memory.restack_blocked(key, LOWER_GONE) {
+                        memory.block_restack(key, LOWER_GONE);
+                        self.escalate_orphaned_stack(entry, worker_name, lower);
+                    }
+                    continue;
+                }
+            };
+            if memory.restack_blocked(key, &onto) {
+                continue;
+            }
+
+            let worktree = crate::config::worktree_path(&repo_entry.path, worker_name);
+            let Ok(branch) = crate::git::Repo::worktree_branch(&worktree) else {
+                continue;
+            };
+            let outcome = match auto_rebase::attempt_onto(
+                &repo_entry.path,
+                &worktree,
+                &branch,
+                &onto,
+                stack_base,
+            ) {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    tracing::warn!(worker = %key, "restack failed: {}", e);
+                    continue;
+                }
+            };
+
+            match outcome {
+                auto_rebase::Outcome::Pushed { .. }
+                | auto_rebase::Outcome::RebasedLocally { .. }
+                | auto_rebase::Outcome::Unchanged => {
+                    let mut event = Event::new(EventType::Restack);
+                    let message = if landed {
+                        let base = onto.strip_prefix("origin/").unwrap_or(&onto);
+                        let retargeted = self.retarget_stacked_pr(&repo_entry.path, entry, base);
+                        tracing::info!(worker = %key, lower = %lower, base, "lower branch landed, moved stack onto base");
+                        event = event.with_field("landed", true).with_field("onto", base);
+                        format!(
+                            "`{}` has landed. Your branch was rebased onto `{}`{}. \
+                             Run `git log --oneline -5` to see what changed.",
+                            lower,
+                            base,
+                            if retargeted {
+                                " and your PR now targets it"
+                            } else {
+                                ""
+                            }
+                        )
+                    } else {
+                        tracing::info!(worker = %key, lower = %lower, "restacked onto lower branch");
+                        event = event
+                            .with_field("onto", lower.as_str())
+                            .with_field("stack_base", onto.as_str());
+                        format!(
+                            "`{}` has new commits. Your branch was rebased onto them. \
+                             Run `git log --oneline -5` to see what changed.",
+                            lower
+                        )
+                    };
+                    if let Err(e) = EventLog::for_worker(&entry.repo, worker_name)
+                        .and_then(|log| log.append(&event))
+                    {
+                        tracing::warn!(worker = %key, "failed to record restack: {}", e);
+                    }
+
+                    // Nothing moved, so there is nothing to tell the agent.
+                    if outcome == auto_rebase::Outcome::Unchanged {
+                        continue;
+                    }
+                    let session = format!("{}{}", self.daemon_config.session_prefix, entry.repo);
+                    if self
+                        .tmux
+                        .has_window(&TmuxTarget::new(&session, worker_name))
+                    {
+                        nudges.push(messages::NudgeRequest {
+                            session,
+                            window: worker_name.to_string(),
+                            message,
+                            nudge_type_key: "restack".to_string(),
+                            is_stuck: false,
+                            repo_name: entry.repo.clone(),
+                            worker_name: worker_name.to_string(),
+                            worker_key: key.clone(),
+                        });
+                        self.record(|m| m.record_nudge("restack"));
+                    }
+                }
+                auto_rebase::Outcome::Conflicts(conflicts) => {
+                    memory.block_restack(key, &onto);
+                    let files: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+                    tracing::warn!(worker = %key, lower = %lower, files = ?files, "restack hit conflicts");
+                    self.record(|m| m.record_escalation());
+                    let event = NotificationEvent::NeedsIntervention {
+                        repo: entry.repo.clone(),
+                        worker: worker_name.to_string(),
+                        reason: format!(
+                            "Restacking {} onto {} conflicts in {}",
+                            worker_name,
+                            if landed {
+                                onto.as_str()
+                            } else {
+                                lower.as_str()
+                            },
+                            files.join(", ")
+                        ),
+                    };
+                    if let Err(e) = self.notifier.emit(event) {
+                        tracing::warn!("NeedsIntervention notification failed: {}", e);
+                    }
+                }
+                auto_rebase::Outcome::Dirty => {
+                    tracing::debug!(worker = %key, "worktree dirty, restacking next tick");
+                }
+            }
+        }
+        nudges
+    }
+
+    /// Whether a worker's PR has merged, per the forge. `None` without a PR
+    /// or when the forge can't be asked.
+    fn pr_merged(repo_path: &std::path::Path, entry: &WorkerEntry) -> Option<bool> {
+        let number = entry
+            .pr_url
+            .as_deref()
+            .and_then(github_actor::pr_number_from_url)?;
+        match crate::forge::for_repo(repo_path).and_then(|forge| forge.get_pr_state(number)) {
+            Ok(state) => Some(state.state == crate::github::PrState::Merged),
+            Err(e) => {
+                tracing::warn!(pr = number, "failed to read lower PR state: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Tell a human that `worker`'s lower worker went away without merging.
+    fn escalate_orphaned_stack(&self, entry: &WorkerEntry, worker_name: &str, lower: &str) {
+        tracing::warn!(worker = %worker_name, lower = %lower, "lower worker gone without a merge");
+        self.record(|m| m.record_escalation());
+        let event = NotificationEvent::NeedsIntervention {
+            repo: entry.repo.clone(),
+            worker: worker_name.to_string(),
+            reason: format!(
+                "{} is stacked on {}, which went away without merging",
+                worker_name, lower
+            ),
+        };
+        if let Err(e) = self.notifier.emit(event) {
+            tracing::warn!("NeedsIntervention notification failed: {}", e);
+        }
+    }
+
+    /// Point a stacked worker's PR at `base` once its lower branch landed.
+    /// Returns whether a PR was retargeted.
+    fn retarget_stacked_pr(
+        &self,
+        repo_path: &std::path::Path,
+        entry: &WorkerEntry,
+        base: &str,
+    ) -> bool {
+        let Some(number) = entry
+            .pr_url
+            .as_deref()
+            .and_then(github_actor::pr_number_from_url)
+        else {
+            return false;
+        };
+        match crate::forge::for_repo(repo_path).and_then(|forge| forge.retarget_pr(number, base)) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!(pr = number, base, "failed to retarget stacked PR: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Send the notification digest when its window has elapsed.
+    fn flush_notification_digest(&self, result: &mut TickResult) {
+        match self.notifier.flush_digest() {
+            Ok(Some(digest)) => {
+                tracing::info!(summary = %digest.summary, "sent notification digest");
+                result.notifications_sent += 1;
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!("notification digest failed: {}", e),
+        }
+    }
+
+    /// Deliver notifications held back by quiet hours once they are over.
+    fn release_held_notifications(&self, result: &mut TickResult) {
+        match self.notifier.release_held() {
+            Ok(0) => {}
+            Ok(released) => {
+                tracing::info!(released, "delivered notifications held for quiet hours");
+                result.notifications_sent += released;
+            }
+            Err(e) => tracing::warn!("failed to release held notifications: {}", e),
+        }
+    }
+
+    /// Apply finished merges, queue newly approved workers and hand the head
+    /// of the merge queue to the merge actor.
+    fn update_merge_queue(
+        &self,
+        workers_state: &WorkersState,
+        registry: &RepoRegistry,
+        runtime: &mut DaemonRuntime,
+        result: &mut TickResult,
+    ) {
+        let Some(mut queue) = Self::load_merge_queue() else {
+            return;
+        };
+        if let Some(complete) = runtime.drain_merge() {
+            self.record_merge(&mut queue, registry, complete, result);
+        }
+        self.refresh_merge_queue(&mut queue, workers_state, registry);
+
+        if !runtime.merge_pending() {
+            queue.requeue_landing();
+            if let Some(req) = Self::next_merge(&queue, workers_state, registry) {
+                let key = req.worker_key.clone();
+                if runtime.send_merge(req) {
+                    queue.start(&key);
+                }
+            }
+        }
+
+        if let Err(e) = queue.save() {
+            tracing::warn!("failed to save merge queue: {}", e);
+        }
+    }
+
+    /// Blocking counterpart of [`Self::update_merge_queue`] for
+    /// [`Self::tick_once`]: queue newly approved workers and land the head of
+    /// the queue on this thread.
+    fn land_merge_queue(
+        &self,
+        workers_state: &WorkersState,
+        registry: &RepoRegistry,
+        result: &mut TickResult,
+    ) {
+        let Some(mut queue) = Self::load_merge_queue() else {
+            return;
+        };
+        self.refresh_merge_queue(&mut queue, workers_state, registry);
+        // Nothing lands between blocking ticks, so a landing entry was left
+        // by a daemon that stopped mid-merge.
+        queue.requeue_landing();
+
+        if let Some(req) = Self::next_merge(&queue, workers_state, registry) {
+            queue.start(&req.worker_key);
+            // Saved first so `jig queue` shows the entry landing meanwhile.
+            if let Err(e) = queue.save() {
+                tracing::warn!("failed to save merge queue: {}", e);
+            }
+            let complete = merge_actor::process(&req);
+            self.record_merge(&mut queue, registry, complete, result);
+        }
+
+        if let Err(e) = queue.save() {
+            tracing::warn!("failed to save merge queue: {}", e);
+        }
+    }
+
+    fn load_merge_queue() -> Option<MergeQueue> {
+        MergeQueue::load()
+            .map_err(|e| tracing::warn!("failed to load merge queue: {}", e))
+            .ok()
+    }
+
+    fn merge_queue_config(registry: &RepoRegistry, repo_name: &str) -> MergeQueueConfig {
+        Self::find_repo_path(registry, repo_name)
+            .and_then(|entry| JigToml::load(&entry.path).ok().flatten())
+            .map(|toml| toml.merge_queue)
+            .unwrap_or_default()
+    }
+
+    /// Record the outcome of a landing attempt: a landed worker leaves the
+    /// queue, a failed one is retried or escalated.
+    fn record_merge(
+        &self,
+        queue: &mut MergeQueue,
+        registry: &RepoRegistry,
+        complete: MergeComplete,
+        result: &mut TickResult,
+    ) {
+        let now = chrono::Utc::now().timestamp();
+        let (repo_name, worker_name) = complete
+            .worker_key
+            .split_once('/')
+            .unwrap_or(("", &complete.worker_key));
+        match complete.error {
+            None => {
+                queue.finish(&complete.worker_key);
+                if Self::merge_queue_config(registry, repo_name).method == MergeMethod::Local {
+                    // Forge merges are picked up by the PR monitor.
+                    let event = Event::new(EventType::Terminal).with_field("terminal", "merged");
+                    if let Err(e) = EventLog::for_worker(repo_name, worker_name)
+                        .and_then(|log| log.append(&event))
+                    {
+                        tracing::warn!(worker = %complete.worker_key, "failed to record merge: {}", e);
+                    }
+                    let event = NotificationEvent::WorkCompleted {
+                        repo: repo_name.to_string(),
+                        worker: worker_name.to_string(),
+                        pr_url: None,
+                    };
+                    if let Err(e) = self.notifier.emit(event) {
+                        tracing::warn!("WorkCompleted notification failed: {}", e);
+                    }
+                }
+            }
+            Some(err) => {
+                let max_attempts = Self::merge_queue_config(registry, repo_name).max_attempts;
+                if queue.fail(&complete.worker_key, &err, max_attempts, now) {
+                    self.record(|m| m.record_escalation());
+                    let event = NotificationEvent::NeedsIntervention {
+                        repo: repo_name.to_string(),
+                        worker: worker_name.to_string(),
+                        reason: format!("Merge queue gave up on {}: {}", worker_name, err),
+                    };
+                    if let Err(e) = self.notifier.emit(event) {
+                        tracing::warn!("NeedsIntervention notification failed: {}", e);
+                    }
+                }
+                result
+                    .errors
+                    .push(format!("merge {}: {}", complete.worker_key, err));
+            }
+        }
+    }
+
+    /// Replace each repo's queue entries with its currently approved workers.
+    fn refresh_merge_queue(
+        &self,
+        queue: &mut MergeQueue,
+        workers_state: &WorkersState,
+        registry: &RepoRegistry,
+    ) {
+        let now = chrono::Utc::now().timestamp();
+        for entry in registry.filtered_repos(self.daemon_config.repo_filter.as_deref()) {
+            let Some(repo_name) = entry.path.file_name().map(|n| n.to_string_lossy()) else {
+                continue;
+            };
+            let approved = if Self::merge_queue_config(registry, &repo_name).enabled {
+                self.approved_entries(workers_state, queue, &repo_name, &entry.path, now)
+            } else {
+                Vec::new()
+            };
+            queue.refresh(&repo_name, approved);
+        }
+    }
+
+    /// The request that lands the head of the queue, if it is ready.
+    fn next_merge(
+        queue: &MergeQueue,
+        workers_state: &WorkersState,
+        registry: &RepoRegistry,
+    ) -> Option<MergeRequest> {
+        let next = queue.next()?;
+        let entry = Self::find_repo_path(registry, &next.repo)?;
+        let key = next.key();
+        Some(MergeRequest {
+            worker_key: key.clone(),
+            repo_path: entry.path.clone(),
+            worktree_path: crate::config::worktree_path(&entry.path, &next.worker),
+            branch: next.branch.clone(),
+            base_branch: RepoContext::resolve_base_branch_for(&entry.path)
+                .unwrap_or_else(|_| crate::config::DEFAULT_BASE_BRANCH.to_string()),
+            pr_number: workers_state
+                .workers
+                .get(&key)
+                .and_then(|w| w.pr_url.as_deref())
+                .and_then(github_actor::pr_number_from_url),
+            config: Self::merge_queue_config(registry, &next.repo),
+        })
+    }
+
+    /// Queue entries for a repo's approved workers. Priority and dependencies
+    /// are looked up only for workers not already queued.
+    fn approved_entries(
+        &self,
+        workers_state: &WorkersState,
+        queue: &MergeQueue,
+        repo_name: &str,
+        repo_path: &std::path::Path,
+        now: i64,
+    ) -> Vec<QueueEntry> {
+        let mut provider = None;
+        let mut entries = Vec::new();
+        for (key, worker) in workers_state.workers_for_repo(repo_name) {
+            if worker.status != WorkerStatus::Approved.as_str() {
+                continue;
+            }
+            let worker_name = key.split_once('/').map_or(key.as_str(), |(_, w)| w);
+            if let Some(queued) = queue.entries.iter().find(|e| &e.key() == key) {
+                entries.push(queued.clone());
+                continue;
+            }
+
+            let mut entry = QueueEntry::new(repo_name, worker_name, &worker.branch, now);
+            if let Some(issue_id) = &worker.issue {
+                entry.issue = Some(issue_id.clone());
+                let provider = provider.get_or_insert_with(|| {
+                    let base = RepoContext::resolve_base_branch_for(repo_path)
+                        .unwrap_or_else(|_| crate::config::DEFAULT_BASE_BRANCH.to_string());
+                    RepoContext::from_path(repo_path)
+                        .and_then(|ctx| ctx.issue_provider_with_ref(&base))
+                        .ok()
+                });
+                if let Some(Ok(Some(issue))) = provider.as_ref().map(|p| p.get(issue_id)) {
+                    entry.priority = issue.priority;
+                    entry.depends_on = issue.depends_on;
+                }
+            }
+            entries.push(entry);
+        }
+        entries
+    }
+
+    /// Nudge a worker now, outside the normal nudge schedule.
+    ///
+    /// `worker` is `repo/worker` or a worker name unique across repos. Without
+    /// `message` the nudge the worker would get next is rendered (idle if
+    /// none applies). Returns the worker key.
+    pub fn nudge_now(&self, worker: &str, message: Option<&str>) -> Result<String> {
+        let registry = RepoRegistry::load().unwrap_or_default();
+        let mut worker_list = discover_workers(&registry);
+        if let Some(ref filter) = self.daemon_config.repo_filter {
+            worker_list.retain(|(repo_name, _)| repo_name == filter);
+        }
+        let (repo_name, worker_name) = find_worker(&worker_list, worker)?;
+        let key = format!("{}/{}", repo_name, worker_name);
+
+        let repo_health = Self::load_repo_health_config(&registry, &repo_name);
+        let effective_health = Self::effective_health_config(&repo_health, &self.config.health);
+        let resolve = Self::make_nudge_resolver(&repo_health, &self.config.health);
+
+        let event_log = EventLog::for_worker(&repo_name, &worker_name)?;
+        let state = event_log.reduce(&effective_health)?;
+
+        let session = format!("{}{}", self.daemon_config.session_prefix, repo_name);
+        let target = TmuxTarget::new(&session, worker_branch(&state, &worker_name));
+        if !self.tmux.has_window(&target) {
+            return Err(Error::Custom(format!("{} has no tmux window", key)));
+        }
+
+        let (nudge_key, message) = match message {
+            Some(message) => ("manual", message.to_string()),
+            None => {
+                let nudge_type = classify_nudge(&state, &resolve).unwrap_or(NudgeType::Idle);
+                let ctx =
+                    build_nudge_context(nudge_type, &state, resolve(nudge_type.count_key()), None);
+                let message = self.engine.render(nudge_type.template_name(), &ctx)?;
+                if nudge_type == NudgeType::Stuck {
+                    self.tmux.auto_approve(&target)?;
+                    std::thread::sleep(Duration::from_millis(500));
+                }
+                (nudge_type.count_key(), message)
+            }
+        };
+        self.tmux.send_message(&target, &message)?;
+
+        let event = Event::new(EventType::Nudge)
+            .with_field("nudge_type", nudge_key)
+            .with_field("message", message);
+        event_log.append(&event)?;
+
+        tracing::info!(worker = %key, nudge_type = nudge_key, "nudged on request");
+        Ok(key)
+    }
+
+    /// Stop a worker's window and unregister it, on request.
+    pub fn kill_now(&self, worker: &str) -> Result<String> {
+        let (repo, key, name) = self.locate_worker(worker)?;
+        crate::spawn::kill_window(&repo, &name)?;
+        crate::spawn::unregister(&repo, &name)?;
+        tracing::info!(worker = %key, "killed on request");
+        Ok(key)
+    }
+
+    /// Relaunch a worker whose window is gone, on request.
+    pub fn resume_worker_now(&self, worker: &str) -> Result<String> {
+        let (repo, key, name) = self.locate_worker(worker)?;
+        let wt = crate::worktree::Worktree::open(&repo.repo_root, &repo.worktrees_dir, &name)?;
+        if wt.has_tmux_window() {
+            return Err(Error::Custom(format!(
+                "{} is already running — attach instead",
+                key
+            )));
+        }
+        let repo_name = key.split_once('/').map(|(r, _)| r).unwrap_or_default();
+        let context = recovery::RecoveryScanner::read_spawn_context(repo_name, &name);
+        wt.resume(context.as_deref())?;
+        tracing::info!(worker = %key, "resumed on request");
+        Ok(key)
+    }
+
+    /// Land a worker's branch, on request. Returns what was done.
+    pub fn merge_now(&self, worker: &str, strategy: Strategy, cleanup: bool) -> Result<String> {
+        let (repo, key, name) = self.locate_worker(worker)?;
+        let landing = Landing {
+            name,
+            strategy,
+            message: None,
+            cleanup,
+        };
+        let notes = landing
+            .land(&repo, &crate::git::Repo::open(&repo.repo_root)?)
+            .map_err(|e| match e {
+                LandError::Core(e) => e,
+                e => Error::Custom(e.to_string()),
+            })?;
+        tracing::info!(worker = %key, "landed on request");
+        let lines: Vec<String> = notes
+            .into_iter()
+            .map(|note| match note {
+                Note::Success(line) | Note::Warning(line) => line,
+            })
+            .collect();
+        Ok(lines.join("; "))
+    }
+
+    /// Resolve a `repo/worker` or worker name to its repo, key and name.
+    fn locate_worker(&self, worker: &str) -> Result<(RepoContext, String, String)> {
+        let registry = RepoRegistry::load().unwrap_or_default();
+        let mut worker_list = discover_workers(&registry);
+        if let Some(ref filter) = self.daemon_config.repo_filter {
+            worker_list.retain(|(repo_name, _)| repo_name == filter);
+        }
+        let (repo_name, worker_name) = find_worker(&worker_list, worker)?;
+        let entry = registry
+            .filtered_repos(Some(&repo_name))
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Custom(format!("repo '{}' is not tracked", repo_name)))?;
+        let repo = RepoContext::from_path(&entry.path)?;
+        Ok((repo, format!("{}/{}", repo_name, worker_name), worker_name))
+    }
+
     /// Auto-spawn a worker for an issue.
     ///
     /// Delegates to [`crate::spawn::spawn_worker_for_issue`] for the core spawn
@@ -2205,6 +3638,8 @@ impl<'a> Daemon<'a> {
             worker_name: &issue.worker_name,
             provider_kind: issue.provider_kind,
             kind: issue.kind,
+            agent: issue.issue.agent.as_deref(),
+            model: issue.issue.model.as_deref(),
         };
         spawn::spawn_worker_for_issue(&input).map_err(crate::error::Error::Custom)?;
 
@@ -2260,10 +3695,11 @@ impl<'a> Daemon<'a> {
     }
 }
 
-/// Build a Notifier from global config.
+/// Build a Notifier from global config, with per-repo `[notify]` overrides.
 fn make_notifier(global_config: &GlobalConfig) -> Result<Notifier> {
     let queue = crate::notify::NotificationQueue::global()?;
-    Ok(Notifier::new(global_config.notify.clone(), queue))
+    Ok(Notifier::new(global_config.notify.clone(), queue)
+        .with_context(Box::new(crate::notify::RegistryContext)))
 }
 
 /// Install SIGINT/SIGTERM handler that sets the quit flag for graceful shutdown.
@@ -2343,6 +3779,9 @@ fn log_shutdown(reason: &str) {
 ///
 /// A shared `quit` flag is provided so that external code (e.g. a key-polling thread)
 /// can signal the tick to bail early between workers.
+///
+/// Callers should follow a running daemon over the [`control`] socket
+/// instead of calling this, so that only one loop orchestrates workers.
 pub fn run_with<F>(
     daemon_config: &DaemonConfig,
     runtime_config: RuntimeConfig,
@@ -2411,17 +3850,37 @@ where
     result
 }
 
+/// Restack-conflict marker for a stacked worker whose lower worker went away
+/// without merging, so it is escalated once.
+const LOWER_GONE: &str = "lower-gone";
+
+/// How often an idle daemon checks the quit flag.
+const QUIT_POLL: Duration = Duration::from_secs(1);
+
 /// Run the daemon loop (simple blocking mode). Returns after one pass if `config.once` is true.
+///
+/// Fails if another daemon holds the pidfile lock. Unless running once, the loop serves the control socket (see [`control`])
+/// between ticks.
 pub fn run(daemon_config: &DaemonConfig) -> Result<()> {
-    let global_config = GlobalConfig::load()?;
+    let mut global_config = GlobalConfig::load()?;
+    // Held until return: one daemon at a time.
+    let _lock = service::PidLock::acquire_global()?;
+    let control = if daemon_config.once {
+        None
+    } else {
+        Some(control::ControlServer::bind_global()?)
+    };
 
     // Startup: lifecycle logging + recovery
     startup_recovery(&global_config);
 
     let tmux = TmuxClient::new();
     let engine = TemplateEngine::new();
-    let notifier = make_notifier(&global_config)?;
-    let daemon = Daemon::new(&global_config, &tmux, &engine, &notifier, daemon_config);
+    let mut notifier = make_notifier(&global_config)?;
+    let mut status = control::DaemonStatus::new(daemon_config.interval_seconds);
+    let metrics = Metrics::default();
+    let exporter = MetricsExporter::from_config(&global_config.daemon.metrics)?;
+    let memory = Mutex::new(TickMemory::default());
 
     let quit = Arc::new(AtomicBool::new(false));
     install_signal_handler(&quit);
@@ -2431,8 +3890,17 @@ pub fn run(daemon_config: &DaemonConfig) -> Result<()> {
             if quit.load(Ordering::Relaxed) {
                 return Ok(());
             }
+            let daemon = Daemon::new(&global_config, &tmux, &engine, &notifier, daemon_config)
+                .with_auto_spawn_paused(status.paused)
+                .with_metrics(&metrics)
+                .with_memory(&memory);
+            let started = std::time::Instant::now();
             match daemon.tick_once() {
                 Ok(tick) => {
+                    metrics.record_tick(&tick, started.elapsed());
+                    if let Some(ref exporter) = exporter {
+                        exporter.publish(&metrics);
+                    }
                     if tick.workers_checked > 0 || !tick.errors.is_empty() {
                         eprintln!(
                             "[tick] {} workers, {} actions, {} nudges, {} notifications, {} errors",
@@ -2443,19 +3911,99 @@ pub fn run(daemon_config: &DaemonConfig) -> Result<()> {
                             tick.errors.len(),
                         );
                     }
+                    status.record_tick(&tick);
+                    if let Some(ref control) = control {
+                        control.publish(&tick);
+                    }
                     if daemon_config.once {
                         return Ok(());
                     }
-                    std::thread::sleep(Duration::from_secs(daemon_config.interval_seconds));
                 }
                 Err(e) => {
                     tracing::error!("tick failed: {}", e);
                     if daemon_config.once {
                         return Err(e);
                     }
-                    std::thread::sleep(Duration::from_secs(daemon_config.interval_seconds));
                 }
             }
+
+            // Wait out the interval, answering control requests as they arrive.
+            let deadline =
+                std::time::Instant::now() + Duration::from_secs(daemon_config.interval_seconds);
+            while let Some(cmd) = next_control_command(control.as_ref(), deadline, &quit) {
+                let response = match &cmd.request {
+                    control::ControlRequest::Tick => {
+                        cmd.reply(control::ControlResponse::ok("tick started"));
+                        break;
+                    }
+                    control::ControlRequest::Pause => {
+                        status.paused = true;
+                        control::ControlResponse::ok("auto-spawn paused")
+                    }
+                    control::ControlRequest::Resume => {
+                        status.paused = false;
+                        control::ControlResponse::ok("auto-spawn resumed")
+                    }
+                    control::ControlRequest::Nudge { worker, message } => {
+                        let daemon =
+                            Daemon::new(&global_config, &tmux, &engine, &notifier, daemon_config);
+                        match daemon.nudge_now(worker, message.as_deref()) {
+                            Ok(key) => control::ControlResponse::ok(format!("nudged {}", key)),
+                            Err(e) => control::ControlResponse::error(e.to_string()),
+                        }
+                    }
+                    control::ControlRequest::Kill { worker } => {
+                        let daemon =
+                            Daemon::new(&global_config, &tmux, &engine, &notifier, daemon_config);
+                        match daemon.kill_now(worker) {
+                            Ok(key) => control::ControlResponse::ok(format!("killed {}", key)),
+                            Err(e) => control::ControlResponse::error(e.to_string()),
+                        }
+                    }
+                    control::ControlRequest::ResumeWorker { worker } => {
+                        let daemon =
+                            Daemon::new(&global_config, &tmux, &engine, &notifier, daemon_config);
+                        match daemon.resume_worker_now(worker) {
+                            Ok(key) => control::ControlResponse::ok(format!("resumed {}", key)),
+                            Err(e) => control::ControlResponse::error(e.to_string()),
+                        }
+                    }
+                    control::ControlRequest::Merge {
+                        worker,
+                        strategy,
+                        cleanup,
+                    } => {
+                        let daemon =
+                            Daemon::new(&global_config, &tmux, &engine, &notifier, daemon_config);
+                        match daemon.merge_now(worker, *strategy, *cleanup) {
+                            Ok(summary) => control::ControlResponse::ok(summary),
+                            Err(e) => control::ControlResponse::error(e.to_string()),
+                        }
+                    }
+                    control::ControlRequest::Reload => {
+                        match GlobalConfig::load().and_then(|c| Ok((make_notifier(&c)?, c))) {
+                            Ok((n, c)) => {
+                                notifier = n;
+                                global_config = c;
+                                tracing::info!("reloaded global config");
+                                control::ControlResponse::ok("config reloaded")
+                            }
+                            Err(e) => control::ControlResponse::error(format!(
+                                "reload failed, keeping current config: {}",
+                                e
+                            )),
+                        }
+                    }
+                    control::ControlRequest::Status => {
+                        control::ControlResponse::status(status.clone())
+                    }
+                    // Subscriptions are handled on the connection thread.
+                    control::ControlRequest::Subscribe => {
+                        control::ControlResponse::error("unexpected subscribe")
+                    }
+                };
+                cmd.reply(response);
+            }
         }
     })();
 
@@ -2463,6 +4011,49 @@ pub fn run(daemon_config: &DaemonConfig) -> Result<()> {
     result
 }
 
+/// Wait for the next control request until `deadline`, returning `None` when
+/// the deadline passes or the quit flag is set.
+fn next_control_command(
+    control: Option<&control::ControlServer>,
+    deadline: std::time::Instant,
+    quit: &AtomicBool,
+) -> Option<control::ControlCommand> {
+    loop {
+        if quit.load(Ordering::Relaxed) {
+            return None;
+        }
+        let remaining = deadline.checked_duration_since(std::time::Instant::now())?;
+        let wait = remaining.min(QUIT_POLL);
+        match control {
+            Some(control) => {
+                if let Some(cmd) = control.recv_timeout(wait) {
+                    return Some(cmd);
+                }
+            }
+            None => std::thread::sleep(wait),
+        }
+    }
+}
+
+/// Resolve `repo/worker` or a bare worker name against discovered workers.
+fn find_worker(workers: &[(String, String)], spec: &str) -> Result<(String, String)> {
+    let matches: Vec<&(String, String)> = match spec.split_once('/') {
+        Some((repo, name)) => workers
+            .iter()
+            .filter(|(r, w)| r == repo && w == name)
+            .collect(),
+        None => workers.iter().filter(|(_, w)| w == spec).collect(),
+    };
+    match matches.as_slice() {
+        [one] => Ok((*one).clone()),
+        [] => Err(Error::Custom(format!("no worker named {}", spec))),
+        _ => Err(Error::Custom(format!(
+            "{} matches workers in several repos; use repo/worker",
+            spec
+        ))),
+    }
+}
+
 /// Convert a WorkerEntry (from workers.json) back to a WorkerState for comparison.
 fn entry_to_worker_state(entry: &WorkerEntry) -> WorkerState {
     use crate::worker::WorkerStatus;
@@ -2481,6 +4072,15 @@ fn entry_to_worker_state(entry: &WorkerEntry) -> WorkerState {
         last_event_at: Some(entry.last_event_at),
         parent_issue: None,
         parent_branch: entry.parent_branch.clone(),
+        stacked_on: entry.stacked_on.clone(),
+        stack_base: entry.stack_base.clone(),
+        agent: entry.agent.clone(),
+        model: entry.model.clone(),
+        issue_labels: entry.issue_labels.clone(),
+        branch: None,
+        telemetry: Default::default(),
+        budget_exceeded: None,
+        last_pattern_at: HashMap::new(),
     }
 }
 
@@ -2489,6 +4089,29 @@ mod tests {
     use super::*;
     use std::collections::HashMap;
 
+    #[test]
+    fn find_worker_by_key_or_unique_name() {
+        let workers = vec![
+            ("api".to_string(), "fix-login".to_string()),
+            ("api".to_string(), "docs".to_string()),
+            ("web".to_string(), "docs".to_string()),
+        ];
+        let found = |spec| find_worker(&workers, spec).map_err(|e| e.to_string());
+
+        assert_eq!(
+            found("fix-login"),
+            Ok(("api".to_string(), "fix-login".to_string()))
+        );
+        assert_eq!(
+            found("web/docs"),
+            Ok(("web".to_string(), "docs".to_string()))
+        );
+        assert!(found("docs").unwrap_err().contains("several repos"));
+        assert!(found("api/missing")
+            .unwrap_err()
+            .contains("no worker named"));
+    }
+
     #[test]
     fn entry_to_state_roundtrip() {
         let entry = WorkerEntry {
@@ -2502,7 +4125,12 @@ mod tests {
             nudge_counts: HashMap::new(),
             review_feedback_count: None,
             parent_branch: None,
+            stacked_on: None,
+            stack_base: None,
             last_reviewed_sha: None,
+            agent: None,
+            model: None,
+            issue_labels: vec![],
         };
         let state = entry_to_worker_state(&entry);
         assert_eq!(state.status, crate::worker::WorkerStatus::Running);
@@ -2526,6 +4154,144 @@ mod tests {
         assert!(result.errors.is_empty());
     }
 
+    fn budgeted_worker(input_tokens: u64) -> WorkerState {
+        let mut state = WorkerState::reduce(
+            &[
+                Event::new(EventType::Spawn),
+                Event::new(EventType::Stop)
+                    .with_field("session", "s1")
+                    .with_field("input_tokens", input_tokens),
+            ],
+            &HealthConfig::default(),
+        );
+        state.status = WorkerStatus::Idle;
+        state
+    }
+
+    fn token_budget(worker: Option<u64>, repo: Option<u64>) -> BudgetConfig {
+        let mut config = BudgetConfig::default();
+        config.worker.tokens = worker;
+        config.repo.tokens = repo;
+        config
+    }
+
+    #[test]
+    fn budget_over_worker_limit_stops_and_escalates() {
+        let state = budgeted_worker(1200);
+        let actions = Daemon::budget_actions("w", &state, &token_budget(Some(1000), None), None);
+        assert_eq!(
+            actions,
+            vec![
+                Action::EnforceBudget {
+                    worker_id: "w".to_string(),
+                    reason: "worker budget exceeded: 1200 of 1000 tokens".to_string(),
+                    on_exceed: BudgetAction::Interrupt,
+                },
+                Action::Notify {
+                    worker_id: "w".to_string(),
+                    message: "worker budget exceeded: 1200 of 1000 tokens".to_string(),
+                    kind: NotifyKind::NeedsIntervention,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn budget_over_repo_limit_stops_worker() {
+        let state = budgeted_worker(100);
+        let repo = Spend {
+            tokens: 5000,
+            ..Default::default()
+        };
+        let actions = Daemon::budget_actions(
+            "w",
+            &state,
+            &token_budget(Some(1000), Some(4000)),
+            Some(&repo),
+        );
+        assert!(matches!(
+            &actions[0],
+            Action::EnforceBudget { reason, .. } if reason.starts_with("repo budget exceeded")
+        ));
+    }
+
+    #[test]
+    fn budget_near_limit_warns_once() {
+        let mut state = budgeted_worker(900);
+        let config = token_budget(Some(1000), None);
+        assert_eq!(
+            Daemon::budget_actions("w", &state, &config, None),
+            vec![Action::Nudge {
+                worker_id: "w".to_string(),
+                nudge_type: NudgeType::Budget,
+            }]
+        );
+
+        state.nudge_counts.insert("budget".to_string(), 1);
+        assert!(Daemon::budget_actions("w", &state, &config, None).is_empty());
+    }
+
+    #[test]
+    fn budget_leaves_stopped_and_unbudgeted_workers_alone() {
+        let mut state = budgeted_worker(5000);
+        assert!(Daemon::budget_actions("w", &state, &BudgetConfig::default(), None).is_empty());
+
+        state.budget_exceeded = Some("worker budget exceeded".to_string());
+        let config = token_budget(Some(1000), None);
+        assert!(Daemon::budget_actions("w", &state, &config, None).is_empty());
+    }
+
+    fn stuck_nudge() -> Action {
+        Action::Nudge {
+            worker_id: "w".to_string(),
+            nudge_type: NudgeType::Stuck,
+        }
+    }
+
+    #[test]
+    fn pane_rule_replaces_stuck_nudge() {
+        let rules = patterns::builtin();
+        let mut actions = vec![stuck_nudge()];
+        Daemon::pane_rule_actions(
+            &mut actions,
+            "w",
+            &WorkerState::default(),
+            &rules,
+            "Overwrite existing file? (y/n)",
+            1000,
+        );
+        assert_eq!(actions.len(), 1);
+        assert!(matches!(
+            &actions[0],
+            Action::PaneRule { rule, .. } if rule.name == "yes-no"
+        ));
+    }
+
+    #[test]
+    fn pane_rule_in_cooldown_holds_nudge_back() {
+        let rules = patterns::builtin();
+        let mut state = WorkerState::default();
+        state.last_pattern_at.insert("yes-no".to_string(), 980);
+        let mut actions = vec![stuck_nudge()];
+        Daemon::pane_rule_actions(&mut actions, "w", &state, &rules, "(y/n)", 1000);
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn unmatched_pane_keeps_nudge() {
+        let rules = patterns::builtin();
+        let mut actions = vec![stuck_nudge()];
+        Daemon::pane_rule_actions(
+            &mut actions,
+            "w",
+            &WorkerState::default(),
+            &rules,
+            "$ ",
+            1000,
+        );
+        assert_eq!(actions, vec![stuck_nudge()]);
+    }
+
     #[test]
     fn runtime_config_defaults() {
         let config = RuntimeConfig::default();
@@ -2710,7 +4476,12 @@ mod tests {
                 nudge_counts: HashMap::new(),
                 review_feedback_count: None,
                 parent_branch: None,
+                stacked_on: None,
+                stack_base: None,
                 last_reviewed_sha: None,
+                agent: None,
+                model: None,
+                issue_labels: vec![],
             },
         );
 
@@ -2761,7 +4532,12 @@ mod tests {
             nudge_counts: HashMap::new(),
             review_feedback_count: None,
             parent_branch: None,
+            stacked_on: None,
+            stack_base: None,
             last_reviewed_sha: None,
+            agent: None,
+            model: None,
+            issue_labels: vec![],
         }
     }
 
@@ -22,31 +22,7 @@ pub fn spawn(
             let tmux = TmuxClient::new();
 
             while let Ok(req) = rx.recv() {
-                let target = TmuxTarget::new(&req.session, &req.window);
-                let error = deliver_nudge(&tmux, &target, &req);
-
-                if let Some(ref err) = error {
-                    tracing::warn!(
-                        worker = %req.worker_key,
-                        nudge_type = %req.nudge_type_key,
-                        "nudge delivery failed: {}",
-                        err
-                    );
-                } else {
-                    tracing::info!(
-                        worker = %req.worker_key,
-                        nudge_type = %req.nudge_type_key,
-                        "nudge delivered"
-                    );
-                }
-
-                let resp = NudgeComplete {
-                    worker_key: req.worker_key,
-                    nudge_type_key: req.nudge_type_key,
-                    error,
-                };
-
-                if tx.send(resp).is_err() {
+                if tx.send(deliver(&tmux, req)).is_err() {
                     break;
                 }
             }
@@ -54,6 +30,33 @@ pub fn spawn(
         .expect("failed to spawn nudge actor thread")
 }
 
+/// Deliver one nudge on the calling thread.
+pub fn deliver(tmux: &TmuxClient, req: NudgeRequest) -> NudgeComplete {
+    let target = TmuxTarget::new(&req.session, &req.window);
+    let error = deliver_nudge(tmux, &target, &req);
+
+    if let Some(ref err) = error {
+        tracing::warn!(
+            worker = %req.worker_key,
+            nudge_type = %req.nudge_type_key,
+            "nudge delivery failed: {}",
+            err
+        );
+    } else {
+        tracing::info!(
+            worker = %req.worker_key,
+            nudge_type = %req.nudge_type_key,
+            "nudge delivered"
+        );
+    }
+
+    NudgeComplete {
+        worker_key: req.worker_key,
+        nudge_type_key: req.nudge_type_key,
+        error,
+    }
+}
+
 /// Deliver a single nudge, returning an error string on failure.
 fn deliver_nudge(tmux: &TmuxClient, target: &TmuxTarget, req: &NudgeRequest) -> Option<String> {
     let result = if req.is_stuck {
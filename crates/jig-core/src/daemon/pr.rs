@@ -3,7 +3,7 @@
 use crate::config::ResolvedNudgeConfig;
 use crate::dispatch::{Action, NotifyKind};
 use crate::events::WorkerState;
-use crate::github::GitHubClient;
+use crate::forge::{self, Forge};
 use crate::global::GlobalConfig;
 use crate::registry::RepoRegistry;
 
@@ -27,7 +27,7 @@ pub(crate) struct PrLifecycleResult {
 
 /// Monitors PR state and injects appropriate actions.
 pub(crate) struct PrMonitor<'a, F> {
-    client: &'a GitHubClient,
+    client: &'a dyn Forge,
     config: &'a GlobalConfig,
     resolve: F,
 }
@@ -36,7 +36,7 @@ impl<'a, F> PrMonitor<'a, F>
 where
     F: Fn(&str) -> ResolvedNudgeConfig,
 {
-    pub(super) fn new(client: &'a GitHubClient, config: &'a GlobalConfig, resolve: F) -> Self {
+    pub(super) fn new(client: &'a dyn Forge, config: &'a GlobalConfig, resolve: F) -> Self {
         Self {
             client,
             config,
@@ -230,8 +230,8 @@ where
     }
 }
 
-/// Create a GitHub client for a repo by looking up its path in the registry.
-pub(super) fn make_github_client(repo_name: &str, registry: &RepoRegistry) -> Option<GitHubClient> {
+/// Create the forge client for a repo by looking up its path in the registry.
+pub(super) fn make_forge(repo_name: &str, registry: &RepoRegistry) -> Option<Box<dyn Forge>> {
     registry
         .repos()
         .iter()
@@ -242,9 +242,9 @@ pub(super) fn make_github_client(repo_name: &str, registry: &RepoRegistry) -> Op
                 .unwrap_or(false)
         })
         .and_then(|entry| {
-            GitHubClient::from_repo_path(&entry.path)
+            forge::for_repo(&entry.path)
                 .map_err(|e| {
-                    tracing::info!(repo = repo_name, error = %e, "GitHub client failed");
+                    tracing::info!(repo = repo_name, error = %e, "forge client failed");
                     e
                 })
                 .ok()
@@ -65,7 +65,10 @@ fn prune_single(target: &super::messages::PruneTarget) -> std::result::Result<()
         repo.prune_stale_worktrees();
     }
 
-    // Remove event logs
+    // Remove event logs, keeping their stats
+    if let Ok(log) = crate::events::EventLog::for_worker(&target.repo_name, &target.worker_name) {
+        crate::stats::retire_worker_log(&target.repo_name, &target.worker_name, &log);
+    }
     if let Ok(events_dir) = crate::global::global_state_dir().map(|d| d.join("events")) {
         let sanitized = format!(
             "{}-{}",
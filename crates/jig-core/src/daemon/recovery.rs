@@ -7,7 +7,7 @@ use std::path::Path;
 
 use crate::config::JIG_DIR;
 use crate::error::Result;
-use crate::events::{EventLog, EventType, WorkerState};
+use crate::events::{EventLog, EventType};
 use crate::global::HealthConfig;
 use crate::registry::RepoRegistry;
 use crate::worker::WorkerStatus;
@@ -155,12 +155,9 @@ impl<'a> RecoveryScanner<'a> {
 
         // Must have event log with non-terminal state
         let event_log = EventLog::for_worker(repo_name, &wt.name).ok()?;
-        let events = event_log.read_all().ok()?;
-        if events.is_empty() {
-            return None;
-        }
-
-        let state = WorkerState::reduce(&events, &self.health);
+        let state = event_log.reduce(&self.health).ok()?;
+        // No events yet
+        state.last_event_at?;
 
         // Only recover active workers — skip terminal and "done" states
         if Self::should_recover(state.status) {
@@ -5,7 +5,7 @@
 
 use std::process::{Command, Stdio};
 
-use crate::adapter::get_adapter;
+use crate::adapter::AgentRegistry;
 use crate::review;
 
 use super::messages::{ReviewComplete, ReviewRequest};
@@ -128,7 +128,10 @@ fn run_review_inner(req: &ReviewRequest) -> Result<(), String> {
     );
 
     // Build the ephemeral command
-    let adapter = get_adapter("claude").ok_or("claude adapter not found")?;
+    // Review relies on Claude's tool allowlist; honour any `[agents.claude]` overrides.
+    let adapter = AgentRegistry::load(worktree_path)
+        .and_then(|registry| registry.resolve("claude"))
+        .map_err(|e| e.to_string())?;
     let cmd = adapter.build_ephemeral_command(
         &prompt,
         &[
@@ -1,8 +1,10 @@
 //! DaemonRuntime — owns actor channels and thread handles.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::{Deserialize, Serialize};
 
 use crate::context::RepoContext;
 use crate::registry::RepoRegistry;
@@ -10,12 +12,12 @@ use crate::registry::RepoRegistry;
 use super::messages::*;
 use super::triage_tracker::TriageTracker;
 use super::{
-    github_actor, issue_actor, nudge_actor, prune_actor, review_actor, spawn_actor, sync_actor,
-    triage_actor,
+    github_actor, issue_actor, merge_actor, nudge_actor, prune_actor, review_actor, spawn_actor,
+    sync_actor, triage_actor,
 };
 
 /// Timer info for display in the ps watch footer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimerInfo {
     /// Seconds until the next git sync fires.
     pub sync_remaining: u64,
@@ -23,6 +25,71 @@ pub struct TimerInfo {
     pub poll_remaining: Option<u64>,
 }
 
+/// How often in-flight workers are checked for overlapping files.
+const OVERLAP_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+/// What the checks run on the tick thread remember between ticks.
+/// [`DaemonRuntime`] owns one; `jig daemon` keeps one across the `Daemon`
+/// it builds for each tick.
+#[derive(Debug, Default)]
+pub struct TickMemory {
+    last_overlap_check: Option<Instant>,
+    /// Overlapping worker pairs already warned about.
+    reported_overlaps: HashSet<String>,
+    /// Stacked workers whose restack conflicted, keyed by worker key, with the
+    /// lower head that conflicted. Escalated once per lower head.
+    restack_conflicts: HashMap<String, String>,
+    /// Size and mtime of each worker's event log when its spend was last
+    /// recorded, keyed by worker key.
+    spend_logs: HashMap<String, (u64, Option<SystemTime>)>,
+}
+
+impl TickMemory {
+    /// Whether the periodic overlap check is due. Resets the timer when it is.
+    pub fn overlap_check_due(&mut self) -> bool {
+        if self
+            .last_overlap_check
+            .is_some_and(|last| last.elapsed() < OVERLAP_CHECK_INTERVAL)
+        {
+            return false;
+        }
+        self.last_overlap_check = Some(Instant::now());
+        true
+    }
+
+    /// Record the overlapping pairs found by a check and return those not
+    /// reported before. Pairs that stopped overlapping are forgotten, so they
+    /// are reported again if they recur.
+    pub fn new_overlaps(&mut self, current: HashSet<String>) -> HashSet<String> {
+        let new = current
+            .difference(&self.reported_overlaps)
+            .cloned()
+            .collect();
+        self.reported_overlaps = current;
+        new
+    }
+
+    /// Whether restacking a worker onto `head` already conflicted.
+    pub fn restack_blocked(&self, worker_key: &str, head: &str) -> bool {
+        self.restack_conflicts.get(worker_key).map(String::as_str) == Some(head)
+    }
+
+    /// Whether a worker's event log was written since its spend was last
+    /// recorded. Remembers the log's size and mtime for next time.
+    pub fn spend_log_changed(&mut self, worker_key: &str, log: &std::path::Path) -> bool {
+        let seen = std::fs::metadata(log)
+            .map(|m| (m.len(), m.modified().ok()))
+            .unwrap_or_default();
+        self.spend_logs.insert(worker_key.to_string(), seen) != Some(seen)
+    }
+
+    /// Remember that restacking a worker onto `head` conflicted.
+    pub fn block_restack(&mut self, worker_key: &str, head: &str) {
+        self.restack_conflicts
+            .insert(worker_key.to_string(), head.to_string());
+    }
+}
+
 /// Runtime configuration for the daemon actors.
 #[derive(Debug, Clone)]
 pub struct RuntimeConfig {
@@ -53,9 +120,13 @@ pub struct DaemonRuntime {
     last_sync: Instant,
 
     // GitHub actor
-    github_tx: flume::Sender<GitHubRequest>,
-    github_rx: flume::Receiver<GitHubResponse>,
+    github_tx: flume::Sender<Vec<GitHubRequest>>,
+    github_rx: flume::Receiver<GitHubBatch>,
     github_cache: HashMap<String, GitHubResponse>,
+    /// Forge query latencies received since the last `take_github_latencies`.
+    github_latencies: Vec<Duration>,
+    /// Requests collected this tick, sent as one batch by `flush_pr_checks`.
+    github_pending: Vec<GitHubRequest>,
     /// When each worker's GitHub request was last queued, to throttle API calls.
     github_last_requested: HashMap<String, Instant>,
 
@@ -91,6 +162,14 @@ pub struct DaemonRuntime {
     triage_rx: flume::Receiver<TriageComplete>,
     triage_pending: bool,
 
+    // Merge actor
+    merge_tx: flume::Sender<MergeRequest>,
+    merge_rx: flume::Receiver<MergeComplete>,
+    merge_pending: bool,
+
+    /// State of the checks that run on the tick thread.
+    memory: TickMemory,
+
     config: RuntimeConfig,
 
     /// Tracks in-flight triage workers to prevent duplicate spawns.
@@ -110,8 +189,8 @@ impl DaemonRuntime {
         let (sync_resp_tx, sync_resp_rx) = flume::bounded(1);
         let sync_handle = sync_actor::spawn(sync_req_rx, sync_resp_tx);
 
-        let (gh_req_tx, gh_req_rx) = flume::bounded(16);
-        let (gh_resp_tx, gh_resp_rx) = flume::bounded(16);
+        let (gh_req_tx, gh_req_rx) = flume::bounded(4);
+        let (gh_resp_tx, gh_resp_rx) = flume::bounded(4);
         let gh_handle = github_actor::spawn(gh_req_rx, gh_resp_tx);
 
         let (issue_req_tx, issue_req_rx) = flume::bounded(1);
@@ -138,6 +217,10 @@ impl DaemonRuntime {
         let (triage_resp_tx, triage_resp_rx) = flume::bounded(1);
         let triage_handle = triage_actor::spawn(triage_req_rx, triage_resp_tx);
 
+        let (merge_req_tx, merge_req_rx) = flume::bounded(1);
+        let (merge_resp_tx, merge_resp_rx) = flume::bounded(1);
+        let merge_handle = merge_actor::spawn(merge_req_rx, merge_resp_tx);
+
         // Start with past timestamps so first tick triggers sync/poll immediately
         let past = Instant::now();
 
@@ -150,6 +233,8 @@ impl DaemonRuntime {
             github_tx: gh_req_tx,
             github_rx: gh_resp_rx,
             github_cache: HashMap::new(),
+            github_latencies: Vec::new(),
+            github_pending: Vec::new(),
             github_last_requested: HashMap::new(),
 
             issue_tx: issue_req_tx,
@@ -177,6 +262,12 @@ impl DaemonRuntime {
             triage_rx: triage_resp_rx,
             triage_pending: false,
 
+            merge_tx: merge_req_tx,
+            merge_rx: merge_resp_rx,
+            merge_pending: false,
+
+            memory: TickMemory::default(),
+
             config,
             triage_tracker: TriageTracker::new(),
             first_poll_done: false,
@@ -189,6 +280,7 @@ impl DaemonRuntime {
                 nudge_handle,
                 review_handle,
                 triage_handle,
+                merge_handle,
             ],
         }
     }
@@ -253,10 +345,11 @@ impl DaemonRuntime {
         }
     }
 
-    /// Send a PR check request to the GitHub actor (non-blocking).
+    /// Queue a PR check request for the GitHub actor.
     ///
-    /// Throttles requests to once per 60s per worker to align with `gh api --cache 60s`.
-    /// This prevents spamming the GitHub API on every 2-second tick.
+    /// Requests are held until [`flush_pr_checks`](Self::flush_pr_checks) so a
+    /// tick's worth of workers goes out as one batch. Throttles to once per
+    /// 60s per worker to prevent spamming the GitHub API on every 2-second tick.
     pub fn request_pr_check(
         &mut self,
         worker_key: &str,
@@ -271,35 +364,60 @@ impl DaemonRuntime {
                 return;
             }
         }
+        if self
+            .github_pending
+            .iter()
+            .any(|r| r.worker_key == worker_key)
+        {
+            return;
+        }
 
         let previous_is_draft = self
             .github_cache
             .get(worker_key)
             .map(|r| r.is_draft)
             .unwrap_or(false);
-        if self
-            .github_tx
-            .try_send(GitHubRequest {
-                worker_key: worker_key.to_string(),
-                repo_name: repo_name.to_string(),
-                branch: branch.to_string(),
-                pr_url: pr_url.map(|s| s.to_string()),
-                previous_is_draft,
-            })
-            .is_ok()
-        {
-            self.github_last_requested
-                .insert(worker_key.to_string(), Instant::now());
+        self.github_pending.push(GitHubRequest {
+            worker_key: worker_key.to_string(),
+            repo_name: repo_name.to_string(),
+            branch: branch.to_string(),
+            pr_url: pr_url.map(|s| s.to_string()),
+            previous_is_draft,
+        });
+    }
+
+    /// Send this tick's queued PR check requests to the GitHub actor as one
+    /// batch (non-blocking). If the actor is backed up the batch is dropped
+    /// and the workers are retried next tick.
+    pub fn flush_pr_checks(&mut self) {
+        if self.github_pending.is_empty() {
+            return;
+        }
+        let batch = std::mem::take(&mut self.github_pending);
+        let keys: Vec<String> = batch.iter().map(|r| r.worker_key.clone()).collect();
+        if self.github_tx.try_send(batch).is_ok() {
+            let now = Instant::now();
+            for key in keys {
+                self.github_last_requested.insert(key, now);
+            }
         }
     }
 
     /// Drain all pending GitHub responses into the cache (non-blocking).
     pub fn drain_github(&mut self) {
-        while let Ok(resp) = self.github_rx.try_recv() {
-            self.github_cache.insert(resp.worker_key.clone(), resp);
+        while let Ok(batch) = self.github_rx.try_recv() {
+            for resp in batch.responses {
+                self.github_cache.insert(resp.worker_key.clone(), resp);
+            }
+            self.github_latencies.extend(batch.latencies);
         }
     }
 
+    /// Take the forge query latencies drained since the last call.
+    pub fn take_github_latencies(&mut self) -> Vec<Duration> {
+        std::mem::take(&mut self.github_latencies)
+    }
+
     /// Get cached PR info for a worker.
     pub fn get_cached_pr(&self, worker_key: &str) -> Option<&GitHubResponse> {
         self.github_cache.get(worker_key)
@@ -514,6 +632,40 @@ impl DaemonRuntime {
         self.triage_pending
     }
 
+    /// Send the head of the merge queue to the merge actor (non-blocking).
+    /// Returns whether it was sent.
+    pub fn send_merge(&mut self, req: MergeRequest) -> bool {
+        if self.merge_pending {
+            return false;
+        }
+        if self.merge_tx.try_send(req).is_ok() {
+            self.merge_pending = true;
+            tracing::debug!("triggered merge");
+        }
+        self.merge_pending
+    }
+
+    /// Drain a completed merge response (non-blocking).
+    pub fn drain_merge(&mut self) -> Option<MergeComplete> {
+        match self.merge_rx.try_recv() {
+            Ok(result) => {
+                self.merge_pending = false;
+                Some(result)
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Whether a merge is currently in flight.
+    pub fn merge_pending(&self) -> bool {
+        self.merge_pending
+    }
+
+    /// State kept across ticks by the checks on the tick thread.
+    pub fn memory(&mut self) -> &mut TickMemory {
+        &mut self.memory
+    }
+
     /// Get runtime config reference.
     pub fn config(&self) -> &RuntimeConfig {
         &self.config
@@ -529,6 +681,24 @@ impl DaemonRuntime {
         self.first_poll_done = true;
     }
 
+    /// Requests waiting in each actor's queue, for metrics.
+    pub fn queue_depths(&self) -> Vec<(String, usize)> {
+        [
+            ("sync", self.sync_tx.len()),
+            ("github", self.github_tx.len()),
+            ("issue", self.issue_tx.len()),
+            ("prune", self.prune_tx.len()),
+            ("spawn", self.spawn_tx.len()),
+            ("nudge", self.nudge_tx.len()),
+            ("review", self.review_tx.len()),
+            ("triage", self.triage_tx.len()),
+            ("merge", self.merge_tx.len()),
+        ]
+        .into_iter()
+        .map(|(actor, depth)| (actor.to_string(), depth))
+        .collect()
+    }
+
     /// Compute timer info for display.
     pub fn timer_info(&self) -> TimerInfo {
         let sync_elapsed = self.last_sync.elapsed().as_secs();
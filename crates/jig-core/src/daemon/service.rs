@@ -0,0 +1,395 @@
+//! Running the daemon as a background service — pidfile lock, start/stop,
+//! log rotation, and the systemd user unit.
+//!
+//! A running daemon holds an exclusive lock on `~/.config/jig/state/daemon.pid`
+//! for its whole lifetime, so a second daemon cannot start and race on
+//! `workers.json`. The lock, not the pid, is the source of truth: a pidfile
+//! left behind by a crash is unlocked and simply gets overwritten.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+use crate::global::{daemon_output_path, daemon_pid_path};
+
+use super::lifecycle::{DaemonEvent, DaemonLifecycleLog};
+
+/// Rotate a log once it grows past this size.
+pub const LOG_MAX_BYTES: u64 = 1024 * 1024;
+/// Rotated copies kept (`.1` is the newest).
+pub const LOG_KEEP: usize = 3;
+
+/// How long `PidLock::acquire` keeps retrying a lock held by a status probe.
+const PROBE_GRACE: Duration = Duration::from_millis(200);
+/// How long `start` waits for the new daemon to take the lock.
+const START_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long `stop` waits for the daemon to finish its tick and exit.
+pub const STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Exclusive lock on the pidfile, held while the daemon runs.
+#[derive(Debug)]
+pub struct PidLock {
+    // Dropping the file releases the lock.
+    file: File,
+}
+
+impl PidLock {
+    /// Take the global daemon lock.
+    pub fn acquire_global() -> Result<Self> {
+        Self::acquire(&daemon_pid_path()?)
+    }
+
+    /// Take the lock at `path` and record our pid, or fail if another
+    /// process holds it.
+    pub fn acquire(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        // `running_pid` probes with a brief shared lock (e.g. `start` polling
+        // for us), so a lock held by a process that isn't a daemon gets a
+        // moment to clear.
+        let deadline = Instant::now() + PROBE_GRACE;
+        loop {
+            match file.try_lock() {
+                Ok(()) => break,
+                Err(fs::TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(fs::TryLockError::WouldBlock) => {
+                    let pid = read_pid(&mut file)
+                        .map(|p| format!(" (pid {})", p))
+                        .unwrap_or_default();
+                    return Err(Error::Custom(format!("daemon already running{}", pid)));
+                }
+                Err(fs::TryLockError::Error(e)) => return Err(e.into()),
+            }
+        }
+
+        file.set_len(0)?;
+        writeln!(file, "{}", std::process::id())?;
+        file.flush()?;
+
+        Ok(Self { file })
+    }
+}
+
+impl Drop for PidLock {
+    // Clear our pid but keep the file: unlinking it while locked would let
+    // the next daemon lock a fresh file at the same path while a process
+    // that opened the old one still locks that.
+    fn drop(&mut self) {
+        let _ = self.file.set_len(0);
+    }
+}
+
+fn read_pid(file: &mut File) -> Option<u32> {
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    content.trim().parse().ok()
+}
+
+/// Pid of the daemon holding the lock at `path`, if one is running.
+pub fn running_pid(path: &Path) -> Option<u32> {
+    let mut file = File::open(path).ok()?;
+    match file.try_lock_shared() {
+        // Nobody holds it: a leftover from a crash.
+        Ok(()) => None,
+        Err(fs::TryLockError::WouldBlock) => read_pid(&mut file),
+        Err(fs::TryLockError::Error(_)) => None,
+    }
+}
+
+/// What `jig daemon status` reports.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ServiceStatus {
+    pub pid: Option<u32>,
+    /// When the running daemon started.
+    pub started_at: Option<i64>,
+    /// When the last daemon stopped, and why.
+    pub stopped_at: Option<i64>,
+    pub stop_reason: Option<String>,
+    /// The last daemon exited without logging a stop (only when none is running).
+    pub previous_run_crashed: bool,
+    pub log_path: Option<PathBuf>,
+}
+
+impl ServiceStatus {
+    /// Status of the global daemon.
+    pub fn global() -> Result<Self> {
+        Ok(Self::from_parts(
+            running_pid(&daemon_pid_path()?),
+            &DaemonLifecycleLog::global()?,
+            Some(daemon_output_path()?),
+        ))
+    }
+
+    fn from_parts(pid: Option<u32>, log: &DaemonLifecycleLog, log_path: Option<PathBuf>) -> Self {
+        let mut status = Self {
+            pid,
+            log_path,
+            ..Default::default()
+        };
+        match log.last_event().ok().flatten() {
+            Some(DaemonEvent::Started { ts, .. }) if pid.is_some() => status.started_at = Some(ts),
+            Some(DaemonEvent::Started { .. }) => status.previous_run_crashed = true,
+            Some(DaemonEvent::Stopped { ts, reason, .. }) => {
+                status.stopped_at = Some(ts);
+                status.stop_reason = Some(reason);
+            }
+            None => {}
+        }
+        status
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.pid.is_some()
+    }
+}
+
+/// Start `jig daemon` detached from the terminal, with output appended to
+/// `daemon.log`. `args` are passed after `daemon` (e.g. `--interval 10`).
+/// Returns the new daemon's pid once it holds the lock.
+pub fn start(args: &[String]) -> Result<u32> {
+    let pid_path = daemon_pid_path()?;
+    if let Some(pid) = running_pid(&pid_path) {
+        return Err(Error::Custom(format!(
+            "daemon already running (pid {})",
+            pid
+        )));
+    }
+
+    let log_path = daemon_output_path()?;
+    if let Some(parent) = log_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    rotate_log(&log_path, LOG_MAX_BYTES, LOG_KEEP)?;
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)?;
+
+    let mut command = Command::new(std::env::current_exe()?);
+    command
+        .arg("daemon")
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(log.try_clone()?)
+        .stderr(log);
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Own process group, so Ctrl+C in the launching terminal doesn't reach it.
+        command.process_group(0);
+    }
+    let mut child = command.spawn()?;
+
+    let deadline = Instant::now() + START_TIMEOUT;
+    loop {
+        if running_pid(&pid_path) == Some(child.id()) {
+            return Ok(child.id());
+        }
+        if let Some(exit) = child.try_wait()? {
+            return Err(Error::Custom(format!(
+                "daemon exited during startup ({}); see {}",
+                exit,
+                log_path.display()
+            )));
+        }
+        if Instant::now() >= deadline {
+            return Err(Error::Custom(format!(
+                "daemon did not start within {}s; see {}",
+                START_TIMEOUT.as_secs(),
+                log_path.display()
+            )));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Ask the running daemon to shut down (SIGTERM) and wait for it to release
+/// the lock. Returns the stopped pid, or `None` if no daemon was running.
+pub fn stop(timeout: Duration) -> Result<Option<u32>> {
+    let pid_path = daemon_pid_path()?;
+    let Some(pid) = running_pid(&pid_path) else {
+        return Ok(None);
+    };
+
+    terminate(pid)?;
+
+    let deadline = Instant::now() + timeout;
+    while running_pid(&pid_path).is_some() {
+        if Instant::now() >= deadline {
+            return Err(Error::Custom(format!(
+                "daemon (pid {}) did not stop within {}s",
+                pid,
+                timeout.as_secs()
+            )));
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    Ok(Some(pid))
+}
+
+#[cfg(unix)]
+fn terminate(pid: u32) -> Result<()> {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    kill(Pid::from_raw(pid as i32), Signal::SIGTERM)
+        .map_err(|e| Error::Custom(format!("failed to signal daemon (pid {}): {}", pid, e)))
+}
+
+#[cfg(not(unix))]
+fn terminate(pid: u32) -> Result<()> {
+    Err(Error::Custom(format!(
+        "stopping the daemon (pid {}) is only supported on unix",
+        pid
+    )))
+}
+
+/// Rotate `path` to `path.1` (shifting older copies up to `path.<keep>`)
+/// once it exceeds `max_bytes`.
+pub fn rotate_log(path: &Path, max_bytes: u64, keep: usize) -> Result<()> {
+    let size = match fs::metadata(path) {
+        Ok(m) => m.len(),
+        Err(_) => return Ok(()),
+    };
+    if size <= max_bytes || keep == 0 {
+        return Ok(());
+    }
+
+    let rotated = |n: usize| {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    };
+    let _ = fs::remove_file(rotated(keep));
+    for n in (1..keep).rev() {
+        let from = rotated(n);
+        if from.exists() {
+            fs::rename(&from, rotated(n + 1))?;
+        }
+    }
+    fs::rename(path, rotated(1))?;
+    Ok(())
+}
+
+/// `~/.config/systemd/user/jig-daemon.service`
+pub fn systemd_unit_path() -> Result<PathBuf> {
+    dirs::config_dir()
+        .map(|d| d.join("systemd").join("user").join("jig-daemon.service"))
+        .ok_or_else(|| Error::Custom("could not determine config directory".to_string()))
+}
+
+/// A systemd user unit running `exe daemon` in the foreground.
+pub fn systemd_unit(exe: &Path) -> String {
+    format!(
+        "[Unit]\n\
+         Description=jig daemon\n\
+         \n\
+         [Service]\n\
+         ExecStart={} daemon\n\
+         Restart=on-failure\n\
+         \n\
+         [Install]\n\
+         WantedBy=default.target\n",
+        exe.display()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pid_lock_is_exclusive() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.pid");
+
+        let lock = PidLock::acquire(&path).unwrap();
+        assert_eq!(running_pid(&path), Some(std::process::id()));
+
+        let err = PidLock::acquire(&path).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("already running (pid {})", std::process::id())));
+
+        drop(lock);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+        assert_eq!(running_pid(&path), None);
+        PidLock::acquire(&path).unwrap();
+    }
+
+    #[test]
+    fn stale_pidfile_is_not_running() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.pid");
+        fs::write(&path, "999999\n").unwrap();
+
+        assert_eq!(running_pid(&path), None);
+        let _lock = PidLock::acquire(&path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap().trim(),
+            std::process::id().to_string()
+        );
+    }
+
+    #[test]
+    fn status_reports_crash_only_when_stopped() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = DaemonLifecycleLog::at(tmp.path().join("daemon.jsonl"));
+        log.record_started().unwrap();
+
+        let running = ServiceStatus::from_parts(Some(42), &log, None);
+        assert!(running.is_running());
+        assert!(running.started_at.is_some());
+        assert!(!running.previous_run_crashed);
+
+        let crashed = ServiceStatus::from_parts(None, &log, None);
+        assert!(crashed.previous_run_crashed);
+
+        log.record_stopped("normal").unwrap();
+        let stopped = ServiceStatus::from_parts(None, &log, None);
+        assert!(!stopped.previous_run_crashed);
+        assert_eq!(stopped.stop_reason.as_deref(), Some("normal"));
+    }
+
+    #[test]
+    fn rotate_log_shifts_copies() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("daemon.log");
+
+        fs::write(&path, "small").unwrap();
+        rotate_log(&path, 10, 2).unwrap();
+        assert!(path.exists());
+
+        for content in ["first log!!", "second log!", "third log!!"] {
+            fs::write(&path, content).unwrap();
+            rotate_log(&path, 10, 2).unwrap();
+        }
+        assert!(!path.exists());
+        let read = |n| fs::read_to_string(tmp.path().join(format!("daemon.log.{}", n))).unwrap();
+        assert_eq!(read(1), "third log!!");
+        assert_eq!(read(2), "second log!");
+        assert!(!tmp.path().join("daemon.log.3").exists());
+    }
+
+    #[test]
+    fn systemd_unit_runs_daemon() {
+        let unit = systemd_unit(Path::new("/usr/local/bin/jig"));
+        assert!(unit.contains("ExecStart=/usr/local/bin/jig daemon\n"));
+        assert!(unit.contains("WantedBy=default.target"));
+    }
+}
@@ -68,6 +68,8 @@ fn spawn_single(issue: &SpawnableIssue) -> std::result::Result<(), String> {
         worker_name: &issue.worker_name,
         provider_kind: issue.provider_kind,
         kind: issue.kind,
+        agent: issue.issue.agent.as_deref(),
+        model: issue.issue.model.as_deref(),
     };
     spawn::spawn_worker_for_issue(&input)
 }
@@ -103,6 +103,9 @@ mod tests {
                 labels: vec![],
                 branch_name: None,
                 parent: None,
+                agent: None,
+                model: None,
+                stacked_on: None,
             },
             worker_name: worker.to_string(),
             provider_kind: ProviderKind::Linear,
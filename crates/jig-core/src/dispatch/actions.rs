@@ -1,5 +1,6 @@
 //! Action types for the dispatch system.
 
+use crate::config::{BudgetAction, PanePattern};
 use crate::nudge::NudgeType;
 
 /// Distinguishes the semantic meaning of a notification so that the executor
@@ -42,4 +43,17 @@ pub enum Action {
 
     /// Mark a linked issue as Complete after PR merge.
     UpdateIssueStatus { worker_id: String, issue_id: String },
+
+    /// Interrupt or stop a worker that went over its budget.
+    EnforceBudget {
+        worker_id: String,
+        reason: String,
+        on_exceed: BudgetAction,
+    },
+
+    /// Answer what a worker's pane shows it is stuck on.
+    PaneRule {
+        worker_id: String,
+        rule: PanePattern,
+    },
 }
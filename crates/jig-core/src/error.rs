@@ -53,6 +53,9 @@ pub enum Error {
     #[error("Worker '{0}' failed during setup: {1}")]
     WorkerSetupFailed(String, String),
 
+    #[error("Unknown agent '{0}'. Known agents: {1}")]
+    UnknownAgent(String, String),
+
     #[error("Merge conflict with branch '{0}'")]
     MergeConflict(String),
 
@@ -80,6 +83,12 @@ pub enum Error {
     #[error("Linear API error: {0}")]
     Linear(String),
 
+    #[error("GitHub API error: {0}")]
+    GitHub(String),
+
+    #[error("GitLab API error: {0}")]
+    GitLab(String),
+
     #[error("{0}")]
     Custom(String),
 }
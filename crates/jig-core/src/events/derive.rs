@@ -46,7 +46,12 @@ pub fn derive_status(events: &[Event], config: &HealthConfig) -> WorkerStatus {
         EventType::Initializing => WorkerStatus::Initializing,
         EventType::Spawn | EventType::Resume => WorkerStatus::Spawned,
         EventType::Review => WorkerStatus::WaitingReview,
-        EventType::CiStatus | EventType::Nudge => WorkerStatus::Running,
+        EventType::CiStatus
+        | EventType::Nudge
+        | EventType::BudgetExceeded
+        | EventType::PaneMatch
+        | EventType::AutoRebase
+        | EventType::Restack => WorkerStatus::Running,
         EventType::Terminal => WorkerStatus::Archived,
     }
 }
@@ -123,6 +128,7 @@ mod tests {
     fn derive_stalled_from_silence() {
         let old_ts = chrono::Utc::now().timestamp() - 600; // 10 min ago
         let events = vec![Event {
+            v: 1,
             ts: old_ts,
             event_type: EventType::ToolUseEnd,
             data: serde_json::Value::Object(serde_json::Map::new()),
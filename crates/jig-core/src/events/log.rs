@@ -1,19 +1,57 @@
 //! JSONL event log reader/writer.
+//!
+//! Each worker directory holds the active `events.jsonl`, archived segments
+//! (`events.<millis>.jsonl`, or `events.<millis>-<n>.jsonl` when several are
+//! rotated within a millisecond) rotated out of it, and `snapshot.json`, the state
+//! reduced so far (see [`super::snapshot`]).
 
 use std::fs::{self, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
 use crate::error::Result;
-use crate::global::worker_events_dir;
+use crate::global::{global_state_dir, worker_events_dir, HealthConfig};
 
-use super::Event;
+use super::snapshot::Snapshot;
+use super::{Event, WorkerState, SCHEMA_VERSION};
+
+/// Rotate the active log into an archive once it grows past this size.
+pub const MAX_LOG_BYTES: u64 = 2 * 1024 * 1024;
 
 /// Append-only JSONL event log for a worker.
 pub struct EventLog {
     path: PathBuf,
 }
 
+/// A log line that doesn't parse as a supported [`Event`].
+#[derive(Debug, Clone)]
+pub struct InvalidLine {
+    pub path: PathBuf,
+    /// 1-based line number.
+    pub line: usize,
+    pub error: String,
+    pub content: String,
+}
+
+/// Result of [`EventLog::validate`].
+#[derive(Debug, Clone, Default)]
+pub struct Validation {
+    /// Lines that parsed.
+    pub valid: usize,
+    /// Parsed lines written before events carried a schema version.
+    pub unversioned: usize,
+    pub invalid: Vec<InvalidLine>,
+}
+
+/// What [`EventLog::compact`] did.
+#[derive(Debug, Clone, Default)]
+pub struct CompactStats {
+    /// Bytes moved from the active log into a new archive (0 if it was empty).
+    pub rotated_bytes: u64,
+    /// Archives on disk afterwards.
+    pub archives: usize,
+}
+
 impl EventLog {
     /// Create an event log for a worker, using the global state directory.
     ///
@@ -31,6 +69,60 @@ impl EventLog {
         Self { path }
     }
 
+    /// Every worker event log in the global state directory, keyed by
+    /// directory name (`<repo>-<worker>`).
+    pub fn all() -> Result<Vec<(String, Self)>> {
+        let root = global_state_dir()?.join("events");
+        let entries = match fs::read_dir(&root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut logs: Vec<(String, Self)> = entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| {
+                (
+                    e.file_name().to_string_lossy().to_string(),
+                    Self::new(e.path().join("events.jsonl")),
+                )
+            })
+            .collect();
+        logs.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(logs)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn snapshot_path(&self) -> PathBuf {
+        self.path.with_file_name("snapshot.json")
+    }
+
+    /// Archived segments, oldest first.
+    pub fn archives(&self) -> Result<Vec<PathBuf>> {
+        let Some(dir) = self.path.parent() else {
+            return Ok(Vec::new());
+        };
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut archives: Vec<((u64, u64), PathBuf)> = entries
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                let name = e.file_name().to_string_lossy().to_string();
+                let stamp = name.strip_prefix("events.")?.strip_suffix(".jsonl")?;
+                let (millis, n) = stamp.split_once('-').unwrap_or((stamp, "0"));
+                Some(((millis.parse().ok()?, n.parse().ok()?), e.path()))
+            })
+            .collect();
+        archives.sort();
+        Ok(archives.into_iter().map(|(_, p)| p).collect())
+    }
+
     /// Append a single event to the log file.
     pub fn append(&self, event: &Event) -> Result<()> {
         if let Some(parent) = self.path.parent() {
@@ -45,21 +137,189 @@ impl EventLog {
         Ok(())
     }
 
-    /// Read all events from the log. Returns empty vec if file doesn't exist.
+    /// Read all events, archives first. Returns empty vec if there are none.
+    ///
+    /// This parses the whole history; to get the worker's state use
+    /// [`EventLog::reduce`], which only reads what's new.
     pub fn read_all(&self) -> Result<Vec<Event>> {
-        if !self.path.exists() {
-            return Ok(Vec::new());
+        Ok(self.read_history()?.0)
+    }
+
+    /// All events, archives first, with the active-log offset to continue
+    /// from with [`EventLog::read_from`]. A trailing partial line (an append
+    /// in progress) is left for the next read.
+    pub fn read_history(&self) -> Result<(Vec<Event>, u64)> {
+        let mut events = Vec::new();
+        for archive in self.archives()? {
+            read_into(&archive, &mut events)?;
         }
-        let file = fs::File::open(&self.path)?;
-        let reader = BufReader::new(file);
+        let offset = read_complete(&self.path, 0, &mut events)?;
+        Ok((events, offset))
+    }
+
+    /// Read complete events appended to the active log after byte `offset`,
+    /// returning them with the offset to resume from. If the log was rotated
+    /// since, the rest of the newest archive is read first.
+    pub fn read_from(&self, offset: u64) -> Result<(Vec<Event>, u64)> {
+        let len = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
         let mut events = Vec::new();
-        for line in reader.lines() {
-            let line = line?;
-            if !line.is_empty() {
-                events.push(serde_json::from_str(&line)?);
+        let mut from = offset;
+        if len < offset {
+            if let Some(archive) = self.archives()?.pop() {
+                read_complete(&archive, offset, &mut events)?;
             }
+            from = 0;
         }
-        Ok(events)
+        let next = read_complete(&self.path, from, &mut events)?;
+        Ok((events, next))
+    }
+
+    /// Reduce the log into a `WorkerState`.
+    ///
+    /// Starts from the snapshot and folds only events appended since, then
+    /// saves the new snapshot. The active log is rotated into an archive
+    /// once it passes [`MAX_LOG_BYTES`].
+    pub fn reduce(&self, config: &HealthConfig) -> Result<WorkerState> {
+        let snapshot = self.refresh_snapshot()?;
+        let mut state = snapshot.state;
+        state.check_silence(config);
+        Ok(state)
+    }
+
+    /// Bring the snapshot up to date (rebuilding it if missing or stale) and
+    /// rotate an oversized log.
+    fn refresh_snapshot(&self) -> Result<Snapshot> {
+        let snapshot_path = self.snapshot_path();
+        let (mut snapshot, mut changed) = match Snapshot::load(&snapshot_path) {
+            Some(s) if s.matches(&self.path) => (s, false),
+            _ => {
+                let mut s = Snapshot::default();
+                for archive in self.archives()? {
+                    s.fold(&archive, 0)?;
+                }
+                s.offset = 0;
+                s.tail.clear();
+                (s, true)
+            }
+        };
+
+        let from = snapshot.offset;
+        snapshot.fold(&self.path, from)?;
+        changed |= snapshot.offset != from;
+
+        if snapshot.offset > MAX_LOG_BYTES {
+            self.rotate(&mut snapshot)?;
+            changed = true;
+        }
+        if changed && snapshot.events > 0 {
+            snapshot.save(&snapshot_path)?;
+        }
+        Ok(snapshot)
+    }
+
+    /// Move the active log into a new archive, folding anything appended
+    /// after the snapshot was taken.
+    fn rotate(&self, snapshot: &mut Snapshot) -> Result<()> {
+        let archive = self.reserve_archive()?;
+        fs::rename(&self.path, &archive)?;
+        // Recreate the active log without truncating anything a hook may
+        // have appended since the rename.
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let from = snapshot.offset;
+        snapshot.fold(&archive, from)?;
+        snapshot.offset = 0;
+        snapshot.tail.clear();
+        Ok(())
+    }
+
+    /// Claim an unused archive name by creating it empty, so two rotations
+    /// in the same millisecond never overwrite each other.
+    fn reserve_archive(&self) -> Result<PathBuf> {
+        let millis = chrono::Utc::now().timestamp_millis();
+        for n in 0.. {
+            let name = match n {
+                0 => format!("events.{}.jsonl", millis),
+                n => format!("events.{}-{}.jsonl", millis, n),
+            };
+            let path = self.path.with_file_name(name);
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(path),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        unreachable!("archive names are unbounded")
+    }
+
+    /// Bring the snapshot up to date and rotate the active log regardless of
+    /// size, so the next reduce starts from an empty log. No events are
+    /// dropped: archives keep the full history.
+    pub fn compact(&self) -> Result<CompactStats> {
+        let mut stats = CompactStats::default();
+
+        let mut snapshot = self.refresh_snapshot()?;
+        let len = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if len > 0 {
+            self.rotate(&mut snapshot)?;
+            snapshot.save(&self.snapshot_path())?;
+            stats.rotated_bytes = len;
+        }
+
+        stats.archives = self.archives()?.len();
+        Ok(stats)
+    }
+
+    /// Check every line of the archives and active log against the event
+    /// schema. A trailing line without a newline is an append in progress
+    /// and isn't checked.
+    pub fn validate(&self) -> Result<Validation> {
+        let mut report = Validation::default();
+        let mut files = self.archives()?;
+        if self.path.exists() {
+            files.push(self.path.clone());
+        }
+        for path in files {
+            let content = fs::read_to_string(&path)?;
+            let complete = content.rfind('\n').map_or("", |end| &content[..end]);
+            for (i, line) in complete.lines().enumerate() {
+                if line.is_empty() {
+                    continue;
+                }
+                let error = match serde_json::from_str::<Event>(line) {
+                    Ok(event) if event.v > SCHEMA_VERSION => format!(
+                        "schema version {} is newer than supported ({})",
+                        event.v, SCHEMA_VERSION
+                    ),
+                    Ok(event) => {
+                        report.valid += 1;
+                        if event.v == 0 {
+                            report.unversioned += 1;
+                        }
+                        continue;
+                    }
+                    Err(e) => e.to_string(),
+                };
+                report.invalid.push(InvalidLine {
+                    path: path.clone(),
+                    line: i + 1,
+                    error,
+                    content: line.to_string(),
+                });
+            }
+        }
+        Ok(report)
+    }
+
+    /// Bytes used by the active log and its archives.
+    pub fn disk_usage(&self) -> Result<u64> {
+        let mut total = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        for archive in self.archives()? {
+            total += fs::metadata(&archive).map(|m| m.len()).unwrap_or(0);
+        }
+        Ok(total)
     }
 
     /// Return the last event without loading all events into memory.
@@ -76,10 +336,7 @@ impl EventLog {
                 last_line = Some(line);
             }
         }
-        match last_line {
-            Some(line) => Ok(Some(serde_json::from_str(&line)?)),
-            None => Ok(None),
-        }
+        Ok(last_line.and_then(|line| parse_line(&self.path, &line)))
     }
 
     /// Check if the log file exists.
@@ -87,16 +344,18 @@ impl EventLog {
         self.path.exists()
     }
 
-    /// Truncate the log file (clear all events).
+    /// Truncate the log file (clear all events), dropping archives and the snapshot.
     pub fn reset(&self) -> Result<()> {
+        self.remove_derived()?;
         if self.path.exists() {
             fs::write(&self.path, "")?;
         }
         Ok(())
     }
 
-    /// Remove the log file and its parent directory.
+    /// Remove the log file, archives, snapshot, and the parent directory.
     pub fn remove(&self) -> Result<()> {
+        self.remove_derived()?;
         if self.path.exists() {
             fs::remove_file(&self.path)?;
         }
@@ -105,6 +364,62 @@ impl EventLog {
         }
         Ok(())
     }
+
+    fn remove_derived(&self) -> Result<()> {
+        for archive in self.archives()? {
+            fs::remove_file(archive)?;
+        }
+        let snapshot = self.snapshot_path();
+        if snapshot.exists() {
+            fs::remove_file(snapshot)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse one log line. Corrupt lines are skipped with a warning rather than
+/// failing the whole read; `jig events validate` reports them.
+pub(super) fn parse_line(path: &Path, line: &str) -> Option<Event> {
+    match serde_json::from_str(line) {
+        Ok(event) => Some(event),
+        Err(e) => {
+            tracing::warn!(log = %path.display(), error = %e, "skipping corrupt event line");
+            None
+        }
+    }
+}
+
+fn read_into(path: &Path, events: &mut Vec<Event>) -> Result<()> {
+    let reader = BufReader::new(fs::File::open(path)?);
+    for line in reader.lines() {
+        let line = line?;
+        if !line.is_empty() {
+            events.extend(parse_line(path, &line));
+        }
+    }
+    Ok(())
+}
+
+/// Parse complete lines of `path` from byte `from`, returning the offset just
+/// past the last one.
+fn read_complete(path: &Path, from: u64, events: &mut Vec<Event>) -> Result<u64> {
+    let mut file = match fs::File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(from),
+        Err(e) => return Err(e.into()),
+    };
+    file.seek(SeekFrom::Start(from))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    let Some(end) = buf.iter().rposition(|b| *b == b'\n') else {
+        return Ok(from);
+    };
+    for line in String::from_utf8_lossy(&buf[..end]).lines() {
+        if !line.is_empty() {
+            events.extend(parse_line(path, line));
+        }
+    }
+    Ok(from + end as u64 + 1)
 }
 
 #[cfg(test)]
@@ -200,4 +515,186 @@ mod tests {
         assert!(!log.exists());
         assert!(!subdir.exists());
     }
+
+    fn tool_burst(log: &EventLog, n: usize) {
+        for _ in 0..n {
+            log.append(&Event::new(EventType::ToolUseStart).with_field("tool", "bash"))
+                .unwrap();
+            log.append(&Event::new(EventType::ToolUseEnd).with_field("tool", "bash"))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn read_from_follows_appends_and_rotation() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+        let (events, offset) = log.read_from(0).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(log.read_from(offset).unwrap().0.len(), 0);
+
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        log.compact().unwrap();
+        log.append(&Event::new(EventType::Stop)).unwrap();
+
+        let (events, _) = log.read_from(offset).unwrap();
+        let types: Vec<_> = events.iter().map(|e| e.event_type.clone()).collect();
+        assert_eq!(types, vec![EventType::Commit, EventType::Stop]);
+    }
+
+    #[test]
+    fn corrupt_lines_are_skipped_and_reported() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        let config = HealthConfig::default();
+
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+        let mut content = std::fs::read_to_string(&log.path).unwrap();
+        content.push_str("{\"ts\":1,\"type\":\"tool_use_end\",\"tool\":\"a\"b\"}\n");
+        content.push_str("{\"ts\":1,\"type\":\"commit\"}\n");
+        content.push_str("{\"v\":99,\"ts\":1,\"type\":\"stop\"}\n");
+        std::fs::write(&log.path, content).unwrap();
+
+        assert_eq!(log.read_all().unwrap().len(), 3);
+        assert_eq!(log.reduce(&config).unwrap().commit_count, 1);
+
+        let report = log.validate().unwrap();
+        assert_eq!(report.valid, 2);
+        assert_eq!(report.unversioned, 1);
+        assert_eq!(report.invalid.len(), 2);
+        assert_eq!(report.invalid[0].line, 2);
+        assert!(report.invalid[1].error.contains("newer than supported"));
+    }
+
+    #[test]
+    fn reduce_folds_incrementally() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        let config = HealthConfig::default();
+
+        log.append(&Event::new(EventType::Spawn).with_field("branch", "feat"))
+            .unwrap();
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        assert_eq!(log.reduce(&config).unwrap().commit_count, 1);
+        assert!(log.snapshot_path().exists());
+
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        let state = log.reduce(&config).unwrap();
+        assert_eq!(state.commit_count, 2);
+        assert_eq!(state.branch.as_deref(), Some("feat"));
+
+        let full = WorkerState::reduce(&log.read_all().unwrap(), &config);
+        assert_eq!(state.commit_count, full.commit_count);
+        assert_eq!(state.status, full.status);
+    }
+
+    #[test]
+    fn reduce_rebuilds_after_rewrite() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        let config = HealthConfig::default();
+
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        log.reduce(&config).unwrap();
+
+        std::fs::write(&log.path, "").unwrap();
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+        assert_eq!(log.reduce(&config).unwrap().commit_count, 0);
+    }
+
+    #[test]
+    fn rotation_keeps_state_and_history() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        let config = HealthConfig::default();
+
+        log.append(&Event::new(EventType::Spawn).with_field("branch", "feat"))
+            .unwrap();
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        let stats = log.compact().unwrap();
+        assert!(stats.rotated_bytes > 0);
+        assert_eq!(log.archives().unwrap().len(), 1);
+        assert_eq!(std::fs::read_to_string(&log.path).unwrap(), "");
+
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        let state = log.reduce(&config).unwrap();
+        assert_eq!(state.commit_count, 2);
+        assert_eq!(state.branch.as_deref(), Some("feat"));
+        assert_eq!(log.read_all().unwrap().len(), 3);
+
+        // A lost snapshot is rebuilt from the archives.
+        std::fs::remove_file(log.snapshot_path()).unwrap();
+        assert_eq!(log.reduce(&config).unwrap().commit_count, 2);
+    }
+
+    #[test]
+    fn archives_in_the_same_millisecond_stay_ordered() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        for name in ["events.20.jsonl", "events.5-1.jsonl", "events.5.jsonl"] {
+            std::fs::write(tmp.path().join(name), "").unwrap();
+        }
+        let names: Vec<String> = log
+            .archives()
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["events.5.jsonl", "events.5-1.jsonl", "events.20.jsonl"]
+        );
+
+        for _ in 0..3 {
+            log.append(&Event::new(EventType::Commit)).unwrap();
+            log.compact().unwrap();
+        }
+        assert_eq!(log.archives().unwrap().len(), 6);
+        assert_eq!(log.read_all().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn compact_keeps_tool_telemetry() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        let config = HealthConfig::default();
+
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+        tool_burst(&log, 50);
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        let before = log.reduce(&config).unwrap();
+
+        let stats = log.compact().unwrap();
+        assert!(stats.rotated_bytes > 0);
+        assert_eq!(stats.archives, 1);
+        assert_eq!(log.compact().unwrap().rotated_bytes, 0);
+
+        std::fs::remove_file(log.snapshot_path()).unwrap();
+        let after = log.reduce(&config).unwrap();
+        assert_eq!(after.status, before.status);
+        assert_eq!(after.telemetry.tool_calls(), 50);
+        assert_eq!(after.telemetry.tools["bash"].timed_calls, 50);
+    }
+
+    #[test]
+    fn reset_drops_snapshot_and_archives() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+
+        log.append(&Event::new(EventType::Commit)).unwrap();
+        log.compact().unwrap();
+        log.append(&Event::new(EventType::Commit)).unwrap();
+
+        log.reset().unwrap();
+        assert!(log.read_all().unwrap().is_empty());
+        assert!(log.archives().unwrap().is_empty());
+        assert!(!log.snapshot_path().exists());
+        assert_eq!(
+            log.reduce(&HealthConfig::default()).unwrap().commit_count,
+            0
+        );
+    }
 }
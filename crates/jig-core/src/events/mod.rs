@@ -6,8 +6,11 @@ mod derive;
 mod log;
 mod reducer;
 mod schema;
+mod snapshot;
+mod telemetry;
 
 pub use derive::derive_status;
-pub use log::EventLog;
+pub use log::{CompactStats, EventLog, InvalidLine, Validation, MAX_LOG_BYTES};
 pub use reducer::WorkerState;
-pub use schema::{Event, EventType};
+pub use schema::{Event, EventType, SCHEMA_VERSION};
+pub use telemetry::{Telemetry, ToolStats, Usage};
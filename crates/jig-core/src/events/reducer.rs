@@ -2,13 +2,17 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use crate::global::HealthConfig;
 use crate::worker::WorkerStatus;
 
 use super::schema::{Event, EventType};
+use super::telemetry::Telemetry;
 
 /// Rich derived state for a worker, computed by replaying events.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct WorkerState {
     pub status: WorkerStatus,
     pub commit_count: u32,
@@ -23,6 +27,25 @@ pub struct WorkerState {
     pub parent_issue: Option<String>,
     /// Parent issue's branch name, used as base branch for this child worktree.
     pub parent_branch: Option<String>,
+    /// Worker whose branch this one is stacked on, until that branch lands.
+    pub stacked_on: Option<String>,
+    /// Commit of the lower branch this worker's commits sit on.
+    pub stack_base: Option<String>,
+    /// Agent adapter the worker was launched with (e.g. "codex").
+    pub agent: Option<String>,
+    /// Model override the worker was launched with.
+    pub model: Option<String>,
+    /// Labels of the issue the worker was spawned for.
+    pub issue_labels: Vec<String>,
+    /// Branch from the first spawn event.
+    pub branch: Option<String>,
+    /// Tool, file and token metrics.
+    pub telemetry: Telemetry,
+    /// Why the daemon stopped the worker for going over budget, if it did.
+    /// Cleared when the worker is resumed.
+    pub budget_exceeded: Option<String>,
+    /// When each pane pattern rule last fired, by rule name.
+    pub last_pattern_at: HashMap<String, i64>,
 }
 
 impl Default for WorkerState {
@@ -39,6 +62,15 @@ impl Default for WorkerState {
             last_event_at: None,
             parent_issue: None,
             parent_branch: None,
+            stacked_on: None,
+            stack_base: None,
+            agent: None,
+            model: None,
+            issue_labels: Vec::new(),
+            branch: None,
+            telemetry: Telemetry::default(),
+            budget_exceeded: None,
+            last_pattern_at: HashMap::new(),
         }
     }
 }
@@ -56,12 +88,57 @@ impl WorkerState {
         state
     }
 
-    fn apply(&mut self, event: &Event) {
+    /// Apply one event, returning the new status if it changed. Used to show
+    /// transitions while replaying a log; no silence check is applied.
+    pub fn advance(&mut self, event: &Event) -> Option<WorkerStatus> {
+        let before = self.status;
+        self.apply(event);
+        (self.status != before).then_some(self.status)
+    }
+
+    /// Record the agent/model from a registration event, if present.
+    fn apply_agent(&mut self, event: &Event) {
+        if let Some(agent) = event.data.get("agent").and_then(|v| v.as_str()) {
+            self.agent = Some(agent.to_string());
+        }
+        if let Some(model) = event.data.get("model").and_then(|v| v.as_str()) {
+            self.model = Some(model.to_string());
+        }
+    }
+
+    /// Record the lower worker and fork point of a stacked worker, if present.
+    fn apply_stack(&mut self, event: &Event) {
+        if let Some(lower) = event.data.get("stacked_on").and_then(|v| v.as_str()) {
+            self.stacked_on = Some(lower.to_string());
+        }
+        if let Some(base) = event.data.get("stack_base").and_then(|v| v.as_str()) {
+            self.stack_base = Some(base.to_string());
+        }
+    }
+
+    /// Record the issue labels from a registration event, if present.
+    fn apply_issue_labels(&mut self, event: &Event) {
+        if let Some(labels) = event.data.get("issue_labels").and_then(|v| v.as_array()) {
+            self.issue_labels = labels
+                .iter()
+                .filter_map(|l| l.as_str().map(String::from))
+                .collect();
+        }
+    }
+
+    pub(super) fn apply(&mut self, event: &Event) {
         // Track timestamps
         if self.started_at.is_none() {
             self.started_at = Some(event.ts);
         }
         self.last_event_at = Some(event.ts);
+        self.telemetry.apply(event);
+
+        if self.branch.is_none() && event.event_type == EventType::Spawn {
+            if let Some(branch) = event.data.get("branch").and_then(|v| v.as_str()) {
+                self.branch = Some(branch.to_string());
+            }
+        }
 
         // Check for terminal markers first
         if let Some(terminal) = event.data.get("terminal").and_then(|v| v.as_str()) {
@@ -106,6 +183,9 @@ impl WorkerState {
                 if let Some(pb) = event.data.get("parent_branch").and_then(|v| v.as_str()) {
                     self.parent_branch = Some(pb.to_string());
                 }
+                self.apply_agent(event);
+                self.apply_issue_labels(event);
+                self.apply_stack(event);
             }
             EventType::Spawn => {
                 self.status = WorkerStatus::Spawned;
@@ -118,9 +198,13 @@ impl WorkerState {
                 if let Some(pb) = event.data.get("parent_branch").and_then(|v| v.as_str()) {
                     self.parent_branch = Some(pb.to_string());
                 }
+                self.apply_agent(event);
+                self.apply_issue_labels(event);
+                self.apply_stack(event);
             }
             EventType::Resume => {
                 self.status = WorkerStatus::Spawned;
+                self.budget_exceeded = None;
                 // Preserve existing issue_ref; override only if provided
                 if let Some(issue) = event.data.get("issue").and_then(|v| v.as_str()) {
                     self.issue_ref = Some(issue.to_string());
@@ -158,14 +242,31 @@ impl WorkerState {
             EventType::Review => {
                 self.status = WorkerStatus::WaitingReview;
             }
-            EventType::CiStatus => {}
+            EventType::CiStatus | EventType::AutoRebase => {}
+            EventType::Restack => {
+                if event.data.get("landed").and_then(|v| v.as_bool()) == Some(true) {
+                    self.stacked_on = None;
+                    self.stack_base = None;
+                } else {
+                    self.apply_stack(event);
+                }
+            }
+            EventType::BudgetExceeded => {
+                let reason = event.data.get("reason").and_then(|v| v.as_str());
+                self.budget_exceeded = Some(reason.unwrap_or("over budget").to_string());
+            }
+            EventType::PaneMatch => {
+                if let Some(rule) = event.data.get("rule").and_then(|v| v.as_str()) {
+                    self.last_pattern_at.insert(rule.to_string(), event.ts);
+                }
+            }
             EventType::Terminal => {
                 // Terminal markers are handled above via data.terminal field
             }
         }
     }
 
-    fn check_silence(&mut self, config: &HealthConfig) {
+    pub(super) fn check_silence(&mut self, config: &HealthConfig) {
         if self.status.is_terminal() {
             return;
         }
@@ -214,6 +315,34 @@ mod tests {
         assert!(state.last_commit_at.is_some());
     }
 
+    #[test]
+    fn branch_from_first_spawn() {
+        let events = vec![
+            Event::new(EventType::Spawn).with_field("branch", "feat/login"),
+            Event::new(EventType::Spawn).with_field("branch", "other"),
+        ];
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(state.branch.as_deref(), Some("feat/login"));
+    }
+
+    #[test]
+    fn advance_reports_transitions() {
+        let mut state = WorkerState::default();
+        assert_eq!(
+            state.advance(&Event::new(EventType::Spawn)),
+            Some(WorkerStatus::Spawned)
+        );
+        assert_eq!(
+            state.advance(&Event::new(EventType::ToolUseStart)),
+            Some(WorkerStatus::Running)
+        );
+        assert_eq!(state.advance(&Event::new(EventType::ToolUseEnd)), None);
+        assert_eq!(
+            state.advance(&Event::new(EventType::Stop)),
+            Some(WorkerStatus::Idle)
+        );
+    }
+
     #[test]
     fn pr_url_extracted() {
         let events = vec![
@@ -261,16 +390,19 @@ mod tests {
         let events = vec![
             Event::new(EventType::Spawn),
             Event {
+                v: 1,
                 ts: now - 600,
                 event_type: EventType::Nudge,
                 data: serde_json::json!({"nudge_type": "ci"}),
             },
             Event {
+                v: 1,
                 ts: now - 100,
                 event_type: EventType::Nudge,
                 data: serde_json::json!({"nudge_type": "ci"}),
             },
             Event {
+                v: 1,
                 ts: now - 500,
                 event_type: EventType::Nudge,
                 data: serde_json::json!({"nudge_type": "review"}),
@@ -335,6 +467,7 @@ mod tests {
     fn silence_triggers_stalled() {
         let old_ts = chrono::Utc::now().timestamp() - 600;
         let events = vec![Event {
+            v: 1,
             ts: old_ts,
             event_type: EventType::ToolUseEnd,
             data: serde_json::Value::Object(serde_json::Map::new()),
@@ -382,6 +515,7 @@ mod tests {
     fn initializing_not_marked_stalled() {
         let old_ts = chrono::Utc::now().timestamp() - 600;
         let events = vec![Event {
+            v: 1,
             ts: old_ts,
             event_type: EventType::Initializing,
             data: serde_json::Value::Object(serde_json::Map::new()),
@@ -423,4 +557,83 @@ mod tests {
         assert_eq!(state.parent_issue, None);
         assert_eq!(state.parent_branch, None);
     }
+
+    #[test]
+    fn stack_follows_restacks_until_landed() {
+        let mut events = vec![
+            Event::new(EventType::Spawn)
+                .with_field("stacked_on", "auth-api")
+                .with_field("stack_base", "aaa"),
+            Event::new(EventType::Restack)
+                .with_field("onto", "auth-api")
+                .with_field("stack_base", "bbb"),
+        ];
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(state.stacked_on.as_deref(), Some("auth-api"));
+        assert_eq!(state.stack_base.as_deref(), Some("bbb"));
+
+        events.push(
+            Event::new(EventType::Restack)
+                .with_field("onto", "origin/main")
+                .with_field("landed", true),
+        );
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(state.stacked_on, None);
+        assert_eq!(state.stack_base, None);
+    }
+
+    #[test]
+    fn issue_labels_extracted_from_registration() {
+        let events = vec![Event::new(EventType::Initializing)
+            .with_field("issue", "JIG-7")
+            .with_field("issue_labels", vec!["urgent", "backend"])];
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(state.issue_labels, vec!["urgent", "backend"]);
+    }
+
+    #[test]
+    fn agent_and_model_extracted_from_registration() {
+        let events = vec![
+            Event::new(EventType::Initializing)
+                .with_field("agent", "codex")
+                .with_field("model", "o3"),
+            Event::new(EventType::Spawn).with_field("branch", "feat"),
+        ];
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(state.agent.as_deref(), Some("codex"));
+        assert_eq!(state.model.as_deref(), Some("o3"));
+    }
+
+    #[test]
+    fn budget_exceeded_until_resumed() {
+        let events = vec![
+            Event::new(EventType::Spawn),
+            Event::new(EventType::BudgetExceeded)
+                .with_field("reason", "worker budget exceeded: 5 of 4 hours"),
+        ];
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(
+            state.budget_exceeded.as_deref(),
+            Some("worker budget exceeded: 5 of 4 hours")
+        );
+
+        let mut events = events;
+        events.push(Event::new(EventType::Resume));
+        let state = WorkerState::reduce(&events, &default_config());
+        assert!(state.budget_exceeded.is_none());
+    }
+
+    #[test]
+    fn last_pattern_at_tracked_by_rule() {
+        let events = vec![
+            Event::new(EventType::Spawn),
+            Event::new(EventType::PaneMatch)
+                .with_field("rule", "usage-limit")
+                .with_field("action", "notify"),
+        ];
+        let state = WorkerState::reduce(&events, &default_config());
+        assert_eq!(state.last_pattern_at.len(), 1);
+        assert!(state.last_pattern_at.contains_key("usage-limit"));
+        assert_eq!(state.status, WorkerStatus::Spawned);
+    }
 }
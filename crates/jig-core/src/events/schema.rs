@@ -21,13 +21,93 @@ pub enum EventType {
     CiStatus,
     Review,
     Terminal,
+    BudgetExceeded,
+    PaneMatch,
+    AutoRebase,
+    Restack,
 }
 
+impl EventType {
+    pub const ALL: [EventType; 19] = [
+        Self::Create,
+        Self::Initializing,
+        Self::Spawn,
+        Self::Resume,
+        Self::ToolUseStart,
+        Self::ToolUseEnd,
+        Self::Commit,
+        Self::Push,
+        Self::PrOpened,
+        Self::Notification,
+        Self::Stop,
+        Self::Nudge,
+        Self::CiStatus,
+        Self::Review,
+        Self::Terminal,
+        Self::BudgetExceeded,
+        Self::PaneMatch,
+        Self::AutoRebase,
+        Self::Restack,
+    ];
+
+    /// The name used in the log (`tool_use_end`, `pr_opened`, ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Initializing => "initializing",
+            Self::Spawn => "spawn",
+            Self::Resume => "resume",
+            Self::ToolUseStart => "tool_use_start",
+            Self::ToolUseEnd => "tool_use_end",
+            Self::Commit => "commit",
+            Self::Push => "push",
+            Self::PrOpened => "pr_opened",
+            Self::Notification => "notification",
+            Self::Stop => "stop",
+            Self::Nudge => "nudge",
+            Self::CiStatus => "ci_status",
+            Self::Review => "review",
+            Self::Terminal => "terminal",
+            Self::BudgetExceeded => "budget_exceeded",
+            Self::PaneMatch => "pane_match",
+            Self::AutoRebase => "auto_rebase",
+            Self::Restack => "restack",
+        }
+    }
+}
+
+impl std::str::FromStr for EventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|t| t.as_str() == name)
+            .ok_or_else(|| {
+                let valid: Vec<_> = Self::ALL.iter().map(|t| t.as_str()).collect();
+                format!(
+                    "unknown event type '{}' (expected one of: {})",
+                    s,
+                    valid.join(", ")
+                )
+            })
+    }
+}
+
+/// Version of the event line format written by this build.
+///
+/// Lines without a `v` field predate versioning and read as version 0.
+pub const SCHEMA_VERSION: u32 = 1;
+
 /// A single event in the worker event log.
 ///
-/// Serializes flat: `{"ts":..., "type":"spawn", "sha":"abc123"}`
+/// Serializes flat: `{"v":1, "ts":..., "type":"spawn", "sha":"abc123"}`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
+    /// Schema version the line was written with.
+    #[serde(default)]
+    pub v: u32,
     pub ts: i64,
     #[serde(rename = "type")]
     pub event_type: EventType,
@@ -39,6 +119,7 @@ impl Event {
     /// Create a new event with the given type, timestamped to now.
     pub fn new(event_type: EventType) -> Self {
         Self {
+            v: SCHEMA_VERSION,
             ts: chrono::Utc::now().timestamp(),
             event_type,
             data: serde_json::Value::Object(serde_json::Map::new()),
@@ -85,6 +166,7 @@ mod tests {
         let json = serde_json::to_string(&event).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
 
+        assert_eq!(parsed["v"], SCHEMA_VERSION);
         assert!(parsed["ts"].is_i64());
         assert_eq!(parsed["type"], "spawn");
         assert_eq!(parsed["sha"], "abc123");
@@ -107,4 +189,28 @@ mod tests {
         assert_eq!(map["url"], "https://github.com/pr/1");
         assert_eq!(map["draft"], true);
     }
+
+    #[test]
+    fn unversioned_line_reads_as_v0() {
+        let event: Event = serde_json::from_str(r#"{"ts":1700000000,"type":"stop"}"#).unwrap();
+        assert_eq!(event.v, 0);
+        assert!(event.data.get("v").is_none());
+    }
+
+    #[test]
+    fn event_type_names_match_serde() {
+        for event_type in EventType::ALL {
+            let json = serde_json::to_string(&event_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", event_type.as_str()));
+            assert_eq!(
+                event_type.as_str().parse::<EventType>().unwrap(),
+                event_type
+            );
+        }
+        assert_eq!(
+            "tool-use-end".parse::<EventType>().unwrap(),
+            EventType::ToolUseEnd
+        );
+        assert!("bogus".parse::<EventType>().is_err());
+    }
 }
@@ -0,0 +1,192 @@
+//! Reduced-state checkpoints for event logs.
+//!
+//! A snapshot records the `WorkerState` folded from a worker's events up to a
+//! byte offset in the active `events.jsonl`, so reducing the log only has to
+//! parse what was appended since.
+
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::log::parse_line;
+use super::WorkerState;
+
+/// Version of the reduced state a snapshot holds. Bump it whenever
+/// `WorkerState` or its reducer changes, so snapshots written by an older
+/// jig are rebuilt from the logs instead of missing the new fields.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// `WorkerState` (before the silence check) up to `offset` in the active log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// [`SNAPSHOT_VERSION`] when written; absent in pre-versioned snapshots.
+    #[serde(default)]
+    pub version: u32,
+    /// Byte offset just past the last folded event in the active log.
+    pub offset: u64,
+    /// Events folded in so far, archives included.
+    pub events: u64,
+    /// The last folded line, used to check the log wasn't rewritten underneath us.
+    #[serde(default)]
+    pub tail: String,
+    pub state: WorkerState,
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            offset: 0,
+            events: 0,
+            tail: String::new(),
+            state: WorkerState::default(),
+        }
+    }
+}
+
+impl Snapshot {
+    /// Load a snapshot, treating a missing, unreadable or outdated file as none.
+    pub fn load(path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str::<Self>(&content)
+            .ok()
+            .filter(|s| s.version == SNAPSHOT_VERSION)
+    }
+
+    /// Write atomically, so a concurrent reader never sees half a snapshot.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, serde_json::to_string(self)?)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Whether `log` still holds the folded prefix: the tail line must end
+    /// exactly at `offset`.
+    pub fn matches(&self, log: &Path) -> bool {
+        if self.offset == 0 {
+            return true;
+        }
+        let expected = format!("{}\n", self.tail);
+        let Some(start) = self.offset.checked_sub(expected.len() as u64) else {
+            return false;
+        };
+        let mut buf = vec![0; expected.len()];
+        let read = File::open(log).and_then(|mut f| {
+            f.seek(SeekFrom::Start(start))?;
+            f.read_exact(&mut buf)
+        });
+        read.is_ok() && buf == expected.as_bytes()
+    }
+
+    /// Fold complete lines of `path` from `from` onward into the snapshot.
+    /// A trailing line without a newline (an append in progress) is left for
+    /// next time. Returns the number of events folded.
+    pub fn fold(&mut self, path: &Path, from: u64) -> Result<u64> {
+        let mut file = match File::open(path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+        file.seek(SeekFrom::Start(from))?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+
+        let Some(end) = buf.iter().rposition(|b| *b == b'\n') else {
+            return Ok(0);
+        };
+        let complete = String::from_utf8_lossy(&buf[..end]);
+
+        let mut folded = 0;
+        for line in complete.lines() {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(event) = parse_line(path, line) {
+                self.state.apply(&event);
+                folded += 1;
+            }
+            self.tail = line.to_string();
+        }
+        self.offset = from + end as u64 + 1;
+        self.events += folded;
+        Ok(folded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event, EventLog, EventType};
+    use crate::worker::WorkerStatus;
+
+    #[test]
+    fn fold_skips_partial_line() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let log = EventLog::new(path.clone());
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+
+        let mut partial = fs::read_to_string(&path).unwrap();
+        partial.push_str("{\"ts\":1,\"type\":\"com");
+        fs::write(&path, &partial).unwrap();
+
+        let mut snap = Snapshot::default();
+        assert_eq!(snap.fold(&path, 0).unwrap(), 1);
+        assert_eq!(snap.state.status, WorkerStatus::Spawned);
+        assert!(snap.matches(&path));
+        assert!((snap.offset as usize) < partial.len());
+    }
+
+    #[test]
+    fn matches_detects_rewritten_log() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let log = EventLog::new(path.clone());
+        log.append(&Event::new(EventType::Spawn)).unwrap();
+
+        let mut snap = Snapshot::default();
+        snap.fold(&path, 0).unwrap();
+
+        fs::write(&path, "").unwrap();
+        assert!(!snap.matches(&path));
+
+        log.append(&Event::new(EventType::Stop).with_field("pad", "xxxxxxxx"))
+            .unwrap();
+        assert!(!snap.matches(&path));
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("snapshot.json");
+        let mut snap = Snapshot {
+            offset: 42,
+            events: 3,
+            tail: "{}".into(),
+            ..Default::default()
+        };
+        snap.state.branch = Some("feat".into());
+        snap.save(&path).unwrap();
+
+        let loaded = Snapshot::load(&path).unwrap();
+        assert_eq!(loaded.offset, 42);
+        assert_eq!(loaded.state.branch.as_deref(), Some("feat"));
+        assert!(Snapshot::load(&tmp.path().join("missing.json")).is_none());
+    }
+
+    #[test]
+    fn load_rejects_other_versions() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("snapshot.json");
+        fs::write(&path, r#"{"offset":0,"events":1,"state":{}}"#).unwrap();
+        assert!(Snapshot::load(&path).is_none());
+
+        Snapshot::default().save(&path).unwrap();
+        assert!(Snapshot::load(&path).is_some());
+    }
+}
@@ -0,0 +1,309 @@
+//! Tool and token telemetry aggregated from a worker's events.
+//!
+//! Agent hooks record the tool name, the file path or command it acted on,
+//! and (on stop) the session's token usage. Tool durations come from pairing
+//! a `tool_use_start` with its `tool_use_end`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+use super::schema::{Event, EventType};
+
+/// Calls and time spent in one tool.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolStats {
+    pub calls: u32,
+    /// Calls whose start was seen, so their duration is known.
+    pub timed_calls: u32,
+    pub total_seconds: u64,
+    pub max_seconds: u64,
+}
+
+/// Token usage (and cost, when the agent reports it) for one session.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Usage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    pub cost_usd: Option<f64>,
+}
+
+impl Usage {
+    /// Event fields carrying usage; see [`Usage::to_fields`].
+    const FIELDS: [&'static str; 5] = [
+        "input_tokens",
+        "output_tokens",
+        "cache_read_tokens",
+        "cache_creation_tokens",
+        "cost_usd",
+    ];
+
+    /// Read usage fields from an event, if it has any.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if !Self::FIELDS.iter().any(|f| event.data.get(f).is_some()) {
+            return None;
+        }
+        let tokens = |key: &str| event.data.get(key).and_then(as_u64).unwrap_or(0);
+        Some(Self {
+            input_tokens: tokens("input_tokens"),
+            output_tokens: tokens("output_tokens"),
+            cache_read_tokens: tokens("cache_read_tokens"),
+            cache_creation_tokens: tokens("cache_creation_tokens"),
+            cost_usd: event.data.get("cost_usd").and_then(as_f64),
+        })
+    }
+
+    /// Usage as event fields, for `Event::with_field`.
+    pub fn to_fields(&self) -> Vec<(&'static str, serde_json::Value)> {
+        let mut fields = vec![
+            ("input_tokens", self.input_tokens.into()),
+            ("output_tokens", self.output_tokens.into()),
+            ("cache_read_tokens", self.cache_read_tokens.into()),
+            ("cache_creation_tokens", self.cache_creation_tokens.into()),
+        ];
+        if let Some(cost) = self.cost_usd {
+            fields.push(("cost_usd", cost.into()));
+        }
+        fields
+    }
+
+    /// All tokens, cached ones included.
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens + self.cache_read_tokens + self.cache_creation_tokens
+    }
+
+    pub fn add(&mut self, other: &Usage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_creation_tokens += other.cache_creation_tokens;
+        self.cost_usd = match (self.cost_usd, other.cost_usd) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+        };
+    }
+
+    /// Sum the usage reported in a Claude Code session transcript (JSONL).
+    ///
+    /// A message can span several transcript lines that repeat its usage, so
+    /// each message id is counted once.
+    pub fn from_claude_transcript(path: &Path) -> Result<Self> {
+        let reader = BufReader::new(std::fs::File::open(path)?);
+        let mut by_message: HashMap<String, Usage> = HashMap::new();
+        for (i, line) in reader.lines().enumerate() {
+            let Ok(entry) = serde_json::from_str::<serde_json::Value>(&line?) else {
+                continue;
+            };
+            let message = &entry["message"];
+            let Some(usage) = message.get("usage") else {
+                continue;
+            };
+            let id = message["id"]
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| format!("line-{}", i));
+            let tokens = |key: &str| usage.get(key).and_then(as_u64).unwrap_or(0);
+            by_message.insert(
+                id,
+                Usage {
+                    input_tokens: tokens("input_tokens"),
+                    output_tokens: tokens("output_tokens"),
+                    cache_read_tokens: tokens("cache_read_input_tokens"),
+                    cache_creation_tokens: tokens("cache_creation_input_tokens"),
+                    cost_usd: None,
+                },
+            );
+        }
+        let mut total = Usage::default();
+        for usage in by_message.values() {
+            total.add(usage);
+        }
+        Ok(total)
+    }
+}
+
+/// Per-worker metrics folded from tool and usage events.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Telemetry {
+    /// Per tool name.
+    pub tools: BTreeMap<String, ToolStats>,
+    /// Distinct file paths tools acted on.
+    pub files: BTreeSet<String>,
+    /// Shell commands run.
+    pub commands: u32,
+    pub last_command: Option<String>,
+    /// Latest cumulative usage reported per agent session.
+    pub sessions: BTreeMap<String, Usage>,
+    /// Start times of tools in flight, keyed by tool use id (or tool name).
+    pending: HashMap<String, i64>,
+}
+
+impl Telemetry {
+    pub(super) fn apply(&mut self, event: &Event) {
+        let field = |key: &str| event.data.get(key).and_then(|v| v.as_str());
+
+        match event.event_type {
+            EventType::ToolUseStart => {
+                let key = field("tool_use_id").or(field("tool")).unwrap_or("unknown");
+                self.pending.insert(key.to_string(), event.ts);
+            }
+            EventType::ToolUseEnd => {
+                let tool = field("tool").unwrap_or("unknown");
+                let key = field("tool_use_id").unwrap_or(tool);
+                let started = self.pending.remove(key);
+
+                let stats = self.tools.entry(tool.to_string()).or_default();
+                stats.calls += 1;
+                if let Some(started) = started {
+                    let secs = (event.ts - started).max(0) as u64;
+                    stats.timed_calls += 1;
+                    stats.total_seconds += secs;
+                    stats.max_seconds = stats.max_seconds.max(secs);
+                }
+
+                if let Some(path) = field("path") {
+                    self.files.insert(path.to_string());
+                }
+                if let Some(command) = field("command") {
+                    self.commands += 1;
+                    self.last_command = Some(command.to_string());
+                }
+            }
+            // Nothing is in flight once the agent stops.
+            EventType::Stop => self.pending.clear(),
+            _ => {}
+        }
+
+        // Usage is reported as a running total for the session.
+        if let Some(usage) = Usage::from_event(event) {
+            let session = field("session").unwrap_or_default();
+            self.sessions.insert(session.to_string(), usage);
+        }
+    }
+
+    /// Usage summed over sessions.
+    pub fn usage(&self) -> Usage {
+        let mut total = Usage::default();
+        for usage in self.sessions.values() {
+            total.add(usage);
+        }
+        total
+    }
+
+    /// Tool calls across all tools.
+    pub fn tool_calls(&self) -> u32 {
+        self.tools.values().map(|t| t.calls).sum()
+    }
+}
+
+fn as_u64(v: &serde_json::Value) -> Option<u64> {
+    v.as_u64().or_else(|| v.as_str()?.parse().ok())
+}
+
+fn as_f64(v: &serde_json::Value) -> Option<f64> {
+    v.as_f64().or_else(|| v.as_str()?.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(event: Event, ts: i64) -> Event {
+        Event { ts, ..event }
+    }
+
+    #[test]
+    fn pairs_start_and_end_for_duration() {
+        let mut t = Telemetry::default();
+        t.apply(&at(
+            Event::new(EventType::ToolUseStart)
+                .with_field("tool", "Bash")
+                .with_field("tool_use_id", "a"),
+            100,
+        ));
+        t.apply(&at(
+            Event::new(EventType::ToolUseStart)
+                .with_field("tool", "Read")
+                .with_field("tool_use_id", "b"),
+            101,
+        ));
+        t.apply(&at(
+            Event::new(EventType::ToolUseEnd)
+                .with_field("tool", "Read")
+                .with_field("tool_use_id", "b")
+                .with_field("path", "src/main.rs"),
+            102,
+        ));
+        t.apply(&at(
+            Event::new(EventType::ToolUseEnd)
+                .with_field("tool", "Bash")
+                .with_field("tool_use_id", "a")
+                .with_field("command", "cargo test"),
+            130,
+        ));
+        // No start seen: counted, not timed.
+        t.apply(&at(
+            Event::new(EventType::ToolUseEnd).with_field("tool", "Bash"),
+            131,
+        ));
+
+        let bash = &t.tools["Bash"];
+        assert_eq!(bash.calls, 2);
+        assert_eq!(bash.timed_calls, 1);
+        assert_eq!(bash.total_seconds, 30);
+        assert_eq!(t.tools["Read"].total_seconds, 1);
+        assert_eq!(t.tool_calls(), 3);
+        assert!(t.files.contains("src/main.rs"));
+        assert_eq!(t.commands, 1);
+        assert_eq!(t.last_command.as_deref(), Some("cargo test"));
+        assert!(t.pending.is_empty());
+    }
+
+    #[test]
+    fn usage_is_latest_per_session() {
+        let mut t = Telemetry::default();
+        let stop = |session: &str, input: u64| {
+            Event::new(EventType::Stop)
+                .with_field("session", session)
+                .with_field("input_tokens", input)
+                .with_field("output_tokens", 10)
+        };
+        t.apply(&stop("s1", 100));
+        t.apply(&stop("s1", 250));
+        t.apply(&stop("s2", 50));
+
+        let usage = t.usage();
+        assert_eq!(usage.input_tokens, 300);
+        assert_eq!(usage.output_tokens, 20);
+        assert_eq!(usage.cost_usd, None);
+    }
+
+    #[test]
+    fn transcript_counts_each_message_once() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("session.jsonl");
+        let lines = [
+            r#"{"type":"user","message":{"role":"user","content":"hi"}}"#,
+            r#"{"type":"assistant","message":{"id":"m1","usage":{"input_tokens":10,"output_tokens":5,"cache_read_input_tokens":100}}}"#,
+            r#"{"type":"assistant","message":{"id":"m1","usage":{"input_tokens":10,"output_tokens":5,"cache_read_input_tokens":100}}}"#,
+            r#"{"type":"assistant","message":{"id":"m2","usage":{"input_tokens":20,"output_tokens":7,"cache_creation_input_tokens":3}}}"#,
+            "not json",
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let usage = Usage::from_claude_transcript(&path).unwrap();
+        assert_eq!(usage.input_tokens, 30);
+        assert_eq!(usage.output_tokens, 12);
+        assert_eq!(usage.cache_read_tokens, 100);
+        assert_eq!(usage.cache_creation_tokens, 3);
+        assert_eq!(usage.total_tokens(), 145);
+    }
+}
@@ -0,0 +1,712 @@
+//! GitLab merge request client over the REST v4 API.
+
+use chrono::DateTime;
+
+use crate::config::ForgeConfig;
+use crate::error::{Error, Result};
+use crate::github::{
+    CheckRun, CheckStatus, PrCommit, PrInfo, PrState, PrStateInfo, ReviewComment, ReviewState,
+};
+use crate::global::GlobalConfig;
+
+use super::{Forge, ForgeKind, RemoteUrl};
+
+/// gitlab.com REST API base URL.
+const DEFAULT_API_URL: &str = "https://gitlab.com/api/v4";
+
+/// Minimal sync client for GitLab merge requests, pipelines and discussions.
+pub struct GitLabClient {
+    /// REST API base URL without a trailing slash.
+    api_url: String,
+    token: String,
+    /// Project path (`group/subgroup/project`).
+    project: String,
+}
+
+impl GitLabClient {
+    /// Create a client for a project.
+    pub fn new(
+        api_url: impl Into<String>,
+        token: impl Into<String>,
+        project: impl Into<String>,
+    ) -> Self {
+        Self {
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+            project: project.into(),
+        }
+    }
+
+    /// Resolve project, API URL and token from config and the remote URL.
+    ///
+    /// API URL: `[forge] api_url` > `[gitlab] api_url` > `https://HOST/api/v4`
+    /// for the remote host > gitlab.com. Token: `[gitlab] token` > `GITLAB_TOKEN`.
+    pub fn from_config(
+        forge: &ForgeConfig,
+        global: &GlobalConfig,
+        remote_url: Option<&str>,
+    ) -> Result<Self> {
+        let remote = remote_url.and_then(RemoteUrl::parse);
+
+        let project = forge
+            .project
+            .clone()
+            .or_else(|| remote.as_ref().map(|r| r.path.clone()))
+            .ok_or_else(|| {
+                Error::Custom(
+                    "Could not determine GitLab project. Set [forge] project in jig.toml.".into(),
+                )
+            })?;
+
+        let api_url = forge
+            .api_url
+            .clone()
+            .or_else(|| global.gitlab.api_url.clone())
+            .or_else(|| remote.as_ref().map(|r| format!("{}/api/v4", r.web_base)))
+            .unwrap_or_else(|| DEFAULT_API_URL.to_string());
+
+        let token = global
+            .gitlab
+            .token
+            .clone()
+            .or_else(|| std::env::var("GITLAB_TOKEN").ok())
+            .filter(|t| !t.trim().is_empty())
+            .ok_or_else(|| {
+                Error::Custom(
+                    "GitLab token not configured. Set GITLAB_TOKEN or [gitlab] token in the global config."
+                        .into(),
+                )
+            })?;
+
+        Ok(Self::new(api_url, token, project))
+    }
+
+    /// REST API base URL.
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    /// `projects/:id` path segment with the project path URL-encoded.
+    fn project_endpoint(&self) -> String {
+        format!("projects/{}", urlencoding::encode(&self.project))
+    }
+
+    fn mr_endpoint(&self, iid: u64) -> String {
+        format!("{}/merge_requests/{}", self.project_endpoint(), iid)
+    }
+
+    /// Issue a `GET` and parse the JSON response.
+    fn get(&self, endpoint: &str) -> Result<serde_json::Value> {
+        let url = format!("{}/{}", self.api_url, endpoint);
+        let response = ureq::get(&url)
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .header("PRIVATE-TOKEN", &self.token)
+            .header("User-Agent", "jig")
+            .call();
+        Self::read_json(response)
+    }
+
+    /// Issue a `PUT` with a JSON body and parse the JSON response.
+    fn put(&self, endpoint: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        let url = format!("{}/{}", self.api_url, endpoint);
+        let response = ureq::put(&url)
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .header("PRIVATE-TOKEN", &self.token)
+            .header("User-Agent", "jig")
+            .send_json(body);
+        Self::read_json(response)
+    }
+
+    /// Issue a `POST` with a JSON body and parse the JSON response.
+    fn post(&self, endpoint: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        let url = format!("{}/{}", self.api_url, endpoint);
+        let response = ureq::post(&url)
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .header("PRIVATE-TOKEN", &self.token)
+            .header("User-Agent", "jig")
+            .send_json(body);
+        Self::read_json(response)
+    }
+
+    fn read_json(
+        response: std::result::Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> Result<serde_json::Value> {
+        let response =
+            response.map_err(|e| Error::GitLab(format!("HTTP request failed: {}", e)))?;
+        let status = response.status();
+        let body = response
+            .into_body()
+            .read_to_string()
+            .map_err(|e| Error::GitLab(format!("failed to read response: {}", e)))?;
+
+        if status.as_u16() >= 400 {
+            return Err(Error::GitLab(format!("HTTP {}: {}", status.as_u16(), body)));
+        }
+
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    fn get_array(&self, endpoint: &str) -> Result<Vec<serde_json::Value>> {
+        match self.get(endpoint)? {
+            serde_json::Value::Array(items) => Ok(items),
+            _ => Err(Error::GitLab(format!(
+                "expected a JSON array from {}",
+                endpoint
+            ))),
+        }
+    }
+
+    /// Latest note timestamp across all non-system discussion notes.
+    fn latest_note_at(&self, iid: u64) -> Result<Option<String>> {
+        let discussions = self.get_array(&format!(
+            "{}/discussions?per_page=100",
+            self.mr_endpoint(iid)
+        ))?;
+        Ok(discussions
+            .iter()
+            .filter_map(|d| d["notes"].as_array())
+            .flatten()
+            .filter(|n| n["system"].as_bool() != Some(true))
+            .filter_map(|n| n["created_at"].as_str())
+            .max_by(|a, b| compare_timestamps(a, b))
+            .map(|s| s.to_string()))
+    }
+}
+
+impl Forge for GitLabClient {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::GitLab
+    }
+
+    fn repo(&self) -> &str {
+        &self.project
+    }
+
+    fn get_pr_for_branch(&self, branch: &str) -> Result<Option<PrInfo>> {
+        let mrs = self.get_array(&format!(
+            "{}/merge_requests?source_branch={}&state=opened",
+            self.project_endpoint(),
+            urlencoding::encode(branch)
+        ))?;
+        let Some(mr) = mrs.first() else {
+            return Ok(None);
+        };
+
+        Ok(Some(PrInfo {
+            number: mr["iid"].as_u64().unwrap_or(0),
+            title: mr["title"].as_str().unwrap_or("").to_string(),
+            state: PrState::Open,
+            mergeable: mr["detailed_merge_status"]
+                .as_str()
+                .or_else(|| mr["merge_status"].as_str())
+                .map(|s| s.to_uppercase()),
+            head_branch: branch.to_string(),
+            url: mr["web_url"].as_str().unwrap_or("").to_string(),
+        }))
+    }
+
+    fn get_pr_state(&self, pr_number: u64) -> Result<PrStateInfo> {
+        let mr = self.get(&self.mr_endpoint(pr_number))?;
+
+        let state = match mr["state"].as_str() {
+            Some("merged") => PrState::Merged,
+            Some("closed") | Some("locked") => PrState::Closed,
+            _ => PrState::Open,
+        };
+        let is_draft = mr["draft"]
+            .as_bool()
+            .or_else(|| mr["work_in_progress"].as_bool())
+            .unwrap_or(false);
+
+        Ok(PrStateInfo {
+            state,
+            is_draft,
+            head_sha: mr["sha"].as_str().map(|s| s.to_string()),
+        })
+    }
+
+    /// Jobs of the latest pipeline for a branch or commit SHA. Jobs marked
+    /// `allow_failure` are ignored.
+    fn get_check_runs(&self, git_ref: &str) -> Result<Vec<CheckRun>> {
+        let filter = if is_commit_sha(git_ref) { "sha" } else { "ref" };
+        let pipelines = self.get_array(&format!(
+            "{}/pipelines?{}={}&per_page=1&order_by=id&sort=desc",
+            self.project_endpoint(),
+            filter,
+            urlencoding::encode(git_ref)
+        ))?;
+        let Some(pipeline_id) = pipelines.first().and_then(|p| p["id"].as_u64()) else {
+            return Ok(vec![]);
+        };
+
+        let jobs = self.get_array(&format!(
+            "{}/pipelines/{}/jobs?per_page=100",
+            self.project_endpoint(),
+            pipeline_id
+        ))?;
+
+        Ok(jobs
+            .iter()
+            .filter(|j| j["allow_failure"].as_bool() != Some(true))
+            .map(job_to_check_run)
+            .collect())
+    }
+
+    /// Failed jobs of the latest pipeline for a branch or commit SHA.
+    fn get_failed_checks(&self, git_ref: &str) -> Result<Vec<CheckRun>> {
+        let runs = self.get_check_runs(git_ref)?;
+        Ok(runs.into_iter().filter(|r| r.is_failure()).collect())
+    }
+
+    fn has_conflicts(&self, pr_number: u64) -> Result<bool> {
+        let mr = self.get(&self.mr_endpoint(pr_number))?;
+        Ok(mr["has_conflicts"].as_bool().unwrap_or(false)
+            || mr["detailed_merge_status"].as_str() == Some("conflict"))
+    }
+
+    /// Reviewer verdicts. Falls back to the approvals endpoint on GitLab
+    /// versions without per-reviewer state.
+    fn get_reviews(&self, pr_number: u64) -> Result<Vec<ReviewComment>> {
+        let reviewers = match self.get_array(&format!("{}/reviewers", self.mr_endpoint(pr_number)))
+        {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::debug!(pr_number, error = %e, "reviewers API failed, using approvals");
+                let approvals = self.get(&format!("{}/approvals", self.mr_endpoint(pr_number)))?;
+                return Ok(approvals["approved_by"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|a| review(ReviewState::Approved, &a["user"]))
+                    .collect());
+            }
+        };
+
+        Ok(reviewers
+            .iter()
+            .filter_map(|r| {
+                let state = match r["state"].as_str()? {
+                    "approved" => ReviewState::Approved,
+                    "requested_changes" => ReviewState::ChangesRequested,
+                    "reviewed" => ReviewState::Commented,
+                    _ => return None, // unreviewed
+                };
+                Some(review(state, &r["user"]))
+            })
+            .collect())
+    }
+
+    /// First note of each unresolved, resolvable discussion.
+    fn get_review_comments(&self, pr_number: u64) -> Result<Vec<ReviewComment>> {
+        let discussions = self.get_array(&format!(
+            "{}/discussions?per_page=100",
+            self.mr_endpoint(pr_number)
+        ))?;
+
+        Ok(discussions
+            .iter()
+            .filter_map(|d| d["notes"].as_array()?.first())
+            .filter(|n| n["system"].as_bool() != Some(true))
+            .filter(|n| n["resolvable"].as_bool() == Some(true))
+            .filter(|n| n["resolved"].as_bool() != Some(true))
+            .map(|n| ReviewComment {
+                body: n["body"].as_str().unwrap_or("").to_string(),
+                path: n["position"]["new_path"].as_str().map(|s| s.to_string()),
+                line: n["position"]["new_line"]
+                    .as_u64()
+                    .or_else(|| n["position"]["old_line"].as_u64()),
+                state: ReviewState::Commented,
+                author: n["author"]["username"].as_str().unwrap_or("").to_string(),
+                commit_id: n["position"]["head_sha"]
+                    .as_str()
+                    .map(|s| s.chars().take(7).collect()),
+                submitted_at: n["created_at"].as_str().map(|s| s.to_string()),
+            })
+            .collect())
+    }
+
+    fn get_pr_commits(&self, pr_number: u64) -> Result<Vec<PrCommit>> {
+        let commits = self.get_array(&format!(
+            "{}/commits?per_page=100",
+            self.mr_endpoint(pr_number)
+        ))?;
+
+        // GitLab lists newest first.
+        Ok(commits
+            .iter()
+            .rev()
+            .map(|c| PrCommit {
+                sha: c["id"].as_str().unwrap_or("").chars().take(7).collect(),
+                message: c["title"].as_str().unwrap_or("").to_string(),
+            })
+            .collect())
+    }
+
+    fn dev_pushed_after_reviews(&self, pr_number: u64) -> bool {
+        let commits = match self.get_array(&format!(
+            "{}/commits?per_page=100",
+            self.mr_endpoint(pr_number)
+        )) {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::debug!(pr_number, error = %e, "dev_pushed_after_reviews: commits API failed");
+                return false;
+            }
+        };
+        let Some(latest_commit) = commits
+            .iter()
+            .filter_map(|c| c["committed_date"].as_str())
+            .max_by(|a, b| compare_timestamps(a, b))
+        else {
+            return false;
+        };
+
+        let latest_note = match self.latest_note_at(pr_number) {
+            Ok(Some(n)) => n,
+            Ok(None) => return false,
+            Err(e) => {
+                tracing::debug!(pr_number, error = %e, "dev_pushed_after_reviews: discussions API failed");
+                return false;
+            }
+        };
+
+        compare_timestamps(latest_commit, &latest_note).is_gt()
+    }
+
+    /// With `sha`, GitLab refuses unless the MR head still matches it.
+    fn merge_pr(&self, pr_number: u64, sha: Option<&str>) -> Result<()> {
+        let body = match sha {
+            Some(sha) => serde_json::json!({ "sha": sha }),
+            None => serde_json::json!({}),
+        };
+        self.put(&format!("{}/merge", self.mr_endpoint(pr_number)), &body)?;
+        Ok(())
+    }
+
+    fn retarget_pr(&self, pr_number: u64, base: &str) -> Result<()> {
+        self.put(
+            &self.mr_endpoint(pr_number),
+            &serde_json::json!({ "target_branch": base }),
+        )?;
+        Ok(())
+    }
+
+    fn create_pr(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+        draft: bool,
+    ) -> Result<PrInfo> {
+        // GitLab marks drafts by title prefix.
+        let title = if draft {
+            format!("Draft: {}", title)
+        } else {
+            title.to_string()
+        };
+        let mr = self.post(
+            &format!("{}/merge_requests", self.project_endpoint()),
+            &serde_json::json!({
+                "source_branch": head,
+                "target_branch": base,
+                "title": title,
+                "description": body,
+            }),
+        )?;
+        Ok(PrInfo {
+            number: mr["iid"].as_u64().unwrap_or(0),
+            title: mr["title"].as_str().unwrap_or(&title).to_string(),
+            state: PrState::Open,
+            mergeable: None,
+            head_branch: head.to_string(),
+            url: mr["web_url"].as_str().unwrap_or("").to_string(),
+        })
+    }
+}
+
+fn review(state: ReviewState, user: &serde_json::Value) -> ReviewComment {
+    ReviewComment {
+        body: String::new(),
+        path: None,
+        line: None,
+        state,
+        author: user["username"].as_str().unwrap_or("").to_string(),
+        commit_id: None,
+        submitted_at: None,
+    }
+}
+
+/// Map a pipeline job onto a check run.
+fn job_to_check_run(job: &serde_json::Value) -> CheckRun {
+    let (status, conclusion) = match job["status"].as_str() {
+        Some("success") => (CheckStatus::Completed, Some("success")),
+        Some("failed") => (CheckStatus::Completed, Some("failure")),
+        Some("canceled") => (CheckStatus::Completed, Some("cancelled")),
+        Some("skipped") => (CheckStatus::Completed, Some("skipped")),
+        Some("running") => (CheckStatus::InProgress, None),
+        _ => (CheckStatus::Queued, None),
+    };
+    CheckRun {
+        name: job["name"].as_str().unwrap_or("").to_string(),
+        status,
+        conclusion: conclusion.map(|s| s.to_string()),
+        details_url: job["web_url"].as_str().map(|s| s.to_string()),
+    }
+}
+
+fn is_commit_sha(git_ref: &str) -> bool {
+    git_ref.len() == 40 && git_ref.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Compare RFC 3339 timestamps. GitLab mixes `Z` and `+00:00` offsets, so
+/// parse when possible and fall back to string order.
+fn compare_timestamps(a: &str, b: &str) -> std::cmp::Ordering {
+    match (
+        DateTime::parse_from_rfc3339(a),
+        DateTime::parse_from_rfc3339(b),
+    ) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_config_derives_api_url_from_remote() {
+        let global = GlobalConfig {
+            gitlab: crate::global::GitLabConfig {
+                api_url: None,
+                token: Some("tok".into()),
+            },
+            ..Default::default()
+        };
+        let client = GitLabClient::from_config(
+            &ForgeConfig::default(),
+            &global,
+            Some("git@gitlab.example.com:group/sub/app.git"),
+        )
+        .unwrap();
+        assert_eq!(client.api_url(), "https://gitlab.example.com/api/v4");
+        assert_eq!(client.repo(), "group/sub/app");
+        assert_eq!(client.project_endpoint(), "projects/group%2Fsub%2Fapp");
+    }
+
+    #[test]
+    fn from_config_repo_overrides_win() {
+        let global = GlobalConfig {
+            gitlab: crate::global::GitLabConfig {
+                api_url: Some("https://global/api/v4".into()),
+                token: Some("tok".into()),
+            },
+            ..Default::default()
+        };
+        let forge = ForgeConfig {
+            provider: Some("gitlab".into()),
+            project: Some("team/app".into()),
+            api_url: Some("https://repo/api/v4/".into()),
+        };
+        let client = GitLabClient::from_config(&forge, &global, None).unwrap();
+        assert_eq!(client.api_url(), "https://repo/api/v4");
+        assert_eq!(client.repo(), "team/app");
+    }
+
+    #[test]
+    fn job_status_mapping() {
+        let failed = job_to_check_run(&serde_json::json!({"name": "test", "status": "failed"}));
+        assert!(failed.is_failure());
+        let canceled = job_to_check_run(&serde_json::json!({"status": "canceled"}));
+        assert!(canceled.is_failure());
+        let running = job_to_check_run(&serde_json::json!({"status": "running"}));
+        assert_eq!(running.status, CheckStatus::InProgress);
+        let manual = job_to_check_run(&serde_json::json!({"status": "manual"}));
+        assert!(!manual.is_failure());
+    }
+
+    #[test]
+    fn commit_sha_detection() {
+        assert!(is_commit_sha(&"a".repeat(40)));
+        assert!(!is_commit_sha("feature/login"));
+        assert!(!is_commit_sha("abc1234"));
+    }
+
+    #[test]
+    fn timestamps_compare_across_offsets() {
+        use std::cmp::Ordering;
+        assert_eq!(
+            compare_timestamps("2026-01-01T10:00:00.000+02:00", "2026-01-01T09:00:00Z"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_timestamps("2026-01-02T00:00:00Z", "2026-01-01T00:00:00.000+00:00"),
+            Ordering::Greater
+        );
+    }
+
+    /// Local HTTP stand-in for the GitLab API. Answers each request with the
+    /// first route whose key is a prefix of the request path, 404 otherwise.
+    fn serve(routes: Vec<(String, serde_json::Value)>) -> String {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}/api/v4", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { break };
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("");
+                let path = path.trim_start_matches("/api/v4/");
+                let (status, body) = match routes.iter().find(|(k, _)| path.starts_with(k)) {
+                    Some((_, v)) => ("200 OK", v.to_string()),
+                    None => (
+                        "404 Not Found",
+                        r#"{"message":"404 Not Found"}"#.to_string(),
+                    ),
+                };
+                let mut stream = reader.into_inner();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+        base
+    }
+
+    fn mr_routes(state: &str) -> Vec<(String, serde_json::Value)> {
+        let mr = "projects/group%2Fapp/merge_requests/5";
+        vec![
+            (
+                "projects/group%2Fapp/merge_requests?source_branch=feat%2Fx".to_string(),
+                serde_json::json!([{
+                    "iid": 5, "title": "Add x", "web_url": "https://gitlab.example.com/group/app/-/merge_requests/5",
+                    "detailed_merge_status": "mergeable"
+                }]),
+            ),
+            (
+                format!("{}/reviewers", mr),
+                serde_json::json!([
+                    {"user": {"username": "rev"}, "state": "requested_changes"},
+                    {"user": {"username": "other"}, "state": "unreviewed"}
+                ]),
+            ),
+            (
+                format!("{}/discussions", mr),
+                serde_json::json!([
+                    {"notes": [{"body": "rename this", "system": false, "resolvable": true, "resolved": false,
+                                "author": {"username": "rev"}, "created_at": "2026-01-02T00:00:00.000Z",
+                                "position": {"new_path": "src/x.rs", "new_line": 12, "head_sha": "abcdef123"}}]},
+                    {"notes": [{"body": "done", "system": false, "resolvable": true, "resolved": true,
+                                "author": {"username": "rev"}, "created_at": "2026-01-01T00:00:00.000Z"}]},
+                    {"notes": [{"body": "added 1 commit", "system": true, "resolvable": false,
+                                "created_at": "2026-01-05T00:00:00.000Z"}]}
+                ]),
+            ),
+            (
+                format!("{}/commits", mr),
+                serde_json::json!([
+                    {"id": "bbbbbbbbbb", "title": "wip", "committed_date": "2026-01-01T12:00:00.000+00:00"},
+                    {"id": "aaaaaaaaaa", "title": "feat: x", "committed_date": "2026-01-01T00:00:00.000+00:00"}
+                ]),
+            ),
+            (
+                mr.to_string(),
+                serde_json::json!({
+                    "iid": 5, "state": state, "draft": true, "has_conflicts": true,
+                    "sha": "cccccccccccccccccccccccccccccccccccccccc"
+                }),
+            ),
+            (
+                "projects/group%2Fapp/pipelines?sha=cccccccccccccccccccccccccccccccccccccccc"
+                    .to_string(),
+                serde_json::json!([{"id": 77}]),
+            ),
+            (
+                "projects/group%2Fapp/pipelines/77/jobs".to_string(),
+                serde_json::json!([
+                    {"name": "test", "status": "failed", "web_url": "https://ci/test"},
+                    {"name": "lint", "status": "failed", "allow_failure": true},
+                    {"name": "build", "status": "success"}
+                ]),
+            ),
+        ]
+    }
+
+    #[test]
+    fn snapshot_via_http_stand_in() {
+        let client = GitLabClient::new(serve(mr_routes("opened")), "tok", "group/app");
+
+        let snaps = client
+            .get_pr_snapshots(&[crate::github::PrLookup::Branch("feat/x".into())])
+            .unwrap();
+        let snap = snaps[0].as_ref().unwrap();
+        assert_eq!(snap.number, 5);
+        assert_eq!(
+            snap.url,
+            "https://gitlab.example.com/group/app/-/merge_requests/5"
+        );
+        assert_eq!(snap.state, PrState::Open);
+        assert!(snap.is_draft);
+        assert!(snap.has_conflicts);
+        assert_eq!(snap.check_runs.len(), 1);
+        assert_eq!(snap.check_runs[0].name, "test");
+        assert_eq!(snap.reviews.len(), 1);
+        assert_eq!(snap.reviews[0].state, ReviewState::ChangesRequested);
+        assert_eq!(snap.review_comments.len(), 1);
+        assert_eq!(snap.review_comments[0].line, Some(12));
+        assert_eq!(snap.commits[0].message, "feat: x");
+        assert!(!snap.pushed_after_feedback);
+
+        let checks = crate::github::check_snapshot(snap);
+        let nudged: Vec<&str> = checks
+            .iter()
+            .filter(|(_, c)| c.nudge.is_some())
+            .map(|(n, _)| *n)
+            .collect();
+        assert_eq!(nudged, vec!["ci", "conflicts", "reviews", "commits"]);
+    }
+
+    #[test]
+    fn merged_mr_state() {
+        let client = GitLabClient::new(serve(mr_routes("merged")), "tok", "group/app");
+        let info = client.get_pr_state(5).unwrap();
+        assert_eq!(info.state, PrState::Merged);
+    }
+
+    #[test]
+    fn missing_mr_for_branch() {
+        let client = GitLabClient::new(serve(vec![]), "tok", "group/app");
+        assert!(client.get_pr_for_branch("nope").is_err());
+
+        let client = GitLabClient::new(
+            serve(vec![(
+                "projects/group%2Fapp/merge_requests?".to_string(),
+                serde_json::json!([]),
+            )]),
+            "tok",
+            "group/app",
+        );
+        assert!(client.get_pr_for_branch("nope").unwrap().is_none());
+    }
+}
@@ -354,6 +354,25 @@ impl Repo {
         Ok(stats)
     }
 
+    /// Files the branch checked out at `path` changed since it forked from
+    /// `base_branch`. Commits that landed on the base after the fork don't
+    /// count, unlike [`Repo::diff_stats`].
+    pub fn files_changed_since_fork(path: &Path, base_branch: &str) -> Result<Vec<String>> {
+        let repo = Self::open(path)?;
+        let head = repo.inner.head()?.peel_to_commit()?;
+        let base = repo.resolve_to_commit(base_branch)?;
+        let fork = repo
+            .inner
+            .find_commit(repo.inner.merge_base(base.id(), head.id())?)?;
+        let diff = repo
+            .inner
+            .diff_tree_to_tree(Some(&fork.tree()?), Some(&head.tree()?), None)?;
+        Ok(diff
+            .deltas()
+            .filter_map(|d| d.new_file().path().map(|p| p.to_string_lossy().to_string()))
+            .collect())
+    }
+
     /// Get full diff for a worktree.
     pub fn diff(path: &Path, base_branch: &str) -> Result<String> {
         let repo = Self::open(path)?;
@@ -445,6 +464,68 @@ impl Repo {
         Err(Error::MergeConflict(branch.to_string()))
     }
 
+    /// Squash a branch into a single commit with `message` on the current branch.
+    pub fn squash_merge(&self, branch: &str, message: &str) -> Result<()> {
+        let branch_commit = self
+            .inner
+            .find_branch(branch, git2::BranchType::Local)
+            .map_err(|_| Error::BranchNotFound(branch.to_string()))?
+            .get()
+            .peel_to_commit()?;
+        let head_commit = self.inner.head()?.peel_to_commit()?;
+
+        let mut index = self
+            .inner
+            .merge_commits(&head_commit, &branch_commit, None)?;
+        if index.has_conflicts() {
+            return Err(Error::MergeConflict(branch.to_string()));
+        }
+        let tree_oid = index.write_tree_to(&self.inner)?;
+        if tree_oid == head_commit.tree_id() {
+            return Ok(());
+        }
+        let tree = self.inner.find_tree(tree_oid)?;
+
+        // Check out first so local changes in the way abort before committing.
+        self.inner.checkout_tree(
+            tree.as_object(),
+            Some(git2::build::CheckoutBuilder::new().safe()),
+        )?;
+        let sig = self
+            .inner
+            .signature()
+            .or_else(|_| git2::Signature::now("jig", "jig@localhost"))?;
+        self.inner
+            .commit(Some("HEAD"), &sig, &sig, message, &tree, &[&head_commit])?;
+        Ok(())
+    }
+
+    /// Rebase the branch checked out at `path` onto `onto` using a subprocess.
+    ///
+    /// A rebase that stops on conflicts is aborted, leaving the branch as it was.
+    pub fn rebase(path: &Path, onto: &str) -> Result<()> {
+        let output = std::process::Command::new("git")
+            .args(["rebase", onto])
+            .current_dir(path)
+            .stdin(std::process::Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            let _ = std::process::Command::new("git")
+                .args(["rebase", "--abort"])
+                .current_dir(path)
+                .stdin(std::process::Stdio::null())
+                .output();
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(Error::Custom(format!(
+                "git rebase {} failed: {}",
+                onto, stderr
+            )));
+        }
+
+        Ok(())
+    }
+
     // ------------------------------------------------------------------
     // Remote operations
     // ------------------------------------------------------------------
@@ -567,6 +648,26 @@ impl Repo {
         Ok(())
     }
 
+    /// Force-push a rewritten branch to origin, refusing if the remote moved
+    /// since it was last fetched.
+    pub fn force_push_branch(repo_path: &Path, branch: &str) -> Result<()> {
+        let output = std::process::Command::new("git")
+            .args(["push", "--force-with-lease", "origin", branch])
+            .current_dir(repo_path)
+            .stdin(std::process::Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(Error::Custom(format!(
+                "git push --force-with-lease origin {} failed: {}",
+                branch, stderr
+            )));
+        }
+
+        Ok(())
+    }
+
     // ------------------------------------------------------------------
     // Private helpers
     // ------------------------------------------------------------------
@@ -1019,4 +1120,134 @@ mod tests {
             "remote branch should exist after push"
         );
     }
+
+    /// Helper: write a file and commit it on the current branch.
+    fn commit_file(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+        Command::new("git")
+            .args(["add", name])
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-q", "-m", name])
+            .current_dir(dir)
+            .output()
+            .unwrap();
+    }
+
+    #[test]
+    fn rebase_moves_branch_onto_base() {
+        let dir = TempDir::new().unwrap();
+        init_repo(dir.path());
+        Command::new("git")
+            .args(["checkout", "-q", "-b", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "feature.txt", "feature");
+        Command::new("git")
+            .args(["checkout", "-q", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "main.txt", "main");
+        Command::new("git")
+            .args(["checkout", "-q", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        Repo::rebase(dir.path(), "main").unwrap();
+
+        assert!(dir.path().join("main.txt").exists());
+        assert_eq!(Repo::commits_ahead(dir.path(), "main").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn files_changed_since_fork_ignores_base_commits() {
+        let dir = TempDir::new().unwrap();
+        init_repo(dir.path());
+        Command::new("git")
+            .args(["checkout", "-q", "-b", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "feature.txt", "feature");
+        Command::new("git")
+            .args(["checkout", "-q", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "main.txt", "main");
+        Command::new("git")
+            .args(["checkout", "-q", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        assert_eq!(
+            Repo::files_changed_since_fork(dir.path(), "main").unwrap(),
+            vec!["feature.txt".to_string()]
+        );
+    }
+
+    #[test]
+    fn rebase_conflict_aborts() {
+        let dir = TempDir::new().unwrap();
+        init_repo(dir.path());
+        Command::new("git")
+            .args(["checkout", "-q", "-b", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "shared.txt", "feature");
+        Command::new("git")
+            .args(["checkout", "-q", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "shared.txt", "main");
+        Command::new("git")
+            .args(["checkout", "-q", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        assert!(Repo::rebase(dir.path(), "main").is_err());
+        assert!(!dir.path().join(".git/rebase-merge").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("shared.txt")).unwrap(),
+            "feature"
+        );
+    }
+
+    #[test]
+    fn squash_merge_lands_one_commit() {
+        let dir = TempDir::new().unwrap();
+        init_repo(dir.path());
+        Command::new("git")
+            .args(["checkout", "-q", "-b", "feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "a.txt", "a");
+        commit_file(dir.path(), "b.txt", "b");
+        Command::new("git")
+            .args(["checkout", "-q", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        commit_file(dir.path(), "main.txt", "main");
+
+        let repo = Repo::open(dir.path()).unwrap();
+        repo.squash_merge("feature", "feat: add a and b").unwrap();
+
+        let head = repo.inner.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("feat: add a and b"));
+        assert_eq!(head.parent_count(), 1);
+        assert!(dir.path().join("a.txt").exists());
+        assert!(dir.path().join("b.txt").exists());
+        assert!(!Repo::has_uncommitted_changes(dir.path()).unwrap());
+    }
 }
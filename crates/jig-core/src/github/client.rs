@@ -1,12 +1,14 @@
-//! GitHub client wrapping `gh` CLI.
+//! GitHub client over a pluggable [`GitHubTransport`].
 
 use std::path::Path;
 use std::process::{Command, Stdio};
 
 use crate::error::{Error, Result};
 
+use super::transport::{default_transport, GitHubTransport};
 use super::types::{
-    CheckRun, CheckStatus, PrCommit, PrInfo, PrState, PrStateInfo, ReviewComment, ReviewState,
+    CheckRun, CheckStatus, PrCommit, PrInfo, PrLookup, PrSnapshot, PrState, PrStateInfo,
+    ReviewComment, ReviewState,
 };
 
 /// Internal commit struct with timestamp for feedback filtering.
@@ -17,23 +19,39 @@ struct PrCommitFull {
     committed_at: Option<String>,
 }
 
-/// GitHub API client using `gh` CLI.
+/// GitHub API client.
 ///
-/// Auth is delegated entirely to `gh` — it uses `GITHUB_TOKEN`,
-/// `gh auth login`, or whatever the user has configured.
+/// Calls go through the native HTTP transport when a token is configured
+/// (`[github] token` or `GITHUB_TOKEN`), otherwise through the `gh` CLI.
 pub struct GitHubClient {
     /// Repository in `owner/repo` format.
     repo: String,
+    transport: Box<dyn GitHubTransport>,
 }
 
 impl GitHubClient {
-    /// Create a client for the given repository.
+    /// Create a client for the given repository using the default transport.
     pub fn new(repo: impl Into<String>) -> Self {
-        Self { repo: repo.into() }
+        Self::with_transport(repo, default_transport())
+    }
+
+    /// Create a client for the given repository with an explicit transport.
+    pub fn with_transport(repo: impl Into<String>, transport: Box<dyn GitHubTransport>) -> Self {
+        Self {
+            repo: repo.into(),
+            transport,
+        }
     }
 
     /// Detect the repository from the current git remote.
     pub fn from_remote() -> Result<Self> {
+        let transport = default_transport();
+        if transport.name() != "gh" {
+            if let Some(repo) = repo_from_git_remote(Path::new(".")) {
+                return Ok(Self::with_transport(repo, transport));
+            }
+        }
+
         let output = Command::new("gh")
             .args([
                 "repo",
@@ -57,11 +75,26 @@ impl GitHubClient {
             return Err(Error::Custom("Could not determine repository name".into()));
         }
 
-        Ok(Self { repo })
+        Ok(Self::with_transport(repo, transport))
     }
 
-    /// Detect the repository from a specific repo path (runs `gh` in that directory).
+    /// Detect the repository from a specific repo path.
+    ///
+    /// With the HTTP transport the `origin` remote URL is parsed directly;
+    /// otherwise (or if that fails) `gh repo view` is run in that directory.
     pub fn from_repo_path(repo_path: &Path) -> Result<Self> {
+        let transport = default_transport();
+        if transport.name() != "gh" {
+            if let Some(repo) = repo_from_git_remote(repo_path) {
+                tracing::debug!(
+                    repo_path = %repo_path.display(),
+                    owner_repo = %repo,
+                    "created GitHub client from git remote"
+                );
+                return Ok(Self::with_transport(repo, transport));
+            }
+        }
+
         let output = Command::new("gh")
             .args([
                 "repo",
@@ -96,7 +129,12 @@ impl GitHubClient {
             "created GitHub client from repo path"
         );
 
-        Ok(Self { repo })
+        Ok(Self::with_transport(repo, transport))
+    }
+
+    /// Repository in `owner/repo` format.
+    pub fn repo(&self) -> &str {
+        &self.repo
     }
 
     /// Get PR info for a branch.
@@ -281,6 +319,62 @@ impl GitHubClient {
         })
     }
 
+    /// Merge a PR with a merge commit. With `sha`, GitHub refuses unless
+    /// the PR head still matches it.
+    pub fn merge_pr(&self, pr_number: u64, sha: Option<&str>) -> Result<()> {
+        let mut body = serde_json::json!({ "merge_method": "merge" });
+        if let Some(sha) = sha {
+            body["sha"] = sha.into();
+        }
+        self.gh_api_send(
+            "PUT",
+            &format!("repos/{}/pulls/{}/merge", self.repo, pr_number),
+            &body,
+        )?;
+        Ok(())
+    }
+
+    /// Open a PR from `head` into `base`.
+    pub fn create_pr(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+        draft: bool,
+    ) -> Result<PrInfo> {
+        let response = self.gh_api_send(
+            "POST",
+            &format!("repos/{}/pulls", self.repo),
+            &serde_json::json!({
+                "head": head,
+                "base": base,
+                "title": title,
+                "body": body,
+                "draft": draft,
+            }),
+        )?;
+        let pr: serde_json::Value = serde_json::from_str(&response)?;
+        Ok(PrInfo {
+            number: pr["number"].as_u64().unwrap_or(0),
+            title: pr["title"].as_str().unwrap_or(title).to_string(),
+            state: PrState::Open,
+            mergeable: None,
+            head_branch: head.to_string(),
+            url: pr["html_url"].as_str().unwrap_or("").to_string(),
+        })
+    }
+
+    /// Change a PR's base branch.
+    pub fn retarget_pr(&self, pr_number: u64, base: &str) -> Result<()> {
+        self.gh_api_send(
+            "PATCH",
+            &format!("repos/{}/pulls/{}", self.repo, pr_number),
+            &serde_json::json!({ "base": base }),
+        )?;
+        Ok(())
+    }
+
     /// Get aggregated PR feedback: reviews + inline comments with commit context.
     ///
     /// If `between` is `Some((start_sha, end_sha))`, only returns feedback
@@ -376,8 +470,12 @@ impl GitHubClient {
             .collect())
     }
 
-    /// Check if `gh` CLI is available and authenticated.
+    /// Check if GitHub is reachable: a token is configured for the HTTP
+    /// transport, or the `gh` CLI is available and authenticated.
     pub fn is_available() -> bool {
+        if default_transport().name() != "gh" {
+            return true;
+        }
         Command::new("gh")
             .args(["auth", "status"])
             .stdin(Stdio::null())
@@ -492,43 +590,33 @@ impl GitHubClient {
         result
     }
 
-    /// Execute a `gh api` call and return the response body.
+    /// Issue a REST `GET` and return the response body.
     pub(crate) fn gh_api(&self, endpoint: &str) -> Result<String> {
-        let output = Command::new("gh")
-            .args(["api", endpoint, "--cache", "60s"])
-            .stdin(Stdio::null())
-            .output()?;
+        self.transport.get(endpoint)
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(Error::Custom(format!("gh api failed: {}", stderr)));
-        }
+    /// Issue an uncached REST `GET` and return the HTTP status with the body.
+    /// Error statuses are returned rather than raised.
+    pub(crate) fn gh_api_fresh(&self, endpoint: &str) -> Result<(u16, String)> {
+        self.transport.get_fresh(endpoint)
+    }
 
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    /// Issue a mutating REST call (`POST`, `PATCH`, `PUT`, ...) with a JSON
+    /// request body and return the response body.
+    ///
+    /// Unlike [`gh_api`](Self::gh_api) the response is never cached.
+    pub(crate) fn gh_api_send(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: &serde_json::Value,
+    ) -> Result<String> {
+        self.transport.send(method, endpoint, body)
     }
 
-    /// Execute a `gh api graphql` call and return the parsed JSON response.
+    /// Run a GraphQL query and return the parsed JSON response.
     fn gh_graphql(&self, query: &str) -> Result<serde_json::Value> {
-        let output = Command::new("gh")
-            .args([
-                "api",
-                "graphql",
-                "--cache",
-                "60s",
-                "-f",
-                &format!("query={}", query),
-            ])
-            .stdin(Stdio::null())
-            .output()?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(Error::Custom(format!("gh graphql failed: {}", stderr)));
-        }
-
-        let body = String::from_utf8_lossy(&output.stdout);
-        let parsed: serde_json::Value = serde_json::from_str(&body)?;
-        Ok(parsed)
+        self.transport.graphql(query)
     }
 
     /// Fetch review comments from unresolved threads only (via GraphQL).
@@ -596,11 +684,283 @@ impl GitHubClient {
 
         Ok(comments)
     }
+
+    /// Fetch PR snapshots for several lookups in a single GraphQL query.
+    ///
+    /// Each lookup becomes an aliased field on one `repository` query, so a
+    /// whole tick's worth of workers in a repo costs one round trip. Results
+    /// are in lookup order; `None` means no PR was found for that lookup.
+    pub fn get_pr_snapshots(&self, lookups: &[PrLookup]) -> Result<Vec<Option<PrSnapshot>>> {
+        if lookups.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (owner, name) = self
+            .repo
+            .split_once('/')
+            .ok_or_else(|| Error::Custom("invalid repo format".to_string()))?;
+
+        let data = self.gh_graphql(&pr_snapshot_query(owner, name, lookups))?;
+        let repository = &data["data"]["repository"];
+        if repository.is_null() {
+            return Err(Error::Custom(
+                "unexpected graphql response shape".to_string(),
+            ));
+        }
+
+        Ok(lookups
+            .iter()
+            .enumerate()
+            .map(|(i, lookup)| {
+                let field = &repository[format!("pr{}", i)];
+                let node = match lookup {
+                    PrLookup::Number(_) => field,
+                    PrLookup::Branch(_) => &field["nodes"][0],
+                };
+                parse_pr_snapshot(node)
+            })
+            .collect())
+    }
+}
+
+/// GraphQL fields fetched for every PR in a snapshot query.
+const PR_SNAPSHOT_FIELDS: &str = r#"
+  number
+  url
+  state
+  isDraft
+  mergeable
+  head: commits(last: 1) {
+    nodes {
+      commit {
+        statusCheckRollup {
+          contexts(first: 100) {
+            nodes {
+              __typename
+              ... on CheckRun { name status conclusion detailsUrl }
+            }
+          }
+        }
+      }
+    }
+  }
+  commits(last: 100) {
+    nodes { commit { oid messageHeadline committedDate } }
+  }
+  reviews(last: 100) {
+    nodes { state body submittedAt author { login } commit { oid } }
+  }
+  reviewThreads(first: 100) {
+    nodes {
+      isResolved
+      comments(first: 1) {
+        nodes {
+          body
+          path
+          line: originalLine
+          createdAt
+          commit { abbreviatedOid }
+          author { login }
+        }
+      }
+      latest: comments(last: 1) { nodes { createdAt } }
+    }
+  }
+"#;
+
+/// Build the batched snapshot query: one aliased field (`pr0`, `pr1`, ...)
+/// per lookup on a single `repository` node.
+fn pr_snapshot_query(owner: &str, name: &str, lookups: &[PrLookup]) -> String {
+    let mut query = format!(
+        "query {{\n  repository(owner: {}, name: {}) {{\n",
+        serde_json::Value::from(owner),
+        serde_json::Value::from(name)
+    );
+    for (i, lookup) in lookups.iter().enumerate() {
+        match lookup {
+            PrLookup::Number(number) => {
+                query.push_str(&format!(
+                    "    pr{}: pullRequest(number: {}) {{ ...SnapshotFields }}\n",
+                    i, number
+                ));
+            }
+            PrLookup::Branch(branch) => {
+                query.push_str(&format!(
+                    "    pr{}: pullRequests(headRefName: {}, states: OPEN, first: 1) {{ nodes {{ ...SnapshotFields }} }}\n",
+                    i,
+                    serde_json::Value::from(branch.as_str())
+                ));
+            }
+        }
+    }
+    query.push_str("  }\n}\n");
+    query.push_str("fragment SnapshotFields on PullRequest {");
+    query.push_str(PR_SNAPSHOT_FIELDS);
+    query.push_str("}\n");
+    query
+}
+
+/// Parse one `PullRequest` node from a snapshot query.
+fn parse_pr_snapshot(node: &serde_json::Value) -> Option<PrSnapshot> {
+    let number = node["number"].as_u64()?;
+
+    let state = match node["state"].as_str() {
+        Some("MERGED") => PrState::Merged,
+        Some("CLOSED") => PrState::Closed,
+        _ => PrState::Open,
+    };
+
+    let check_runs = node["head"]["nodes"][0]["commit"]["statusCheckRollup"]["contexts"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .filter(|n| n["__typename"].as_str() == Some("CheckRun"))
+                .map(|r| CheckRun {
+                    name: r["name"].as_str().unwrap_or("").to_string(),
+                    status: match r["status"].as_str() {
+                        Some("COMPLETED") => CheckStatus::Completed,
+                        Some("IN_PROGRESS") => CheckStatus::InProgress,
+                        _ => CheckStatus::Queued,
+                    },
+                    conclusion: r["conclusion"].as_str().map(|s| s.to_lowercase()),
+                    details_url: r["detailsUrl"].as_str().map(|s| s.to_string()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let commit_nodes = node["commits"]["nodes"].as_array();
+    let commits: Vec<PrCommit> = commit_nodes
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|c| PrCommit {
+                    sha: c["commit"]["oid"]
+                        .as_str()
+                        .unwrap_or("")
+                        .chars()
+                        .take(7)
+                        .collect(),
+                    message: c["commit"]["messageHeadline"]
+                        .as_str()
+                        .unwrap_or("")
+                        .to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let last_commit_at = commit_nodes
+        .and_then(|nodes| nodes.last())
+        .and_then(|c| c["commit"]["committedDate"].as_str());
+
+    let reviews: Vec<ReviewComment> = node["reviews"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .filter_map(|r| {
+                    let state = match r["state"].as_str()? {
+                        "APPROVED" => ReviewState::Approved,
+                        "CHANGES_REQUESTED" => ReviewState::ChangesRequested,
+                        "COMMENTED" => ReviewState::Commented,
+                        "DISMISSED" => ReviewState::Dismissed,
+                        _ => return None, // Skip unsubmitted draft reviews
+                    };
+                    Some(ReviewComment {
+                        body: r["body"].as_str().unwrap_or("").to_string(),
+                        path: None,
+                        line: None,
+                        state,
+                        author: r["author"]["login"].as_str().unwrap_or("").to_string(),
+                        commit_id: r["commit"]["oid"]
+                            .as_str()
+                            .map(|s| s.chars().take(7).collect()),
+                        submitted_at: r["submittedAt"].as_str().map(|s| s.to_string()),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let threads = node["reviewThreads"]["nodes"].as_array();
+    let review_comments: Vec<ReviewComment> = threads
+        .map(|threads| {
+            threads
+                .iter()
+                .filter(|t| t["isResolved"].as_bool() != Some(true))
+                .filter_map(|t| t["comments"]["nodes"].as_array()?.first())
+                .map(|first| ReviewComment {
+                    body: first["body"].as_str().unwrap_or("").to_string(),
+                    path: first["path"].as_str().map(|s| s.to_string()),
+                    line: first["line"].as_u64(),
+                    state: ReviewState::Commented,
+                    author: first["author"]["login"].as_str().unwrap_or("").to_string(),
+                    commit_id: first["commit"]["abbreviatedOid"]
+                        .as_str()
+                        .map(|s| s.to_string()),
+                    submitted_at: first["createdAt"].as_str().map(|s| s.to_string()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let latest_comment = threads
+        .into_iter()
+        .flatten()
+        .filter_map(|t| t["latest"]["nodes"][0]["createdAt"].as_str())
+        .max();
+    let latest_review = reviews
+        .iter()
+        .filter_map(|r| r.submitted_at.as_deref())
+        .max();
+    let last_feedback_at = latest_review.max(latest_comment);
+    let pushed_after_feedback = match (last_commit_at, last_feedback_at) {
+        (Some(commit), Some(feedback)) => commit > feedback,
+        _ => false,
+    };
+
+    Some(PrSnapshot {
+        number,
+        url: node["url"].as_str().unwrap_or("").to_string(),
+        state,
+        is_draft: node["isDraft"].as_bool().unwrap_or(false),
+        has_conflicts: node["mergeable"].as_str() == Some("CONFLICTING"),
+        check_runs,
+        reviews,
+        review_comments,
+        commits,
+        pushed_after_feedback,
+    })
+}
+
+/// Read `owner/repo` from a repository's `origin` remote URL.
+fn repo_from_git_remote(repo_path: &Path) -> Option<String> {
+    let repo = git2::Repository::discover(repo_path).ok()?;
+    let remote = repo.find_remote("origin").ok()?;
+    parse_remote_repo(remote.url()?)
+}
+
+/// Extract `owner/repo` from an SSH or HTTPS git remote URL.
+fn parse_remote_repo(url: &str) -> Option<String> {
+    let path = if let Some((_, rest)) = url.split_once("://") {
+        // https://host/owner/repo, ssh://git@host/owner/repo
+        rest.split_once('/')?.1
+    } else {
+        // git@host:owner/repo
+        url.split_once(':')?.1
+    };
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    let mut parts = path.rsplitn(3, '/');
+    let name = parts.next().filter(|s| !s.is_empty())?;
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    Some(format!("{}/{}", owner, name))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::github::HttpTransport;
 
     #[test]
     fn new_sets_repo() {
@@ -613,4 +973,191 @@ mod tests {
         // Just verify it doesn't panic — may return true or false depending on env
         let _ = GitHubClient::is_available();
     }
+
+    #[test]
+    fn parse_remote_repo_formats() {
+        for url in [
+            "git@github.com:acme/app.git",
+            "https://github.com/acme/app.git",
+            "https://github.com/acme/app",
+            "ssh://git@ghe.example.com/acme/app.git",
+            "https://token@ghe.example.com/acme/app/",
+        ] {
+            assert_eq!(parse_remote_repo(url).as_deref(), Some("acme/app"), "{url}");
+        }
+        assert_eq!(parse_remote_repo("/local/path"), None);
+        assert_eq!(parse_remote_repo("git@github.com:app"), None);
+    }
+
+    #[test]
+    fn snapshot_query_aliases_each_lookup() {
+        let query = pr_snapshot_query(
+            "acme",
+            "app",
+            &[PrLookup::Number(7), PrLookup::Branch("feat/\"x\"".into())],
+        );
+        assert!(query.contains(r#"repository(owner: "acme", name: "app")"#));
+        assert!(query.contains("pr0: pullRequest(number: 7)"));
+        assert!(query.contains(r#"pr1: pullRequests(headRefName: "feat/\"x\"", states: OPEN"#));
+        assert!(query.contains("fragment SnapshotFields on PullRequest"));
+    }
+
+    fn snapshot_json() -> serde_json::Value {
+        serde_json::json!({
+            "number": 7,
+            "url": "https://github.com/acme/app/pull/7",
+            "state": "OPEN",
+            "isDraft": true,
+            "mergeable": "CONFLICTING",
+            "head": {"nodes": [{"commit": {"statusCheckRollup": {"contexts": {"nodes": [
+                {"__typename": "CheckRun", "name": "test", "status": "COMPLETED",
+                 "conclusion": "FAILURE", "detailsUrl": "https://ci/1"},
+                {"__typename": "StatusContext"}
+            ]}}}}]},
+            "commits": {"nodes": [
+                {"commit": {"oid": "aaaaaaaaaa", "messageHeadline": "feat: a",
+                            "committedDate": "2026-01-01T00:00:00Z"}},
+                {"commit": {"oid": "bbbbbbbbbb", "messageHeadline": "wip",
+                            "committedDate": "2026-01-03T00:00:00Z"}}
+            ]},
+            "reviews": {"nodes": [
+                {"state": "CHANGES_REQUESTED", "body": "no", "submittedAt": "2026-01-02T00:00:00Z",
+                 "author": {"login": "rev"}, "commit": {"oid": "aaaaaaaaaa"}},
+                {"state": "PENDING", "body": "draft", "submittedAt": null,
+                 "author": {"login": "rev"}, "commit": null}
+            ]},
+            "reviewThreads": {"nodes": [
+                {"isResolved": false,
+                 "comments": {"nodes": [{"body": "fix", "path": "a.rs", "line": 3,
+                   "createdAt": "2026-01-02T00:00:00Z", "commit": {"abbreviatedOid": "aaaaaaa"},
+                   "author": {"login": "rev"}}]},
+                 "latest": {"nodes": [{"createdAt": "2026-01-02T12:00:00Z"}]}},
+                {"isResolved": true,
+                 "comments": {"nodes": [{"body": "old", "createdAt": "2026-01-01T00:00:00Z",
+                   "author": {"login": "rev"}}]},
+                 "latest": {"nodes": [{"createdAt": "2026-01-04T00:00:00Z"}]}}
+            ]}
+        })
+    }
+
+    #[test]
+    fn parse_pr_snapshot_maps_fields() {
+        let snap = parse_pr_snapshot(&snapshot_json()).unwrap();
+        assert_eq!(snap.number, 7);
+        assert_eq!(snap.state, PrState::Open);
+        assert!(snap.is_draft);
+        assert!(snap.has_conflicts);
+        assert_eq!(snap.check_runs.len(), 1);
+        assert!(snap.check_runs[0].is_failure());
+        assert_eq!(snap.commits[0].sha, "aaaaaaa");
+        assert_eq!(snap.commits[1].message, "wip");
+        assert_eq!(snap.reviews.len(), 1);
+        assert_eq!(snap.reviews[0].state, ReviewState::ChangesRequested);
+        assert_eq!(snap.review_comments.len(), 1);
+        assert_eq!(snap.review_comments[0].path.as_deref(), Some("a.rs"));
+        // Resolved thread activity (01-04) is newer than the last commit (01-03).
+        assert!(!snap.pushed_after_feedback);
+    }
+
+    #[test]
+    fn parse_pr_snapshot_missing_node() {
+        assert!(parse_pr_snapshot(&serde_json::Value::Null).is_none());
+    }
+
+    /// Serve canned responses on a local port, recording each request as
+    /// `"METHOD PATH\nBODY"`. Every connection is closed after one response.
+    fn mock_server(responses: Vec<&'static str>) -> (String, std::sync::mpsc::Receiver<String>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for body in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                let mut auth = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    let lower = line.to_ascii_lowercase();
+                    if let Some(v) = lower.strip_prefix("content-length:") {
+                        content_length = v.trim().parse().unwrap();
+                    }
+                    if lower.starts_with("authorization:") {
+                        auth = line.trim().to_string();
+                    }
+                }
+                let mut req_body = vec![0; content_length];
+                reader.read_exact(&mut req_body).unwrap();
+                let parts: Vec<&str> = request_line.split_whitespace().collect();
+                tx.send(format!(
+                    "{} {}\n{}\n{}",
+                    parts[0],
+                    parts[1],
+                    auth,
+                    String::from_utf8_lossy(&req_body)
+                ))
+                .unwrap();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base, rx)
+    }
+
+    #[test]
+    fn http_transport_rest_get() {
+        let (base, rx) = mock_server(vec![
+            r#"{"state": "closed", "merged": true, "draft": false, "head": {"sha": "abc"}}"#,
+        ]);
+        let client =
+            GitHubClient::with_transport("acme/app", Box::new(HttpTransport::new(base, "tok")));
+
+        let info = client.get_pr_state(7).unwrap();
+        assert_eq!(info.state, PrState::Merged);
+        assert_eq!(info.head_sha.as_deref(), Some("abc"));
+
+        let req = rx.recv().unwrap();
+        assert!(req.starts_with("GET /repos/acme/app/pulls/7\n"));
+        assert!(req.contains("Bearer tok"));
+    }
+
+    #[test]
+    fn http_transport_batched_snapshots() {
+        let response = serde_json::json!({"data": {"repository": {
+            "pr0": snapshot_json(),
+            "pr1": {"nodes": []}
+        }}});
+        let body: &'static str = Box::leak(response.to_string().into_boxed_str());
+        let (base, rx) = mock_server(vec![body]);
+        let client =
+            GitHubClient::with_transport("acme/app", Box::new(HttpTransport::new(base, "tok")));
+
+        let snaps = client
+            .get_pr_snapshots(&[PrLookup::Number(7), PrLookup::Branch("feat/y".into())])
+            .unwrap();
+        assert_eq!(snaps.len(), 2);
+        assert_eq!(snaps[0].as_ref().unwrap().number, 7);
+        assert!(snaps[1].is_none());
+
+        // One round trip for both lookups.
+        let req = rx.recv().unwrap();
+        assert!(req.starts_with("POST /graphql\n"));
+        assert!(req.contains("pr0: pullRequest(number: 7)"));
+        assert!(req.contains(r#"headRefName: \"feat/y\""#));
+        assert!(rx.try_recv().is_err());
+    }
 }
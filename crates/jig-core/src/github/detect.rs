@@ -1,4 +1,4 @@
-//! Detection functions — check GitHub state and classify nudge types.
+//! Detection functions — check forge PR state and classify nudge types.
 
 use std::sync::LazyLock;
 
@@ -7,7 +7,9 @@ use regex::Regex;
 use crate::error::Result;
 use crate::nudge::NudgeType;
 
-use super::client::GitHubClient;
+use crate::forge::Forge;
+
+use super::types::{CheckRun, PrCommit, PrSnapshot, ReviewComment};
 
 /// Default conventional commit pattern (compiled once).
 static CONVENTIONAL_COMMIT_RE: LazyLock<Regex> = LazyLock::new(|| {
@@ -29,16 +31,19 @@ pub struct PrCheck {
 }
 
 /// Check CI status for a branch and return a nudge if checks are failing.
-pub fn check_ci(client: &GitHubClient, git_ref: &str) -> Result<PrCheck> {
+pub fn check_ci(client: &dyn Forge, git_ref: &str) -> Result<PrCheck> {
     let failures = client.get_failed_checks(git_ref)?;
+    Ok(ci_check(&failures))
+}
 
+fn ci_check(failures: &[CheckRun]) -> PrCheck {
     if failures.is_empty() {
-        return Ok(PrCheck {
+        return PrCheck {
             nudge: None,
             details: vec![],
             review_comment_count: None,
             changes_requested_count: None,
-        });
+        };
     }
 
     let details: Vec<String> = failures
@@ -58,40 +63,55 @@ pub fn check_ci(client: &GitHubClient, git_ref: &str) -> Result<PrCheck> {
         })
         .collect();
 
-    Ok(PrCheck {
+    PrCheck {
         nudge: Some(NudgeType::Ci),
         details,
         review_comment_count: None,
         changes_requested_count: None,
-    })
+    }
 }
 
 /// Check if a PR has merge conflicts.
-pub fn check_conflicts(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
+pub fn check_conflicts(client: &dyn Forge, pr_number: u64) -> Result<PrCheck> {
     let has_conflicts = client.has_conflicts(pr_number)?;
+    Ok(conflict_check(has_conflicts))
+}
 
+fn conflict_check(has_conflicts: bool) -> PrCheck {
     if !has_conflicts {
-        return Ok(PrCheck {
+        return PrCheck {
             nudge: None,
             details: vec![],
             review_comment_count: None,
             changes_requested_count: None,
-        });
+        };
     }
 
-    Ok(PrCheck {
+    PrCheck {
         nudge: Some(NudgeType::Conflict),
         details: vec!["PR has merge conflicts".to_string()],
         review_comment_count: None,
         changes_requested_count: None,
-    })
+    }
 }
 
 /// Check if a PR has unresolved review comments.
-pub fn check_reviews(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
+pub fn check_reviews(client: &dyn Forge, pr_number: u64) -> Result<PrCheck> {
     let reviews = client.get_reviews(pr_number)?;
     let inline = client.get_review_comments(pr_number)?;
+    Ok(review_check(pr_number, &reviews, &inline, || {
+        client.dev_pushed_after_reviews(pr_number)
+    }))
+}
 
+/// Classify review feedback. `dev_pushed_after` is only consulted when
+/// there is feedback, since answering it may cost API calls.
+fn review_check(
+    pr_number: u64,
+    reviews: &[ReviewComment],
+    inline: &[ReviewComment],
+    dev_pushed_after: impl FnOnce() -> bool,
+) -> PrCheck {
     let changes_requested_count = reviews
         .iter()
         .filter(|r| r.state == super::types::ReviewState::ChangesRequested)
@@ -113,12 +133,12 @@ pub fn check_reviews(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
         .collect();
 
     if !has_changes_requested && unresolved_comments.is_empty() {
-        return Ok(PrCheck {
+        return PrCheck {
             nudge: None,
             details: vec![],
             review_comment_count: Some(review_comment_count),
             changes_requested_count: Some(changes_requested_count),
-        });
+        };
     }
 
     // If the dev already pushed commits after the latest review feedback,
@@ -129,13 +149,13 @@ pub fn check_reviews(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
         review_comment_count,
         "check_reviews: has feedback, checking if dev pushed after"
     );
-    if client.dev_pushed_after_reviews(pr_number) {
-        return Ok(PrCheck {
+    if dev_pushed_after() {
+        return PrCheck {
             nudge: None,
             details: vec!["Dev pushed after latest review feedback".to_string()],
             review_comment_count: Some(review_comment_count),
             changes_requested_count: Some(changes_requested_count),
-        });
+        };
     }
 
     let mut details = vec![];
@@ -144,17 +164,21 @@ pub fn check_reviews(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
     }
     details.extend(unresolved_comments);
 
-    Ok(PrCheck {
+    PrCheck {
         nudge: Some(NudgeType::Review),
         details,
         review_comment_count: Some(review_comment_count),
         changes_requested_count: Some(changes_requested_count),
-    })
+    }
 }
 
 /// Check if PR commits follow conventional commit format.
-pub fn check_commits(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
+pub fn check_commits(client: &dyn Forge, pr_number: u64) -> Result<PrCheck> {
     let commits = client.get_pr_commits(pr_number)?;
+    Ok(commit_check(&commits))
+}
+
+fn commit_check(commits: &[PrCommit]) -> PrCheck {
     let re = &*CONVENTIONAL_COMMIT_RE;
 
     let bad: Vec<String> = commits
@@ -164,20 +188,46 @@ pub fn check_commits(client: &GitHubClient, pr_number: u64) -> Result<PrCheck> {
         .collect();
 
     if bad.is_empty() {
-        return Ok(PrCheck {
+        return PrCheck {
             nudge: None,
             details: vec![],
             review_comment_count: None,
             changes_requested_count: None,
-        });
+        };
     }
 
-    Ok(PrCheck {
+    PrCheck {
         nudge: Some(NudgeType::BadCommits),
         details: bad,
         review_comment_count: None,
         changes_requested_count: None,
-    })
+    }
+}
+
+/// Run all four checks against a batched [`PrSnapshot`] without further API
+/// calls. Returns `(name, check)` pairs named like the individual checks.
+pub fn check_snapshot(snapshot: &PrSnapshot) -> Vec<(&'static str, PrCheck)> {
+    let failures: Vec<CheckRun> = snapshot
+        .check_runs
+        .iter()
+        .filter(|r| r.is_failure())
+        .cloned()
+        .collect();
+
+    vec![
+        ("ci", ci_check(&failures)),
+        ("conflicts", conflict_check(snapshot.has_conflicts)),
+        (
+            "reviews",
+            review_check(
+                snapshot.number,
+                &snapshot.reviews,
+                &snapshot.review_comments,
+                || snapshot.pushed_after_feedback,
+            ),
+        ),
+        ("commits", commit_check(&snapshot.commits)),
+    ]
 }
 
 fn truncate(s: &str, max: usize) -> String {
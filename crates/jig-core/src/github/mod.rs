@@ -1,15 +1,22 @@
-//! GitHub integration via `gh` CLI.
+//! GitHub integration.
 //!
-//! Wraps `gh api` calls for PR status, CI checks, and review comments.
-//! Auth is handled by `gh` (uses `GITHUB_TOKEN` or `gh auth login`).
+//! Wraps REST and GraphQL calls for PR status, CI checks, and review
+//! comments. Calls go over a native HTTP client when a token is configured
+//! (`[github] token` or `GITHUB_TOKEN`), otherwise through the `gh` CLI.
 
 mod client;
 mod detect;
+mod transport;
 mod types;
 
 pub use client::GitHubClient;
-pub use detect::{check_ci, check_commits, check_conflicts, check_reviews, PrCheck};
+pub use detect::{
+    check_ci, check_commits, check_conflicts, check_reviews, check_snapshot, PrCheck,
+};
+pub use transport::{
+    default_transport, GhCliTransport, GitHubTransport, HttpTransport, DEFAULT_API_URL,
+};
 pub use types::{
-    CheckRun, CheckStatus, PrCommit, PrFeedback, PrInfo, PrState, PrStateInfo, ReviewComment,
-    ReviewState,
+    CheckRun, CheckStatus, PrCommit, PrFeedback, PrInfo, PrLookup, PrSnapshot, PrState,
+    PrStateInfo, ReviewComment, ReviewState,
 };
@@ -0,0 +1,367 @@
+//! Transports for talking to the GitHub API.
+//!
+//! [`HttpTransport`] speaks REST and GraphQL directly over `ureq` using a
+//! token, and works against GitHub Enterprise Server via a custom API base
+//! URL. [`GhCliTransport`] shells out to `gh api` and is used when no token
+//! is configured.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::global::GlobalConfig;
+
+/// Public GitHub REST API base URL.
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// Upper bound on a whole HTTP request, so a hung call can't stall a tick.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Upper bound on opening the connection.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A way of issuing GitHub API calls.
+///
+/// Endpoints are REST paths relative to the API base URL
+/// (e.g. `repos/owner/repo/pulls/1`), optionally with a query string.
+pub trait GitHubTransport: Send + Sync {
+    /// Issue a `GET` and return the response body.
+    fn get(&self, endpoint: &str) -> Result<String>;
+
+    /// Issue a `GET` that bypasses any response cache and return the HTTP
+    /// status with the body. Error statuses are returned, not raised, so
+    /// callers can tell a missing resource from a failed request.
+    fn get_fresh(&self, endpoint: &str) -> Result<(u16, String)>;
+
+    /// Issue a mutating request (`POST`, `PATCH`, `PUT`, ...) with a JSON
+    /// body and return the response body.
+    fn send(&self, method: &str, endpoint: &str, body: &serde_json::Value) -> Result<String>;
+
+    /// Run a GraphQL query and return the full parsed response
+    /// (including the top-level `data` key).
+    fn graphql(&self, query: &str) -> Result<serde_json::Value>;
+
+    /// Short name for logging ("http" or "gh").
+    fn name(&self) -> &'static str;
+}
+
+/// Pick a transport from global config and the environment.
+///
+/// A token from `[github] token` or `GITHUB_TOKEN` selects the native HTTP
+/// client; otherwise calls go through the `gh` CLI. The choice is resolved
+/// once per process and shared by every client built afterwards.
+pub fn default_transport() -> Box<dyn GitHubTransport> {
+    static RESOLVED: OnceLock<Option<HttpTransport>> = OnceLock::new();
+    match RESOLVED.get_or_init(resolve_http_transport) {
+        Some(http) => Box::new(http.clone()),
+        None => Box::new(GhCliTransport),
+    }
+}
+
+/// The HTTP transport the config and environment ask for, if any.
+fn resolve_http_transport() -> Option<HttpTransport> {
+    let config = GlobalConfig::load().unwrap_or_else(|e| {
+        tracing::warn!("failed to load global config for GitHub: {}", e);
+        GlobalConfig::default()
+    });
+    let token = config
+        .github
+        .token
+        .clone()
+        .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+        .filter(|t| !t.trim().is_empty())?;
+    let base_url = config
+        .github
+        .api_url
+        .clone()
+        .or_else(|| std::env::var("GITHUB_API_URL").ok())
+        .filter(|u| !u.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string());
+    Some(HttpTransport::new(base_url, token))
+}
+
+/// Native REST/GraphQL client authenticated with a token.
+///
+/// Clones share one connection pool.
+#[derive(Clone)]
+pub struct HttpTransport {
+    base_url: String,
+    token: String,
+    agent: ureq::Agent,
+}
+
+impl HttpTransport {
+    /// Create a transport for the given API base URL
+    /// (`https://api.github.com` or `https://HOST/api/v3` for GHES).
+    pub fn new(base_url: impl Into<String>, token: impl Into<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .timeout_connect(Some(CONNECT_TIMEOUT))
+            .build()
+            .into();
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+            agent,
+        }
+    }
+
+    /// REST API base URL without a trailing slash.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// GraphQL endpoint for the configured base URL.
+    ///
+    /// GHES serves REST under `/api/v3` and GraphQL under `/api/graphql`;
+    /// everywhere else GraphQL lives at `{base}/graphql`.
+    pub fn graphql_url(&self) -> String {
+        match self.base_url.strip_suffix("/api/v3") {
+            Some(host) => format!("{}/api/graphql", host),
+            None => format!("{}/graphql", self.base_url),
+        }
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}/{}", self.base_url, endpoint.trim_start_matches('/'))
+    }
+
+    fn read(response: ureq::http::Response<ureq::Body>) -> Result<String> {
+        let (status, body) = Self::read_status(response)?;
+        if status >= 400 {
+            return Err(Error::GitHub(format!("HTTP {}: {}", status, body)));
+        }
+        Ok(body)
+    }
+
+    fn read_status(response: ureq::http::Response<ureq::Body>) -> Result<(u16, String)> {
+        let status = response.status().as_u16();
+        let body = response
+            .into_body()
+            .read_to_string()
+            .map_err(|e| Error::GitHub(format!("failed to read response: {}", e)))?;
+        Ok((status, body))
+    }
+
+    fn call_get(&self, endpoint: &str) -> Result<ureq::http::Response<ureq::Body>> {
+        self.agent
+            .get(&self.url(endpoint))
+            .header("Authorization", &format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "jig")
+            .call()
+            .map_err(|e| Error::GitHub(format!("HTTP request failed: {}", e)))
+    }
+}
+
+impl GitHubTransport for HttpTransport {
+    fn get(&self, endpoint: &str) -> Result<String> {
+        Self::read(self.call_get(endpoint)?)
+    }
+
+    fn get_fresh(&self, endpoint: &str) -> Result<(u16, String)> {
+        // Nothing is cached on this path.
+        Self::read_status(self.call_get(endpoint)?)
+    }
+
+    fn send(&self, method: &str, endpoint: &str, body: &serde_json::Value) -> Result<String> {
+        let url = self.url(endpoint);
+        let request = match method.to_ascii_uppercase().as_str() {
+            "POST" => self.agent.post(&url),
+            "PATCH" => self.agent.patch(&url),
+            "PUT" => self.agent.put(&url),
+            other => {
+                return Err(Error::GitHub(format!("unsupported HTTP method: {}", other)));
+            }
+        };
+        let response = request
+            .header("Authorization", &format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "jig")
+            .send_json(body)
+            .map_err(|e| Error::GitHub(format!("HTTP request failed: {}", e)))?;
+        Self::read(response)
+    }
+
+    fn graphql(&self, query: &str) -> Result<serde_json::Value> {
+        let response = self
+            .agent
+            .post(&self.graphql_url())
+            .header("Authorization", &format!("Bearer {}", self.token))
+            .header("User-Agent", "jig")
+            .send_json(serde_json::json!({ "query": query }))
+            .map_err(|e| Error::GitHub(format!("HTTP request failed: {}", e)))?;
+        let body = Self::read(response)?;
+        let parsed: serde_json::Value = serde_json::from_str(&body)?;
+        graphql_errors(&parsed)?;
+        Ok(parsed)
+    }
+
+    fn name(&self) -> &'static str {
+        "http"
+    }
+}
+
+/// Transport that shells out to the `gh` CLI.
+///
+/// Auth is delegated entirely to `gh` — it uses `gh auth login` or whatever
+/// the user has configured.
+pub struct GhCliTransport;
+
+impl GitHubTransport for GhCliTransport {
+    fn get(&self, endpoint: &str) -> Result<String> {
+        let output = Command::new("gh")
+            .args(["api", endpoint, "--cache", "60s"])
+            .stdin(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Custom(format!("gh api failed: {}", stderr)));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    fn get_fresh(&self, endpoint: &str) -> Result<(u16, String)> {
+        // `--include` puts the status line on stdout, even when `gh` exits
+        // non-zero for an error status.
+        let output = Command::new("gh")
+            .args(["api", "--include", endpoint])
+            .stdin(Stdio::null())
+            .output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        parse_included_response(&stdout).ok_or_else(|| {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Error::Custom(format!("gh api failed: {}", stderr))
+        })
+    }
+
+    fn send(&self, method: &str, endpoint: &str, body: &serde_json::Value) -> Result<String> {
+        let mut child = Command::new("gh")
+            .args(["api", "-X", method, endpoint, "--input", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(body.to_string().as_bytes())?;
+        }
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Custom(format!("gh api failed: {}", stderr)));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    fn graphql(&self, query: &str) -> Result<serde_json::Value> {
+        let output = Command::new("gh")
+            .args([
+                "api",
+                "graphql",
+                "--cache",
+                "60s",
+                "-f",
+                &format!("query={}", query),
+            ])
+            .stdin(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Custom(format!("gh graphql failed: {}", stderr)));
+        }
+
+        let body = String::from_utf8_lossy(&output.stdout);
+        let parsed: serde_json::Value = serde_json::from_str(&body)?;
+        Ok(parsed)
+    }
+
+    fn name(&self) -> &'static str {
+        "gh"
+    }
+}
+
+/// Split `gh api --include` output into the HTTP status and the body.
+fn parse_included_response(output: &str) -> Option<(u16, String)> {
+    let status = output
+        .lines()
+        .next()?
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()?;
+    let body = output
+        .split_once("\r\n\r\n")
+        .or_else(|| output.split_once("\n\n"))
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default();
+    Some((status, body))
+}
+
+/// Turn a GraphQL `errors` array into an error when no data came back.
+///
+/// Partial responses (data plus errors, e.g. one aliased lookup failing)
+/// are passed through so callers can use what did resolve.
+fn graphql_errors(parsed: &serde_json::Value) -> Result<()> {
+    let Some(errors) = parsed["errors"].as_array().filter(|e| !e.is_empty()) else {
+        return Ok(());
+    };
+    if !parsed["data"].is_null() {
+        tracing::debug!(?errors, "GraphQL response contained partial errors");
+        return Ok(());
+    }
+    let msgs: Vec<&str> = errors
+        .iter()
+        .filter_map(|e| e["message"].as_str())
+        .collect();
+    Err(Error::GitHub(msgs.join("; ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphql_url_public() {
+        let t = HttpTransport::new("https://api.github.com/", "t");
+        assert_eq!(t.base_url(), "https://api.github.com");
+        assert_eq!(t.graphql_url(), "https://api.github.com/graphql");
+    }
+
+    #[test]
+    fn graphql_url_enterprise() {
+        let t = HttpTransport::new("https://ghe.example.com/api/v3", "t");
+        assert_eq!(t.graphql_url(), "https://ghe.example.com/api/graphql");
+    }
+
+    #[test]
+    fn included_response_yields_status_and_body() {
+        let output = "HTTP/2.0 404 Not Found\r\nContent-Type: application/json\r\n\r\n{\"message\":\"Not Found\"}";
+        let (status, body) = parse_included_response(output).unwrap();
+        assert_eq!(status, 404);
+        assert_eq!(body, "{\"message\":\"Not Found\"}");
+        assert_eq!(parse_included_response("gh: not logged in"), None);
+    }
+
+    #[test]
+    fn graphql_errors_without_data_fail() {
+        let resp = serde_json::json!({"data": null, "errors": [{"message": "bad"}]});
+        let err = graphql_errors(&resp).unwrap_err();
+        assert!(err.to_string().contains("bad"));
+    }
+
+    #[test]
+    fn graphql_partial_errors_pass() {
+        let resp = serde_json::json!({"data": {"repository": {}}, "errors": [{"message": "x"}]});
+        assert!(graphql_errors(&resp).is_ok());
+    }
+}
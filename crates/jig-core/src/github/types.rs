@@ -108,6 +108,35 @@ pub struct PrFeedback {
     pub inline_comments: Vec<ReviewComment>,
 }
 
+/// How to locate a PR in a batched snapshot query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrLookup {
+    /// A known PR number.
+    Number(u64),
+    /// The open PR whose head is this branch.
+    Branch(String),
+}
+
+/// Everything the daemon's PR checks need, fetched in one GraphQL round trip.
+#[derive(Debug, Clone)]
+pub struct PrSnapshot {
+    pub number: u64,
+    pub url: String,
+    pub state: PrState,
+    pub is_draft: bool,
+    pub has_conflicts: bool,
+    /// Check runs on the PR's head commit.
+    pub check_runs: Vec<CheckRun>,
+    /// Submitted top-level reviews (pending drafts excluded).
+    pub reviews: Vec<ReviewComment>,
+    /// First comment of each unresolved review thread.
+    pub review_comments: Vec<ReviewComment>,
+    pub commits: Vec<PrCommit>,
+    /// Whether the newest commit is later than the newest review activity
+    /// (resolved threads included), i.e. feedback has likely been addressed.
+    pub pushed_after_feedback: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,7 +8,9 @@ use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
+use crate::config::AgentDefinition;
 use crate::error::{Error, Result};
+use crate::notify::WebhookFormat;
 
 use super::paths::global_config_dir;
 
@@ -39,8 +41,94 @@ pub struct NotifyConfig {
     pub exec: Option<String>,
     /// Webhook URL for notifications.
     pub webhook: Option<String>,
+    /// Webhook payload shape: `json` (default), `slack`, `discord`, or `ntfy`.
+    pub webhook_format: WebhookFormat,
+    /// Shared secret for signing webhook bodies (HMAC-SHA256).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+    /// Retries after a failed delivery (default 3).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_retries: Option<u32>,
+    /// Show a desktop notification (`notify-send` / `osascript`).
+    pub desktop: bool,
     /// Event names to subscribe to.
     pub events: Vec<String>,
+    /// Routing rules, checked in order. The targets above are the default
+    /// route, used only when no rule matches.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<NotifyRule>,
+    /// Local-time window (`"22:00-07:00"`) during which deliveries are held
+    /// back. Notifications are still queued.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<String>,
+    /// Suppress repeats of the same notification within this many seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dedup_seconds: Option<u64>,
+    /// Batch notifications into periodic summaries (`[notify.digest]`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<DigestConfig>,
+}
+
+/// Digest mode: matching events are queued but not delivered one by one;
+/// the daemon sends a single summary through the default targets instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DigestConfig {
+    /// Seconds between digests.
+    pub window_seconds: u64,
+    /// Event types to batch. Empty means every type.
+    pub events: Vec<String>,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            window_seconds: 900,
+            events: vec![],
+        }
+    }
+}
+
+/// A notification routing rule (`[[notify.rules]]`).
+///
+/// Every match field is optional; an empty field matches anything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotifyRule {
+    /// Repo names this rule applies to (glob patterns).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub repos: Vec<String>,
+    /// Worker names this rule applies to (glob patterns).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub workers: Vec<String>,
+    /// Event type names this rule applies to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<String>,
+    /// Issue labels the worker's issue must all carry.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Shell command to exec.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec: Option<String>,
+    /// Webhook URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+    /// Webhook payload shape; defaults to `[notify] webhook_format`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_format: Option<WebhookFormat>,
+    /// Webhook signing secret; defaults to `[notify] webhook_secret`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+    /// Show a desktop notification.
+    pub desktop: bool,
+    /// Deliver even during quiet hours.
+    pub ignore_quiet_hours: bool,
+    /// Dedup window for this rule; defaults to `[notify] dedup_seconds`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dedup_seconds: Option<u64>,
+    /// Keep checking later rules after this one matches.
+    #[serde(rename = "continue")]
+    pub continue_matching: bool,
 }
 
 /// GitHub integration configuration.
@@ -51,6 +139,14 @@ pub struct GitHubConfig {
     pub auto_cleanup_merged: bool,
     /// Auto-cleanup workers whose PRs have been closed without merging.
     pub auto_cleanup_closed: bool,
+    /// REST API base URL. Set to `https://HOST/api/v3` for GitHub Enterprise
+    /// Server. Falls back to `GITHUB_API_URL`, then `https://api.github.com`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    /// API token for the native HTTP client. Falls back to `GITHUB_TOKEN`;
+    /// without either, calls go through the `gh` CLI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
 }
 
 impl Default for GitHubConfig {
@@ -58,10 +154,26 @@ impl Default for GitHubConfig {
         Self {
             auto_cleanup_merged: true,
             auto_cleanup_closed: false,
+            api_url: None,
+            token: None,
         }
     }
 }
 
+/// GitLab API configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitLabConfig {
+    /// API base URL. Defaults to `https://HOST/api/v4` for the repo's
+    /// `origin` host.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    /// Personal or project access token (`read_api` scope). Falls back to
+    /// `GITLAB_TOKEN`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
 /// Linear API configuration.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -119,6 +231,8 @@ pub struct GlobalDaemonConfig {
     pub interval_seconds: u64,
     /// Tmux session prefix (default: "jig-").
     pub session_prefix: String,
+    /// Prometheus metrics export.
+    pub metrics: MetricsConfig,
 }
 
 impl Default for GlobalDaemonConfig {
@@ -127,10 +241,24 @@ impl Default for GlobalDaemonConfig {
             auto_recover: true,
             interval_seconds: 30,
             session_prefix: "jig-".to_string(),
+            metrics: MetricsConfig::default(),
         }
     }
 }
 
+/// Metrics export in `[daemon.metrics]`. Both outputs are off by default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Write Prometheus text here after every tick, e.g. for node_exporter's
+    /// textfile collector.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub textfile: Option<String>,
+    /// Serve `/metrics` on this address, e.g. `127.0.0.1:9464`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listen: Option<String>,
+}
+
 /// Global configuration stored at `~/.config/jig/config.toml`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -138,9 +266,13 @@ pub struct GlobalConfig {
     pub health: HealthConfig,
     pub notify: NotifyConfig,
     pub github: GitHubConfig,
+    pub gitlab: GitLabConfig,
     pub linear: LinearConfig,
     pub spawn: GlobalSpawnConfig,
     pub daemon: GlobalDaemonConfig,
+    /// Custom or overridden agent adapters, keyed by name.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub agents: HashMap<String, AgentDefinition>,
 }
 
 impl GlobalConfig {
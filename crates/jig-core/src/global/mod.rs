@@ -7,10 +7,11 @@ pub mod paths;
 pub mod state;
 
 pub use config::{
-    GitHubConfig, GlobalConfig, GlobalDaemonConfig, GlobalSpawnConfig, HealthConfig, NotifyConfig,
+    DigestConfig, GitHubConfig, GitLabConfig, GlobalConfig, GlobalDaemonConfig, GlobalSpawnConfig,
+    HealthConfig, MetricsConfig, NotifyConfig, NotifyRule,
 };
 pub use paths::{
-    daemon_log_path, ensure_global_dirs, global_config_dir, global_hooks_dir, global_state_dir,
-    worker_events_dir,
+    daemon_log_path, daemon_output_path, daemon_pid_path, daemon_socket_path, ensure_global_dirs,
+    global_config_dir, global_hooks_dir, global_state_dir, worker_events_dir,
 };
 pub use state::{WorkerEntry, WorkersState};
@@ -27,6 +27,21 @@ pub fn daemon_log_path() -> Result<PathBuf> {
     Ok(global_state_dir()?.join("daemon.jsonl"))
 }
 
+/// `~/.config/jig/state/daemon.pid`
+pub fn daemon_pid_path() -> Result<PathBuf> {
+    Ok(global_state_dir()?.join("daemon.pid"))
+}
+
+/// `~/.config/jig/state/daemon.log` — output of a daemon started with `jig daemon start`.
+pub fn daemon_output_path() -> Result<PathBuf> {
+    Ok(global_state_dir()?.join("daemon.log"))
+}
+
+/// `~/.config/jig/state/daemon.sock`
+pub fn daemon_socket_path() -> Result<PathBuf> {
+    Ok(global_state_dir()?.join("daemon.sock"))
+}
+
 /// `~/.config/jig/state/events/<repo>-<worker>/`
 pub fn worker_events_dir(repo: &str, worker: &str) -> Result<PathBuf> {
     Ok(global_state_dir()?
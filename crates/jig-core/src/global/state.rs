@@ -32,9 +32,24 @@ pub struct WorkerEntry {
     /// Used by the daemon to identify parent worktrees for auto-update.
     #[serde(default)]
     pub parent_branch: Option<String>,
+    /// Worker this one is stacked on, until its branch lands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stacked_on: Option<String>,
+    /// Commit of the lower branch the stacked worker's commits sit on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_base: Option<String>,
     /// SHA of the last reviewed commit. Used to detect new work since last review.
     #[serde(default)]
     pub last_reviewed_sha: Option<String>,
+    /// Agent adapter the worker runs (e.g. "claude", "codex").
+    #[serde(default)]
+    pub agent: Option<String>,
+    /// Model override the worker was launched with, if any.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Labels of the issue the worker was spawned for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issue_labels: Vec<String>,
 }
 
 /// Aggregated worker state across all repos.
@@ -126,7 +141,12 @@ mod tests {
             nudge_counts: HashMap::new(),
             review_feedback_count: None,
             parent_branch: None,
+            stacked_on: None,
+            stack_base: None,
             last_reviewed_sha: None,
+            agent: None,
+            model: None,
+            issue_labels: vec![],
         }
     }
 
@@ -6,6 +6,7 @@ use crate::error::Result;
 
 /// Hook templates: (filename, script content).
 pub const CLAUDE_HOOK_TEMPLATES: &[(&str, &str)] = &[
+    ("PreToolUse", include_str!("templates/PreToolUse.sh")),
     ("PostToolUse", include_str!("templates/PostToolUse.sh")),
     ("Notification", include_str!("templates/Notification.sh")),
     ("Stop", include_str!("templates/Stop.sh")),
@@ -173,6 +174,11 @@ mod tests {
                 "{} missing shebang",
                 name
             );
+            assert!(
+                content.contains("jig hooks emit"),
+                "{} should write through jig hooks emit",
+                name
+            );
         }
     }
 
@@ -182,7 +188,7 @@ mod tests {
         let hooks_dir = tmp.path().join("hooks");
 
         let result = install_claude_hooks_to(&hooks_dir).unwrap();
-        assert_eq!(result.installed.len(), 3);
+        assert_eq!(result.installed.len(), 4);
         assert!(result.skipped.is_empty());
 
         for (name, _) in CLAUDE_HOOK_TEMPLATES {
@@ -209,7 +215,7 @@ mod tests {
 
         let result = install_claude_hooks_to(&hooks_dir).unwrap();
         // All should be in skipped (existing) but content updated
-        assert_eq!(result.skipped.len(), 3);
+        assert_eq!(result.skipped.len(), 4);
         assert!(result.installed.is_empty());
 
         // Content should be updated
@@ -8,8 +8,10 @@ use std::process::Command;
 
 use crate::commits;
 use crate::config::JigToml;
+use crate::context::RepoContext;
 use crate::error::Result;
 use crate::events::{Event, EventLog, EventType};
+use crate::git::{self, Repo};
 
 /// Handle post-commit hook: emit a Commit event with the HEAD SHA.
 ///
@@ -104,6 +106,27 @@ pub fn handle_pre_commit(_repo_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Handle `jig hooks emit`: append an agent hook's event to the log of the
+/// worker whose worktree contains the current directory.
+///
+/// Outside a jig worktree the worker is named after the current branch, as
+/// the shell hooks did. Returns the log written to.
+pub fn handle_emit(repo: &RepoContext, event: &Event) -> Result<EventLog> {
+    let repo_name = repo
+        .repo_root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let worker = match git::get_current_worktree_name(&repo.worktrees_dir)? {
+        Some(name) => name,
+        None => Repo::discover()?.current_branch()?,
+    };
+
+    let log = EventLog::for_worker(&repo_name, &worker)?;
+    log.append(event)?;
+    Ok(log)
+}
+
 /// Try to identify the repo name and worker name from the repo path.
 ///
 /// Returns `None` if not in a jig-managed worktree.
@@ -1,22 +1,14 @@
 #!/bin/bash
 # jig: write tool_use_end event to event log
-# Claude Code passes JSON on stdin with tool_name, tool_input, cwd, etc.
-
-INPUT=$(cat)
-
-REPO=$(basename "$(git rev-parse --show-toplevel 2>/dev/null)" 2>/dev/null || echo "unknown")
-BRANCH=$(git rev-parse --abbrev-ref HEAD 2>/dev/null || echo "unknown")
-WORKER_ID="${REPO}-${BRANCH//\//-}"
-
-if command -v jq &>/dev/null; then
-  TOOL_NAME=$(printf '%s' "$INPUT" | jq -r '.tool_name // "unknown"')
-else
-  TOOL_NAME="unknown"
-fi
-
-JIG_STATE_DIR="${XDG_CONFIG_HOME:-$HOME/.config}/jig/state"
-EVENT_DIR="$JIG_STATE_DIR/events/$WORKER_ID"
-mkdir -p "$EVENT_DIR"
-
-printf '{"ts":%d,"type":"tool_use_end","tool":"%s"}\n' \
-  "$(date +%s)" "$TOOL_NAME" >> "$EVENT_DIR/events.jsonl"
+# Claude Code passes JSON on stdin with tool_name, tool_use_id, tool_input, cwd, etc.
+# Records the file path or command the tool acted on, when it has one.
+
+command -v jig &>/dev/null || exit 0
+
+exec jig hooks emit tool_use_end \
+  --stdin-field tool=tool_name \
+  --stdin-field tool_use_id=tool_use_id \
+  --stdin-field session=session_id \
+  --stdin-field path=tool_input.file_path \
+  --stdin-field path=tool_input.notebook_path \
+  --stdin-field command=tool_input.command
@@ -0,0 +1,10 @@
+#!/bin/bash
+# jig: write tool_use_start event to event log
+# Claude Code passes JSON on stdin with tool_name, tool_use_id, tool_input, etc.
+# Paired with the tool_use_end from PostToolUse to time each tool call.
+
+command -v jig &>/dev/null || exit 0
+
+exec jig hooks emit tool_use_start \
+  --stdin-field tool=tool_name \
+  --stdin-field tool_use_id=tool_use_id
@@ -14,7 +14,9 @@ use std::process::{Command, Stdio};
 use crate::error::{Error, Result};
 
 use super::provider::IssueProvider;
-use super::types::{ChildIssue, Issue, IssueFilter, IssuePriority, IssueStatus, ParentIssue};
+use super::types::{
+    label_value, ChildIssue, Issue, IssueFilter, IssuePriority, IssueStatus, ParentIssue,
+};
 
 /// File-based issue provider that reads from an `issues/` directory.
 pub struct FileProvider {
@@ -657,7 +659,7 @@ fn parse_issue_content(rel_path: &str, content: &str) -> Result<Issue> {
 
     let children = extract_children(content, rel);
 
-    let labels = extract_field(content, "Labels")
+    let labels: Vec<String> = extract_field(content, "Labels")
         .map(|s| {
             s.split(',')
                 .map(|l| l.trim().to_string())
@@ -666,6 +668,11 @@ fn parse_issue_content(rel_path: &str, content: &str) -> Result<Issue> {
         })
         .unwrap_or_default();
 
+    let agent = extract_field(content, "Agent").or_else(|| label_value(&labels, "agent"));
+    let model = extract_field(content, "Model").or_else(|| label_value(&labels, "model"));
+    let stacked_on =
+        extract_field(content, "Stacked-On").or_else(|| label_value(&labels, "stacked-on"));
+
     let parent = extract_field(content, "Parent").map(|s| {
         if let Some((id, title)) = s.split_once(" — ") {
             (id.trim().to_string(), title.trim().to_string())
@@ -693,6 +700,9 @@ fn parse_issue_content(rel_path: &str, content: &str) -> Result<Issue> {
             status: None,
             body: None,
         }),
+        agent,
+        model,
+        stacked_on,
     })
 }
 
@@ -755,7 +765,7 @@ fn parse_issue_file(path: &Path, issues_dir: &Path) -> Result<Issue> {
 
     let children = extract_children(&content, rel);
 
-    let labels = extract_field(&content, "Labels")
+    let labels: Vec<String> = extract_field(&content, "Labels")
         .map(|s| {
             s.split(',')
                 .map(|l| l.trim().to_string())
@@ -764,6 +774,11 @@ fn parse_issue_file(path: &Path, issues_dir: &Path) -> Result<Issue> {
         })
         .unwrap_or_default();
 
+    let agent = extract_field(&content, "Agent").or_else(|| label_value(&labels, "agent"));
+    let model = extract_field(&content, "Model").or_else(|| label_value(&labels, "model"));
+    let stacked_on =
+        extract_field(&content, "Stacked-On").or_else(|| label_value(&labels, "stacked-on"));
+
     let parent = extract_field(&content, "Parent").map(|s| {
         if let Some((id, title)) = s.split_once(" — ") {
             (id.trim().to_string(), title.trim().to_string())
@@ -791,6 +806,9 @@ fn parse_issue_file(path: &Path, issues_dir: &Path) -> Result<Issue> {
             status: None,
             body: None,
         }),
+        agent,
+        model,
+        stacked_on,
     })
 }
 
@@ -806,7 +824,7 @@ fn extract_title(content: &str) -> Option<String> {
 }
 
 /// Extract a `**Key:** Value` field from markdown frontmatter.
-fn extract_field(content: &str, key: &str) -> Option<String> {
+pub(crate) fn extract_field(content: &str, key: &str) -> Option<String> {
     let prefix = format!("**{}:**", key);
     for line in content.lines() {
         let trimmed = line.trim();
@@ -1185,6 +1203,32 @@ mod tests {
         assert_eq!(issues[0].labels, vec!["backend", "auth", "sprint-12"]);
     }
 
+    #[test]
+    fn parse_agent_override_field_and_label() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("field.md"),
+            "# Field\n\n**Status:** Planned\n**Agent:** codex\n**Model:** o3\n**Stacked-On:** auth-api\n**Labels:** agent:aider\n",
+        )
+        .unwrap();
+        std::fs::write(
+            tmp.path().join("label.md"),
+            "# Label\n\n**Status:** Planned\n**Labels:** backend, Agent:gemini, stacked-on:features/auth\n",
+        )
+        .unwrap();
+
+        let provider = FileProvider::new(tmp.path());
+        let field = provider.get("field").unwrap().unwrap();
+        assert_eq!(field.agent.as_deref(), Some("codex"));
+        assert_eq!(field.model.as_deref(), Some("o3"));
+        assert_eq!(field.stacked_on.as_deref(), Some("auth-api"));
+
+        let label = provider.get("label").unwrap().unwrap();
+        assert_eq!(label.agent.as_deref(), Some("gemini"));
+        assert_eq!(label.model, None);
+        assert_eq!(label.stacked_on.as_deref(), Some("features/auth"));
+    }
+
     #[test]
     fn is_spawnable_with_deps_no_deps() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1219,6 +1263,9 @@ mod tests {
                 labels: vec![],
                 branch_name: None,
                 parent: None,
+                agent: None,
+                model: None,
+                stacked_on: None,
             },
             Issue {
                 id: "a-urgent".into(),
@@ -1233,6 +1280,9 @@ mod tests {
                 labels: vec![],
                 branch_name: None,
                 parent: None,
+                agent: None,
+                model: None,
+                stacked_on: None,
             },
         ];
         sort_issues(&mut issues);
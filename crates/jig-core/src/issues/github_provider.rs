@@ -0,0 +1,460 @@
+//! GitHub Issues provider.
+//!
+//! Reads issues from the repository's GitHub Issues through the same
+//! `gh api` path used by [`GitHubClient`]. GitHub has no native workflow
+//! states, so status and priority are carried as labels:
+//!
+//! - `status:<value>` (e.g. `status:in-progress`) maps to [`IssueStatus`];
+//!   open issues without a status label are `Planned`, closed issues are
+//!   always `Complete`.
+//! - `priority:<value>` (e.g. `priority:high`) maps to [`IssuePriority`].
+//!
+//! Dependencies use the same `**Depends-On:** #12, #13` convention as
+//! file-based issues, written in the issue body.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::config::GitHubIssuesConfig;
+use crate::error::{Error, Result};
+use crate::github::GitHubClient;
+
+use super::file_provider::extract_field;
+use super::provider::{IssueProvider, ProviderKind};
+use super::types::{label_value, Issue, IssueFilter, IssuePriority, IssueStatus};
+
+/// Label prefix carrying an issue's jig status.
+const STATUS_LABEL_PREFIX: &str = "status:";
+/// Label prefix carrying an issue's priority.
+const PRIORITY_LABEL_PREFIX: &str = "priority:";
+/// Issues fetched per page (GitHub's page size limit).
+const PAGE_SIZE: usize = 100;
+
+/// Issue provider backed by GitHub Issues.
+pub struct GitHubProvider {
+    client: GitHubClient,
+    labels: Vec<String>,
+}
+
+impl GitHubProvider {
+    /// The provider kind for GitHub issues.
+    pub const PROVIDER_KIND: ProviderKind = ProviderKind::GitHub;
+
+    /// Build a provider from repo config.
+    ///
+    /// Uses `[issues.github] repo` when set, otherwise detects `owner/repo`
+    /// from the git remote at `repo_root`.
+    pub fn from_config(
+        repo_root: &Path,
+        github_config: Option<&GitHubIssuesConfig>,
+    ) -> Result<Self> {
+        let client = match github_config.and_then(|c| c.repo.as_deref()) {
+            Some(repo) => GitHubClient::new(repo),
+            None => GitHubClient::from_repo_path(repo_root)?,
+        };
+        let labels = github_config.map(|c| c.labels.clone()).unwrap_or_default();
+        Ok(Self { client, labels })
+    }
+
+    /// Fetch a single raw issue by number. Returns `None` for pull requests
+    /// and issues that don't exist.
+    ///
+    /// Always bypasses the response cache: status updates rewrite the label
+    /// set read here, so a stale copy would undo concurrent label changes.
+    fn fetch(&self, number: u64) -> Result<Option<RawIssue>> {
+        let endpoint = format!("repos/{}/issues/{}", self.client.repo(), number);
+        let (status, body) = self.client.gh_api_fresh(&endpoint)?;
+        match status {
+            404 | 410 => return Ok(None),
+            s if s >= 400 => {
+                return Err(Error::GitHub(format!(
+                    "fetching issue #{} failed: HTTP {}: {}",
+                    number, s, body
+                )))
+            }
+            _ => {}
+        }
+        let raw: RawIssue = serde_json::from_str(&body)?;
+        Ok(raw.pull_request.is_none().then_some(raw))
+    }
+}
+
+impl IssueProvider for GitHubProvider {
+    fn name(&self) -> &str {
+        "github"
+    }
+
+    fn kind(&self) -> ProviderKind {
+        Self::PROVIDER_KIND
+    }
+
+    fn list(&self, filter: &IssueFilter) -> Result<Vec<Issue>> {
+        // Push the open/closed split server-side; finer status matching
+        // happens on labels below.
+        let state = match filter.status {
+            Some(IssueStatus::Complete) => "closed",
+            Some(_) => "open",
+            None => "all",
+        };
+
+        let effective_labels: &[String] = if !filter.labels.is_empty() {
+            &filter.labels
+        } else {
+            &self.labels
+        };
+
+        let mut endpoint = format!(
+            "repos/{}/issues?state={}&per_page={}",
+            self.client.repo(),
+            state,
+            PAGE_SIZE
+        );
+        if !effective_labels.is_empty() {
+            endpoint.push_str("&labels=");
+            endpoint.push_str(&urlencoding::encode(&effective_labels.join(",")));
+        }
+
+        // Page through until a short page. Pull requests count toward the
+        // page size, so they are filtered out only afterwards.
+        let mut raw: Vec<RawIssue> = Vec::new();
+        for page in 1.. {
+            let output = self.client.gh_api(&format!("{}&page={}", endpoint, page))?;
+            let batch: Vec<RawIssue> = serde_json::from_str(&output)?;
+            let done = batch.len() < PAGE_SIZE;
+            raw.extend(batch);
+            if done {
+                break;
+            }
+        }
+
+        let mut issues: Vec<Issue> = raw
+            .into_iter()
+            .filter(|r| r.pull_request.is_none())
+            .map(RawIssue::into_issue)
+            .filter(|i| i.matches(filter))
+            .collect();
+
+        // Sort by priority then issue number, consistent with other providers.
+        issues.sort_by(|a, b| {
+            let pa = a.priority.as_ref().map(|p| p.clone() as u8).unwrap_or(99);
+            let pb = b.priority.as_ref().map(|p| p.clone() as u8).unwrap_or(99);
+            pa.cmp(&pb)
+                .then_with(|| issue_number(&a.id).cmp(&issue_number(&b.id)))
+        });
+
+        Ok(issues)
+    }
+
+    fn get(&self, id: &str) -> Result<Option<Issue>> {
+        let Some(number) = issue_number(id) else {
+            return Ok(None);
+        };
+        Ok(self.fetch(number)?.map(RawIssue::into_issue))
+    }
+
+    fn update_status(&self, id: &str, new_status: &IssueStatus) -> Result<()> {
+        let number = issue_number(id)
+            .ok_or_else(|| Error::Custom(format!("invalid GitHub issue number: {}", id)))?;
+        let raw = self
+            .fetch(number)?
+            .ok_or_else(|| Error::Custom(format!("GitHub issue #{} not found", number)))?;
+
+        // Replace any existing status label. Closing the issue is the
+        // Complete marker, so no label is kept for it.
+        let mut labels: Vec<String> = raw
+            .labels
+            .into_iter()
+            .map(|l| l.name)
+            .filter(|l| !is_status_label(l))
+            .collect();
+        if *new_status != IssueStatus::Complete {
+            labels.push(status_label(new_status));
+        }
+
+        let repo = self.client.repo();
+        self.client.gh_api_send(
+            "PUT",
+            &format!("repos/{}/issues/{}/labels", repo, number),
+            &serde_json::json!({ "labels": labels }),
+        )?;
+
+        let is_closed = raw.state == "closed";
+        let state_change = match (new_status, is_closed) {
+            (IssueStatus::Complete, false) => {
+                Some(serde_json::json!({ "state": "closed", "state_reason": "completed" }))
+            }
+            (s, true) if *s != IssueStatus::Complete => {
+                Some(serde_json::json!({ "state": "open" }))
+            }
+            _ => None,
+        };
+        if let Some(body) = state_change {
+            self.client.gh_api_send(
+                "PATCH",
+                &format!("repos/{}/issues/{}", repo, number),
+                &body,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// -- Response types -----------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+struct RawIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    html_url: String,
+    #[serde(default)]
+    labels: Vec<RawLabel>,
+    milestone: Option<RawMilestone>,
+    /// Present when the "issue" is actually a pull request.
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawMilestone {
+    title: String,
+}
+
+// -- Conversion ---------------------------------------------------------------
+
+impl RawIssue {
+    fn into_issue(self) -> Issue {
+        let label_names: Vec<String> = self.labels.into_iter().map(|l| l.name).collect();
+
+        let status = if self.state == "closed" {
+            IssueStatus::Complete
+        } else {
+            label_names
+                .iter()
+                .find_map(|l| strip_prefix_ci(l, STATUS_LABEL_PREFIX))
+                .and_then(IssueStatus::from_str_loose)
+                .unwrap_or(IssueStatus::Planned)
+        };
+
+        let priority = label_names
+            .iter()
+            .find_map(|l| strip_prefix_ci(l, PRIORITY_LABEL_PREFIX))
+            .and_then(IssuePriority::from_str_loose);
+
+        let agent = label_value(&label_names, "agent");
+        let model = label_value(&label_names, "model");
+        let stacked_on = label_value(&label_names, "stacked-on");
+
+        let labels: Vec<String> = label_names
+            .into_iter()
+            .filter(|l| !is_status_label(l) && !is_priority_label(l))
+            .collect();
+
+        let description = self.body.unwrap_or_default();
+        let depends_on = extract_field(&description, "Depends-On")
+            .map(|s| {
+                s.split(',')
+                    .map(|d| d.trim().trim_start_matches('#').to_string())
+                    .filter(|d| !d.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let stacked_on = stacked_on.or_else(|| {
+            extract_field(&description, "Stacked-On").map(|s| s.trim_start_matches('#').to_string())
+        });
+
+        let body = if description.is_empty() {
+            format!("# {}", self.title)
+        } else {
+            format!("# {}\n\n{}", self.title, description)
+        };
+
+        let branch_name = format!("issue-{}-{}", self.number, slugify(&self.title));
+
+        Issue {
+            id: self.number.to_string(),
+            title: self.title,
+            status,
+            priority,
+            category: self.milestone.map(|m| m.title),
+            depends_on,
+            body,
+            source: self.html_url,
+            children: vec![],
+            labels,
+            branch_name: Some(branch_name),
+            parent: None,
+            agent,
+            model,
+            stacked_on,
+        }
+    }
+}
+
+/// Parse an issue number from an ID like `"42"` or `"#42"`.
+fn issue_number(id: &str) -> Option<u64> {
+    id.trim().trim_start_matches('#').parse().ok()
+}
+
+/// The label used to mark an issue with the given status, e.g. `status:in-progress`.
+fn status_label(status: &IssueStatus) -> String {
+    format!(
+        "{}{}",
+        STATUS_LABEL_PREFIX,
+        status.as_str().to_lowercase().replace(' ', "-")
+    )
+}
+
+fn is_status_label(label: &str) -> bool {
+    strip_prefix_ci(label, STATUS_LABEL_PREFIX).is_some()
+}
+
+fn is_priority_label(label: &str) -> bool {
+    strip_prefix_ci(label, PRIORITY_LABEL_PREFIX).is_some()
+}
+
+/// Case-insensitive `strip_prefix`.
+fn strip_prefix_ci<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+/// Lowercase, dash-separated slug of an issue title, capped at a few words
+/// so branch names stay readable.
+fn slugify(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .take(6)
+        .map(|w| w.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(json: serde_json::Value) -> RawIssue {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn open_issue_without_status_label_is_planned() {
+        let issue = raw(serde_json::json!({
+            "number": 7,
+            "title": "Add dark mode",
+            "body": "Make it dark.",
+            "state": "open",
+            "html_url": "https://github.com/acme/app/issues/7",
+            "labels": [{ "name": "frontend" }]
+        }))
+        .into_issue();
+
+        assert_eq!(issue.id, "7");
+        assert_eq!(issue.status, IssueStatus::Planned);
+        assert_eq!(issue.priority, None);
+        assert_eq!(issue.labels, vec!["frontend"]);
+        assert_eq!(issue.branch_name.as_deref(), Some("issue-7-add-dark-mode"));
+        assert!(issue.body.starts_with("# Add dark mode\n\nMake it dark."));
+    }
+
+    #[test]
+    fn status_and_priority_labels_are_mapped_and_hidden() {
+        let issue = raw(serde_json::json!({
+            "number": 12,
+            "title": "Fix login",
+            "body": null,
+            "state": "open",
+            "html_url": "",
+            "labels": [
+                { "name": "Status:In-Progress" },
+                { "name": "priority:high" },
+                { "name": "auth" }
+            ],
+            "milestone": { "title": "v2" }
+        }))
+        .into_issue();
+
+        assert_eq!(issue.status, IssueStatus::InProgress);
+        assert_eq!(issue.priority, Some(IssuePriority::High));
+        assert_eq!(issue.labels, vec!["auth"]);
+        assert_eq!(issue.category.as_deref(), Some("v2"));
+    }
+
+    #[test]
+    fn agent_and_model_labels_become_overrides() {
+        let issue = raw(serde_json::json!({
+            "number": 3,
+            "title": "Port parser",
+            "body": null,
+            "state": "open",
+            "html_url": "",
+            "labels": [{ "name": "agent:codex" }, { "name": "model:o3" }]
+        }))
+        .into_issue();
+
+        assert_eq!(issue.agent.as_deref(), Some("codex"));
+        assert_eq!(issue.model.as_deref(), Some("o3"));
+    }
+
+    #[test]
+    fn closed_issue_is_complete() {
+        let issue = raw(serde_json::json!({
+            "number": 3,
+            "title": "Old",
+            "state": "closed",
+            "html_url": "",
+            "labels": [{ "name": "status:planned" }]
+        }))
+        .into_issue();
+
+        assert_eq!(issue.status, IssueStatus::Complete);
+    }
+
+    #[test]
+    fn depends_on_parsed_from_body() {
+        let issue = raw(serde_json::json!({
+            "number": 5,
+            "title": "Follow-up",
+            "body": "**Depends-On:** #3, #4\n\nDetails",
+            "state": "open",
+            "html_url": ""
+        }))
+        .into_issue();
+
+        assert_eq!(issue.depends_on, vec!["3", "4"]);
+    }
+
+    #[test]
+    fn status_label_roundtrip() {
+        for status in [
+            IssueStatus::Triage,
+            IssueStatus::Backlog,
+            IssueStatus::Planned,
+            IssueStatus::InProgress,
+            IssueStatus::Blocked,
+        ] {
+            let label = status_label(&status);
+            let parsed =
+                strip_prefix_ci(&label, STATUS_LABEL_PREFIX).and_then(IssueStatus::from_str_loose);
+            assert_eq!(parsed, Some(status));
+        }
+        assert_eq!(status_label(&IssueStatus::InProgress), "status:in-progress");
+    }
+
+    #[test]
+    fn issue_number_accepts_hash_prefix() {
+        assert_eq!(issue_number("42"), Some(42));
+        assert_eq!(issue_number("#42"), Some(42));
+        assert_eq!(issue_number("features/foo"), None);
+    }
+}
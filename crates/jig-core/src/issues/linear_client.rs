@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
 
-use super::types::{ChildIssue, Issue, IssuePriority, IssueStatus, ParentIssue};
+use super::types::{label_value, ChildIssue, Issue, IssuePriority, IssueStatus, ParentIssue};
 
 const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 
@@ -481,6 +481,9 @@ impl From<RawIssue> for Issue {
         };
 
         let labels: Vec<String> = raw.labels.nodes.into_iter().map(|l| l.name).collect();
+        let agent = label_value(&labels, "agent");
+        let model = label_value(&labels, "model");
+        let stacked_on = label_value(&labels, "stacked-on");
 
         let parent = raw.parent.map(|p| ParentIssue {
             id: p.identifier,
@@ -503,6 +506,9 @@ impl From<RawIssue> for Issue {
             labels,
             branch_name: raw.branch_name,
             parent,
+            agent,
+            model,
+            stacked_on,
         }
     }
 }
@@ -1,10 +1,11 @@
 //! Issue provider system.
 //!
-//! Abstracts issue backends (file-based, Linear, GitHub, etc.) behind a
+//! Abstracts issue backends (file-based, Linear, GitHub) behind a
 //! common trait. The default `FileProvider` reads `issues/` markdown files
 //! with `**Key:** Value` frontmatter.
 
 pub mod file_provider;
+pub mod github_provider;
 pub mod linear_client;
 pub mod linear_provider;
 pub mod naming;
@@ -12,6 +13,7 @@ pub mod provider;
 pub mod types;
 
 pub use file_provider::FileProvider;
+pub use github_provider::GitHubProvider;
 pub use linear_provider::LinearProvider;
 pub use provider::{IssueProvider, ProviderKind};
 pub use types::{Issue, IssueFilter, IssuePriority, IssueStatus, ParentIssue};
@@ -25,8 +27,9 @@ use crate::global::GlobalConfig;
 /// Create an issue provider based on repo and global configuration.
 ///
 /// When `provider = "linear"`, requires an `[issues.linear]` section in
-/// `jig.toml` and a matching profile in the global config. Otherwise
-/// falls back to the file-based provider reading from the working tree.
+/// `jig.toml` and a matching profile in the global config. When
+/// `provider = "github"`, reads GitHub Issues via `gh`. Otherwise falls
+/// back to the file-based provider reading from the working tree.
 pub fn make_provider(
     repo_root: &Path,
     jig_toml: &JigToml,
@@ -64,6 +67,11 @@ pub fn make_linear_provider(
     LinearProvider::from_config(global_config, linear_config)
 }
 
+/// Create a GitHub Issues provider (for mutation operations).
+pub fn make_github_provider(repo_root: &Path, jig_toml: &JigToml) -> Result<GitHubProvider> {
+    GitHubProvider::from_config(repo_root, jig_toml.issues.github.as_ref())
+}
+
 fn make_provider_inner(
     repo_root: &Path,
     jig_toml: &JigToml,
@@ -80,6 +88,7 @@ fn make_provider_inner(
                 linear_config,
             )?))
         }
+        "github" => Ok(Box::new(make_github_provider(repo_root, jig_toml)?)),
         _ => {
             let issues_dir = repo_root.join(&jig_toml.issues.directory);
             let provider = FileProvider::new(&issues_dir);
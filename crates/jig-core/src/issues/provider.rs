@@ -13,6 +13,8 @@ pub enum ProviderKind {
     File,
     /// Linear integration provider.
     Linear,
+    /// GitHub Issues provider.
+    GitHub,
 }
 
 impl fmt::Display for ProviderKind {
@@ -20,6 +22,7 @@ impl fmt::Display for ProviderKind {
         match self {
             ProviderKind::File => write!(f, "file"),
             ProviderKind::Linear => write!(f, "linear"),
+            ProviderKind::GitHub => write!(f, "github"),
         }
     }
 }
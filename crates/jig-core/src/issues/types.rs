@@ -146,6 +146,15 @@ pub struct Issue {
     pub branch_name: Option<String>,
     /// Parent issue reference with eagerly-fetched metadata.
     pub parent: Option<ParentIssue>,
+    /// Agent adapter override for workers spawned from this issue
+    /// (`agent:<name>` label or `**Agent:**` field).
+    pub agent: Option<String>,
+    /// Model override for workers spawned from this issue
+    /// (`model:<name>` label or `**Model:**` field).
+    pub model: Option<String>,
+    /// Worker or issue whose branch workers spawned from this issue build on
+    /// (`stacked-on:<id>` label or `**Stacked-On:**` field).
+    pub stacked_on: Option<String>,
 }
 
 /// Filter criteria for listing issues.
@@ -182,6 +191,12 @@ impl Issue {
             ProviderKind::Linear => "\n\nISSUE COMPLETION: This issue is tracked by Linear. \
                  Status sync is handled automatically — no manual status update is needed."
                 .to_string(),
+            ProviderKind::GitHub => format!(
+                "\n\nISSUE COMPLETION: This issue is tracked in GitHub Issues (#{0}). \
+                 Include `Closes #{0}` in your PR description so GitHub closes the \
+                 issue when the PR merges — no manual status update is needed.",
+                self.id
+            ),
         };
         let parent_section = match &self.parent {
             Some(parent) => {
@@ -251,6 +266,17 @@ impl Issue {
     }
 }
 
+/// Value of the first `<key>:<value>` label, matching `key` case-insensitively.
+///
+/// Used for per-issue overrides carried as labels, e.g. `agent:codex`.
+pub fn label_value(labels: &[String], key: &str) -> Option<String> {
+    labels.iter().find_map(|label| {
+        let (k, v) = label.split_once(':')?;
+        let v = v.trim();
+        (k.trim().eq_ignore_ascii_case(key) && !v.is_empty()).then(|| v.to_string())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,6 +317,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         assert!(issue.matches(&IssueFilter::default()));
@@ -319,6 +348,9 @@ mod tests {
             labels: vec!["backend".into(), "Auth".into()],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         // Single label match (case-insensitive)
@@ -364,6 +396,9 @@ mod tests {
             labels: vec!["backend".into(), "sprint-1".into()],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         // Empty spawn_labels → auto = true (all issues eligible)
@@ -400,6 +435,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         let context = issue.to_spawn_context(ProviderKind::File);
@@ -462,6 +500,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
         let backlog_issue = Issue {
             id: "backlog-1".into(),
@@ -476,6 +517,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
         let planned_issue = Issue {
             id: "planned-1".into(),
@@ -490,6 +534,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         let planned_filter = IssueFilter {
@@ -524,6 +571,9 @@ mod tests {
                 status: None,
                 body: Some("Epic description".into()),
             }),
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         let context = issue.to_spawn_context(ProviderKind::Linear);
@@ -559,6 +609,9 @@ mod tests {
                 status: None,
                 body: None,
             }),
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         let context = issue.to_spawn_context(ProviderKind::File);
@@ -567,6 +620,33 @@ mod tests {
         assert!(context.contains("Another subtask"));
     }
 
+    #[test]
+    fn to_spawn_context_github_provider() {
+        let issue = Issue {
+            id: "42".into(),
+            title: "Fix the flaky test".into(),
+            status: IssueStatus::Planned,
+            priority: None,
+            category: None,
+            depends_on: vec![],
+            body: "Flake details".into(),
+            source: String::new(),
+            children: vec![],
+            labels: vec![],
+            branch_name: Some("issue-42-fix-the-flaky-test".into()),
+            parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
+        };
+
+        let context = issue.to_spawn_context(ProviderKind::GitHub);
+        assert!(context.contains("Fix the flaky test"));
+        assert!(context.contains("GitHub Issues (#42)"));
+        assert!(context.contains("Closes #42"));
+        assert!(!context.contains("file provider"));
+    }
+
     #[test]
     fn to_spawn_context_linear_provider() {
         let issue = Issue {
@@ -582,6 +662,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         };
 
         let context = issue.to_spawn_context(ProviderKind::Linear);
@@ -605,6 +688,9 @@ mod tests {
             labels: vec![],
             branch_name: None,
             parent: None,
+            agent: None,
+            model: None,
+            stacked_on: None,
         }
     }
 
@@ -0,0 +1,192 @@
+//! Landing a worker — merge its branch into the repo's current branch, stop
+//! its window and unregister it or clean it up.
+//!
+//! Shared by `jig merge`, the `jig ps --watch` dashboard and the daemon's
+//! `merge` control request.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::commits;
+use crate::config::JigToml;
+use crate::context::RepoContext;
+use crate::error::Error;
+use crate::forge;
+use crate::git::Repo;
+use crate::issues::IssueStatus;
+use crate::spawn;
+use crate::state::OrchestratorState;
+use crate::worktree::Worktree;
+
+/// How a worker branch is landed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Strategy {
+    /// Fast-forward, or a merge commit if the branches diverged
+    Merge,
+    /// One commit with a validated conventional message
+    Squash,
+    /// Rebase the branch onto the current branch, then fast-forward
+    Rebase,
+    /// Merge the branch's open PR through the forge API
+    Pr,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LandError {
+    #[error(transparent)]
+    Core(#[from] Error),
+    #[error("no open PR for branch '{0}'")]
+    NoPr(String),
+    #[error("squash message is not a valid conventional commit: {0}")]
+    Validation(String),
+}
+
+/// A line of landing output, left to the caller to show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Note {
+    Success(String),
+    Warning(String),
+}
+
+/// One worker to land.
+#[derive(Debug, Clone)]
+pub struct Landing {
+    pub name: String,
+    pub strategy: Strategy,
+    /// Squash commit message (defaults to the branch's first commit).
+    pub message: Option<String>,
+    /// Also remove the worktree, archive the event log and complete the linked issue.
+    pub cleanup: bool,
+}
+
+impl Landing {
+    /// Land the worker's branch into `target`'s current branch, stop its
+    /// window and unregister it (or clean it up with `cleanup`).
+    pub fn land(&self, repo: &RepoContext, target: &Repo) -> Result<Vec<Note>, LandError> {
+        let worktree_path = repo.worktrees_dir.join(&self.name);
+
+        if !worktree_path.exists() {
+            return Err(Error::WorktreeNotFound(self.name.clone()).into());
+        }
+
+        // Check for uncommitted changes
+        if Repo::has_uncommitted_changes(&worktree_path)? {
+            return Err(Error::UncommittedChanges.into());
+        }
+
+        // Get branch name
+        let branch = Repo::worktree_branch(&worktree_path)?;
+
+        let landed = match self.strategy {
+            Strategy::Merge => {
+                target.merge_branch(&branch)?;
+                format!("Merged branch '{}' into current branch", branch)
+            }
+            Strategy::Squash => {
+                let current = target.current_branch()?;
+                let message = match &self.message {
+                    Some(m) => m.clone(),
+                    None => first_commit_subject(&worktree_path, &current)?,
+                };
+                validate_message(repo, &message)?;
+                target.squash_merge(&branch, &message)?;
+                format!("Squashed branch '{}' into current branch", branch)
+            }
+            Strategy::Rebase => {
+                Repo::rebase(&worktree_path, &target.current_branch()?)?;
+                target.merge_branch(&branch)?;
+                format!("Rebased branch '{}' onto current branch", branch)
+            }
+            Strategy::Pr => {
+                let forge = forge::for_repo(&repo.repo_root)?;
+                let pr = forge
+                    .get_pr_for_branch(&branch)?
+                    .ok_or_else(|| LandError::NoPr(branch.clone()))?;
+                forge.merge_pr(pr.number, None)?;
+                format!("Merged PR #{} for branch '{}'", pr.number, branch)
+            }
+        };
+        let mut notes = vec![Note::Success(landed)];
+
+        // Kill tmux window if running
+        spawn::kill_window(repo, &self.name)?;
+
+        if self.cleanup {
+            notes.extend(self.clean_up(repo)?);
+        } else {
+            // Unregister from spawn state
+            spawn::unregister(repo, &self.name)?;
+        }
+
+        Ok(notes)
+    }
+
+    /// Remove the worktree, archive the event log and complete the linked issue.
+    fn clean_up(&self, repo: &RepoContext) -> Result<Vec<Note>, LandError> {
+        let issue_ref = OrchestratorState::load(&repo.repo_root)?.and_then(|state| {
+            state
+                .get_worker_by_name(&self.name)
+                .and_then(|w| w.task.as_ref())
+                .and_then(|t| t.issue_ref.clone())
+        });
+
+        spawn::archive(repo, &self.name)?;
+        Worktree::open(&repo.repo_root, &repo.worktrees_dir, &self.name)?.remove(false)?;
+        let mut notes = vec![Note::Success(format!("Removed worktree '{}'", self.name))];
+
+        if let Some(issue_ref) = issue_ref {
+            // The merge already landed, so a failed update is only a warning.
+            match complete_issue(repo, &issue_ref) {
+                Ok(true) => notes.push(Note::Success(format!(
+                    "Marked issue '{}' complete",
+                    issue_ref
+                ))),
+                Ok(false) => {}
+                Err(e) => notes.push(Note::Warning(format!(
+                    "Could not complete issue '{}': {}",
+                    issue_ref, e
+                ))),
+            }
+        }
+
+        Ok(notes)
+    }
+}
+
+/// Mark an issue Complete. Returns false if it was already complete or is gone.
+fn complete_issue(repo: &RepoContext, issue_ref: &str) -> crate::Result<bool> {
+    let provider = repo.issue_provider()?;
+    match provider.get(issue_ref)? {
+        Some(issue) if issue.status != IssueStatus::Complete => {
+            provider.update_status(issue_ref, &IssueStatus::Complete)?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Subject of the oldest commit on the worktree's branch that `base` lacks.
+fn first_commit_subject(worktree_path: &Path, base: &str) -> Result<String, Error> {
+    Repo::commits_ahead(worktree_path, base)?
+        .last()
+        .and_then(|c| c.split_once(' '))
+        .map(|(_, subject)| subject.to_string())
+        .ok_or_else(|| Error::Custom(format!("nothing to squash: no commits ahead of {}", base)))
+}
+
+fn validate_message(repo: &RepoContext, message: &str) -> Result<(), LandError> {
+    let config = JigToml::load(&repo.repo_root)?
+        .unwrap_or_default()
+        .commits
+        .to_validation_config();
+    match commits::parse_and_validate(message, &config) {
+        Ok((_, errors)) if errors.is_empty() => Ok(()),
+        Ok((_, errors)) => {
+            let summary: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+            Err(LandError::Validation(summary.join("; ")))
+        }
+        Err(e) => Err(LandError::Validation(e.to_string())),
+    }
+}
@@ -9,6 +9,8 @@
 //! - Agent adapters for different AI assistants
 
 pub mod adapter;
+pub mod auto_rebase;
+pub mod budget;
 pub mod commits;
 pub mod config;
 pub mod context;
@@ -16,37 +18,46 @@ pub mod daemon;
 pub mod dispatch;
 pub mod error;
 pub mod events;
+pub mod forge;
 pub mod git;
 pub mod github;
 pub mod global;
 pub mod hooks;
 pub mod issues;
+pub mod land;
+pub mod merge_queue;
 pub mod notify;
 pub mod nudge;
+pub mod overlap;
+pub mod patterns;
 pub mod registry;
 pub mod review;
 pub mod session;
 pub mod spawn;
+pub mod stack;
 pub mod state;
+pub mod stats;
 pub mod templates;
 pub mod terminal;
 pub mod tmux;
 pub mod worker;
 pub mod worktree;
 
-pub use adapter::{get_adapter, AgentAdapter, AgentType, CLAUDE_CODE};
-pub use config::{Config, JigToml, LinearIssuesConfig, RepoConfig, ReviewConfig};
+pub use adapter::{get_adapter, AgentAdapter, AgentRegistry, AgentType, CLAUDE_CODE};
+pub use config::{AgentDefinition, Config, JigToml, LinearIssuesConfig, RepoConfig, ReviewConfig};
 pub use context::RepoContext;
 pub use error::{Error, Result};
 pub use events::{derive_status, Event, EventLog, EventType, WorkerState};
+pub use forge::{Forge, ForgeKind, GitLabClient};
 pub use github::GitHubClient;
 pub use global::{
     ensure_global_dirs, global_config_dir, global_state_dir, GlobalConfig, WorkerEntry,
     WorkersState,
 };
 pub use issues::{
-    make_file_provider, make_linear_provider, make_provider, make_provider_with_ref, FileProvider,
-    Issue, IssueFilter, IssuePriority, IssueProvider, IssueStatus, LinearProvider,
+    make_file_provider, make_github_provider, make_linear_provider, make_provider,
+    make_provider_with_ref, FileProvider, GitHubProvider, Issue, IssueFilter, IssuePriority,
+    IssueProvider, IssueStatus, LinearProvider,
 };
 pub use nudge::{classify_nudge, execute_nudge, NudgeType};
 pub use registry::RepoRegistry;
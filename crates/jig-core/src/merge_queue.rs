@@ -0,0 +1,378 @@
+//! Merge queue — approved workers landed one at a time.
+//!
+//! Stored at `~/.config/jig/state/merge_queue.json`. The daemon adds
+//! `Approved` workers of repos with `[merge_queue] enabled`, lands the head of
+//! the queue through the merge actor, and records the outcome here so
+//! `jig queue` can show progress.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::global::global_state_dir;
+use crate::issues::types::IssuePriority;
+
+/// Where a queued worker is in landing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryState {
+    /// Waiting for its turn.
+    #[default]
+    Queued,
+    /// Being rebased, verified and merged.
+    Landing,
+    /// Out of attempts; left for a human.
+    Failed,
+}
+
+impl EntryState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Landing => "landing",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// An approved worker waiting to land.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueEntry {
+    pub repo: String,
+    pub worker: String,
+    pub branch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<IssuePriority>,
+    /// Issues this worker's issue depends on. Queued workers for those land first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// When the entry joined, or rejoined after a failed attempt.
+    pub enqueued_at: i64,
+    #[serde(default)]
+    pub state: EntryState,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl QueueEntry {
+    pub fn new(repo: &str, worker: &str, branch: &str, now: i64) -> Self {
+        Self {
+            repo: repo.to_string(),
+            worker: worker.to_string(),
+            branch: branch.to_string(),
+            issue: None,
+            priority: None,
+            depends_on: Vec::new(),
+            enqueued_at: now,
+            state: EntryState::Queued,
+            attempts: 0,
+            last_error: None,
+        }
+    }
+
+    /// Worker key ("repo/worker").
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.repo, self.worker)
+    }
+
+    /// Whether this entry must wait for `other` to land.
+    fn waits_for(&self, other: &QueueEntry) -> bool {
+        self.repo == other.repo
+            && other
+                .issue
+                .as_ref()
+                .is_some_and(|id| self.depends_on.contains(id))
+    }
+}
+
+/// All repos' queues, in landing order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeQueue {
+    pub entries: Vec<QueueEntry>,
+}
+
+impl MergeQueue {
+    /// Load from the default path. Returns an empty queue if missing.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&global_state_dir()?.join("merge_queue.json"))
+    }
+
+    /// Load from a specific path. Returns an empty queue if missing.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save to the default path.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&global_state_dir()?.join("merge_queue.json"))
+    }
+
+    /// Save to a specific path, creating parent directories.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Entries for one repo, in landing order.
+    pub fn for_repo<'a>(&'a self, repo: &'a str) -> impl Iterator<Item = &'a QueueEntry> + 'a {
+        self.entries.iter().filter(move |e| e.repo == repo)
+    }
+
+    /// Replace `repo`'s entries with its currently approved workers.
+    ///
+    /// Workers already queued keep their place, attempts and state; new ones
+    /// join the back. Workers no longer approved drop out, unless they are
+    /// landing right now.
+    pub fn refresh(&mut self, repo: &str, approved: Vec<QueueEntry>) {
+        let mut kept = Vec::new();
+        for entry in std::mem::take(&mut self.entries) {
+            let still_approved = approved.iter().any(|a| a.worker == entry.worker);
+            if entry.repo != repo || still_approved || entry.state == EntryState::Landing {
+                kept.push(entry);
+            }
+        }
+        for entry in approved {
+            if !kept
+                .iter()
+                .any(|e| e.repo == repo && e.worker == entry.worker)
+            {
+                kept.push(entry);
+            }
+        }
+        self.entries = kept;
+        self.order();
+    }
+
+    /// The next entry to land, unless one is already landing. An entry
+    /// whose dependencies are still in the queue (queued, landing or failed)
+    /// waits until they have merged.
+    pub fn next(&self) -> Option<&QueueEntry> {
+        if self.entries.iter().any(|e| e.state == EntryState::Landing) {
+            return None;
+        }
+        self.entries.iter().find(|e| {
+            e.state == EntryState::Queued && !self.entries.iter().any(|other| e.waits_for(other))
+        })
+    }
+
+    /// Mark an entry as landing.
+    pub fn start(&mut self, key: &str) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key() == key) {
+            entry.state = EntryState::Landing;
+        }
+    }
+
+    /// Put entries left landing by a daemon that stopped mid-merge back in line.
+    pub fn requeue_landing(&mut self) {
+        for entry in &mut self.entries {
+            if entry.state == EntryState::Landing {
+                entry.state = EntryState::Queued;
+            }
+        }
+    }
+
+    /// Remove a landed entry.
+    pub fn finish(&mut self, key: &str) {
+        self.entries.retain(|e| e.key() != key);
+    }
+
+    /// Record a failed attempt. The entry goes to the back of its priority
+    /// band, or is marked failed once `max_attempts` is reached. Returns
+    /// whether it was marked failed.
+    pub fn fail(&mut self, key: &str, error: &str, max_attempts: u32, now: i64) -> bool {
+        let Some(entry) = self.entries.iter_mut().find(|e| e.key() == key) else {
+            return false;
+        };
+        entry.attempts += 1;
+        entry.last_error = Some(error.to_string());
+        let failed = entry.attempts >= max_attempts;
+        if failed {
+            entry.state = EntryState::Failed;
+        } else {
+            entry.state = EntryState::Queued;
+            entry.enqueued_at = now;
+        }
+        self.order();
+        failed
+    }
+
+    /// Sort by priority, then time in the queue, then pull each entry behind
+    /// the queued entries it depends on.
+    fn order(&mut self) {
+        self.entries
+            .sort_by_key(|e| (priority_rank(e.priority.as_ref()), e.enqueued_at));
+
+        // Bounded so a dependency cycle can't loop forever.
+        for _ in 0..self.entries.len() {
+            let moved = (0..self.entries.len()).find_map(|i| {
+                (i + 1..self.entries.len())
+                    .rev()
+                    .find(|&j| self.entries[i].waits_for(&self.entries[j]))
+                    .map(|j| (i, j))
+            });
+            let Some((i, j)) = moved else {
+                break;
+            };
+            let entry = self.entries.remove(i);
+            self.entries.insert(j, entry);
+        }
+    }
+}
+
+/// Urgent first; issues without a priority last.
+fn priority_rank(priority: Option<&IssuePriority>) -> u8 {
+    match priority {
+        Some(IssuePriority::Urgent) => 0,
+        Some(IssuePriority::High) => 1,
+        Some(IssuePriority::Medium) => 2,
+        Some(IssuePriority::Low) => 3,
+        None => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(worker: &str, now: i64) -> QueueEntry {
+        QueueEntry::new("repo", worker, worker, now)
+    }
+
+    fn workers(queue: &MergeQueue) -> Vec<&str> {
+        queue.entries.iter().map(|e| e.worker.as_str()).collect()
+    }
+
+    #[test]
+    fn orders_by_priority_then_age() {
+        let mut queue = MergeQueue::default();
+        let mut urgent = entry("urgent", 30);
+        urgent.priority = Some(IssuePriority::Urgent);
+        let mut low = entry("low", 10);
+        low.priority = Some(IssuePriority::Low);
+        queue.refresh(
+            "repo",
+            vec![entry("none", 5), low, urgent, entry("older", 1)],
+        );
+        assert_eq!(workers(&queue), vec!["urgent", "low", "older", "none"]);
+    }
+
+    #[test]
+    fn dependencies_land_first() {
+        let mut queue = MergeQueue::default();
+        let mut child = entry("child", 1);
+        child.priority = Some(IssuePriority::Urgent);
+        child.depends_on = vec!["ENG-1".to_string()];
+        let mut base = entry("base", 2);
+        base.issue = Some("ENG-1".to_string());
+        queue.refresh("repo", vec![child, base, entry("other", 3)]);
+        assert_eq!(workers(&queue), vec!["base", "child", "other"]);
+    }
+
+    #[test]
+    fn dependents_wait_for_a_failed_dependency() {
+        let mut queue = MergeQueue::default();
+        let mut child = entry("child", 1);
+        child.depends_on = vec!["ENG-1".to_string()];
+        let mut base = entry("base", 2);
+        base.issue = Some("ENG-1".to_string());
+        queue.refresh("repo", vec![child, base, entry("other", 3)]);
+
+        queue.start("repo/base");
+        assert!(queue.fail("repo/base", "verify failed", 1, 10));
+        assert_eq!(queue.next().unwrap().worker, "other");
+        queue.finish("repo/other");
+        assert!(queue.next().is_none());
+
+        queue.finish("repo/base");
+        assert_eq!(queue.next().unwrap().worker, "child");
+    }
+
+    #[test]
+    fn dependency_cycle_terminates() {
+        let mut queue = MergeQueue::default();
+        let mut a = entry("a", 1);
+        a.issue = Some("A".to_string());
+        a.depends_on = vec!["B".to_string()];
+        let mut b = entry("b", 2);
+        b.issue = Some("B".to_string());
+        b.depends_on = vec!["A".to_string()];
+        queue.refresh("repo", vec![a, b]);
+        assert_eq!(queue.entries.len(), 2);
+    }
+
+    #[test]
+    fn refresh_keeps_state_and_drops_unapproved() {
+        let mut queue = MergeQueue::default();
+        queue.refresh("repo", vec![entry("a", 1), entry("b", 2)]);
+        queue.entries.push(QueueEntry::new("other", "c", "c", 3));
+        queue.start("repo/a");
+
+        queue.refresh("repo", vec![entry("b", 50), entry("d", 60)]);
+        assert_eq!(workers(&queue), vec!["a", "b", "c", "d"]);
+        assert_eq!(queue.entries[0].state, EntryState::Landing);
+        assert_eq!(queue.entries[1].enqueued_at, 2);
+    }
+
+    #[test]
+    fn one_entry_lands_at_a_time() {
+        let mut queue = MergeQueue::default();
+        queue.refresh("repo", vec![entry("a", 1), entry("b", 2)]);
+        assert_eq!(queue.next().unwrap().worker, "a");
+
+        queue.start("repo/a");
+        assert!(queue.next().is_none());
+
+        queue.finish("repo/a");
+        assert_eq!(queue.next().unwrap().worker, "b");
+
+        queue.start("repo/b");
+        queue.requeue_landing();
+        assert_eq!(queue.next().unwrap().worker, "b");
+    }
+
+    #[test]
+    fn failure_requeues_then_gives_up() {
+        let mut queue = MergeQueue::default();
+        queue.refresh("repo", vec![entry("a", 1), entry("b", 2)]);
+        queue.start("repo/a");
+
+        assert!(!queue.fail("repo/a", "verify failed", 2, 10));
+        assert_eq!(workers(&queue), vec!["b", "a"]);
+        assert_eq!(queue.entries[1].state, EntryState::Queued);
+
+        assert!(queue.fail("repo/a", "verify failed", 2, 20));
+        let a = &queue.entries[1];
+        assert_eq!(a.state, EntryState::Failed);
+        assert_eq!(a.attempts, 2);
+        assert_eq!(a.last_error.as_deref(), Some("verify failed"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("merge_queue.json");
+        let mut queue = MergeQueue::default();
+        queue.refresh("repo", vec![entry("a", 1)]);
+        queue.save_to(&path).unwrap();
+
+        let loaded = MergeQueue::load_from(&path).unwrap();
+        assert_eq!(loaded.entries, queue.entries);
+        assert!(MergeQueue::load_from(&tmp.path().join("missing.json"))
+            .unwrap()
+            .entries
+            .is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+//! Desktop notifications via `osascript` (macOS) or `notify-send` (elsewhere).
+
+use std::process::Command;
+
+use crate::error::{Error, Result};
+
+/// Show a desktop notification.
+pub fn show(title: &str, body: &str) -> Result<()> {
+    let output = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification \"{}\" with title \"{}\"",
+            escape_applescript(body),
+            escape_applescript(title)
+        );
+        Command::new("osascript").args(["-e", &script]).output()?
+    } else {
+        Command::new("notify-send")
+            .args(["--app-name", "jig", title, body])
+            .output()?
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Error::Custom(format!(
+            "desktop notification failed: {}",
+            stderr.trim()
+        )));
+    }
+    Ok(())
+}
+
+fn escape_applescript(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_applescript_quotes() {
+        assert_eq!(escape_applescript(r#"say "hi" \o/"#), r#"say \"hi\" \\o/"#);
+    }
+}
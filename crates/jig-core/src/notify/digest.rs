@@ -0,0 +1,235 @@
+//! Notification digests — one summary for everything queued over a window.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::error::Result;
+
+use super::{Notification, NotificationQueue};
+
+/// Event types in the order they appear in a digest summary, with the
+/// singular and plural phrasing of their count.
+const PHRASES: &[(&str, &str, &str)] = &[
+    ("work_started", "started", "started"),
+    ("pr_opened", "PR opened", "PRs opened"),
+    ("feedback_received", "got feedback", "got feedback"),
+    (
+        "feedback_addressed",
+        "addressed feedback",
+        "addressed feedback",
+    ),
+    ("review_approved", "approved", "approved"),
+    (
+        "needs_intervention",
+        "needs intervention",
+        "need intervention",
+    ),
+    ("work_completed", "completed", "completed"),
+    (
+        "overlap_warning",
+        "overlaps another worker",
+        "overlap other workers",
+    ),
+];
+
+/// Notifications queued in `[since, until)`, summarised.
+#[derive(Debug, Clone, Serialize)]
+pub struct Digest {
+    /// Always `"digest"`, so hooks can tell a digest from a notification.
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub since: i64,
+    pub until: i64,
+    /// Count per event type.
+    pub counts: BTreeMap<String, usize>,
+    pub summary: String,
+    pub notifications: Vec<Notification>,
+}
+
+impl Digest {
+    /// Build a digest from notifications, keeping those in `[since, until)`
+    /// whose type is in `events` (empty = all types).
+    pub fn from_notifications(
+        notifications: Vec<Notification>,
+        since: i64,
+        until: i64,
+        events: &[String],
+    ) -> Self {
+        let notifications: Vec<Notification> = notifications
+            .into_iter()
+            .filter(|n| n.ts >= since && n.ts < until)
+            .filter(|n| events.is_empty() || events.iter().any(|e| e == n.event.type_name()))
+            .collect();
+
+        let mut counts = BTreeMap::new();
+        for n in &notifications {
+            *counts.entry(n.event.type_name().to_string()).or_insert(0) += 1;
+        }
+
+        Self {
+            kind: "digest",
+            since,
+            until,
+            summary: summarize(&counts),
+            counts,
+            notifications,
+        }
+    }
+
+    /// Read a digest for `[since, until)` from the queue.
+    pub fn collect(
+        queue: &NotificationQueue,
+        since: i64,
+        until: i64,
+        events: &[String],
+    ) -> Result<Self> {
+        let notifications = queue.read_since(since - 1)?;
+        Ok(Self::from_notifications(
+            notifications,
+            since,
+            until,
+            events,
+        ))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Summary line followed by one line per notification.
+    pub fn to_text(&self) -> String {
+        let mut text = self.summary.clone();
+        for n in &self.notifications {
+            text.push_str("\n• ");
+            text.push_str(&n.event.summary());
+        }
+        text
+    }
+}
+
+/// "3 PRs opened, 1 needs intervention, 2 completed".
+fn summarize(counts: &BTreeMap<String, usize>) -> String {
+    let parts: Vec<String> = PHRASES
+        .iter()
+        .filter_map(|(kind, one, many)| {
+            let n = *counts.get(*kind)?;
+            Some(format!("{} {}", n, if n == 1 { one } else { many }))
+        })
+        .collect();
+    if parts.is_empty() {
+        "no notifications".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::NotificationEvent;
+
+    fn at(ts: i64, event: NotificationEvent) -> Notification {
+        Notification {
+            ts,
+            id: format!("n{}", ts),
+            event,
+        }
+    }
+
+    fn opened(ts: i64) -> Notification {
+        at(
+            ts,
+            NotificationEvent::PrOpened {
+                repo: "jig".into(),
+                worker: format!("w{}", ts),
+                pr_url: format!("https://github.com/o/r/pull/{}", ts),
+            },
+        )
+    }
+
+    fn completed(ts: i64) -> Notification {
+        at(
+            ts,
+            NotificationEvent::WorkCompleted {
+                repo: "jig".into(),
+                worker: format!("w{}", ts),
+                pr_url: None,
+            },
+        )
+    }
+
+    fn intervention(ts: i64) -> Notification {
+        at(
+            ts,
+            NotificationEvent::NeedsIntervention {
+                repo: "jig".into(),
+                worker: format!("w{}", ts),
+                reason: "stalled".into(),
+            },
+        )
+    }
+
+    #[test]
+    fn summary_counts_by_type() {
+        let digest = Digest::from_notifications(
+            vec![
+                opened(1),
+                completed(2),
+                opened(3),
+                intervention(4),
+                opened(5),
+                completed(6),
+            ],
+            0,
+            100,
+            &[],
+        );
+        assert_eq!(
+            digest.summary,
+            "3 PRs opened, 1 needs intervention, 2 completed"
+        );
+        assert_eq!(digest.counts["pr_opened"], 3);
+        assert_eq!(digest.to_text().lines().count(), 7);
+    }
+
+    #[test]
+    fn window_and_event_filter() {
+        let digest = Digest::from_notifications(
+            vec![opened(5), completed(10), intervention(15), opened(20)],
+            10,
+            20,
+            &["work_completed".to_string(), "pr_opened".to_string()],
+        );
+        assert_eq!(digest.notifications.len(), 1);
+        assert_eq!(digest.summary, "1 completed");
+
+        let empty = Digest::from_notifications(vec![], 0, 10, &[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.summary, "no notifications");
+    }
+
+    #[test]
+    fn digest_json_is_tagged() {
+        let digest = Digest::from_notifications(vec![opened(1)], 0, 10, &[]);
+        let v: serde_json::Value = serde_json::from_str(&digest.to_json().unwrap()).unwrap();
+        assert_eq!(v["type"], "digest");
+        assert_eq!(v["counts"]["pr_opened"], 1);
+        assert_eq!(v["notifications"][0]["type"], "pr_opened");
+    }
+
+    #[test]
+    fn collect_reads_queue() {
+        let tmp = tempfile::tempdir().unwrap();
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        queue.emit(opened(0).event).unwrap();
+        let now = chrono::Utc::now().timestamp();
+
+        let digest = Digest::collect(&queue, now - 60, now + 1, &[]).unwrap();
+        assert_eq!(digest.notifications.len(), 1);
+    }
+}
@@ -36,6 +36,13 @@ pub enum NotificationEvent {
         worker: String,
         pr_url: Option<String>,
     },
+    /// A warning that `worker` and `other` are editing the same files.
+    OverlapWarning {
+        repo: String,
+        worker: String,
+        other: String,
+        files: String,
+    },
     ReviewApproved {
         repo: String,
         worker: String,
@@ -43,6 +50,95 @@ pub enum NotificationEvent {
     },
 }
 
+impl NotificationEvent {
+    /// Repo the event is about.
+    pub fn repo(&self) -> &str {
+        match self {
+            Self::WorkStarted { repo, .. }
+            | Self::PrOpened { repo, .. }
+            | Self::FeedbackReceived { repo, .. }
+            | Self::FeedbackAddressed { repo, .. }
+            | Self::NeedsIntervention { repo, .. }
+            | Self::WorkCompleted { repo, .. }
+            | Self::OverlapWarning { repo, .. }
+            | Self::ReviewApproved { repo, .. } => repo,
+        }
+    }
+
+    /// Worker the event is about.
+    pub fn worker(&self) -> &str {
+        match self {
+            Self::WorkStarted { worker, .. }
+            | Self::PrOpened { worker, .. }
+            | Self::FeedbackReceived { worker, .. }
+            | Self::FeedbackAddressed { worker, .. }
+            | Self::NeedsIntervention { worker, .. }
+            | Self::WorkCompleted { worker, .. }
+            | Self::OverlapWarning { worker, .. }
+            | Self::ReviewApproved { worker, .. } => worker,
+        }
+    }
+
+    /// One-line human-readable description, used for chat webhooks.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::WorkStarted {
+                repo,
+                worker,
+                issue,
+            } => match issue {
+                Some(issue) => format!("{}/{}: started work on {}", repo, worker, issue),
+                None => format!("{}/{}: started work", repo, worker),
+            },
+            Self::PrOpened {
+                repo,
+                worker,
+                pr_url,
+            } => format!("{}/{}: opened PR {}", repo, worker, pr_url),
+            Self::FeedbackReceived {
+                repo,
+                worker,
+                pr_url,
+            } => format!("{}/{}: received feedback on {}", repo, worker, pr_url),
+            Self::FeedbackAddressed {
+                repo,
+                worker,
+                pr_url,
+            } => format!("{}/{}: addressed feedback on {}", repo, worker, pr_url),
+            Self::NeedsIntervention {
+                repo,
+                worker,
+                reason,
+            } => format!("{}/{}: needs intervention: {}", repo, worker, reason),
+            Self::WorkCompleted {
+                repo,
+                worker,
+                pr_url,
+            } => match pr_url {
+                Some(url) => format!("{}/{}: work completed ({})", repo, worker, url),
+                None => format!("{}/{}: work completed", repo, worker),
+            },
+            Self::OverlapWarning {
+                repo,
+                worker,
+                other,
+                files,
+            } => format!(
+                "{}/{}: editing the same files as {}: {}",
+                repo, worker, other, files
+            ),
+            Self::ReviewApproved {
+                repo,
+                worker,
+                pr_url,
+            } => match pr_url {
+                Some(url) => format!("{}/{}: review approved ({})", repo, worker, url),
+                None => format!("{}/{}: review approved", repo, worker),
+            },
+        }
+    }
+}
+
 /// A timestamped, uniquely identified notification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Notification {
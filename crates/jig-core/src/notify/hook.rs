@@ -6,17 +6,34 @@ use std::process::{Command, Stdio};
 use crate::error::Result;
 use crate::global::NotifyConfig;
 
-use super::{NotificationEvent, NotificationQueue};
+use super::route::{self, Deduper, QuietHours, Route, RouteContext};
+use super::{
+    desktop, DeliveryFailure, Digest, Notification, NotificationEvent, NotificationQueue,
+    WebhookSender,
+};
 
-/// Notifier wraps the queue and executes hooks on emit.
+/// Notifier wraps the queue and routes each notification to its targets.
 pub struct Notifier {
     config: NotifyConfig,
     queue: NotificationQueue,
+    context: Option<Box<dyn RouteContext>>,
+    dedup: Deduper,
 }
 
 impl Notifier {
     pub fn new(config: NotifyConfig, queue: NotificationQueue) -> Self {
-        Self { config, queue }
+        Self {
+            config,
+            queue,
+            context: None,
+            dedup: Deduper::default(),
+        }
+    }
+
+    /// Resolve per-repo `[notify]` overrides and issue labels through `context`.
+    pub fn with_context(mut self, context: Box<dyn RouteContext>) -> Self {
+        self.context = Some(context);
+        self
     }
 
     /// Access the underlying config.
@@ -29,54 +46,242 @@ impl Notifier {
         &self.queue
     }
 
-    /// Emit a notification: write to queue, then trigger hooks.
-    /// Hook failures are logged but swallowed (best-effort, for daemon use).
+    /// Emit a notification: write to queue, then deliver to each route.
+    /// Delivery failures are logged but swallowed (best-effort, for daemon use).
     pub fn emit(&self, event: NotificationEvent) -> Result<()> {
         // Always write to queue
-        self.queue.emit(event.clone())?;
-
-        // Check if this event type should trigger hooks
-        if !self.should_trigger(&event) {
+        let notification = self.queue.emit(event)?;
+        if self.is_digested(&notification.event) {
             return Ok(());
         }
+        let routes = self.active_routes(&notification);
+        self.deliver(&notification, routes)
+    }
 
-        let json = serde_json::to_string(&event)?;
+    /// Deliver a queued notification to `routes`, best-effort.
+    fn deliver(&self, notification: &Notification, routes: Vec<Route>) -> Result<()> {
+        let json = serde_json::to_string(&notification.event)?;
+
+        for route in routes {
+            // Execute script hook (best-effort, don't fail the operation)
+            if let Some(exec) = &route.exec {
+                if let Err(e) = self.exec_hook(exec, &json) {
+                    tracing::warn!(route = %route.name, "notification hook failed: {}", e);
+                } else {
+                    tracing::debug!(
+                        event_type = notification.event.type_name(),
+                        route = %route.name,
+                        "notification hook executed"
+                    );
+                }
+            }
 
-        // Execute script hook (best-effort, don't fail the operation)
-        if let Some(exec) = &self.config.exec {
-            if let Err(e) = self.exec_hook(exec, &json) {
-                tracing::warn!("notification hook failed: {}", e);
-            } else {
-                tracing::debug!(event_type = event.type_name(), "notification hook executed");
+            // Deliver the webhook in the background so retries don't stall the caller
+            if let Some(sender) = route.webhook {
+                let queue = self.queue.clone();
+                let notification = notification.clone();
+                std::thread::spawn(move || {
+                    let _ = deliver_webhook(&sender, &queue, &notification);
+                });
+            }
+
+            if route.desktop {
+                if let Err(e) = show_desktop(&notification.event) {
+                    tracing::warn!(route = %route.name, "{}", e);
+                }
             }
         }
 
         Ok(())
     }
 
-    /// Emit a notification strictly: write to queue, then trigger hooks.
-    /// Hook failures are returned as errors (for CLI use).
+    /// Emit a notification strictly: write to queue, then deliver to each
+    /// route. The first delivery failure is returned as an error (for CLI use).
     pub fn emit_strict(&self, event: NotificationEvent) -> Result<()> {
         // Always write to queue
-        self.queue.emit(event.clone())?;
-
-        // Check if this event type should trigger hooks
-        if !self.should_trigger(&event) {
+        let notification = self.queue.emit(event)?;
+        if self.is_digested(&notification.event) {
             return Ok(());
         }
+        let json = serde_json::to_string(&notification.event)?;
 
-        let json = serde_json::to_string(&event)?;
+        let mut first_err = None;
+        for route in self.active_routes(&notification) {
+            let mut results = Vec::new();
+            if let Some(exec) = &route.exec {
+                results.push(self.exec_hook_strict(exec, &json));
+            }
+            if let Some(sender) = &route.webhook {
+                results.push(deliver_webhook(sender, &self.queue, &notification));
+            }
+            if route.desktop {
+                results.push(show_desktop(&notification.event));
+            }
+            if let Some(e) = results.into_iter().find_map(|r| r.err()) {
+                first_err.get_or_insert(e);
+            }
+        }
 
-        // Execute script hook — propagate errors to caller
-        if let Some(exec) = &self.config.exec {
-            self.exec_hook_strict(exec, &json)?;
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(()),
         }
+    }
 
-        Ok(())
+    /// Effective config for a repo: jig.toml `[notify]` merged over global.
+    pub fn config_for_repo(&self, repo: &str) -> NotifyConfig {
+        match self.context.as_ref().and_then(|c| c.repo_config(repo)) {
+            Some(repo_config) => repo_config.resolve(&self.config),
+            None => self.config.clone(),
+        }
+    }
+
+    /// Routes for a notification after quiet hours and de-duplication.
+    ///
+    /// When quiet hours hold back any route, the notification is kept in the
+    /// queue's held list until [`release_held`](Self::release_held) delivers it.
+    fn active_routes(&self, notification: &Notification) -> Vec<Route> {
+        let event = &notification.event;
+        let config = self.config_for_repo(event.repo());
+        let quiet = is_quiet(&config);
+
+        let (held, routes): (Vec<Route>, Vec<Route>) = self
+            .matching_routes(&config, event)
+            .into_iter()
+            .partition(|r| quiet && !r.ignore_quiet_hours);
+        if !held.is_empty() {
+            tracing::debug!(routes = held.len(), "quiet hours, holding notification");
+            if let Err(e) = self.queue.hold(notification) {
+                tracing::warn!("failed to hold notification: {}", e);
+            }
+        }
+
+        self.dedup_routes(event, routes)
+    }
+
+    /// Routes with targets that match an event.
+    fn matching_routes(&self, config: &NotifyConfig, event: &NotificationEvent) -> Vec<Route> {
+        let labels = match &self.context {
+            Some(ctx) if config.rules.iter().any(|r| !r.labels.is_empty()) => {
+                ctx.issue_labels(event.repo(), event.worker())
+            }
+            _ => Vec::new(),
+        };
+
+        route::routes(config, event, &labels)
+            .into_iter()
+            .filter(|r| r.has_targets())
+            .collect()
+    }
+
+    /// Drop routes that already delivered the same notification within their
+    /// de-duplication window.
+    fn dedup_routes(&self, event: &NotificationEvent, routes: Vec<Route>) -> Vec<Route> {
+        let now = chrono::Utc::now().timestamp();
+        routes
+            .into_iter()
+            .filter(|r| match r.dedup_seconds {
+                Some(window) => {
+                    let fresh = self.dedup.check(&route::dedup_key(r, event), window, now);
+                    if !fresh {
+                        tracing::debug!(route = %r.name, "duplicate notification suppressed");
+                    }
+                    fresh
+                }
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Deliver notifications held back by quiet hours once their repo's
+    /// quiet hours are over.
+    ///
+    /// Routes that ignore quiet hours already delivered them, so only the
+    /// held routes run. Delivery is best-effort (for daemon use). Returns
+    /// how many notifications were released.
+    pub fn release_held(&self) -> Result<usize> {
+        let mut released = 0;
+        for notification in self.queue.take_held()? {
+            let config = self.config_for_repo(notification.event.repo());
+            if is_quiet(&config) {
+                self.queue.hold(&notification)?;
+                continue;
+            }
+            let routes = self
+                .matching_routes(&config, &notification.event)
+                .into_iter()
+                .filter(|r| !r.ignore_quiet_hours)
+                .collect();
+            let routes = self.dedup_routes(&notification.event, routes);
+            self.deliver(&notification, routes)?;
+            released += 1;
+        }
+        Ok(released)
+    }
+
+    /// Whether an event is held for the digest instead of delivered now.
+    fn is_digested(&self, event: &NotificationEvent) -> bool {
+        self.config
+            .digest
+            .as_ref()
+            .is_some_and(|d| d.events.is_empty() || d.events.iter().any(|e| e == event.type_name()))
+    }
+
+    /// Send a digest if digest mode is on and the window has elapsed.
+    ///
+    /// Covers everything queued since the previous digest. Delivery is
+    /// best-effort (for daemon use); held back during quiet hours. Returns
+    /// the digest when one was sent.
+    pub fn flush_digest(&self) -> Result<Option<Digest>> {
+        let Some(digest_config) = &self.config.digest else {
+            return Ok(None);
+        };
+        let now = chrono::Utc::now().timestamp();
+        let Some(since) = self.queue.last_digest()? else {
+            // First run: start the window now rather than digesting history
+            self.queue.set_last_digest(now)?;
+            return Ok(None);
+        };
+        if now - since < digest_config.window_seconds as i64 || is_quiet(&self.config) {
+            return Ok(None);
+        }
+
+        let digest = Digest::collect(&self.queue, since, now, &digest_config.events)?;
+        self.queue.set_last_digest(now)?;
+        if digest.is_empty() {
+            return Ok(None);
+        }
+        if let Err(e) = self.send_digest(&digest) {
+            tracing::warn!("notification digest delivery failed: {}", e);
+        }
+        Ok(Some(digest))
     }
 
-    /// Check if this event type is in the configured filter list.
-    /// Empty list means trigger for all events.
+    /// Deliver a digest through the default `[notify]` targets. The first
+    /// failure is returned as an error.
+    pub fn send_digest(&self, digest: &Digest) -> Result<()> {
+        let route = Route::default_route(&self.config);
+        let mut results = Vec::new();
+        if let Some(exec) = &route.exec {
+            results.push(self.exec_hook_strict(exec, &digest.to_json()?));
+        }
+        if let Some(sender) = &route.webhook {
+            let id = format!("digest-{}", digest.until);
+            results.push(record_webhook_result(
+                sender,
+                &self.queue,
+                &id,
+                sender.send_digest(digest),
+            ));
+        }
+        if route.desktop {
+            results.push(desktop::show("jig: digest", &digest.summary));
+        }
+        results.into_iter().collect()
+    }
+
+    /// Check if this event type is in the configured filter list for the
+    /// default route. Empty list means trigger for all events.
     pub fn should_trigger(&self, event: &NotificationEvent) -> bool {
         if self.config.events.is_empty() {
             return true;
@@ -143,8 +348,70 @@ impl Notifier {
     }
 }
 
+/// Send a notification to the webhook, recording a failure in the queue's
+/// failure log when all attempts are exhausted.
+fn deliver_webhook(
+    sender: &WebhookSender,
+    queue: &NotificationQueue,
+    notification: &Notification,
+) -> Result<()> {
+    record_webhook_result(sender, queue, &notification.id, sender.send(notification))
+}
+
+/// Log a webhook delivery outcome, recording failures in the queue's
+/// failure log.
+fn record_webhook_result(
+    sender: &WebhookSender,
+    queue: &NotificationQueue,
+    id: &str,
+    result: std::result::Result<u32, (crate::error::Error, u32)>,
+) -> Result<()> {
+    match result {
+        Ok(attempts) => {
+            tracing::debug!(id, attempts, "notification webhook delivered");
+            Ok(())
+        }
+        Err((e, attempts)) => {
+            tracing::warn!(
+                "notification webhook failed after {} attempt(s): {}",
+                attempts,
+                e
+            );
+            let failure = DeliveryFailure {
+                ts: chrono::Utc::now().timestamp(),
+                notification_id: id.to_string(),
+                target: sender.url().to_string(),
+                attempts,
+                error: e.to_string(),
+            };
+            if let Err(log_err) = queue.record_failure(&failure) {
+                tracing::warn!("failed to record webhook failure: {}", log_err);
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Whether `[notify] quiet_hours` covers the current local time.
+fn is_quiet(config: &NotifyConfig) -> bool {
+    match config.quiet_hours.as_deref() {
+        Some(spec) => match QuietHours::parse(spec) {
+            Some(hours) => hours.contains(chrono::Local::now().time()),
+            None => {
+                tracing::warn!("ignoring invalid notify quiet_hours: {:?}", spec);
+                false
+            }
+        },
+        None => false,
+    }
+}
+
+fn show_desktop(event: &NotificationEvent) -> Result<()> {
+    desktop::show(&format!("jig: {}", event.type_name()), &event.summary())
+}
+
 /// Expand `~` at the start of a path to the home directory.
-fn expand_tilde(path: &str) -> String {
+pub(crate) fn expand_tilde(path: &str) -> String {
     if let Some(rest) = path.strip_prefix("~/") {
         if let Some(home) = dirs::home_dir() {
             return home.join(rest).to_string_lossy().to_string();
@@ -163,6 +430,7 @@ impl NotificationEvent {
             Self::FeedbackAddressed { .. } => "feedback_addressed",
             Self::NeedsIntervention { .. } => "needs_intervention",
             Self::WorkCompleted { .. } => "work_completed",
+            Self::OverlapWarning { .. } => "overlap_warning",
             Self::ReviewApproved { .. } => "review_approved",
         }
     }
@@ -336,4 +604,231 @@ mod tests {
         assert!(content.contains("needs_intervention"));
         assert!(content.contains("jig"));
     }
+
+    #[test]
+    fn emit_strict_records_webhook_failure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        // Nothing listens on port 9 (discard) on loopback
+        let config = NotifyConfig {
+            webhook: Some("http://127.0.0.1:9/hook".to_string()),
+            webhook_retries: Some(0),
+            ..Default::default()
+        };
+        let notifier = Notifier::new(config, queue);
+
+        assert!(notifier.emit_strict(make_event()).is_err());
+
+        let queued = notifier.queue().tail(1).unwrap();
+        let failures = notifier.queue().failures(10).unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].notification_id, queued[0].id);
+        assert_eq!(failures[0].target, "http://127.0.0.1:9/hook");
+        assert_eq!(failures[0].attempts, 1);
+    }
+
+    struct FakeContext {
+        repo: Option<crate::config::RepoNotifyConfig>,
+        labels: Vec<String>,
+    }
+
+    impl RouteContext for FakeContext {
+        fn repo_config(&self, _repo: &str) -> Option<crate::config::RepoNotifyConfig> {
+            self.repo.clone()
+        }
+
+        fn issue_labels(&self, _repo: &str, _worker: &str) -> Vec<String> {
+            self.labels.clone()
+        }
+    }
+
+    fn append_to(path: &std::path::Path, tag: &str) -> String {
+        format!("cat >/dev/null; echo {} >> {}", tag, path.display())
+    }
+
+    fn hook_log(path: &std::path::Path) -> Vec<String> {
+        std::fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn rules_route_by_label_before_default() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = tmp.path().join("hooks.log");
+        let config = NotifyConfig {
+            exec: Some(append_to(&log, "default")),
+            rules: vec![crate::global::NotifyRule {
+                labels: vec!["urgent".to_string()],
+                exec: Some(append_to(&log, "pager")),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        let notifier = Notifier::new(config.clone(), queue).with_context(Box::new(FakeContext {
+            repo: None,
+            labels: vec!["urgent".to_string()],
+        }));
+        notifier.emit_strict(make_event()).unwrap();
+
+        let queue = NotificationQueue::new(tmp.path().join("n2.jsonl"));
+        let notifier = Notifier::new(config, queue).with_context(Box::new(FakeContext {
+            repo: None,
+            labels: vec![],
+        }));
+        notifier.emit_strict(make_event()).unwrap();
+
+        assert_eq!(hook_log(&log), vec!["pager", "default"]);
+    }
+
+    #[test]
+    fn repo_override_replaces_global_target() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = tmp.path().join("hooks.log");
+        let config = NotifyConfig {
+            exec: Some(append_to(&log, "global")),
+            ..Default::default()
+        };
+        let repo = crate::config::RepoNotifyConfig {
+            exec: Some(append_to(&log, "repo")),
+            ..Default::default()
+        };
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        let notifier = Notifier::new(config, queue).with_context(Box::new(FakeContext {
+            repo: Some(repo),
+            labels: vec![],
+        }));
+
+        notifier.emit_strict(make_event()).unwrap();
+
+        assert_eq!(hook_log(&log), vec!["repo"]);
+    }
+
+    #[test]
+    fn dedup_window_suppresses_repeats_but_queues_them() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = tmp.path().join("hooks.log");
+        let config = NotifyConfig {
+            exec: Some(append_to(&log, "hook")),
+            dedup_seconds: Some(3600),
+            ..Default::default()
+        };
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        let notifier = Notifier::new(config, queue);
+
+        notifier.emit_strict(make_event()).unwrap();
+        notifier.emit_strict(make_event()).unwrap();
+
+        assert_eq!(hook_log(&log), vec!["hook"]);
+        assert_eq!(notifier.queue().tail(10).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn quiet_hours_hold_deliveries_unless_rule_ignores_them() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = tmp.path().join("hooks.log");
+        // A window covering the whole day except one minute that has passed
+        let now = chrono::Local::now().time();
+        let start = (now - chrono::Duration::minutes(1)).format("%H:%M");
+        let end = (now - chrono::Duration::minutes(2)).format("%H:%M");
+        let config = NotifyConfig {
+            quiet_hours: Some(format!("{}-{}", start, end)),
+            rules: vec![
+                crate::global::NotifyRule {
+                    exec: Some(append_to(&log, "held")),
+                    continue_matching: true,
+                    ..Default::default()
+                },
+                crate::global::NotifyRule {
+                    exec: Some(append_to(&log, "urgent")),
+                    ignore_quiet_hours: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        let notifier = Notifier::new(config.clone(), queue.clone());
+
+        notifier.emit_strict(make_event()).unwrap();
+        assert_eq!(hook_log(&log), vec!["urgent"]);
+
+        // Still quiet: nothing is released
+        assert_eq!(notifier.release_held().unwrap(), 0);
+        assert_eq!(hook_log(&log), vec!["urgent"]);
+
+        // Once quiet hours end, only the held route delivers
+        let config = NotifyConfig {
+            quiet_hours: None,
+            ..config
+        };
+        let notifier = Notifier::new(config, queue);
+        assert_eq!(notifier.release_held().unwrap(), 1);
+        assert_eq!(hook_log(&log), vec!["urgent", "held"]);
+        assert_eq!(notifier.release_held().unwrap(), 0);
+    }
+
+    #[test]
+    fn digest_mode_holds_events_then_sends_one_summary() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = tmp.path().join("hooks.log");
+        let config = NotifyConfig {
+            exec: Some(format!(
+                "cat >> {}; echo >> {}",
+                log.display(),
+                log.display()
+            )),
+            digest: Some(crate::global::DigestConfig {
+                window_seconds: 0,
+                events: vec!["pr_opened".to_string()],
+            }),
+            ..Default::default()
+        };
+        let queue = NotificationQueue::new(tmp.path().join("n.jsonl"));
+        let notifier = Notifier::new(config, queue);
+
+        // First flush only starts the window
+        assert!(notifier.flush_digest().unwrap().is_none());
+        let since = notifier.queue().last_digest().unwrap().unwrap();
+        notifier.queue().set_last_digest(since - 10).unwrap();
+
+        for i in 0..3 {
+            notifier
+                .emit_strict(NotificationEvent::PrOpened {
+                    repo: "jig".into(),
+                    worker: format!("w{}", i),
+                    pr_url: format!("https://github.com/o/r/pull/{}", i),
+                })
+                .unwrap();
+        }
+        // Not a digest event: delivered immediately
+        notifier.emit_strict(make_event()).unwrap();
+        assert_eq!(hook_log(&log).len(), 1);
+
+        // Back-date the queue so the notifications fall inside the window
+        let path = notifier.queue().path().to_path_buf();
+        let shifted: String = notifier
+            .queue()
+            .tail(10)
+            .unwrap()
+            .into_iter()
+            .map(|mut n| {
+                n.ts -= 5;
+                n.to_json().unwrap() + "\n"
+            })
+            .collect();
+        std::fs::write(&path, shifted).unwrap();
+
+        let digest = notifier.flush_digest().unwrap().unwrap();
+        assert_eq!(digest.summary, "3 PRs opened");
+
+        let lines = hook_log(&log);
+        assert_eq!(lines.len(), 2);
+        let sent: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+        assert_eq!(sent["type"], "digest");
+        assert_eq!(sent["counts"]["pr_opened"], 3);
+    }
 }
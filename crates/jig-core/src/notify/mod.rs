@@ -1,11 +1,23 @@
 //! Notification system for human-facing alerts.
 //!
 //! Append-only JSONL queue at `~/.config/jig/state/notifications.jsonl`.
+//! Routed by `[notify]` rules to exec hooks, webhooks, and desktop
+//! notifications; failed webhook deliveries are logged alongside the queue.
 
+mod desktop;
+mod digest;
 mod events;
 mod hook;
 mod queue;
+mod route;
+mod webhook;
 
+pub use digest::Digest;
 pub use events::{Notification, NotificationEvent};
+pub(crate) use hook::expand_tilde;
 pub use hook::Notifier;
-pub use queue::NotificationQueue;
+pub use queue::{DeliveryFailure, NotificationQueue};
+pub use route::{rule_matches, QuietHours, RegistryContext, Route, RouteContext};
+pub use webhook::{
+    WebhookFormat, WebhookPayload, WebhookSender, DEFAULT_WEBHOOK_RETRIES, SIGNATURE_HEADER,
+};
@@ -2,14 +2,29 @@
 
 use std::fs::{self, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::error::Result;
 use crate::global::global_state_dir;
 
+use serde::{Deserialize, Serialize};
+
 use super::{Notification, NotificationEvent};
 
+/// A notification that could not be delivered to a target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryFailure {
+    pub ts: i64,
+    /// Id of the queued notification.
+    pub notification_id: String,
+    /// Target that failed, e.g. the webhook URL.
+    pub target: String,
+    pub attempts: u32,
+    pub error: String,
+}
+
 /// Append-only notification queue backed by a JSONL file.
+#[derive(Debug, Clone)]
 pub struct NotificationQueue {
     path: PathBuf,
 }
@@ -27,8 +42,8 @@ impl NotificationQueue {
         Self { path }
     }
 
-    /// Append a notification to the queue.
-    pub fn emit(&self, event: NotificationEvent) -> Result<()> {
+    /// Append a notification to the queue and return it.
+    pub fn emit(&self, event: NotificationEvent) -> Result<Notification> {
         if let Some(parent) = self.path.parent() {
             fs::create_dir_all(parent)?;
         }
@@ -44,9 +59,82 @@ impl NotificationQueue {
             .open(&self.path)?;
         let line = serde_json::to_string(&notification)?;
         writeln!(file, "{}", line)?;
+        Ok(notification)
+    }
+
+    /// Record a failed delivery next to the queue (`notifications.failures.jsonl`).
+    pub fn record_failure(&self, failure: &DeliveryFailure) -> Result<()> {
+        let path = self.failures_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(failure)?)?;
+        Ok(())
+    }
+
+    /// Return the last N delivery failures.
+    pub fn failures(&self, n: usize) -> Result<Vec<DeliveryFailure>> {
+        let all: Vec<DeliveryFailure> = read_jsonl(&self.failures_path())?;
+        let skip = all.len().saturating_sub(n);
+        Ok(all.into_iter().skip(skip).collect())
+    }
+
+    /// Timestamp up to which notifications have been digested, if a digest
+    /// has run.
+    pub fn last_digest(&self) -> Result<Option<i64>> {
+        let path = self.digest_marker_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(fs::read_to_string(&path)?.trim().parse().ok())
+    }
+
+    /// Record that notifications up to `ts` have been digested.
+    pub fn set_last_digest(&self, ts: i64) -> Result<()> {
+        let path = self.digest_marker_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, ts.to_string())?;
+        Ok(())
+    }
+
+    /// Keep a notification for delivery after quiet hours
+    /// (`notifications.held.jsonl`).
+    pub fn hold(&self, notification: &Notification) -> Result<()> {
+        let path = self.held_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(notification)?)?;
         Ok(())
     }
 
+    /// Remove and return every held notification, oldest first.
+    pub fn take_held(&self) -> Result<Vec<Notification>> {
+        let path = self.held_path();
+        let held = read_jsonl(&path)?;
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        Ok(held)
+    }
+
+    fn held_path(&self) -> PathBuf {
+        self.path.with_extension("held.jsonl")
+    }
+
+    fn digest_marker_path(&self) -> PathBuf {
+        self.path.with_extension("digest")
+    }
+
+    /// Path of the delivery failure log.
+    pub fn failures_path(&self) -> PathBuf {
+        self.path.with_extension("failures.jsonl")
+    }
+
     /// Read notifications newer than the given timestamp.
     pub fn read_since(&self, since_ts: i64) -> Result<Vec<Notification>> {
         let all = self.read_all()?;
@@ -61,7 +149,7 @@ impl NotificationQueue {
     }
 
     /// Return the queue file path.
-    pub fn path(&self) -> &std::path::Path {
+    pub fn path(&self) -> &Path {
         &self.path
     }
 
@@ -71,20 +159,24 @@ impl NotificationQueue {
     }
 
     fn read_all(&self) -> Result<Vec<Notification>> {
-        if !self.path.exists() {
-            return Ok(Vec::new());
-        }
-        let file = fs::File::open(&self.path)?;
-        let reader = BufReader::new(file);
-        let mut notifications = Vec::new();
-        for line in reader.lines() {
-            let line = line?;
-            if !line.is_empty() {
-                notifications.push(serde_json::from_str(&line)?);
-            }
+        read_jsonl(&self.path)
+    }
+}
+
+fn read_jsonl<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = fs::File::open(path)?;
+    let reader = BufReader::new(file);
+    let mut entries = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if !line.is_empty() {
+            entries.push(serde_json::from_str(&line)?);
         }
-        Ok(notifications)
     }
+    Ok(entries)
 }
 
 #[cfg(test)]
@@ -205,4 +297,43 @@ mod tests {
         let future = queue.read_since(now + 100).unwrap();
         assert!(future.is_empty());
     }
+
+    #[test]
+    fn record_and_read_failures() {
+        let tmp = tempfile::tempdir().unwrap();
+        let queue = NotificationQueue::new(tmp.path().join("notifications.jsonl"));
+        assert!(queue.failures(10).unwrap().is_empty());
+
+        for i in 0..3 {
+            queue
+                .record_failure(&DeliveryFailure {
+                    ts: i,
+                    notification_id: format!("n{}", i),
+                    target: "https://hooks.example/x".to_string(),
+                    attempts: 4,
+                    error: "HTTP 500".to_string(),
+                })
+                .unwrap();
+        }
+
+        assert_eq!(
+            queue.failures_path(),
+            tmp.path().join("notifications.failures.jsonl")
+        );
+        let last = queue.failures(2).unwrap();
+        assert_eq!(last.len(), 2);
+        assert_eq!(last[1].notification_id, "n2");
+        // Failures don't show up as notifications
+        assert!(queue.tail(10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn digest_marker_roundtrip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let queue = NotificationQueue::new(tmp.path().join("notifications.jsonl"));
+        assert_eq!(queue.last_digest().unwrap(), None);
+
+        queue.set_last_digest(1234).unwrap();
+        assert_eq!(queue.last_digest().unwrap(), Some(1234));
+    }
 }
@@ -0,0 +1,345 @@
+//! Notification routing — rules, quiet hours, and de-duplication.
+//!
+//! `[[notify.rules]]` are checked in order. The first matching rule sends to
+//! its own targets; `continue = true` lets later rules match as well. When no
+//! rule matches, the top-level `[notify]` targets are the default route.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::NaiveTime;
+
+use crate::config::{JigToml, RepoNotifyConfig};
+use crate::global::{NotifyConfig, NotifyRule, WorkersState};
+use crate::registry::RepoRegistry;
+
+use super::{NotificationEvent, WebhookSender, DEFAULT_WEBHOOK_RETRIES};
+
+/// Where one notification goes.
+#[derive(Debug, Clone)]
+pub struct Route {
+    /// `default` or `rule N` (1-based), for logs and dedup keys.
+    pub name: String,
+    pub exec: Option<String>,
+    pub webhook: Option<WebhookSender>,
+    pub desktop: bool,
+    pub ignore_quiet_hours: bool,
+    pub dedup_seconds: Option<u64>,
+}
+
+impl Route {
+    fn from_rule(index: usize, rule: &NotifyRule, config: &NotifyConfig) -> Self {
+        let webhook = rule
+            .webhook
+            .as_deref()
+            .filter(|u| !u.trim().is_empty())
+            .map(|url| {
+                WebhookSender::new(url)
+                    .format(rule.webhook_format.unwrap_or(config.webhook_format))
+                    .secret(
+                        rule.webhook_secret
+                            .clone()
+                            .or_else(|| config.webhook_secret.clone()),
+                    )
+                    .retries(config.webhook_retries.unwrap_or(DEFAULT_WEBHOOK_RETRIES))
+            });
+        Self {
+            name: format!("rule {}", index + 1),
+            exec: rule.exec.clone(),
+            webhook,
+            desktop: rule.desktop,
+            ignore_quiet_hours: rule.ignore_quiet_hours,
+            dedup_seconds: rule.dedup_seconds.or(config.dedup_seconds),
+        }
+    }
+
+    /// The top-level `[notify]` targets.
+    pub(crate) fn default_route(config: &NotifyConfig) -> Self {
+        Self {
+            name: "default".to_string(),
+            exec: config.exec.clone(),
+            webhook: WebhookSender::from_config(config),
+            desktop: config.desktop,
+            ignore_quiet_hours: false,
+            dedup_seconds: config.dedup_seconds,
+        }
+    }
+
+    /// Whether the route has anywhere to deliver.
+    pub fn has_targets(&self) -> bool {
+        self.exec.is_some() || self.webhook.is_some() || self.desktop
+    }
+}
+
+/// Pick the routes for an event. `labels` are the labels of the worker's
+/// issue (only consulted by rules that filter on labels).
+pub fn routes(config: &NotifyConfig, event: &NotificationEvent, labels: &[String]) -> Vec<Route> {
+    let mut routes = Vec::new();
+    for (i, rule) in config.rules.iter().enumerate() {
+        if !rule_matches(rule, event, labels) {
+            continue;
+        }
+        routes.push(Route::from_rule(i, rule, config));
+        if !rule.continue_matching {
+            return routes;
+        }
+    }
+
+    if routes.is_empty() && event_selected(&config.events, event) {
+        routes.push(Route::default_route(config));
+    }
+    routes
+}
+
+/// Whether a rule applies to an event.
+pub fn rule_matches(rule: &NotifyRule, event: &NotificationEvent, labels: &[String]) -> bool {
+    any_glob(&rule.repos, event.repo())
+        && any_glob(&rule.workers, event.worker())
+        && event_selected(&rule.events, event)
+        && rule
+            .labels
+            .iter()
+            .all(|want| labels.iter().any(|l| l.eq_ignore_ascii_case(want)))
+}
+
+/// Empty list selects every event type.
+fn event_selected(events: &[String], event: &NotificationEvent) -> bool {
+    events.is_empty() || events.iter().any(|e| e == event.type_name())
+}
+
+/// Empty list matches anything.
+fn any_glob(patterns: &[String], value: &str) -> bool {
+    patterns.is_empty()
+        || patterns.iter().any(|p| match glob::Pattern::new(p) {
+            Ok(pattern) => pattern.matches(value),
+            Err(_) => p == value,
+        })
+}
+
+/// A daily local-time window, possibly wrapping midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Parse `"HH:MM-HH:MM"`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (start, end) = s.split_once('-')?;
+        Some(Self {
+            start: NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?,
+            end: NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?,
+        })
+    }
+
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Remembers recent deliveries to suppress repeats.
+#[derive(Debug, Default)]
+pub struct Deduper {
+    last_sent: Mutex<HashMap<String, i64>>,
+}
+
+impl Deduper {
+    /// Returns `true` (and records `now`) if `key` wasn't delivered within
+    /// the last `window` seconds.
+    pub fn check(&self, key: &str, window: u64, now: i64) -> bool {
+        let mut last_sent = self.last_sent.lock().unwrap_or_else(|e| e.into_inner());
+        last_sent.retain(|_, ts| now - *ts < window as i64);
+        if last_sent.contains_key(key) {
+            return false;
+        }
+        last_sent.insert(key.to_string(), now);
+        true
+    }
+}
+
+/// Dedup key: the route plus what the notification says.
+pub fn dedup_key(route: &Route, event: &NotificationEvent) -> String {
+    format!("{}|{}|{}", route.name, event.type_name(), event.summary())
+}
+
+/// Per-repo config and issue labels needed for routing.
+pub trait RouteContext: Send + Sync {
+    /// `[notify]` overrides from the repo's jig.toml.
+    fn repo_config(&self, repo: &str) -> Option<RepoNotifyConfig>;
+
+    /// Labels of the issue a worker was spawned for.
+    fn issue_labels(&self, repo: &str, worker: &str) -> Vec<String>;
+}
+
+/// Resolves repos through the global registry and labels through
+/// `workers.json`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RegistryContext;
+
+impl RouteContext for RegistryContext {
+    fn repo_config(&self, repo: &str) -> Option<RepoNotifyConfig> {
+        let registry = RepoRegistry::load().ok()?;
+        let entry = registry.repos().iter().find(|e| {
+            e.path
+                .file_name()
+                .is_some_and(|n| n.to_string_lossy() == repo)
+        })?;
+        JigToml::load(&entry.path).ok().flatten().map(|t| t.notify)
+    }
+
+    fn issue_labels(&self, repo: &str, worker: &str) -> Vec<String> {
+        WorkersState::load()
+            .ok()
+            .and_then(|s| {
+                s.get_worker(&format!("{}/{}", repo, worker))
+                    .map(|e| e.issue_labels.clone())
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intervention(repo: &str, worker: &str) -> NotificationEvent {
+        NotificationEvent::NeedsIntervention {
+            repo: repo.to_string(),
+            worker: worker.to_string(),
+            reason: "stalled".to_string(),
+        }
+    }
+
+    fn rule(f: impl FnOnce(&mut NotifyRule)) -> NotifyRule {
+        let mut rule = NotifyRule::default();
+        f(&mut rule);
+        rule
+    }
+
+    #[test]
+    fn rule_matches_repo_worker_event_and_labels() {
+        let r = rule(|r| {
+            r.repos = vec!["api".into()];
+            r.workers = vec!["fix-*".into()];
+            r.events = vec!["needs_intervention".into()];
+            r.labels = vec!["urgent".into()];
+        });
+        let labels = vec!["Urgent".to_string(), "backend".to_string()];
+
+        assert!(rule_matches(&r, &intervention("api", "fix-login"), &labels));
+        assert!(!rule_matches(
+            &r,
+            &intervention("web", "fix-login"),
+            &labels
+        ));
+        assert!(!rule_matches(&r, &intervention("api", "feat-x"), &labels));
+        assert!(!rule_matches(&r, &intervention("api", "fix-login"), &[]));
+
+        let opened = NotificationEvent::PrOpened {
+            repo: "api".into(),
+            worker: "fix-login".into(),
+            pr_url: "u".into(),
+        };
+        assert!(!rule_matches(&r, &opened, &labels));
+    }
+
+    #[test]
+    fn first_match_wins_unless_continue() {
+        let config = NotifyConfig {
+            exec: Some("default-hook".into()),
+            rules: vec![
+                rule(|r| {
+                    r.repos = vec!["api".into()];
+                    r.exec = Some("api-hook".into());
+                }),
+                rule(|r| r.exec = Some("catch-all".into())),
+            ],
+            ..Default::default()
+        };
+
+        let routes = routes(&config, &intervention("api", "w"), &[]);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].exec.as_deref(), Some("api-hook"));
+
+        let mut config = config;
+        config.rules[0].continue_matching = true;
+        let routes = super::routes(&config, &intervention("api", "w"), &[]);
+        let execs: Vec<_> = routes.iter().map(|r| r.exec.as_deref()).collect();
+        assert_eq!(execs, vec![Some("api-hook"), Some("catch-all")]);
+    }
+
+    #[test]
+    fn default_route_when_no_rule_matches() {
+        let config = NotifyConfig {
+            exec: Some("default-hook".into()),
+            events: vec!["needs_intervention".into()],
+            rules: vec![rule(|r| {
+                r.repos = vec!["api".into()];
+                r.exec = Some("api-hook".into());
+            })],
+            ..Default::default()
+        };
+
+        let routes = routes(&config, &intervention("web", "w"), &[]);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].name, "default");
+        assert_eq!(routes[0].exec.as_deref(), Some("default-hook"));
+
+        // The default route still honours the flat `events` filter
+        let opened = NotificationEvent::PrOpened {
+            repo: "web".into(),
+            worker: "w".into(),
+            pr_url: "u".into(),
+        };
+        assert!(super::routes(&config, &opened, &[]).is_empty());
+    }
+
+    #[test]
+    fn rule_webhook_inherits_global_settings() {
+        let config = NotifyConfig {
+            webhook_format: crate::notify::WebhookFormat::Slack,
+            dedup_seconds: Some(60),
+            rules: vec![rule(|r| r.webhook = Some("https://hooks.example/x".into()))],
+            ..Default::default()
+        };
+        let routes = routes(&config, &intervention("api", "w"), &[]);
+        assert_eq!(
+            routes[0].webhook.as_ref().unwrap().url(),
+            "https://hooks.example/x"
+        );
+        assert_eq!(routes[0].dedup_seconds, Some(60));
+        assert!(routes[0].has_targets());
+    }
+
+    #[test]
+    fn quiet_hours_parse_and_wrap() {
+        let t = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        let night = QuietHours::parse("22:00-07:00").unwrap();
+        assert!(night.contains(t(23, 30)));
+        assert!(night.contains(t(6, 59)));
+        assert!(!night.contains(t(7, 0)));
+        assert!(!night.contains(t(12, 0)));
+
+        let lunch = QuietHours::parse("12:00 - 13:00").unwrap();
+        assert!(lunch.contains(t(12, 30)));
+        assert!(!lunch.contains(t(13, 0)));
+
+        assert!(QuietHours::parse("late").is_none());
+        assert!(QuietHours::parse("25:00-07:00").is_none());
+    }
+
+    #[test]
+    fn deduper_suppresses_within_window() {
+        let dedup = Deduper::default();
+        assert!(dedup.check("k", 60, 1000));
+        assert!(!dedup.check("k", 60, 1030));
+        assert!(dedup.check("other", 60, 1030));
+        assert!(dedup.check("k", 60, 1060));
+    }
+}
@@ -0,0 +1,407 @@
+//! Webhook delivery — HTTP POST with retries, backoff and optional signing.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use crate::error::{Error, Result};
+use crate::global::NotifyConfig;
+
+use super::{Digest, Notification, NotificationEvent};
+
+/// Retries after the first attempt when `webhook_retries` is unset.
+pub const DEFAULT_WEBHOOK_RETRIES: u32 = 3;
+
+/// Delay before the first retry; doubles on each subsequent retry.
+const DEFAULT_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Header carrying the HMAC-SHA256 signature of the request body.
+pub const SIGNATURE_HEADER: &str = "X-Jig-Signature-256";
+
+/// Payload shape for the webhook target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// The raw `Notification` JSON.
+    #[default]
+    Json,
+    /// Slack incoming webhook (`{"text": ...}`).
+    Slack,
+    /// Discord webhook (`{"content": ...}`).
+    Discord,
+    /// ntfy topic URL (plain-text body, title/priority headers).
+    Ntfy,
+}
+
+impl WebhookFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Slack => "slack",
+            Self::Discord => "discord",
+            Self::Ntfy => "ntfy",
+        }
+    }
+}
+
+/// A rendered request body plus format-specific headers.
+#[derive(Debug, Clone)]
+pub struct WebhookPayload {
+    pub body: String,
+    pub content_type: &'static str,
+    pub headers: Vec<(&'static str, String)>,
+}
+
+impl WebhookPayload {
+    /// Render a notification in the given format.
+    pub fn render(format: WebhookFormat, notification: &Notification) -> Result<Self> {
+        let summary = notification.event.summary();
+        let payload = match format {
+            WebhookFormat::Json => Self {
+                body: notification.to_json()?,
+                content_type: "application/json",
+                headers: vec![],
+            },
+            WebhookFormat::Slack => Self {
+                body: serde_json::json!({ "text": summary }).to_string(),
+                content_type: "application/json",
+                headers: vec![],
+            },
+            WebhookFormat::Discord => Self {
+                body: serde_json::json!({ "content": summary }).to_string(),
+                content_type: "application/json",
+                headers: vec![],
+            },
+            WebhookFormat::Ntfy => {
+                let urgent = matches!(
+                    notification.event,
+                    NotificationEvent::NeedsIntervention { .. }
+                );
+                Self {
+                    body: summary,
+                    content_type: "text/plain",
+                    headers: vec![
+                        ("Title", format!("jig: {}", notification.event.type_name())),
+                        ("Priority", if urgent { "high" } else { "default" }.into()),
+                        ("Tags", notification.event.type_name().to_string()),
+                    ],
+                }
+            }
+        };
+        Ok(payload)
+    }
+
+    /// Render a digest in the given format.
+    pub fn render_digest(format: WebhookFormat, digest: &Digest) -> Result<Self> {
+        let text = digest.to_text();
+        let payload = match format {
+            WebhookFormat::Json => Self {
+                body: digest.to_json()?,
+                content_type: "application/json",
+                headers: vec![],
+            },
+            WebhookFormat::Slack => Self {
+                body: serde_json::json!({ "text": text }).to_string(),
+                content_type: "application/json",
+                headers: vec![],
+            },
+            WebhookFormat::Discord => Self {
+                body: serde_json::json!({ "content": text }).to_string(),
+                content_type: "application/json",
+                headers: vec![],
+            },
+            WebhookFormat::Ntfy => Self {
+                body: text,
+                content_type: "text/plain",
+                headers: vec![
+                    ("Title", "jig: digest".to_string()),
+                    ("Tags", "digest".to_string()),
+                ],
+            },
+        };
+        Ok(payload)
+    }
+}
+
+/// Posts notifications to a webhook URL.
+#[derive(Debug, Clone)]
+pub struct WebhookSender {
+    url: String,
+    format: WebhookFormat,
+    secret: Option<String>,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl WebhookSender {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            format: WebhookFormat::Json,
+            secret: None,
+            retries: DEFAULT_WEBHOOK_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+        }
+    }
+
+    /// Build a sender from `[notify]` config. `None` when no webhook is set.
+    pub fn from_config(config: &NotifyConfig) -> Option<Self> {
+        let url = config.webhook.as_deref().filter(|u| !u.trim().is_empty())?;
+        Some(
+            Self::new(url)
+                .format(config.webhook_format)
+                .secret(config.webhook_secret.clone())
+                .retries(config.webhook_retries.unwrap_or(DEFAULT_WEBHOOK_RETRIES)),
+        )
+    }
+
+    pub fn format(mut self, format: WebhookFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn secret(mut self, secret: Option<String>) -> Self {
+        self.secret = secret.filter(|s| !s.is_empty());
+        self
+    }
+
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Deliver a notification, retrying transport errors, 429s and 5xx
+    /// responses with exponential backoff. Returns the attempt count on
+    /// success, or the last error and attempt count on failure.
+    pub fn send(&self, notification: &Notification) -> std::result::Result<u32, (Error, u32)> {
+        let payload = WebhookPayload::render(self.format, notification).map_err(|e| (e, 0))?;
+        self.send_payload(&payload)
+    }
+
+    /// Deliver a digest, with the same retry behaviour as [`Self::send`].
+    pub fn send_digest(&self, digest: &Digest) -> std::result::Result<u32, (Error, u32)> {
+        let payload = WebhookPayload::render_digest(self.format, digest).map_err(|e| (e, 0))?;
+        self.send_payload(&payload)
+    }
+
+    fn send_payload(&self, payload: &WebhookPayload) -> std::result::Result<u32, (Error, u32)> {
+        let mut delay = self.backoff;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self.post(payload) {
+                Ok(()) => return Ok(attempt),
+                Err((e, retryable)) => {
+                    if !retryable || attempt > self.retries {
+                        return Err((e, attempt));
+                    }
+                    tracing::debug!(attempt, error = %e, "webhook delivery failed, retrying");
+                    std::thread::sleep(delay);
+                    delay *= 2;
+                }
+            }
+        }
+    }
+
+    /// One POST. The error flag says whether a retry might help.
+    fn post(&self, payload: &WebhookPayload) -> std::result::Result<(), (Error, bool)> {
+        let mut request = ureq::post(&self.url)
+            .config()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(10)))
+            .build()
+            .header("Content-Type", payload.content_type)
+            .header("User-Agent", "jig");
+        for (name, value) in &payload.headers {
+            request = request.header(*name, value);
+        }
+        if let Some(secret) = &self.secret {
+            let signature = hex(&hmac_sha256(secret.as_bytes(), payload.body.as_bytes()));
+            request = request.header(SIGNATURE_HEADER, &format!("sha256={}", signature));
+        }
+
+        let response = request.send(payload.body.as_bytes()).map_err(|e| {
+            (
+                Error::Custom(format!("webhook request failed: {}", e)),
+                true,
+            )
+        })?;
+
+        let status = response.status().as_u16();
+        if status < 300 {
+            return Ok(());
+        }
+        let body = response.into_body().read_to_string().unwrap_or_default();
+        let retryable = status == 429 || status >= 500;
+        Err((
+            Error::Custom(format!("webhook returned HTTP {}: {}", status, body.trim())),
+            retryable,
+        ))
+    }
+}
+
+/// HMAC-SHA256 (RFC 2104).
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK: usize = 64;
+    let mut block_key = [0u8; BLOCK];
+    if key.len() > BLOCK {
+        block_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block_key.map(|b| b ^ 0x36));
+    inner.update(message);
+    let inner = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block_key.map(|b| b ^ 0x5c));
+    outer.update(inner);
+    outer.finalize().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::sync::mpsc;
+
+    fn notification() -> Notification {
+        Notification {
+            ts: 1000,
+            id: "n1".into(),
+            event: NotificationEvent::NeedsIntervention {
+                repo: "jig".into(),
+                worker: "feat".into(),
+                reason: "stalled".into(),
+            },
+        }
+    }
+
+    /// Answer each connection with the next status code, reporting the
+    /// request headers (lowercased) and body.
+    fn serve(statuses: Vec<u16>) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for status in statuses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut headers = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    let lower = line.to_ascii_lowercase();
+                    if let Some(v) = lower.strip_prefix("content-length:") {
+                        content_length = v.trim().parse().unwrap();
+                    }
+                    headers.push_str(&lower);
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                tx.send((headers, String::from_utf8(body).unwrap()))
+                    .unwrap();
+                let mut stream = reader.into_inner();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+            }
+        });
+        (url, rx)
+    }
+
+    #[test]
+    fn hmac_matches_rfc4231_case_2() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex(&mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn render_formats() {
+        let n = notification();
+        let json = WebhookPayload::render(WebhookFormat::Json, &n).unwrap();
+        assert!(json.body.contains(r#""type":"needs_intervention""#));
+        assert!(json.body.contains(r#""id":"n1""#));
+
+        let slack = WebhookPayload::render(WebhookFormat::Slack, &n).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&slack.body).unwrap();
+        assert!(v["text"].as_str().unwrap().contains("jig/feat"));
+
+        let discord = WebhookPayload::render(WebhookFormat::Discord, &n).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&discord.body).unwrap();
+        assert!(v["content"].as_str().unwrap().contains("stalled"));
+
+        let ntfy = WebhookPayload::render(WebhookFormat::Ntfy, &n).unwrap();
+        assert_eq!(ntfy.content_type, "text/plain");
+        assert!(ntfy.headers.contains(&("Priority", "high".to_string())));
+    }
+
+    #[test]
+    fn send_signs_body() {
+        let (url, rx) = serve(vec![200]);
+        let sender = WebhookSender::new(url).secret(Some("s3cret".into()));
+
+        assert_eq!(sender.send(&notification()).unwrap(), 1);
+
+        let (headers, body) = rx.recv().unwrap();
+        let expected = hex(&hmac_sha256(b"s3cret", body.as_bytes()));
+        assert!(headers.contains(&format!("x-jig-signature-256: sha256={}", expected)));
+    }
+
+    #[test]
+    fn send_retries_server_errors() {
+        let (url, rx) = serve(vec![500, 503, 200]);
+        let sender = WebhookSender::new(url)
+            .retries(3)
+            .backoff(Duration::from_millis(1));
+
+        assert_eq!(sender.send(&notification()).unwrap(), 3);
+        assert_eq!(rx.try_iter().count(), 3);
+    }
+
+    #[test]
+    fn send_gives_up_after_retries() {
+        let (url, _rx) = serve(vec![500, 500]);
+        let sender = WebhookSender::new(url)
+            .retries(1)
+            .backoff(Duration::from_millis(1));
+
+        let (err, attempts) = sender.send(&notification()).unwrap_err();
+        assert_eq!(attempts, 2);
+        assert!(err.to_string().contains("HTTP 500"));
+    }
+
+    #[test]
+    fn send_does_not_retry_client_errors() {
+        let (url, _rx) = serve(vec![404]);
+        let sender = WebhookSender::new(url).backoff(Duration::from_millis(1));
+
+        let (_, attempts) = sender.send(&notification()).unwrap_err();
+        assert_eq!(attempts, 1);
+    }
+}
@@ -3,6 +3,7 @@
 //! Ties together the dispatch system (which decides *when* to nudge)
 //! with templates (which decide *what* to say) and tmux (which delivers it).
 
+use crate::auto_rebase::Conflict;
 use crate::config::ResolvedNudgeConfig;
 use crate::error::Result;
 use crate::events::{Event, EventLog, EventType, WorkerState};
@@ -27,6 +28,8 @@ pub enum NudgeType {
     BadCommits,
     /// Automated review findings from the review agent.
     AutoReview,
+    /// Worker is nearing its token, cost or time budget.
+    Budget,
 }
 
 impl NudgeType {
@@ -40,6 +43,7 @@ impl NudgeType {
             NudgeType::Review => "nudge-review",
             NudgeType::BadCommits => "nudge-bad-commits",
             NudgeType::AutoReview => "nudge-auto-review",
+            NudgeType::Budget => "nudge-budget",
         }
     }
 
@@ -53,6 +57,7 @@ impl NudgeType {
             NudgeType::Review => "review",
             NudgeType::BadCommits => "bad_commits",
             NudgeType::AutoReview => "auto_review",
+            NudgeType::Budget => "budget",
         }
     }
 }
@@ -64,8 +69,8 @@ pub fn classify_nudge<F>(state: &WorkerState, resolve: F) -> Option<NudgeType>
 where
     F: Fn(&str) -> ResolvedNudgeConfig,
 {
-    // Terminal states never get nudged
-    if state.status.is_terminal() {
+    // Terminal states never get nudged, nor do workers stopped for budget
+    if state.status.is_terminal() || state.budget_exceeded.is_some() {
         return None;
     }
 
@@ -170,6 +175,15 @@ pub fn build_nudge_context(
     ctx
 }
 
+/// Attach the conflicts an automatic rebase stopped on to a conflict nudge
+/// context, so the agent sees exactly which hunks to resolve.
+pub fn add_conflict_context(ctx: &mut TemplateContext, conflicts: &[Conflict]) {
+    ctx.set_list(
+        "conflicts",
+        conflicts.iter().map(Conflict::render).collect(),
+    );
+}
+
 /// Execute a nudge: render the template, send via tmux, emit event.
 pub fn execute_nudge(
     target: &TmuxTarget,
@@ -284,6 +298,16 @@ mod tests {
         assert_eq!(classify_nudge(&state, resolve_with_max(3)), None);
     }
 
+    #[test]
+    fn classify_over_budget_no_nudge() {
+        let state = WorkerState {
+            status: WorkerStatus::Idle,
+            budget_exceeded: Some("500 of 400 tokens".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(classify_nudge(&state, resolve_with_max(3)), None);
+    }
+
     #[test]
     fn max_nudges_returns_none() {
         let mut counts = HashMap::new();
@@ -385,6 +409,7 @@ mod tests {
         assert_eq!(NudgeType::Review.template_name(), "nudge-review");
         assert_eq!(NudgeType::BadCommits.template_name(), "nudge-bad-commits");
         assert_eq!(NudgeType::AutoReview.template_name(), "nudge-auto-review");
+        assert_eq!(NudgeType::Budget.template_name(), "nudge-budget");
     }
 
     #[test]
@@ -416,6 +441,32 @@ mod tests {
         assert!(msg.contains("Auto-approving"));
     }
 
+    #[test]
+    fn render_conflict_nudge_with_hunks() {
+        let engine = TemplateEngine::new();
+        let state = WorkerState::default();
+        let mut ctx = build_nudge_context(
+            NudgeType::Conflict,
+            &state,
+            resolved(3),
+            Some("origin/main"),
+        );
+        let plain = engine.render("nudge-conflict", &ctx).unwrap();
+        assert!(!plain.contains("automatic rebase"));
+
+        add_conflict_context(
+            &mut ctx,
+            &[Conflict {
+                path: "Cargo.lock".to_string(),
+                hunks: vec!["line 3:\n<<<<<<< HEAD\na\n=======\nb\n>>>>>>> fix".to_string()],
+            }],
+        );
+        let msg = engine.render("nudge-conflict", &ctx).unwrap();
+        assert!(msg.contains("automatic rebase stopped"));
+        assert!(msg.contains("Cargo.lock:\nline 3:\n<<<<<<< HEAD\na"));
+        assert!(msg.contains("git rebase origin/main"));
+    }
+
     #[test]
     fn render_auto_review_nudge() {
         let engine = TemplateEngine::new();
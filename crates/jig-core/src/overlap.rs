@@ -0,0 +1,226 @@
+//! Conflict prediction — which in-flight workers are editing the same files.
+//!
+//! A worker's files are its diff against the base branch plus the files its
+//! task named when it was spawned. Two workers sharing files will likely
+//! conflict once the first one merges, so the daemon warns about (or holds
+//! back) the overlap before it turns into a conflicted PR.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::context::RepoContext;
+use crate::git::Repo;
+use crate::state::OrchestratorState;
+use crate::worktree::Worktree;
+
+/// The files one worker is touching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkerFiles {
+    pub worker: String,
+    pub files: BTreeSet<String>,
+}
+
+/// Files two workers are both touching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlap {
+    pub a: String,
+    pub b: String,
+    pub files: Vec<String>,
+}
+
+impl Overlap {
+    /// Key for the pair within `repo`.
+    pub fn key(&self, repo: &str) -> String {
+        format!("{}/{}+{}", repo, self.a, self.b)
+    }
+
+    /// The shared files for a notification: the first few, then a count.
+    pub fn summary(&self) -> String {
+        const SHOWN: usize = 3;
+        let shown = self.files[..self.files.len().min(SHOWN)].join(", ");
+        match self.files.len().saturating_sub(SHOWN) {
+            0 => shown,
+            more => format!("{} and {} more", shown, more),
+        }
+    }
+}
+
+/// Files of every worker with a worktree in `repo`, sorted by worker name.
+///
+/// Files matching any `ignore` glob (lockfiles, changelogs) are left out.
+pub fn active_workers(repo: &RepoContext, ignore: &[String]) -> Vec<WorkerFiles> {
+    let base = RepoContext::resolve_base_branch_for(&repo.repo_root)
+        .unwrap_or_else(|_| crate::config::DEFAULT_BASE_BRANCH.to_string());
+    let state = OrchestratorState::load(&repo.repo_root).ok().flatten();
+    let mut workers: Vec<WorkerFiles> = Worktree::list(&repo.repo_root, &repo.worktrees_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|worktree| {
+            let mut files: BTreeSet<String> = Repo::files_changed_since_fork(&worktree.path, &base)
+                .map(|files| files.into_iter().collect())
+                .unwrap_or_default();
+            if let Some(task) = state
+                .as_ref()
+                .and_then(|s| s.get_worker_by_name(&worktree.name))
+                .and_then(|w| w.task.as_ref())
+            {
+                files.extend(task.files_hint.iter().cloned());
+            }
+            files.retain(|f| !ignored(f, ignore));
+            WorkerFiles {
+                worker: worktree.name,
+                files,
+            }
+        })
+        .collect();
+    workers.sort_by(|a, b| a.worker.cmp(&b.worker));
+    workers
+}
+
+/// The files a worker about to be spawned for `text` (its issue body) will
+/// likely touch.
+pub fn planned(root: &Path, worker: &str, text: &str, ignore: &[String]) -> WorkerFiles {
+    let mut files: BTreeSet<String> = mentioned_files(root, text).into_iter().collect();
+    files.retain(|f| !ignored(f, ignore));
+    WorkerFiles {
+        worker: worker.to_string(),
+        files,
+    }
+}
+
+/// Workers sharing files with `candidate`. Workers with its name are skipped.
+pub fn overlapping_with(candidate: &WorkerFiles, workers: &[WorkerFiles]) -> Vec<Overlap> {
+    workers
+        .iter()
+        .filter(|w| w.worker != candidate.worker)
+        .filter_map(|w| {
+            let files: Vec<String> = candidate.files.intersection(&w.files).cloned().collect();
+            (!files.is_empty()).then(|| Overlap {
+                a: w.worker.clone(),
+                b: candidate.worker.clone(),
+                files,
+            })
+        })
+        .collect()
+}
+
+/// Every pair of workers sharing at least one file.
+pub fn overlaps(workers: &[WorkerFiles]) -> Vec<Overlap> {
+    let mut found = Vec::new();
+    for (i, a) in workers.iter().enumerate() {
+        for b in &workers[i + 1..] {
+            let files: Vec<String> = a.files.intersection(&b.files).cloned().collect();
+            if !files.is_empty() {
+                found.push(Overlap {
+                    a: a.worker.clone(),
+                    b: b.worker.clone(),
+                    files,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// Files named in `text` (an issue body or task description) that exist in
+/// `root`, e.g. `src/daemon/mod.rs` or `crates/cli/src/main.rs:42`.
+pub fn mentioned_files(root: &Path, text: &str) -> Vec<String> {
+    let mut files = BTreeSet::new();
+    for token in text.split(|c: char| c.is_whitespace() || "`'\"()[]<>,;".contains(c)) {
+        let token = token.trim_start_matches("./").trim_end_matches(['.', ':']);
+        // Drop a `:line` or `:line:col` suffix.
+        let path = token.split(':').next().unwrap_or_default();
+        if !(path.contains('/') || path.contains('.'))
+            || path.starts_with('/')
+            || path.contains("..")
+        {
+            continue;
+        }
+        if root.join(path).is_file() {
+            files.insert(path.to_string());
+        }
+    }
+    files.into_iter().collect()
+}
+
+fn ignored(path: &str, ignore: &[String]) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    ignore.iter().any(|pattern| {
+        glob::Pattern::new(pattern).is_ok_and(|p| p.matches(path) || p.matches(name))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker(name: &str, files: &[&str]) -> WorkerFiles {
+        WorkerFiles {
+            worker: name.to_string(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn finds_shared_files_pairwise() {
+        let workers = vec![
+            worker("a", &["src/lib.rs", "src/x.rs"]),
+            worker("b", &["src/x.rs", "src/y.rs"]),
+            worker("c", &["src/z.rs"]),
+            worker("d", &["src/lib.rs", "src/x.rs", "src/y.rs"]),
+        ];
+        let found = overlaps(&workers);
+        let pairs: Vec<(&str, &str, usize)> = found
+            .iter()
+            .map(|o| (o.a.as_str(), o.b.as_str(), o.files.len()))
+            .collect();
+        assert_eq!(pairs, vec![("a", "b", 1), ("a", "d", 2), ("b", "d", 2)]);
+        assert_eq!(found[0].files, vec!["src/x.rs"]);
+        assert_eq!(found[0].key("repo"), "repo/a+b");
+        assert_eq!(found[0].summary(), "src/x.rs");
+
+        let many = Overlap {
+            a: "a".to_string(),
+            b: "b".to_string(),
+            files: ["1", "2", "3", "4", "5"].map(String::from).to_vec(),
+        };
+        assert_eq!(many.summary(), "1, 2, 3 and 2 more");
+    }
+
+    #[test]
+    fn candidate_overlaps_skip_itself() {
+        let workers = vec![
+            worker("a", &["src/x.rs"]),
+            worker("new", &["src/x.rs"]),
+            worker("b", &["src/y.rs"]),
+        ];
+        let found = overlapping_with(&worker("new", &["src/x.rs", "src/z.rs"]), &workers);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].a.as_str(), found[0].b.as_str()), ("a", "new"));
+        assert_eq!(found[0].files, vec!["src/x.rs"]);
+    }
+
+    #[test]
+    fn mentioned_files_must_exist() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("src/daemon")).unwrap();
+        std::fs::write(tmp.path().join("src/daemon/mod.rs"), "").unwrap();
+        std::fs::write(tmp.path().join("README.md"), "").unwrap();
+
+        let text = "Fix `src/daemon/mod.rs:42` (see ./README.md). Not src/missing.rs, \
+                    https://example.com/a.rs or ../outside.rs.";
+        assert_eq!(
+            mentioned_files(tmp.path(), text),
+            vec!["README.md", "src/daemon/mod.rs"]
+        );
+    }
+
+    #[test]
+    fn ignore_globs_match_path_or_name() {
+        let ignore = vec!["*.lock".to_string(), "docs/*".to_string()];
+        assert!(ignored("Cargo.lock", &ignore));
+        assert!(ignored("crates/app/Cargo.lock", &ignore));
+        assert!(ignored("docs/daemon.md", &ignore));
+        assert!(!ignored("src/lock.rs", &ignore));
+    }
+}
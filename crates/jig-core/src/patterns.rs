@@ -0,0 +1,170 @@
+//! Pane patterns — what a stuck worker's screen says it is stuck on.
+//!
+//! Event timestamps tell the daemon a worker is waiting, not why. For
+//! `WaitingInput` and `Stalled` workers the daemon also reads the bottom of
+//! the tmux pane and checks it against `[[health.patterns]]` rules and the
+//! built-ins below. The first matching rule's action replaces the generic
+//! idle/stuck nudge.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use regex::Regex;
+
+use crate::config::{PanePattern, PatternAction};
+use crate::events::WorkerState;
+
+/// Lines from the bottom of the pane that rules are matched against. Older
+/// output is left out so earlier conversation doesn't trigger rules.
+pub const PANE_LINES: usize = 15;
+
+/// Compiled rule regexes by source; `None` for ones that don't compile.
+static COMPILED: LazyLock<Mutex<HashMap<String, Option<Regex>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Rules for the prompts Claude Code stops at. Permission and yes/no prompts
+/// only notify: approving them blindly would defeat the prompt.
+pub fn builtin() -> Vec<PanePattern> {
+    vec![
+        rule(
+            "usage-limit",
+            r"(?i)usage limit reached|limit will reset at",
+            PatternAction::Notify,
+            &[],
+            3600,
+        ),
+        rule(
+            "context-full",
+            r"(?i)context low \(\d+% remaining\)|prompt is too long",
+            PatternAction::SendKeys,
+            &["/compact", "Enter"],
+            600,
+        ),
+        rule(
+            "api-error",
+            r"API Error: (5\d\d|Overloaded|Request timed out)",
+            PatternAction::SendKeys,
+            &["continue", "Enter"],
+            300,
+        ),
+        rule(
+            "permission-prompt",
+            r"(?m)^\s*❯\s*1\.\s*Yes",
+            PatternAction::Notify,
+            &[],
+            600,
+        ),
+        rule(
+            "yes-no",
+            r"(?i)\(y/n\)|\[y/n\]",
+            PatternAction::Notify,
+            &[],
+            600,
+        ),
+    ]
+}
+
+fn rule(
+    name: &str,
+    regex: &str,
+    action: PatternAction,
+    keys: &[&str],
+    cooldown_seconds: u64,
+) -> PanePattern {
+    PanePattern {
+        name: name.to_string(),
+        regex: regex.to_string(),
+        action,
+        keys: keys.iter().map(|k| k.to_string()).collect(),
+        cooldown_seconds,
+    }
+}
+
+/// The first rule whose regex matches `pane`. Rules with an invalid regex
+/// are skipped. Each regex is compiled once and reused across calls.
+pub fn find_match<'a>(patterns: &'a [PanePattern], pane: &str) -> Option<&'a PanePattern> {
+    let mut compiled = COMPILED.lock().unwrap_or_else(|e| e.into_inner());
+    patterns.iter().find(|p| {
+        compiled
+            .entry(p.regex.clone())
+            .or_insert_with(|| match Regex::new(&p.regex) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    tracing::warn!(rule = %p.name, error = %e, "invalid pane pattern regex, skipping");
+                    None
+                }
+            })
+            .as_ref()
+            .is_some_and(|re| re.is_match(pane))
+    })
+}
+
+/// Seconds until `rule` may fire again for this worker, if it fired recently.
+pub fn cooldown_remaining(rule: &PanePattern, state: &WorkerState, now: i64) -> Option<u64> {
+    let last = *state.last_pattern_at.get(&rule.name)?;
+    let remaining = last + rule.cooldown_seconds as i64 - now;
+    (remaining > 0).then_some(remaining as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtins_match_claude_code_prompts() {
+        let patterns = builtin();
+        let name = |pane: &str| find_match(&patterns, pane).map(|p| p.name.as_str());
+
+        assert_eq!(
+            name("Claude usage limit reached. Your limit will reset at 5pm."),
+            Some("usage-limit")
+        );
+        assert_eq!(
+            name("Context low (3% remaining) · Run /compact to compact & continue"),
+            Some("context-full")
+        );
+        assert_eq!(
+            name("  ⎿  API Error: 529 {\"type\":\"overloaded_error\"}"),
+            Some("api-error")
+        );
+        assert_eq!(
+            name(" Do you want to make this edit to lib.rs?\n ❯ 1. Yes\n   2. No"),
+            Some("permission-prompt")
+        );
+        assert_eq!(name("Overwrite existing file? (y/n)"), Some("yes-no"));
+        assert_eq!(name("✻ Thinking… (esc to interrupt)"), None);
+    }
+
+    #[test]
+    fn builtins_never_answer_prompts() {
+        for rule in builtin() {
+            if matches!(rule.name.as_str(), "permission-prompt" | "yes-no") {
+                assert_eq!(rule.action, PatternAction::Notify, "{}", rule.name);
+                assert!(rule.keys.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn first_valid_match_wins() {
+        let patterns = vec![
+            rule("broken", "(", PatternAction::Notify, &[], 60),
+            rule("any-error", "(?i)error", PatternAction::Interrupt, &[], 60),
+            rule("api-error", "API Error", PatternAction::Restart, &[], 60),
+        ];
+        let matched = find_match(&patterns, "API Error: 500").unwrap();
+        assert_eq!(matched.name, "any-error");
+        assert!(find_match(&patterns, "all good").is_none());
+    }
+
+    #[test]
+    fn cooldown_from_last_firing() {
+        let rule = rule("yes-no", "y/n", PatternAction::SendKeys, &["y"], 60);
+        let mut state = WorkerState::default();
+        assert_eq!(cooldown_remaining(&rule, &state, 1000), None);
+
+        state.last_pattern_at.insert("yes-no".to_string(), 970);
+        assert_eq!(cooldown_remaining(&rule, &state, 1000), Some(30));
+        assert_eq!(cooldown_remaining(&rule, &state, 1030), None);
+    }
+}
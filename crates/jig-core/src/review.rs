@@ -98,6 +98,18 @@ impl ReviewCategory {
         ReviewCategory::Documentation,
     ];
 
+    /// Snake-case name, as serialized (`error_handling`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Correctness => "correctness",
+            Self::Conventions => "conventions",
+            Self::ErrorHandling => "error_handling",
+            Self::Security => "security",
+            Self::TestCoverage => "test_coverage",
+            Self::Documentation => "documentation",
+        }
+    }
+
     fn heading(&self) -> &'static str {
         match self {
             Self::Correctness => "Correctness",
@@ -131,7 +143,8 @@ pub enum ReviewStatus {
 }
 
 impl ReviewStatus {
-    fn tag(&self) -> &'static str {
+    /// Tag as written in review files (`FAIL`).
+    pub fn tag(&self) -> &'static str {
         match self {
             Self::Pass => "PASS",
             Self::Warn => "WARN",
@@ -594,6 +607,30 @@ pub fn review_history(worktree: &Path) -> Vec<PathBuf> {
     files
 }
 
+/// Every review in a worktree that parses, oldest first (responses excluded)
+pub fn load_reviews(worktree: &Path) -> Vec<Review> {
+    let mut numbered: Vec<(u32, PathBuf)> = review_history(worktree)
+        .into_iter()
+        .filter_map(|p| Some((review_number(&p)?, p)))
+        .collect();
+    numbered.sort();
+    numbered
+        .into_iter()
+        .filter_map(|(_, p)| std::fs::read_to_string(p).ok())
+        .filter_map(|md| Review::from_markdown(&md).ok())
+        .collect()
+}
+
+/// The number of a review file (`001.md`, `1000.md`); `None` for responses
+/// and anything else.
+fn review_number(path: &Path) -> Option<u32> {
+    let stem = path.file_name()?.to_str()?.strip_suffix(".md")?;
+    if stem.is_empty() || !stem.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    stem.parse().ok()
+}
+
 /// Parse the latest review file and return its verdict
 pub fn latest_verdict(worktree: &Path) -> Option<ReviewVerdict> {
     let dir = reviews_dir(worktree);
@@ -905,6 +942,38 @@ Done.
         );
     }
 
+    #[test]
+    fn load_reviews_skips_responses_and_unparseable() {
+        let tmp = TempDir::new().unwrap();
+        let dir = reviews_dir(tmp.path());
+        std::fs::create_dir_all(&dir).unwrap();
+
+        std::fs::write(dir.join("001.md"), sample_review().to_markdown(1)).unwrap();
+        std::fs::write(dir.join("001-response.md"), "# Response to Review 001\n").unwrap();
+        std::fs::write(dir.join("002.md"), "not a review").unwrap();
+
+        let reviews = load_reviews(tmp.path());
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].verdict, ReviewVerdict::ChangesRequested);
+    }
+
+    #[test]
+    fn load_reviews_past_999_in_order() {
+        let tmp = TempDir::new().unwrap();
+        let dir = reviews_dir(tmp.path());
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut approved = sample_review();
+        approved.verdict = ReviewVerdict::Approve;
+        std::fs::write(dir.join("999.md"), sample_review().to_markdown(999)).unwrap();
+        std::fs::write(dir.join("1000.md"), approved.to_markdown(1000)).unwrap();
+
+        let reviews = load_reviews(tmp.path());
+        assert_eq!(reviews.len(), 2);
+        assert_eq!(reviews[0].verdict, ReviewVerdict::ChangesRequested);
+        assert_eq!(reviews[1].verdict, ReviewVerdict::Approve);
+    }
+
     #[test]
     fn review_config_defaults() {
         let toml_str = "";
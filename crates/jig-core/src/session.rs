@@ -4,8 +4,13 @@
 
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 
 use crate::error::{Error, Result};
+use crate::tmux::{TmuxClient, TmuxTarget};
+
+/// How long to wait for an agent to start before typing its prompt.
+const PROMPT_WAIT: Duration = Duration::from_secs(30);
 
 /// Check if a tmux session exists
 pub fn session_exists(session: &str) -> bool {
@@ -95,6 +100,23 @@ pub fn send_keys(session: &str, window: &str, keys: &str) -> Result<()> {
     Ok(())
 }
 
+/// Type an agent's initial prompt into a window once the agent is running.
+pub fn send_prompt(session: &str, window: &str, prompt: &str) -> Result<()> {
+    let deadline = Instant::now() + PROMPT_WAIT;
+    while !pane_is_running(session, window) {
+        if Instant::now() >= deadline {
+            return Err(Error::Custom(format!(
+                "agent in {}:{} did not start within {}s",
+                session,
+                window,
+                PROMPT_WAIT.as_secs()
+            )));
+        }
+        std::thread::sleep(Duration::from_millis(250));
+    }
+    TmuxClient::new().send_message(&TmuxTarget::new(session, window), prompt)
+}
+
 /// Kill a window
 pub fn kill_window(session: &str, window: &str) -> Result<()> {
     if !window_exists(session, window) {
@@ -119,6 +141,23 @@ pub fn select_window(session: &str, window: &str) -> Result<()> {
     Ok(())
 }
 
+/// Switch the current tmux client to a window (only works from inside tmux)
+pub fn switch_client(session: &str, window: &str) -> Result<()> {
+    let output = Command::new("tmux")
+        .args(["switch-client", "-t", &format!("{}:{}", session, window)])
+        .stdin(Stdio::null())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::Custom(format!(
+            "tmux switch-client failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(())
+}
+
 /// Attach to a session (replaces current process on Unix)
 #[cfg(unix)]
 pub fn attach(session: &str) -> Result<()> {
@@ -9,11 +9,11 @@
 
 use std::path::Path;
 
-use crate::adapter;
+use crate::adapter::{self, AgentRegistry};
 use crate::config::{self, JigToml, RepoConfig};
 use crate::context::RepoContext;
 use crate::error::{Error, Result};
-use crate::events::{Event, EventLog, EventType, WorkerState};
+use crate::events::{Event, EventLog, EventType};
 use crate::git::Repo;
 use crate::global::GlobalConfig;
 use crate::issues::{Issue, IssueStatus, ProviderKind};
@@ -36,7 +36,8 @@ pub enum SpawnKind {
 }
 
 /// Task status for ps command
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum TaskStatus {
     Running,
     Exited,
@@ -65,6 +66,10 @@ pub struct SpawnIssueInput<'a> {
     pub worker_name: &'a str,
     pub provider_kind: ProviderKind,
     pub kind: SpawnKind,
+    /// Agent adapter for this worker (None = repo `[agent] type`).
+    pub agent: Option<&'a str>,
+    /// Model override for this worker's agent.
+    pub model: Option<&'a str>,
 }
 
 /// Spawn a single worker for an issue: create worktree, register, run on-create
@@ -82,6 +87,23 @@ pub fn spawn_worker_for_issue(input: &SpawnIssueInput<'_>) -> std::result::Resul
         return Ok(());
     }
 
+    // Reject an unknown `agent:` label before anything is created on disk.
+    if let Some(agent) = input.agent {
+        AgentRegistry::load(repo_root)
+            .and_then(|registry| registry.resolve(agent))
+            .map_err(|e| e.to_string())?;
+    }
+
+    // A stacked issue forks from its lower worker's branch while that worker
+    // is still in flight.
+    let stacked_on = input
+        .issue
+        .stacked_on
+        .as_deref()
+        .filter(|_| input.kind == SpawnKind::Normal)
+        .and_then(|reference| crate::stack::lower_worker(repo_root, reference))
+        .and_then(|lower| crate::stack::branch_of(repo_root, &lower).map(|b| (lower, b)));
+
     let base_branch = if input.kind == SpawnKind::Wrapup {
         // Wrap-up workers use the parent's own integration branch as base.
         // The branch already exists on origin with all child work merged in.
@@ -89,6 +111,8 @@ pub fn spawn_worker_for_issue(input: &SpawnIssueInput<'_>) -> std::result::Resul
             Some(branch) => format!("origin/{}", branch),
             None => return Err("wrap-up spawn requires issue to have a branch_name".to_string()),
         }
+    } else if let Some((_, ref lower_branch)) = stacked_on {
+        lower_branch.clone()
     } else {
         match input
             .issue
@@ -122,6 +146,11 @@ pub fn spawn_worker_for_issue(input: &SpawnIssueInput<'_>) -> std::result::Resul
     )
     .map_err(|e| e.to_string())?;
 
+    wt.agent = input.agent.map(|a| a.to_string());
+    wt.model = input.model.map(|m| m.to_string());
+    wt.issue_labels = input.issue.labels.clone();
+    wt.stacked_on = stacked_on.map(|(lower, _)| lower);
+
     // Set parent info on the worktree so it's included in event data.
     // This allows the daemon to identify parent-child relationships at tick time.
     if let Some(ref parent) = input.issue.parent {
@@ -239,11 +268,13 @@ pub fn run_triage_subprocess(repo_root: &Path, issue: &Issue) -> std::result::Re
     let jig_toml = config::JigToml::load(repo_root)
         .map_err(|e| e.to_string())?
         .unwrap_or_default();
-    let model = &jig_toml.triage.model;
-    let agent_adapter =
-        adapter::get_adapter(&jig_toml.agent.agent_type).unwrap_or(&adapter::CLAUDE_CODE);
+    let model = jig_toml.triage.model_for(&jig_toml.agent.agent_type);
+    let global_config = GlobalConfig::load().map_err(|e| e.to_string())?;
+    let agent_adapter = AgentRegistry::from_config(&jig_toml, &global_config)
+        .get(&jig_toml.agent.agent_type)
+        .unwrap_or(adapter::CLAUDE_CODE);
 
-    let argv = adapter::build_triage_argv(agent_adapter, model, TRIAGE_ALLOWED_TOOLS);
+    let argv = adapter::build_triage_argv(&agent_adapter, &prompt, model, TRIAGE_ALLOWED_TOOLS);
 
     // argv[0] is the command, rest are args
     let (cmd, args) = argv.split_first().ok_or("empty triage argv")?;
@@ -316,7 +347,8 @@ pub fn register(
 
     // Set task context if provided
     if let Some(ctx) = context {
-        let mut task = TaskContext::new(ctx.to_string());
+        let mut task = TaskContext::new(ctx.to_string())
+            .with_files(crate::overlap::mentioned_files(&repo.repo_root, ctx));
         if let Some(issue) = issue_ref {
             task = task.with_issue(issue.to_string());
         }
@@ -338,6 +370,7 @@ pub fn register(
         .unwrap_or_else(|| "unknown".to_string());
 
     if let Ok(event_log) = EventLog::for_worker(&repo_name, name) {
+        crate::stats::retire_worker_log(&repo_name, name, &event_log);
         let _ = event_log.reset();
         let mut event = Event::new(EventType::Spawn)
             .with_field("branch", branch)
@@ -373,21 +406,26 @@ pub fn launch_tmux_window(
 
     // Get adapter from config (fallback to claude-code if not configured)
     let config = JigToml::load(&repo.repo_root)?.unwrap_or_default();
-    let agent_adapter =
-        adapter::get_adapter(&config.agent.agent_type).unwrap_or(&adapter::CLAUDE_CODE);
+    let agent_adapter = AgentRegistry::from_config(&config, &global_config)
+        .get(&config.agent.agent_type)
+        .unwrap_or(adapter::CLAUDE_CODE);
 
     // Create window in tmux
     session::create_window(&repo.session_name, name, worktree_path)?;
 
     // Build spawn command using adapter (always auto)
     let cmd = adapter::build_spawn_command(
-        agent_adapter,
+        &agent_adapter,
         Some(&effective_context),
+        None,
         &config.agent.disallowed_tools,
     );
 
     // Send command to window
     session::send_keys(&repo.session_name, name, &cmd)?;
+    if agent_adapter.send_prompt {
+        session::send_prompt(&repo.session_name, name, &effective_context)?;
+    }
 
     Ok(())
 }
@@ -517,12 +555,9 @@ fn get_worker_event_status(repo: &RepoContext, name: &str) -> Option<WorkerStatu
         .unwrap_or_else(|| "unknown".to_string());
 
     let event_log = EventLog::for_worker(&repo_name, name).ok()?;
-    let events = event_log.read_all().ok()?;
-    if events.is_empty() {
-        return None;
-    }
     let config = GlobalConfig::load().ok()?.health;
-    Some(WorkerState::reduce(&events, &config).status)
+    let state = event_log.reduce(&config).ok()?;
+    state.last_event_at.map(|_| state.status)
 }
 
 /// Get the failure reason from a worker's event log.
@@ -551,6 +586,30 @@ pub fn kill_window(repo: &RepoContext, name: &str) -> Result<()> {
 
 /// Unregister a worker from state (removes entirely) and clean up event log.
 pub fn unregister(repo: &RepoContext, name: &str) -> Result<()> {
+    remove_from_state(repo, name)?;
+
+    // Clean up event log, keeping its stats
+    let repo_name = repo_name(repo);
+    if let Ok(event_log) = EventLog::for_worker(&repo_name, name) {
+        crate::stats::retire_worker_log(&repo_name, name, &event_log);
+        let _ = event_log.remove();
+    }
+
+    Ok(())
+}
+
+/// Unregister a merged worker from state but keep its history: the event log
+/// is closed with a terminal `merged` event and rotated into an archive.
+pub fn archive(repo: &RepoContext, name: &str) -> Result<()> {
+    remove_from_state(repo, name)?;
+
+    let event_log = EventLog::for_worker(&repo_name(repo), name)?;
+    event_log.append(&Event::new(EventType::Terminal).with_field("terminal", "merged"))?;
+    event_log.compact()?;
+    Ok(())
+}
+
+fn remove_from_state(repo: &RepoContext, name: &str) -> Result<()> {
     if let Some(mut state) = OrchestratorState::load(&repo.repo_root)? {
         let id = state.get_worker_by_name(name).map(|w| w.id);
         if let Some(id) = id {
@@ -558,19 +617,14 @@ pub fn unregister(repo: &RepoContext, name: &str) -> Result<()> {
             state.save()?;
         }
     }
+    Ok(())
+}
 
-    // Clean up event log
-    let repo_name = repo
-        .repo_root
+fn repo_name(repo: &RepoContext) -> String {
+    repo.repo_root
         .file_name()
         .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_else(|| "unknown".to_string());
-
-    if let Ok(event_log) = EventLog::for_worker(&repo_name, name) {
-        let _ = event_log.remove();
-    }
-
-    Ok(())
+        .unwrap_or_else(|| "unknown".to_string())
 }
 
 /// Remove stale workers (whose tmux windows no longer exist) from state.
@@ -603,12 +657,11 @@ fn cleanup_stale_workers(
             // Don't clean up workers that are initializing or failed —
             // they intentionally have no tmux window
             if let Ok(event_log) = EventLog::for_worker(&repo_name, &w.name) {
-                if let Ok(events) = event_log.read_all() {
-                    if !events.is_empty() {
-                        let ws = WorkerState::reduce(&events, &health_config);
-                        if matches!(ws.status, WorkerStatus::Initializing | WorkerStatus::Failed) {
-                            return false;
-                        }
+                if let Ok(ws) = event_log.reduce(&health_config) {
+                    if ws.last_event_at.is_some()
+                        && matches!(ws.status, WorkerStatus::Initializing | WorkerStatus::Failed)
+                    {
+                        return false;
                     }
                 }
             }
@@ -643,4 +696,62 @@ mod tests {
         assert_eq!(SpawnKind::Wrapup, SpawnKind::Wrapup);
         assert_ne!(SpawnKind::Normal, SpawnKind::Wrapup);
     }
+
+    #[test]
+    fn unknown_agent_leaves_no_worktree() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(root)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&[
+            "-c",
+            "user.name=T",
+            "-c",
+            "user.email=t@t",
+            "commit",
+            "-q",
+            "--allow-empty",
+            "-m",
+            "init",
+        ]);
+
+        let issue = Issue {
+            id: "1".to_string(),
+            title: "Issue 1".to_string(),
+            status: IssueStatus::Planned,
+            priority: None,
+            category: None,
+            depends_on: vec![],
+            body: String::new(),
+            source: String::new(),
+            children: vec![],
+            labels: vec![],
+            branch_name: None,
+            parent: None,
+            agent: Some("nope".to_string()),
+            model: None,
+            stacked_on: None,
+        };
+        let input = SpawnIssueInput {
+            repo_root: root,
+            issue: &issue,
+            worker_name: "issue-1",
+            provider_kind: ProviderKind::File,
+            kind: SpawnKind::Normal,
+            agent: Some("nope"),
+            model: None,
+        };
+
+        let err = spawn_worker_for_issue(&input).unwrap_err();
+        assert!(err.contains("nope"), "{}", err);
+        assert!(!config::worktree_path(root, "issue-1").exists());
+    }
 }
@@ -0,0 +1,205 @@
+//! Stacked workers — a worker building on another in-flight worker's branch.
+//!
+//! `jig spawn --on <worker>` (or a `Stacked-On` issue field) forks the new
+//! worktree from the lower worker's branch instead of the repo base, so a
+//! follow-up task can start before the first PR lands. The daemon restacks
+//! the upper branch whenever the lower one moves, and once the lower branch
+//! lands it moves the stack onto the base and retargets its PR.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::config;
+use crate::git::Repo;
+use crate::state::OrchestratorState;
+
+/// What the daemon should do with a stacked worker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Plan {
+    /// The lower branch hasn't moved since the last restack.
+    Current,
+    /// The lower branch moved to `head`: rebase the commits after the stack
+    /// base onto it.
+    Restack { head: String },
+    /// The lower worker merged: rebase the commits after the stack base onto
+    /// the repo base and retarget the PR.
+    Land,
+    /// The lower worker went away, or was archived, without a confirmed
+    /// merge. Left for a human.
+    Orphaned,
+}
+
+/// The in-flight worker `reference` names: a worker name, or the issue a
+/// worker was spawned for.
+pub fn lower_worker(repo_root: &Path, reference: &str) -> Option<String> {
+    if config::worktree_path(repo_root, reference).exists() {
+        return Some(reference.to_string());
+    }
+    let state = OrchestratorState::load(repo_root).ok().flatten()?;
+    let mut workers: Vec<&str> = state
+        .workers
+        .values()
+        .filter(|w| w.task.as_ref().and_then(|t| t.issue_ref.as_deref()) == Some(reference))
+        .map(|w| w.name.as_str())
+        .filter(|name| config::worktree_path(repo_root, name).exists())
+        .collect();
+    workers.sort();
+    workers.first().map(|name| name.to_string())
+}
+
+/// The branch checked out in `worker`'s worktree.
+pub fn branch_of(repo_root: &Path, worker: &str) -> Option<String> {
+    Repo::worktree_branch(&config::worktree_path(repo_root, worker)).ok()
+}
+
+/// The commit of `lower` that `branch` forked from.
+pub fn fork_point(repo_root: &Path, branch: &str, lower: &str) -> Option<String> {
+    git(repo_root, &["merge-base", branch, lower])
+}
+
+/// Decide how to bring a worker stacked on `lower` up to date. `stack_base`
+/// is the lower commit it sits on; `lower_status` is the lower worker's
+/// status in the daemon's worker state, if it has one.
+///
+/// Only a `merged` status lands the stack outright. If the lower worktree is
+/// gone or the worker archived, `pr_merged` is asked whether its PR merged:
+/// `None` when that can't be told right now, which leaves the stack as is.
+pub fn plan(
+    repo_root: &Path,
+    lower: &str,
+    stack_base: &str,
+    lower_status: Option<&str>,
+    pr_merged: impl FnOnce() -> Option<bool>,
+) -> Plan {
+    if lower_status == Some("merged") {
+        return Plan::Land;
+    }
+    let lower_path = config::worktree_path(repo_root, lower);
+    if !lower_path.exists() || lower_status == Some("archived") {
+        return match pr_merged() {
+            Some(true) => Plan::Land,
+            Some(false) => Plan::Orphaned,
+            None => Plan::Current,
+        };
+    }
+    match git(&lower_path, &["rev-parse", "HEAD"]) {
+        Some(head) if head == stack_base => Plan::Current,
+        Some(head) => Plan::Restack { head },
+        None => Plan::Current,
+    }
+}
+
+/// Run git, returning trimmed stdout on success.
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run(dir: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    fn commit_file(dir: &Path, name: &str) {
+        std::fs::write(dir.join(name), name).unwrap();
+        run(dir, &["add", name]);
+        run(dir, &["commit", "-q", "-m", name]);
+    }
+
+    /// A repo with a `lower` worktree one commit ahead of main and an
+    /// `upper` worktree forked from it.
+    fn setup() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        run(root, &["init", "-q", "-b", "main"]);
+        run(root, &["config", "user.email", "test@test.com"]);
+        run(root, &["config", "user.name", "Test"]);
+        run(root, &["config", "commit.gpgsign", "false"]);
+        commit_file(root, "init.txt");
+        run(
+            root,
+            &["worktree", "add", "-q", ".jig/lower", "-b", "lower"],
+        );
+        commit_file(&root.join(".jig/lower"), "lower.txt");
+        run(
+            root,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                ".jig/upper",
+                "-b",
+                "upper",
+                "lower",
+            ],
+        );
+        commit_file(&root.join(".jig/upper"), "upper.txt");
+        tmp
+    }
+
+    #[test]
+    fn lower_worker_by_name() {
+        let tmp = setup();
+        assert_eq!(lower_worker(tmp.path(), "lower").as_deref(), Some("lower"));
+        assert_eq!(lower_worker(tmp.path(), "missing"), None);
+        assert_eq!(branch_of(tmp.path(), "lower").as_deref(), Some("lower"));
+    }
+
+    #[test]
+    fn plan_follows_the_lower_branch() {
+        let tmp = setup();
+        let root = tmp.path();
+        let base = fork_point(root, "upper", "lower").unwrap();
+        let unasked = || -> Option<bool> { panic!("PR state should not be needed") };
+        assert_eq!(
+            plan(root, "lower", &base, Some("running"), unasked),
+            Plan::Current
+        );
+
+        commit_file(&root.join(".jig/lower"), "more.txt");
+        let Plan::Restack { head } = plan(root, "lower", &base, None, unasked) else {
+            panic!("expected restack");
+        };
+        assert_ne!(head, base);
+
+        assert_eq!(
+            plan(root, "lower", &base, Some("merged"), unasked),
+            Plan::Land
+        );
+    }
+
+    #[test]
+    fn plan_lands_only_a_confirmed_merge() {
+        let tmp = setup();
+        let root = tmp.path();
+        let base = fork_point(root, "upper", "lower").unwrap();
+
+        assert_eq!(plan(root, "gone", &base, None, || Some(true)), Plan::Land);
+        assert_eq!(
+            plan(root, "gone", &base, None, || Some(false)),
+            Plan::Orphaned
+        );
+        assert_eq!(plan(root, "gone", &base, None, || None), Plan::Current);
+        assert_eq!(
+            plan(root, "lower", &base, Some("archived"), || Some(false)),
+            Plan::Orphaned
+        );
+    }
+}
@@ -0,0 +1,599 @@
+//! Historical analytics over worker event logs, reviews and notifications.
+//!
+//! Each worker's log becomes a [`WorkerRecord`]. [`Report::build`] folds the
+//! records, the reviews still on disk and the escalations in the notification
+//! queue into [`Stats`] overall, per repo and per ISO week. Logs deleted by
+//! cleanup, prune or respawn are first folded into a [`StatsLedger`] so their
+//! workers keep counting.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::events::{Event, EventLog, EventType, WorkerState};
+use crate::global::{global_state_dir, HealthConfig};
+use crate::review::{Review, ReviewStatus, ReviewVerdict};
+use crate::worker::WorkerStatus;
+
+/// What one worker's event log says about how its work went.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerRecord {
+    pub repo: String,
+    pub worker: String,
+    pub issue: Option<String>,
+    pub status: WorkerStatus,
+    pub spawned_at: Option<i64>,
+    pub pr_opened_at: Option<i64>,
+    pub merged_at: Option<i64>,
+    /// Nudges received, by `NudgeType::count_key`.
+    pub nudges: BTreeMap<String, u32>,
+}
+
+impl WorkerRecord {
+    /// Build a record from the events of the log named `log_name`
+    /// (`<repo>-<worker>`). The repo comes from the spawn event, or else the
+    /// longest of `repos` that prefixes the log name.
+    pub fn from_events(log_name: &str, events: &[Event], repos: &[String]) -> Self {
+        let state = WorkerState::reduce(events, &HealthConfig::default());
+
+        let spawn_repo = events
+            .iter()
+            .filter(|e| e.event_type == EventType::Spawn)
+            .find_map(|e| e.data.get("repo").and_then(|v| v.as_str()));
+        let repo = spawn_repo
+            .map(str::to_string)
+            .or_else(|| {
+                repos
+                    .iter()
+                    .filter(|r| log_name.starts_with(&format!("{}-", r)))
+                    .max_by_key(|r| r.len())
+                    .cloned()
+            })
+            .unwrap_or_else(|| "unknown".to_string());
+        let worker = log_name
+            .strip_prefix(&format!("{}-", repo))
+            .unwrap_or(log_name)
+            .to_string();
+
+        let pr_opened_at = events
+            .iter()
+            .find(|e| e.event_type == EventType::PrOpened)
+            .map(|e| e.ts);
+        let merged_at = events
+            .iter()
+            .find(|e| {
+                e.event_type == EventType::Terminal
+                    && e.data.get("terminal").and_then(|v| v.as_str()) == Some("merged")
+            })
+            .map(|e| e.ts);
+        let mut nudges = BTreeMap::new();
+        for event in events.iter().filter(|e| e.event_type == EventType::Nudge) {
+            if let Some(nudge_type) = event.data.get("nudge_type").and_then(|v| v.as_str()) {
+                *nudges.entry(nudge_type.to_string()).or_default() += 1;
+            }
+        }
+
+        Self {
+            repo,
+            worker,
+            issue: state.issue_ref.clone(),
+            status: state.status,
+            spawned_at: state.started_at,
+            pr_opened_at,
+            merged_at,
+            nudges,
+        }
+    }
+
+    /// Seconds from spawn to the PR being opened.
+    pub fn time_to_pr(&self) -> Option<i64> {
+        Some(self.pr_opened_at? - self.spawned_at?).filter(|secs| *secs >= 0)
+    }
+
+    /// Seconds from the PR being opened to its merge.
+    pub fn time_to_merge(&self) -> Option<i64> {
+        Some(self.merged_at? - self.pr_opened_at?).filter(|secs| *secs >= 0)
+    }
+}
+
+/// Records of workers whose event logs have been deleted
+/// (`~/.config/jig/state/stats.jsonl`).
+pub struct StatsLedger {
+    path: PathBuf,
+}
+
+impl StatsLedger {
+    /// Ledger at the global state dir.
+    pub fn global() -> Result<Self> {
+        Ok(Self::new(global_state_dir()?.join("stats.jsonl")))
+    }
+
+    /// Ledger at a specific path (useful for testing).
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn append(&self, record: &WorkerRecord) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+
+    /// Every record, oldest first. Returns nothing if the ledger is missing.
+    pub fn records(&self) -> Result<Vec<WorkerRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let mut records = Vec::new();
+        for line in BufReader::new(fs::File::open(&self.path)?).lines() {
+            let line = line?;
+            if !line.is_empty() {
+                records.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(records)
+    }
+
+    /// Fold `log`'s history into a record before the log is deleted. Empty
+    /// logs are skipped.
+    pub fn retire(&self, repo: &str, worker: &str, log: &EventLog) -> Result<()> {
+        let (events, _) = log.read_history()?;
+        if events.is_empty() {
+            return Ok(());
+        }
+        let log_name = format!("{}-{}", repo, worker.replace('/', "-"));
+        let mut record = WorkerRecord::from_events(&log_name, &events, &[repo.to_string()]);
+        record.worker = worker.to_string();
+        self.append(&record)
+    }
+}
+
+/// Keep the stats of `worker`'s event log before it is removed or reset.
+/// Failures are logged, never fatal: losing a record must not block cleanup.
+pub fn retire_worker_log(repo: &str, worker: &str, log: &EventLog) {
+    if let Err(e) = StatsLedger::global().and_then(|ledger| ledger.retire(repo, worker, log)) {
+        tracing::warn!(repo, worker, "failed to record worker stats: {}", e);
+    }
+}
+
+/// A review read from a worker's `.jig/reviews/`.
+#[derive(Debug, Clone)]
+pub struct ReviewRecord {
+    pub repo: String,
+    pub review: Review,
+}
+
+/// Median and mean of a set of durations.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Timing {
+    pub count: usize,
+    pub median_secs: u64,
+    pub mean_secs: u64,
+}
+
+impl Timing {
+    fn of(mut secs: Vec<i64>) -> Option<Self> {
+        if secs.is_empty() {
+            return None;
+        }
+        secs.sort_unstable();
+        let count = secs.len();
+        let median = if count.is_multiple_of(2) {
+            (secs[count / 2 - 1] + secs[count / 2]) / 2
+        } else {
+            secs[count / 2]
+        };
+        Some(Self {
+            count,
+            median_secs: median as u64,
+            mean_secs: (secs.iter().sum::<i64>() / count as i64) as u64,
+        })
+    }
+}
+
+/// Outcome figures for a set of workers and reviews.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Stats {
+    pub workers: usize,
+    /// Distinct issues the workers were spawned for.
+    pub issues: usize,
+    pub prs: usize,
+    pub merged: usize,
+    pub failed: usize,
+    pub spawn_to_pr: Option<Timing>,
+    pub pr_to_merge: Option<Timing>,
+    /// Nudges sent, by type.
+    pub nudges: BTreeMap<String, u32>,
+    /// `needs_intervention` notifications.
+    pub escalations: usize,
+    pub review_rounds: usize,
+    pub changes_requested: usize,
+    /// Warn and fail findings, by review category.
+    pub findings: BTreeMap<String, usize>,
+    /// Nudges the failed workers received before failing, by type.
+    pub nudges_before_failure: BTreeMap<String, u32>,
+}
+
+impl Stats {
+    /// Fold workers, reviews and escalation count into figures.
+    pub fn compute(
+        workers: &[&WorkerRecord],
+        reviews: &[&ReviewRecord],
+        escalations: usize,
+    ) -> Self {
+        let mut stats = Self {
+            workers: workers.len(),
+            escalations,
+            review_rounds: reviews.len(),
+            ..Default::default()
+        };
+
+        let mut issues = BTreeSet::new();
+        for record in workers {
+            if let Some(issue) = &record.issue {
+                issues.insert(issue.as_str());
+            }
+            if record.pr_opened_at.is_some() {
+                stats.prs += 1;
+            }
+            if record.status == WorkerStatus::Merged {
+                stats.merged += 1;
+            }
+            for (nudge_type, count) in &record.nudges {
+                *stats.nudges.entry(nudge_type.clone()).or_default() += count;
+            }
+            if record.status == WorkerStatus::Failed {
+                stats.failed += 1;
+                for (nudge_type, count) in &record.nudges {
+                    *stats
+                        .nudges_before_failure
+                        .entry(nudge_type.clone())
+                        .or_default() += count;
+                }
+            }
+        }
+        stats.issues = issues.len();
+        stats.spawn_to_pr = Timing::of(workers.iter().filter_map(|r| r.time_to_pr()).collect());
+        stats.pr_to_merge = Timing::of(workers.iter().filter_map(|r| r.time_to_merge()).collect());
+
+        for record in reviews {
+            if record.review.verdict == ReviewVerdict::ChangesRequested {
+                stats.changes_requested += 1;
+            }
+            for section in &record.review.sections {
+                let flagged = section
+                    .findings
+                    .iter()
+                    .filter(|f| f.severity != ReviewStatus::Pass)
+                    .count();
+                if flagged > 0 {
+                    *stats
+                        .findings
+                        .entry(section.category.as_str().to_string())
+                        .or_default() += flagged;
+                }
+            }
+        }
+        stats
+    }
+
+    /// Average nudges per worker.
+    pub fn nudges_per_worker(&self) -> f64 {
+        let total: u32 = self.nudges.values().sum();
+        if self.workers == 0 {
+            0.0
+        } else {
+            total as f64 / self.workers as f64
+        }
+    }
+
+    /// Fraction of review rounds that ended in changes requested.
+    pub fn changes_requested_rate(&self) -> Option<f64> {
+        (self.review_rounds > 0).then(|| self.changes_requested as f64 / self.review_rounds as f64)
+    }
+}
+
+/// Stats overall and broken down by repo and by week.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Report {
+    pub total: Stats,
+    pub by_repo: BTreeMap<String, Stats>,
+    /// Keyed by ISO week (`2026-W07`). Workers count in the week they were
+    /// spawned, reviews and escalations in the week they happened.
+    pub by_week: BTreeMap<String, Stats>,
+}
+
+impl Report {
+    /// `escalations` are the (repo, timestamp) of `needs_intervention`
+    /// notifications.
+    pub fn build(
+        workers: &[WorkerRecord],
+        reviews: &[ReviewRecord],
+        escalations: &[(String, i64)],
+    ) -> Self {
+        let all_workers: Vec<_> = workers.iter().collect();
+        let all_reviews: Vec<_> = reviews.iter().collect();
+        let total = Stats::compute(&all_workers, &all_reviews, escalations.len());
+
+        let repos: BTreeSet<&str> = workers
+            .iter()
+            .map(|w| w.repo.as_str())
+            .chain(reviews.iter().map(|r| r.repo.as_str()))
+            .chain(escalations.iter().map(|(repo, _)| repo.as_str()))
+            .collect();
+        let by_repo = repos
+            .into_iter()
+            .map(|repo| {
+                let stats = Stats::compute(
+                    &workers
+                        .iter()
+                        .filter(|w| w.repo == repo)
+                        .collect::<Vec<_>>(),
+                    &reviews
+                        .iter()
+                        .filter(|r| r.repo == repo)
+                        .collect::<Vec<_>>(),
+                    escalations.iter().filter(|(r, _)| r == repo).count(),
+                );
+                (repo.to_string(), stats)
+            })
+            .collect();
+
+        let worker_week = |w: &WorkerRecord| w.spawned_at.map(week_of);
+        let review_week = |r: &ReviewRecord| week_of(r.review.timestamp);
+        let weeks: BTreeSet<String> = workers
+            .iter()
+            .filter_map(worker_week)
+            .chain(reviews.iter().map(review_week))
+            .chain(escalations.iter().map(|(_, ts)| week_of(*ts)))
+            .collect();
+        let by_week = weeks
+            .into_iter()
+            .map(|week| {
+                let stats = Stats::compute(
+                    &workers
+                        .iter()
+                        .filter(|w| worker_week(w).as_ref() == Some(&week))
+                        .collect::<Vec<_>>(),
+                    &reviews
+                        .iter()
+                        .filter(|r| review_week(r) == week)
+                        .collect::<Vec<_>>(),
+                    escalations
+                        .iter()
+                        .filter(|(_, ts)| week_of(*ts) == week)
+                        .count(),
+                );
+                (week, stats)
+            })
+            .collect();
+
+        Self {
+            total,
+            by_repo,
+            by_week,
+        }
+    }
+}
+
+/// ISO week of a timestamp in local time, e.g. `2026-W07`.
+pub fn week_of(ts: i64) -> String {
+    let week = chrono::DateTime::from_timestamp(ts, 0)
+        .unwrap_or_default()
+        .with_timezone(&chrono::Local)
+        .iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::{Finding, ReviewCategory, ReviewSection};
+
+    fn at(event: Event, ts: i64) -> Event {
+        Event { ts, ..event }
+    }
+
+    fn record(repo: &str, status: WorkerStatus, spawned: i64, pr: Option<i64>) -> WorkerRecord {
+        WorkerRecord {
+            repo: repo.to_string(),
+            worker: "w".to_string(),
+            issue: None,
+            status,
+            spawned_at: Some(spawned),
+            pr_opened_at: pr,
+            merged_at: None,
+            nudges: BTreeMap::new(),
+        }
+    }
+
+    fn review(repo: &str, verdict: ReviewVerdict, ts: i64) -> ReviewRecord {
+        let finding = |severity| Finding {
+            file: None,
+            line: None,
+            message: "m".to_string(),
+            severity,
+        };
+        ReviewRecord {
+            repo: repo.to_string(),
+            review: Review {
+                reviewed_sha: "abc".to_string(),
+                timestamp: ts,
+                verdict,
+                sections: vec![
+                    ReviewSection {
+                        category: ReviewCategory::Correctness,
+                        status: ReviewStatus::Fail,
+                        findings: vec![finding(ReviewStatus::Fail), finding(ReviewStatus::Warn)],
+                    },
+                    ReviewSection {
+                        category: ReviewCategory::Security,
+                        status: ReviewStatus::Pass,
+                        findings: vec![finding(ReviewStatus::Pass)],
+                    },
+                ],
+                summary: String::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn ledger_keeps_retired_logs() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let log = EventLog::new(tmp.path().join("events.jsonl"));
+        let ledger = StatsLedger::new(tmp.path().join("stats.jsonl"));
+
+        ledger.retire("api", "feature/a", &log).unwrap();
+        assert!(ledger.records().unwrap().is_empty());
+
+        log.append(&Event::new(EventType::Spawn).with_field("repo", "api"))
+            .unwrap();
+        log.append(&Event::new(EventType::Terminal).with_field("terminal", "merged"))
+            .unwrap();
+        ledger.retire("api", "feature/a", &log).unwrap();
+        log.remove().unwrap();
+
+        let records = ledger.records().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].repo, "api");
+        assert_eq!(records[0].worker, "feature/a");
+        assert_eq!(records[0].status, WorkerStatus::Merged);
+    }
+
+    #[test]
+    fn record_from_worker_events() {
+        let events = vec![
+            at(
+                Event::new(EventType::Spawn)
+                    .with_field("repo", "api")
+                    .with_field("issue", "JIG-7"),
+                1_000,
+            ),
+            at(
+                Event::new(EventType::Nudge).with_field("nudge_type", "idle"),
+                1_500,
+            ),
+            at(
+                Event::new(EventType::Nudge).with_field("nudge_type", "idle"),
+                1_800,
+            ),
+            at(
+                Event::new(EventType::PrOpened).with_field("pr_url", "https://x/1"),
+                4_600,
+            ),
+            at(
+                Event::new(EventType::Nudge).with_field("nudge_type", "ci"),
+                5_000,
+            ),
+            at(
+                Event::new(EventType::Terminal).with_field("terminal", "merged"),
+                12_000,
+            ),
+        ];
+        let record = WorkerRecord::from_events("api-fix-login", &events, &[]);
+        assert_eq!(record.repo, "api");
+        assert_eq!(record.worker, "fix-login");
+        assert_eq!(record.issue.as_deref(), Some("JIG-7"));
+        assert_eq!(record.status, WorkerStatus::Merged);
+        assert_eq!(record.time_to_pr(), Some(3_600));
+        assert_eq!(record.time_to_merge(), Some(7_400));
+        assert_eq!(record.nudges["idle"], 2);
+        assert_eq!(record.nudges["ci"], 1);
+    }
+
+    #[test]
+    fn repo_falls_back_to_longest_known_prefix() {
+        let events = vec![at(Event::new(EventType::Spawn), 1_000)];
+        let repos = vec!["api".to_string(), "api-v2".to_string()];
+        let record = WorkerRecord::from_events("api-v2-fix", &events, &repos);
+        assert_eq!(
+            (record.repo.as_str(), record.worker.as_str()),
+            ("api-v2", "fix")
+        );
+
+        let record = WorkerRecord::from_events("web-fix", &events, &repos);
+        assert_eq!(
+            (record.repo.as_str(), record.worker.as_str()),
+            ("unknown", "web-fix")
+        );
+    }
+
+    #[test]
+    fn stats_fold_outcomes_reviews_and_failures() {
+        let mut failed = record("api", WorkerStatus::Failed, 0, None);
+        failed.nudges.insert("stuck".to_string(), 3);
+        failed.issue = Some("JIG-1".to_string());
+        let mut retry = record("api", WorkerStatus::Merged, 0, Some(600));
+        retry.issue = Some("JIG-1".to_string());
+        retry.merged_at = Some(4_200);
+        retry.nudges.insert("ci".to_string(), 1);
+        let other = record("api", WorkerStatus::Running, 0, Some(1_800));
+
+        let reviews = [
+            review("api", ReviewVerdict::ChangesRequested, 0),
+            review("api", ReviewVerdict::Approve, 0),
+        ];
+        let stats = Stats::compute(
+            &[&failed, &retry, &other],
+            &reviews.iter().collect::<Vec<_>>(),
+            2,
+        );
+
+        assert_eq!(stats.workers, 3);
+        assert_eq!(stats.issues, 1);
+        assert_eq!((stats.prs, stats.merged, stats.failed), (2, 1, 1));
+        assert_eq!(
+            stats.spawn_to_pr,
+            Some(Timing {
+                count: 2,
+                median_secs: 1_200,
+                mean_secs: 1_200
+            })
+        );
+        assert_eq!(stats.pr_to_merge.as_ref().unwrap().median_secs, 3_600);
+        assert_eq!(stats.nudges_per_worker(), 4.0 / 3.0);
+        assert_eq!(
+            stats.nudges_before_failure,
+            BTreeMap::from([("stuck".to_string(), 3)])
+        );
+        assert_eq!(stats.escalations, 2);
+        assert_eq!(stats.changes_requested_rate(), Some(0.5));
+        assert_eq!(
+            stats.findings,
+            BTreeMap::from([("correctness".to_string(), 4)])
+        );
+    }
+
+    #[test]
+    fn report_breaks_down_by_repo_and_week() {
+        let week = 7 * 86_400;
+        let workers = vec![
+            record("api", WorkerStatus::Merged, 10 * week, None),
+            record("api", WorkerStatus::Failed, 11 * week, None),
+            record("web", WorkerStatus::Running, 11 * week, None),
+        ];
+        let reviews = vec![review("web", ReviewVerdict::Approve, 11 * week)];
+        let escalations = vec![("api".to_string(), 11 * week)];
+
+        let report = Report::build(&workers, &reviews, &escalations);
+        assert_eq!(report.total.workers, 3);
+        assert_eq!(report.by_repo["api"].workers, 2);
+        assert_eq!(report.by_repo["api"].escalations, 1);
+        assert_eq!(report.by_repo["web"].review_rounds, 1);
+
+        assert_eq!(report.by_week.len(), 2);
+        let later = &report.by_week[&week_of(11 * week)];
+        assert_eq!((later.workers, later.failed, later.escalations), (2, 1, 1));
+    }
+}
@@ -12,6 +12,7 @@ pub const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
     ("nudge-review", NUDGE_REVIEW),
     ("nudge-bad-commits", NUDGE_BAD_COMMITS),
     ("nudge-auto-review", NUDGE_AUTO_REVIEW),
+    ("nudge-budget", NUDGE_BUDGET),
 ];
 
 const SPAWN_PREAMBLE: &str = r#"AUTONOMOUS MODE: You have been spawned by jig as a parallel worker in auto mode (--dangerously-skip-permissions). Work independently without human interaction.
@@ -205,6 +206,14 @@ STEPS:
 "#;
 
 const NUDGE_CONFLICT: &str = r#"Your PR has merge conflicts with {{base_branch}} (nudge {{nudge_count}}/{{max_nudges}}).
+{{#if conflicts}}
+
+An automatic rebase stopped on these conflicts:
+{{#each conflicts}}
+
+{{{this}}}
+{{/each}}
+{{/if}}
 
 Resolve them:
 
@@ -237,6 +246,14 @@ WARNING: This is round {{review_round}} of {{max_rounds}}. If not approved after
 {{/if}}
 "#;
 
+const NUDGE_BUDGET: &str = r#"BUDGET WARNING: You are close to this worker's token, cost or time budget. Once it is exceeded you will be stopped.
+
+Wrap up now:
+1. Commit what you have (conventional format) and push
+2. Create or update the draft PR with `jig pr`
+3. Note anything left unfinished in the PR description
+"#;
+
 const NUDGE_BAD_COMMITS: &str = r#"Your PR has commits that don't follow conventional commit format (nudge {{nudge_count}}/{{max_nudges}}).
 
 Bad commits:
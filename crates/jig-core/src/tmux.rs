@@ -321,6 +321,53 @@ impl TmuxClient {
     pub fn pane_is_running(&self, target: &TmuxTarget) -> bool {
         is_running_command(self.pane_command(target).as_deref())
     }
+
+    /// The last `lines` lines of a pane's output, without trailing blank
+    /// lines. Wrapped lines are joined and escape sequences dropped.
+    pub fn capture_pane(&self, target: &TmuxTarget, lines: usize) -> Result<Vec<String>> {
+        let target_str = target.target_str();
+        // Start far enough back in the history that trailing blank lines
+        // below the cursor don't crowd out the output.
+        let start = format!("-{}", lines);
+        let output = run_tmux(
+            &["capture-pane", "-p", "-J", "-t", &target_str, "-S", &start],
+            TMUX_TIMEOUT,
+        )?;
+        if !output.status.success() {
+            return Err(Error::Custom(format!(
+                "tmux capture-pane failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(tail_lines(&String::from_utf8_lossy(&output.stdout), lines))
+    }
+
+    /// The last non-blank line of a pane, trimmed, for one-line previews.
+    pub fn pane_preview(&self, target: &TmuxTarget) -> Option<String> {
+        let lines = self.capture_pane(target, PREVIEW_LINES).ok()?;
+        lines
+            .iter()
+            .rev()
+            .map(|l| l.trim())
+            .find(|l| !l.is_empty())
+            .map(String::from)
+    }
+}
+
+/// Lines captured to find a pane's last non-blank line.
+const PREVIEW_LINES: usize = 20;
+
+/// The last `n` lines of `text`, ignoring trailing blank lines.
+fn tail_lines(text: &str, n: usize) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().map(|l| l.trim_end()).collect();
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(0, |i| i + 1);
+    lines[end.saturating_sub(n)..end]
+        .iter()
+        .map(|l| l.to_string())
+        .collect()
 }
 
 /// Collapse a multiline message into a single line for tmux input.
@@ -349,6 +396,23 @@ mod tests {
         assert_eq!(target.target_str(), "jig-repo:feat/auth");
     }
 
+    #[test]
+    fn tail_lines_skips_trailing_blanks() {
+        let text = "one\ntwo  \nthree\n\n   \n\n";
+        assert_eq!(tail_lines(text, 2), vec!["two", "three"]);
+        assert_eq!(tail_lines(text, 10), vec!["one", "two", "three"]);
+        assert!(tail_lines("\n\n", 5).is_empty());
+        assert!(tail_lines(text, 0).is_empty());
+    }
+
+    #[test]
+    fn capture_pane_nonexistent() {
+        let client = TmuxClient::new();
+        let target = TmuxTarget::new("jig-nonexistent-test-session-xyz", "window");
+        assert!(client.capture_pane(&target, 10).is_err());
+        assert!(client.pane_preview(&target).is_none());
+    }
+
     #[test]
     fn has_session_nonexistent() {
         let client = TmuxClient::new();
@@ -5,7 +5,7 @@
 
 use std::path::{Path, PathBuf};
 
-use crate::adapter;
+use crate::adapter::{self, AgentAdapter, AgentRegistry};
 use crate::config::{self, copy_worktree_files, run_on_create_hook, JigToml, RepoConfig};
 use crate::context::RepoContext;
 use crate::error::{Error, Result};
@@ -36,6 +36,14 @@ pub struct Worktree {
     pub parent_issue: Option<String>,
     /// Parent issue's branch name (the base branch this child was forked from).
     pub parent_branch: Option<String>,
+    /// Agent adapter chosen for this worker (None = repo `[agent] type`).
+    pub agent: Option<String>,
+    /// Model override for this worker's agent (None = agent default).
+    pub model: Option<String>,
+    /// Labels of the issue this worker was spawned for.
+    pub issue_labels: Vec<String>,
+    /// Worker whose branch this one was forked from (`jig spawn --on`).
+    pub stacked_on: Option<String>,
 }
 
 impl Worktree {
@@ -95,6 +103,10 @@ impl Worktree {
             auto_spawned: auto,
             parent_issue: None,
             parent_branch: None,
+            agent: None,
+            model: None,
+            issue_labels: vec![],
+            stacked_on: None,
         })
     }
 
@@ -117,6 +129,10 @@ impl Worktree {
                     auto_spawned: false,
                     parent_issue: None,
                     parent_branch: None,
+                    agent: None,
+                    model: None,
+                    issue_labels: vec![],
+                    stacked_on: None,
                 })
             })
             .collect()
@@ -142,6 +158,10 @@ impl Worktree {
             auto_spawned: false,
             parent_issue: None,
             parent_branch: None,
+            agent: None,
+            model: None,
+            issue_labels: vec![],
+            stacked_on: None,
         })
     }
 
@@ -200,16 +220,19 @@ impl Worktree {
         let effective_context = engine.render("spawn-preamble-wrapup", &tpl_ctx)?;
 
         let config = JigToml::load(&self.repo_root)?.unwrap_or_default();
-        let agent_adapter =
-            adapter::get_adapter(&config.agent.agent_type).unwrap_or(&adapter::CLAUDE_CODE);
+        let agent_adapter = self.agent_adapter(&config, &global_config)?;
 
         session::create_window(&self.session_name, &self.name, &self.path)?;
         let cmd = adapter::build_spawn_command(
-            agent_adapter,
+            &agent_adapter,
             Some(&effective_context),
+            self.agent_model().as_deref(),
             &config.agent.disallowed_tools,
         );
         session::send_keys(&self.session_name, &self.name, &cmd)?;
+        if agent_adapter.send_prompt {
+            session::send_prompt(&self.session_name, &self.name, &effective_context)?;
+        }
 
         Ok(())
     }
@@ -229,23 +252,26 @@ impl Worktree {
         );
         let effective_context = engine.render("spawn-preamble", &tpl_ctx)?;
 
-        // Get adapter from config
+        // Get adapter for this worker
         let config = JigToml::load(&self.repo_root)?.unwrap_or_default();
-        let agent_adapter =
-            adapter::get_adapter(&config.agent.agent_type).unwrap_or(&adapter::CLAUDE_CODE);
+        let agent_adapter = self.agent_adapter(&config, &global_config)?;
 
         // Create window in tmux
         session::create_window(&self.session_name, &self.name, &self.path)?;
 
         // Build spawn command using adapter (always auto)
         let cmd = adapter::build_spawn_command(
-            agent_adapter,
+            &agent_adapter,
             Some(&effective_context),
+            self.agent_model().as_deref(),
             &config.agent.disallowed_tools,
         );
 
         // Send command to window
         session::send_keys(&self.session_name, &self.name, &cmd)?;
+        if agent_adapter.send_prompt {
+            session::send_prompt(&self.session_name, &self.name, &effective_context)?;
+        }
 
         Ok(())
     }
@@ -267,10 +293,9 @@ impl Worktree {
             let _ = event_log.append(&event);
         }
 
-        // Get adapter from config
+        // Get adapter for this worker
         let config = JigToml::load(&self.repo_root)?.unwrap_or_default();
-        let agent_adapter =
-            adapter::get_adapter(&config.agent.agent_type).unwrap_or(&adapter::CLAUDE_CODE);
+        let agent_adapter = self.agent_adapter(&config, &GlobalConfig::load()?)?;
 
         if !agent_adapter.supports_continue() {
             // Adapter doesn't support session continuation — fall back to re-spawn
@@ -279,12 +304,51 @@ impl Worktree {
 
         // Continue the prior session via the adapter's continue flag
         session::create_window(&self.session_name, &self.name, &self.path)?;
-        let cmd = adapter::build_resume_command(agent_adapter);
+        let cmd = adapter::build_resume_command(&agent_adapter, self.agent_model().as_deref());
         session::send_keys(&self.session_name, &self.name, &cmd)?;
 
         Ok(())
     }
 
+    /// Resolve the agent adapter for this worker.
+    ///
+    /// Prefers the agent chosen at spawn time (set on the struct, or recorded
+    /// in the worker's event log), then the repo's `[agent] type`, then Claude.
+    fn agent_adapter(&self, config: &JigToml, global: &GlobalConfig) -> Result<AgentAdapter> {
+        let registry = AgentRegistry::from_config(config, global);
+        match self.agent.clone().or_else(|| self.recorded_field("agent")) {
+            Some(name) => registry.resolve(&name),
+            None => Ok(registry
+                .get(&config.agent.agent_type)
+                .unwrap_or(adapter::CLAUDE_CODE)),
+        }
+    }
+
+    /// Model for this worker's agent: set on the struct, or recorded at registration.
+    fn agent_model(&self) -> Option<String> {
+        self.model.clone().or_else(|| self.recorded_field("model"))
+    }
+
+    /// Name of the agent this worker runs, resolved against current config.
+    fn resolved_agent_name(&self) -> Option<String> {
+        let config = JigToml::load(&self.repo_root).ok()?.unwrap_or_default();
+        let global = GlobalConfig::load().ok()?;
+        let adapter = self.agent_adapter(&config, &global).ok()?;
+        Some(adapter.name.into_owned())
+    }
+
+    /// First value of `key` recorded in this worker's event log, if any.
+    fn recorded_field(&self, key: &str) -> Option<String> {
+        let event_log = EventLog::for_worker(&self.repo_name(), &self.name).ok()?;
+        let events = event_log.read_all().ok()?;
+        events.iter().find_map(|e| {
+            e.data
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        })
+    }
+
     // ---------------------------------------------------------------
     // Registration (absorb from spawn.rs)
     // ---------------------------------------------------------------
@@ -345,7 +409,8 @@ impl Worktree {
 
         // Set task context if provided
         if let Some(ctx) = context {
-            let mut task = TaskContext::new(ctx.to_string());
+            let mut task = TaskContext::new(ctx.to_string())
+                .with_files(crate::overlap::mentioned_files(&self.repo_root, ctx));
             if let Some(issue) = issue_ref {
                 task = task.with_issue(issue.to_string());
             }
@@ -358,10 +423,14 @@ impl Worktree {
         state.add_worker(worker);
         state.save()?;
 
-        // Emit initial event (Spawn or Initializing)
+        // Emit initial event (Spawn or Initializing). Resolve the agent before
+        // the log is reset so a re-registered worker keeps its recorded choice.
         let repo_name = self.repo_name();
+        let agent = self.resolved_agent_name();
+        let model = self.agent_model();
 
         if let Ok(event_log) = EventLog::for_worker(&repo_name, &self.name) {
+            crate::stats::retire_worker_log(&repo_name, &self.name, &event_log);
             let _ = event_log.reset();
             let mut event = Event::new(initial_event_type)
                 .with_field("branch", self.branch.as_str())
@@ -375,12 +444,29 @@ impl Worktree {
             if let Some(title) = issue_title {
                 event = event.with_field("issue_title", title);
             }
+            if let Some(ref agent) = agent {
+                event = event.with_field("agent", agent.as_str());
+            }
+            if let Some(ref model) = model {
+                event = event.with_field("model", model.as_str());
+            }
+            if !self.issue_labels.is_empty() {
+                event = event.with_field("issue_labels", self.issue_labels.clone());
+            }
             if let Some(ref pi) = self.parent_issue {
                 event = event.with_field("parent_issue", pi.as_str());
             }
             if let Some(ref pb) = self.parent_branch {
                 event = event.with_field("parent_branch", pb.as_str());
             }
+            if let Some(ref lower) = self.stacked_on {
+                event = event.with_field("stacked_on", lower.as_str());
+                if let Some(base) = crate::stack::branch_of(&self.repo_root, lower)
+                    .and_then(|lb| crate::stack::fork_point(&self.repo_root, &self.branch, &lb))
+                {
+                    event = event.with_field("stack_base", base);
+                }
+            }
             let _ = event_log.append(&event);
         }
 